@@ -30,6 +30,9 @@ pub struct LintCommand {
     #[bpaf(external)]
     pub warning_options: WarningOptions,
 
+    #[bpaf(external)]
+    pub cache_options: CacheOptions,
+
     #[bpaf(external)]
     pub output_options: OutputOptions,
 
@@ -62,6 +65,8 @@ pub struct BasicOptions {
     /// Oxlint configuration file (experimental)
     ///  * only `.json` extension is supported
     ///  * tries to be compatible with the ESLint v8's format
+    ///  * when not provided, each file is linted with the nearest `.oxlintrc.json`
+    ///  * `.oxlintrc.json` files in parent directories are merged into it, up to `"root": true`
     #[bpaf(long, short, argument("./oxlintrc.json"))]
     pub config: Option<PathBuf>,
 
@@ -175,6 +180,19 @@ pub struct WarningOptions {
     pub max_warnings: Option<usize>,
 }
 
+/// Caching
+#[derive(Debug, Clone, Bpaf)]
+pub struct CacheOptions {
+    /// Only lint files which changed since the previous run with `--cache`.
+    /// Results are reused if the file, the configuration and the version of oxlint are unchanged
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
+
+    /// Path to the cache file. Defaults to `.oxlintcache`
+    #[bpaf(argument("PATH"), fallback(".oxlintcache".into()), hide_usage)]
+    pub cache_location: PathBuf,
+}
+
 /// Output
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
@@ -439,6 +457,32 @@ mod warning_options {
     }
 }
 
+#[cfg(test)]
+mod cache_options {
+    use std::path::PathBuf;
+
+    use super::{lint_command, CacheOptions};
+
+    fn get_cache_options(arg: &str) -> CacheOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command().run_inner(args.as_slice()).unwrap().cache_options
+    }
+
+    #[test]
+    fn default() {
+        let options = get_cache_options(".");
+        assert!(!options.cache);
+        assert_eq!(options.cache_location, PathBuf::from(".oxlintcache"));
+    }
+
+    #[test]
+    fn cache_location() {
+        let options = get_cache_options("--cache --cache-location tmp/cache .");
+        assert!(options.cache);
+        assert_eq!(options.cache_location, PathBuf::from("tmp/cache"));
+    }
+}
+
 #[cfg(test)]
 mod lint_options {
     use std::{fs::File, path::PathBuf};
@@ -7,10 +7,10 @@ use bpaf::Bpaf;
 
 pub use self::{
     ignore::IgnoreOptions,
-    lint::{lint_command, LintCommand, OutputFormat, OutputOptions, WarningOptions},
+    lint::{lint_command, CacheOptions, LintCommand, OutputFormat, OutputOptions, WarningOptions},
 };
 
-const VERSION: &str = match option_env!("OXC_VERSION") {
+pub(crate) const VERSION: &str = match option_env!("OXC_VERSION") {
     Some(v) => v,
     None => "dev",
 };
@@ -1,10 +1,10 @@
-use std::{env, io::BufWriter, time::Instant};
+use std::{env, io::BufWriter, sync::Arc, time::Instant};
 
 use ignore::gitignore::Gitignore;
-use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler};
+use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler, OxcDiagnostic};
 use oxc_linter::{
-    loader::LINT_PARTIAL_LOADER_EXT, AllowWarnDeny, InvalidFilterKind, LintFilter, LintService,
-    LintServiceOptions, Linter, LinterBuilder, Oxlintrc,
+    loader::LINT_PARTIAL_LOADER_EXT, AllowWarnDeny, InvalidFilterKind, LintCache, LintFilter,
+    LintService, LintServiceOptions, Linter, LinterBuilder, Oxlintrc,
 };
 use oxc_span::VALID_EXTENSIONS;
 
@@ -13,6 +13,7 @@ use crate::{
         CliRunResult, LintCommand, LintResult, MiscOptions, OutputFormat, OutputOptions, Runner,
         WarningOptions,
     },
+    command::VERSION,
     walk::{Extensions, Walk},
 };
 
@@ -43,6 +44,7 @@ impl Runner for LintRunner {
             fix_options,
             enable_plugins,
             output_options,
+            cache_options,
             misc_options,
             ..
         } = self.options;
@@ -92,44 +94,36 @@ impl Runner for LintRunner {
             .copied()
             .collect::<Vec<&'static str>>();
 
-        let paths =
-            Walk::new(&paths, &ignore_options).with_extensions(Extensions(extensions)).paths();
-
-        let number_of_files = paths.len();
+        let walk = Walk::new(&paths, &ignore_options).with_extensions(Extensions(extensions));
 
         let cwd = std::env::current_dir().unwrap();
 
-        let mut oxlintrc = if let Some(config_path) = basic_options.config.as_ref() {
-            match Oxlintrc::from_file(config_path) {
-                Ok(config) => config,
-                Err(diagnostic) => {
-                    let handler = GraphicalReportHandler::new();
-                    let mut err = String::new();
-                    handler.render_report(&mut err, &diagnostic).unwrap();
-                    return CliRunResult::InvalidOptions {
-                        message: format!("Failed to parse configuration file.\n{err}"),
-                    };
-                }
-            }
+        // With `-c`, all files are linted with that configuration. Otherwise, each file is linted
+        // with the nearest configuration file in its directory or its ancestors.
+        let groups = if let Some(config_path) = basic_options.config.as_ref() {
+            let oxlintrc = match Oxlintrc::from_file(config_path) {
+                Ok(oxlintrc) => oxlintrc,
+                Err(diagnostic) => return Self::invalid_config(&diagnostic),
+            };
+            let walk = if ignore_options.no_ignore {
+                walk
+            } else {
+                walk.with_ignore_patterns(oxlintrc.ignore_patterns.clone())
+            };
+            vec![(oxlintrc, walk.paths())]
         } else {
-            Oxlintrc::default()
+            match walk.paths_by_config(&cwd, ignore_options.no_ignore) {
+                Ok(groups) => groups,
+                Err(diagnostic) => return Self::invalid_config(&diagnostic),
+            }
         };
 
-        enable_plugins.apply_overrides(&mut oxlintrc.plugins);
-        let builder = LinterBuilder::from_oxlintrc(false, oxlintrc)
-            .with_filters(filter)
-            .with_fix(fix_options.fix_kind());
-
-        let mut options =
-            LintServiceOptions::new(cwd, paths).with_cross_module(builder.plugins().has_import());
-        let linter = builder.build();
+        let number_of_files = groups.iter().map(|(_, paths)| paths.len()).sum();
 
         let tsconfig = basic_options.tsconfig;
         if let Some(path) = tsconfig.as_ref() {
-            if path.is_file() {
-                options = options.with_tsconfig(path);
-            } else {
-                let path = if path.is_relative() { options.cwd().join(path) } else { path.clone() };
+            if !path.is_file() {
+                let path = if path.is_relative() { cwd.join(path) } else { path.clone() };
                 return CliRunResult::InvalidOptions {
                     message: format!(
                         "The tsconfig file {path:?} does not exist, Please provide a valid tsconfig file.",
@@ -138,23 +132,66 @@ impl Runner for LintRunner {
             }
         }
 
-        let lint_service = LintService::new(linter, options);
+        // Shared by all linters. Configurations are part of the cache keys.
+        let cache = cache_options
+            .cache
+            .then(|| Arc::new(LintCache::load(cwd.join(&cache_options.cache_location), VERSION)));
+
+        // One linter for each distinct configuration
+        let lint_services = groups
+            .into_iter()
+            .map(|(mut oxlintrc, paths)| {
+                enable_plugins.apply_overrides(&mut oxlintrc.plugins);
+                let builder = LinterBuilder::from_oxlintrc(false, oxlintrc)
+                    .with_filters(filter.iter().cloned())
+                    .with_fix(fix_options.fix_kind());
+
+                let mut options = LintServiceOptions::new(cwd.clone(), paths)
+                    .with_cross_module(builder.plugins_with_overrides().has_import());
+                if let Some(path) = tsconfig.as_ref() {
+                    options = options.with_tsconfig(path);
+                }
+                if let Some(cache) = cache.as_ref() {
+                    options = options.with_cache(Arc::clone(cache));
+                }
+                LintService::new(builder.build(), options)
+            })
+            .collect::<Vec<_>>();
+
         let mut diagnostic_service =
             Self::get_diagnostic_service(&warning_options, &output_options, &misc_options);
 
         // Spawn linting in another thread so diagnostics can be printed immediately from diagnostic_service.run.
         rayon::spawn({
             let tx_error = diagnostic_service.sender().clone();
-            let lint_service = lint_service.clone();
+            let lint_services = lint_services.clone();
+            let cache_location = cache_options.cache_location;
             move || {
-                lint_service.run(&tx_error);
+                for lint_service in &lint_services {
+                    lint_service.run_without_finishing(&tx_error);
+                }
+                if let Some(Err(err)) = cache.map(|cache| cache.save()) {
+                    let diagnostic =
+                        OxcDiagnostic::warn(format!("Failed to write cache file: {err}"));
+                    let diagnostics =
+                        DiagnosticService::wrap_diagnostics(cache_location, "", vec![diagnostic]);
+                    tx_error.send(Some(diagnostics)).unwrap();
+                }
+                tx_error.send(None).unwrap();
             }
         });
         diagnostic_service.run();
 
+        // Files may be linted with different configurations, report the largest number of rules.
+        let number_of_rules = lint_services
+            .iter()
+            .map(|lint_service| lint_service.linter().number_of_rules())
+            .max()
+            .unwrap_or_default();
+
         CliRunResult::LintResult(LintResult {
             duration: now.elapsed(),
-            number_of_rules: lint_service.linter().number_of_rules(),
+            number_of_rules,
             number_of_files,
             number_of_warnings: diagnostic_service.warnings_count(),
             number_of_errors: diagnostic_service.errors_count(),
@@ -166,6 +203,15 @@ impl Runner for LintRunner {
 }
 
 impl LintRunner {
+    fn invalid_config(diagnostic: &OxcDiagnostic) -> CliRunResult {
+        let handler = GraphicalReportHandler::new();
+        let mut err = String::new();
+        handler.render_report(&mut err, diagnostic).unwrap();
+        CliRunResult::InvalidOptions {
+            message: format!("Failed to parse configuration file.\n{err}"),
+        }
+    }
+
     fn get_diagnostic_service(
         warning_options: &WarningOptions,
         output_options: &OutputOptions,
@@ -524,6 +570,24 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn lint_markdown_file() {
+        let args = &["fixtures/markdown/debugger.md"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn lint_mdx_file() {
+        let args = &["fixtures/markdown/debugger.mdx"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
     #[test]
     fn test_tsconfig_option() {
         // passed
@@ -558,10 +622,216 @@ mod test {
 
     #[test]
     fn test_import_plugin_enabled_in_config() {
-        let args = &["-c", "fixtures/import/.oxlintrc.json", "fixtures/import/test.js"];
+        let args = &["-c", "fixtures/import/oxlintrc.json", "fixtures/import/test.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn test_overrides() {
+        let args = &["-c", "fixtures/overrides/oxlintrc.json", "fixtures/overrides"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        // `test.js`: `no-console` error and `no-debugger` warning
+        // `__tests__/test.js`: `no-debugger` error
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 2);
+    }
+
+    #[test]
+    fn test_import_plugin_enabled_in_overrides() {
+        let args = &["-c", "fixtures/overrides_import/oxlintrc.json", "fixtures/overrides_import"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        // `src/a.js` and `src/b.js` import each other, the cycle is reported once
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn test_nested_config() {
+        let args = &["fixtures/nested_config"];
         let result = test(args);
+        assert_eq!(result.number_of_files, 3);
+        // `debugger.js`: `no-debugger` is turned off
+        // `packages/a/test.js`: `no-console` warning, `no-debugger` is turned off by the parent
+        // `packages/b/test.js`: `no-console` and `no-debugger` warnings, the parent is not used
+        assert_eq!(result.number_of_warnings, 3);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn test_ignore_patterns() {
+        let result = test(&["fixtures/ignore_patterns"]);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+
+        let result =
+            test(&["-c", "fixtures/ignore_patterns/.oxlintrc.json", "fixtures/ignore_patterns"]);
         assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+
+        let result = test(&[
+            "-c",
+            "fixtures/ignore_patterns/.oxlintrc.json",
+            "fixtures/ignore_patterns/test.ignored.js",
+        ]);
+        assert_eq!(result.number_of_files, 0);
+
+        let result = test(&["--no-ignore", "fixtures/ignore_patterns"]);
+        assert_eq!(result.number_of_files, 3);
+        assert_eq!(result.number_of_warnings, 3);
+    }
+
+    #[test]
+    fn test_ignore_patterns_skip_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        let write = |name: &str, content: &str| std::fs::write(dir.path().join(name), content);
+        std::fs::create_dir(dir.path().join("vendor")).unwrap();
+        write(".oxlintrc.json", r#"{ "ignorePatterns": ["vendor/"] }"#).unwrap();
+        write("test.js", "debugger;\n").unwrap();
+        write("vendor/.oxlintrc.json", "{").unwrap();
+        write("vendor/test.js", "debugger;\n").unwrap();
+
+        // `vendor` is not walked, so its invalid configuration file is never loaded
+        let result = test(&[dir.path().to_str().unwrap()]);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+    }
+
+    #[test]
+    fn test_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let write = |name: &str, content: &str| std::fs::write(dir.path().join(name), content);
+        write(
+            "oxlintrc.json",
+            r#"{ "plugins": ["import"], "rules": { "import/named": "error" } }"#,
+        )
+        .unwrap();
+        write("a.js", "import { b } from './b.js';\nexport const a = b;\ndebugger;\n").unwrap();
+        write("b.js", "export const b = 1;\n").unwrap();
+
+        let config = dir.path().join("oxlintrc.json");
+        let cache_location = dir.path().join(".oxlintcache");
+        let files = [dir.path().join("a.js"), dir.path().join("b.js")];
+        let args = [
+            "--cache",
+            "--cache-location",
+            cache_location.to_str().unwrap(),
+            "-c",
+            config.to_str().unwrap(),
+            files[0].to_str().unwrap(),
+            files[1].to_str().unwrap(),
+        ];
+
+        let result = test(&args);
+        assert!(cache_location.is_file());
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+
+        // Cached diagnostics are reported again
+        let result = test(&args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+
+        // `a.js` is unchanged, but imports a binding which `b.js` no longer exports
+        write("b.js", "export const c = 1;\n").unwrap();
+        let result = test(&args);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 1);
+
+        let result = test(&args);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 1);
+
+        // A different configuration does not use the cached results
+        let result = test(&[&args[..], &["-A", "no-debugger"]].concat());
         assert_eq!(result.number_of_warnings, 0);
         assert_eq!(result.number_of_errors, 1);
     }
+
+    #[test]
+    fn test_cache_unresolved_imports() {
+        let dir = tempfile::tempdir().unwrap();
+        let write = |name: &str, content: &str| std::fs::write(dir.path().join(name), content);
+        write(
+            "oxlintrc.json",
+            r#"{ "plugins": ["import"], "rules": { "import/no-cycle": "error" } }"#,
+        )
+        .unwrap();
+        write("a.js", "import { b } from './b.js';\nexport const a = () => b;\n").unwrap();
+
+        let config = dir.path().join("oxlintrc.json");
+        let cache_location = dir.path().join(".oxlintcache");
+        let file = dir.path().join("a.js");
+        let args = [
+            "--cache",
+            "--cache-location",
+            cache_location.to_str().unwrap(),
+            "-c",
+            config.to_str().unwrap(),
+            file.to_str().unwrap(),
+        ];
+
+        let result = test(&args);
+        assert_eq!(result.number_of_errors, 0);
+
+        // `a.js` is unchanged, but `./b.js` now resolves to a module which imports it
+        write("b.js", "import { a } from './a.js';\nexport const b = () => a;\n").unwrap();
+        let result = test(&args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn test_cache_tsconfig() {
+        let dir = tempfile::tempdir().unwrap();
+        let write = |name: &str, content: &str| std::fs::write(dir.path().join(name), content);
+        let tsconfig = |target: &str| {
+            format!(r##"{{ "compilerOptions": {{ "paths": {{ "#dep": ["./{target}"] }} }} }}"##)
+        };
+        write(
+            "oxlintrc.json",
+            r#"{ "plugins": ["import"], "rules": { "import/no-cycle": "error" } }"#,
+        )
+        .unwrap();
+        write("tsconfig.json", &tsconfig("b.js")).unwrap();
+        write("a.js", "import { b } from '#dep';\nexport const a = () => b;\n").unwrap();
+        write("b.js", "import { a } from './a.js';\nexport const b = () => a;\n").unwrap();
+        write("c.js", "export const b = 1;\n").unwrap();
+
+        let config = dir.path().join("oxlintrc.json");
+        let cache_location = dir.path().join(".oxlintcache");
+        let tsconfig_path = dir.path().join("tsconfig.json");
+        let file = dir.path().join("a.js");
+        let args = [
+            "--cache",
+            "--cache-location",
+            cache_location.to_str().unwrap(),
+            "-c",
+            config.to_str().unwrap(),
+            "--tsconfig",
+            tsconfig_path.to_str().unwrap(),
+            file.to_str().unwrap(),
+        ];
+
+        let result = test(&args);
+        assert_eq!(result.number_of_errors, 1);
+
+        // Imports of `a.js` resolve differently, its cached results are not used
+        write("tsconfig.json", &tsconfig("c.js")).unwrap();
+        let result = test(&args);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn test_nested_config_with_config_flag() {
+        let args = &["-c", "fixtures/nested_config/.oxlintrc.json", "fixtures/nested_config"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 3);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 0);
+    }
 }
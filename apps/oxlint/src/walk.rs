@@ -1,10 +1,16 @@
 use std::{
+    collections::BTreeMap,
+    hash::BuildHasherDefault,
     path::{Path, PathBuf},
-    sync::mpsc,
+    sync::{mpsc, Arc, OnceLock},
 };
 
+use dashmap::DashMap;
 use ignore::{overrides::OverrideBuilder, DirEntry};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_linter::{IgnorePatterns, Oxlintrc};
 use oxc_span::VALID_EXTENSIONS;
+use rustc_hash::FxHasher;
 
 use crate::cli::IgnoreOptions;
 
@@ -17,10 +23,15 @@ impl Default for Extensions {
     }
 }
 
+/// Paths to lint, along with the configuration to lint them with.
+pub type ConfigGroup = (Oxlintrc, Vec<Box<Path>>);
+
 pub struct Walk {
-    inner: ignore::WalkParallel,
+    inner: ignore::WalkBuilder,
     /// The file extensions to include during the traversal.
     extensions: Extensions,
+    /// `ignorePatterns` of the configuration file.
+    ignore_patterns: IgnorePatterns,
 }
 
 struct WalkBuilder {
@@ -67,6 +78,41 @@ impl ignore::ParallelVisitor for WalkCollector {
     }
 }
 
+/// A configuration file loaded with its parents, once any walker thread needs it.
+type LoadedConfig = Arc<OnceLock<Result<Oxlintrc, OxcDiagnostic>>>;
+
+/// Configuration files found while walking, shared by all walker threads.
+///
+/// Configuration files are found and loaded outside of the maps' locks, so walker threads only
+/// wait for each other when they need the same configuration file which is still loading.
+#[derive(Default)]
+struct ConfigFiles {
+    /// Nearest configuration file of each directory.
+    nearest: DashMap<PathBuf, Option<PathBuf>, BuildHasherDefault<FxHasher>>,
+    /// Configuration files loaded with their parents. `None` is the default configuration.
+    configs: DashMap<Option<PathBuf>, LoadedConfig, BuildHasherDefault<FxHasher>>,
+}
+
+impl ConfigFiles {
+    /// Find the nearest configuration file of `dir` and load it, if not done yet.
+    fn get(&self, dir: PathBuf) -> (Option<PathBuf>, LoadedConfig) {
+        let cached = self.nearest.get(&dir).map(|config_path| config_path.clone());
+        let config_path = cached.unwrap_or_else(|| {
+            let config_path = Oxlintrc::find_nearest(&dir);
+            self.nearest.insert(dir, config_path.clone());
+            config_path
+        });
+        let config = Arc::clone(&self.configs.entry(config_path.clone()).or_default());
+        config.get_or_init(|| {
+            config_path.as_ref().map_or_else(
+                || Ok(Oxlintrc::default()),
+                |config_path| Oxlintrc::from_file_with_parents(config_path),
+            )
+        });
+        (config_path, config)
+    }
+}
+
 impl Walk {
     /// Will not canonicalize paths.
     /// # Panics
@@ -104,19 +150,102 @@ impl Walk {
         // Turning off `follow_links` because:
         // * following symlinks is a really slow syscall
         // * it is super rare to have symlinked source code
-        let inner =
-            inner.ignore(false).git_global(false).follow_links(options.symlinks).build_parallel();
-        Self { inner, extensions: Extensions::default() }
+        inner.ignore(false).git_global(false).follow_links(options.symlinks);
+        Self {
+            inner,
+            extensions: Extensions::default(),
+            ignore_patterns: IgnorePatterns::default(),
+        }
+    }
+
+    pub fn paths(mut self) -> Vec<Box<Path>> {
+        if !self.ignore_patterns.is_empty() {
+            let ignore_patterns = self.ignore_patterns.clone();
+            self.inner.filter_entry(move |entry| {
+                let is_dir = entry.file_type().is_some_and(|ft| ft.is_dir());
+                !ignore_patterns.is_ignored(entry.path(), is_dir)
+            });
+        }
+        let mut paths = self.walk();
+        // Paths passed explicitly are not filtered while walking
+        if !self.ignore_patterns.is_empty() {
+            paths.retain(|path| !self.ignore_patterns.is_ignored(path, false));
+        }
+        paths
     }
 
-    pub fn paths(self) -> Vec<Box<Path>> {
+    /// Group paths by the nearest configuration file ([`Oxlintrc::FILE_NAME`]) in their directory
+    /// or its ancestors, loaded along with its parents. Relative paths are resolved against `cwd`.
+    /// Paths without a configuration file use the default configuration.
+    ///
+    /// Unless `no_ignore`, files and directories matching `ignorePatterns` of their configuration
+    /// are skipped while walking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a configuration file cannot be loaded.
+    pub fn paths_by_config(
+        mut self,
+        cwd: &Path,
+        no_ignore: bool,
+    ) -> Result<Vec<ConfigGroup>, OxcDiagnostic> {
+        let config_files = Arc::new(ConfigFiles::default());
+        if !no_ignore {
+            let config_files = Arc::clone(&config_files);
+            let cwd = cwd.to_path_buf();
+            self.inner.filter_entry(move |entry| {
+                let dir = cwd.join(entry.path().parent().unwrap_or(Path::new("")));
+                let is_dir = entry.file_type().is_some_and(|ft| ft.is_dir());
+                let (_, config) = config_files.get(dir);
+                // Configuration errors are reported after walking
+                !config.get().and_then(|oxlintrc| oxlintrc.as_ref().ok()).is_some_and(|oxlintrc| {
+                    oxlintrc.ignore_patterns.is_ignored(entry.path(), is_dir)
+                })
+            });
+        }
+        let paths = self.walk();
+        drop(self);
+
+        let config_files = Arc::into_inner(config_files).unwrap();
+        let mut groups = BTreeMap::<Option<PathBuf>, Vec<Box<Path>>>::new();
+        for path in paths {
+            let dir = cwd.join(path.parent().unwrap_or(Path::new("")));
+            let (config_path, config) = config_files.get(dir);
+            // Paths passed explicitly are not filtered while walking
+            if !no_ignore
+                && config
+                    .get()
+                    .and_then(|oxlintrc| oxlintrc.as_ref().ok())
+                    .is_some_and(|oxlintrc| oxlintrc.ignore_patterns.is_ignored(&path, false))
+            {
+                continue;
+            }
+            groups.entry(config_path).or_default().push(path);
+        }
+        groups
+            .into_iter()
+            .map(|(config_path, paths)| {
+                let (_, config) = config_files.configs.remove(&config_path).unwrap();
+                let oxlintrc = Arc::into_inner(config).and_then(OnceLock::into_inner).unwrap()?;
+                Ok((oxlintrc, paths))
+            })
+            .collect()
+    }
+
+    fn walk(&self) -> Vec<Box<Path>> {
         let (sender, receiver) = mpsc::channel::<Vec<Box<Path>>>();
-        let mut builder = WalkBuilder { sender, extensions: self.extensions };
-        self.inner.visit(&mut builder);
+        let mut builder = WalkBuilder { sender, extensions: self.extensions.clone() };
+        self.inner.build_parallel().visit(&mut builder);
         drop(builder);
         receiver.into_iter().flatten().collect()
     }
 
+    /// Skip files and directories matching `ignorePatterns` of a configuration file.
+    pub fn with_ignore_patterns(mut self, ignore_patterns: IgnorePatterns) -> Self {
+        self.ignore_patterns = ignore_patterns;
+        self
+    }
+
     pub fn with_extensions(mut self, extensions: Extensions) -> Self {
         self.extensions = extensions;
         self
@@ -17,12 +17,32 @@ pub struct ParserOptions {
     ///
     /// Default: true
     pub preserve_parens: Option<bool>,
+    /// Emit an [ESTree](https://github.com/estree/estree) conformant AST,
+    /// or a [TS-ESTree](https://typescript-eslint.io/packages/typescript-estree/ast-spec)
+    /// conformant AST for TypeScript files.
+    ///
+    /// Parenthesized expressions are never preserved in this AST,
+    /// and `start` / `end` are UTF-16 offsets.
+    ///
+    /// Default: false
+    pub estree: Option<bool>,
+    /// Return scope analysis in the data model of
+    /// [eslint-scope](https://github.com/eslint/js/tree/main/packages/eslint-scope)'s `ScopeManager`
+    /// as `ParseResult.scopeManager`.
+    ///
+    /// Scopes, variables and references refer to each other by their index,
+    /// and `start` / `end` are the same offsets as in `program`.
+    ///
+    /// Default: false
+    pub scope_manager: Option<bool>,
 }
 
 #[napi(object)]
 pub struct ParseResult {
     pub program: String,
     pub comments: Vec<Comment>,
+    /// JSON of the scope analysis, if `scopeManager` is enabled
+    pub scope_manager: Option<String>,
     pub errors: Vec<String>,
 }
 
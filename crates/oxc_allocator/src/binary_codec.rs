@@ -0,0 +1,541 @@
+use std::{cell::Cell, fmt};
+
+use crate::{Allocator, Box, Vec};
+
+/// A trait to serialize a value to a compact binary format, and deserialize it into an [`Allocator`].
+///
+/// Integers are encoded as LEB128 variable-length integers. Strings and sequences are prefixed
+/// with their length. Strings which are slices of the source text are encoded as their position
+/// in it, and decoded as slices of it, without copying (see [`Encoder::set_source_text`]).
+/// Positions are encoded relative to the previous one, as they are usually close together.
+///
+/// The format is not self-describing, so data must be decoded as the same type it was encoded
+/// from, by the same version of the code.
+///
+/// Implementations for AST types are generated by `oxc_ast_tools`.
+pub trait BinaryCodec<'a>: Sized {
+    /// Write `self` to `encoder`.
+    fn encode(&self, encoder: &mut Encoder);
+
+    /// Read a value from `decoder`.
+    ///
+    /// # Errors
+    /// Returns [`DecodeError`] if data is truncated or invalid.
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError>;
+}
+
+/// Error decoding binary data with [`BinaryCodec::decode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// Data ended before value was complete.
+    UnexpectedEnd,
+    /// Data contains a value which is not valid for the type being decoded.
+    InvalidValue,
+    /// Data was not encoded in this format, or was encoded by an incompatible version.
+    Incompatible,
+    /// Data continues after value was complete.
+    TrailingData,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => f.write_str("Unexpected end of binary data"),
+            Self::InvalidValue => f.write_str("Invalid value in binary data"),
+            Self::Incompatible => f.write_str("Binary data has incompatible format or version"),
+            Self::TrailingData => f.write_str("Unexpected trailing binary data"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Buffer which values are encoded into.
+#[derive(Debug, Default)]
+pub struct Encoder {
+    bytes: std::vec::Vec<u8>,
+    /// Address range of the source text, see [`Encoder::set_source_text`].
+    source_text: std::ops::Range<usize>,
+    /// Last position written with [`Encoder::write_position`].
+    position: u32,
+}
+
+impl Encoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get encoded bytes.
+    pub fn into_bytes(self) -> std::vec::Vec<u8> {
+        self.bytes
+    }
+
+    #[inline]
+    pub fn write_u8(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    #[inline]
+    pub fn write_u32(&mut self, value: u32) {
+        self.write_u64(u64::from(value));
+    }
+
+    #[inline]
+    pub fn write_u64(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            #[allow(clippy::cast_possible_truncation)]
+            self.bytes.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        self.bytes.push(value as u8);
+    }
+
+    /// Write length of a string or sequence. Read it with [`Decoder::read_len`].
+    #[inline]
+    pub fn write_len(&mut self, len: usize) {
+        self.write_u64(len as u64);
+    }
+
+    /// Write bytes, prefixed with their length.
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.write_len(bytes.len());
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    /// Write bytes as is, without a length prefix.
+    pub fn write_raw(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    /// Encode strings written with [`Encoder::write_str`] which are slices of `source_text` as
+    /// their position in it.
+    ///
+    /// The same source text must be passed to [`Decoder::set_source_text`] at the same point
+    /// when decoding.
+    pub fn set_source_text(&mut self, source_text: &str) {
+        let start = source_text.as_ptr() as usize;
+        self.source_text = start..start + source_text.len();
+    }
+
+    /// Write a position in the source text, e.g. the start of a span, relative to the previous one.
+    /// Read it with [`Decoder::read_position`].
+    #[inline]
+    pub fn write_position(&mut self, position: u32) {
+        let delta = position.wrapping_sub(self.position);
+        self.position = position;
+        // Zigzag encoding, so small negative deltas are small too
+        self.write_u32((delta << 1) ^ 0u32.wrapping_sub(delta >> 31));
+    }
+
+    /// Write a string, as its position if it is a slice of the source text.
+    /// Read it with [`Decoder::read_str_in`].
+    #[inline]
+    pub fn write_str(&mut self, s: &str) {
+        let start = s.as_ptr() as usize;
+        if self.source_text.start <= start && start + s.len() <= self.source_text.end {
+            self.write_len((s.len() << 1) | 1);
+            #[allow(clippy::cast_possible_truncation)]
+            self.write_position((start - self.source_text.start) as u32);
+        } else {
+            self.write_len(s.len() << 1);
+            self.write_raw(s.as_bytes());
+        }
+    }
+}
+
+/// Binary data which values are decoded from, allocating them in an [`Allocator`].
+pub struct Decoder<'a, 'd> {
+    allocator: &'a Allocator,
+    bytes: &'d [u8],
+    pos: usize,
+    /// See [`Decoder::set_source_text`].
+    source_text: &'a str,
+    /// Last position read with [`Decoder::read_position`].
+    position: u32,
+}
+
+impl<'a, 'd> Decoder<'a, 'd> {
+    pub fn new(allocator: &'a Allocator, bytes: &'d [u8]) -> Self {
+        Self { allocator, bytes, pos: 0, source_text: "", position: 0 }
+    }
+
+    /// Decode strings which were encoded as slices of the source text as slices of `source_text`.
+    /// See [`Encoder::set_source_text`].
+    pub fn set_source_text(&mut self, source_text: &'a str) {
+        self.source_text = source_text;
+    }
+
+    #[inline]
+    pub fn allocator(&self) -> &'a Allocator {
+        self.allocator
+    }
+
+    /// Returns `true` if all data has been read.
+    pub fn is_finished(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+
+    /// Read a `u8`.
+    ///
+    /// # Errors
+    /// Returns [`DecodeError::UnexpectedEnd`] if there is no data left.
+    #[inline]
+    pub fn read_u8(&mut self) -> Result<u8, DecodeError> {
+        let byte = *self.bytes.get(self.pos).ok_or(DecodeError::UnexpectedEnd)?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    /// Read a `u32`.
+    ///
+    /// # Errors
+    /// Returns [`DecodeError`] if data is truncated, or value is out of range.
+    #[inline]
+    pub fn read_u32(&mut self) -> Result<u32, DecodeError> {
+        // Fast path for the most common case of a single byte
+        if let Some(&byte) = self.bytes.get(self.pos) {
+            if byte < 0x80 {
+                self.pos += 1;
+                return Ok(u32::from(byte));
+            }
+        }
+        u32::try_from(self.read_u64()?).map_err(|_| DecodeError::InvalidValue)
+    }
+
+    /// Read a `u64`.
+    ///
+    /// # Errors
+    /// Returns [`DecodeError`] if data is truncated, or value is out of range.
+    #[inline]
+    pub fn read_u64(&mut self) -> Result<u64, DecodeError> {
+        // Fast path for the most common case of a single byte
+        if let Some(&byte) = self.bytes.get(self.pos) {
+            if byte < 0x80 {
+                self.pos += 1;
+                return Ok(u64::from(byte));
+            }
+        }
+        let mut value = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = self.read_u8()?;
+            if shift == 63 && byte > 1 {
+                return Err(DecodeError::InvalidValue);
+            }
+            value |= u64::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+            shift += 7;
+            if shift > 63 {
+                return Err(DecodeError::InvalidValue);
+            }
+        }
+    }
+
+    /// Read a length, checking it is not longer than the remaining data.
+    ///
+    /// # Errors
+    /// Returns [`DecodeError`] if data is truncated.
+    #[inline]
+    pub fn read_len(&mut self) -> Result<usize, DecodeError> {
+        let len = usize::try_from(self.read_u64()?).map_err(|_| DecodeError::InvalidValue)?;
+        // Every element is at least 1 byte, so this guards against huge allocations from corrupt data
+        if len > self.bytes.len() - self.pos {
+            return Err(DecodeError::UnexpectedEnd);
+        }
+        Ok(len)
+    }
+
+    /// Read bytes written with [`Encoder::write_bytes`].
+    ///
+    /// # Errors
+    /// Returns [`DecodeError`] if data is truncated.
+    pub fn read_bytes(&mut self) -> Result<&'d [u8], DecodeError> {
+        let len = self.read_len()?;
+        self.read_raw(len)
+    }
+
+    /// Read `len` bytes written with [`Encoder::write_raw`].
+    ///
+    /// # Errors
+    /// Returns [`DecodeError::UnexpectedEnd`] if data is truncated.
+    pub fn read_raw(&mut self, len: usize) -> Result<&'d [u8], DecodeError> {
+        let end = self.pos.checked_add(len).ok_or(DecodeError::UnexpectedEnd)?;
+        let bytes = self.bytes.get(self.pos..end).ok_or(DecodeError::UnexpectedEnd)?;
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    /// Read a string written with [`Encoder::write_bytes`].
+    ///
+    /// # Errors
+    /// Returns [`DecodeError`] if data is truncated, or is not valid UTF-8.
+    pub fn read_str(&mut self) -> Result<&'d str, DecodeError> {
+        std::str::from_utf8(self.read_bytes()?).map_err(|_| DecodeError::InvalidValue)
+    }
+
+    /// Read a position written with [`Encoder::write_position`].
+    ///
+    /// # Errors
+    /// Returns [`DecodeError`] if data is truncated, or value is out of range.
+    #[inline]
+    pub fn read_position(&mut self) -> Result<u32, DecodeError> {
+        let zigzag = self.read_u32()?;
+        let delta = (zigzag >> 1) ^ 0u32.wrapping_sub(zigzag & 1);
+        self.position = self.position.wrapping_add(delta);
+        Ok(self.position)
+    }
+
+    /// Read a string written with [`Encoder::write_str`]. A slice of the source text is returned
+    /// as is, other strings are copied into the allocator.
+    ///
+    /// # Errors
+    /// Returns [`DecodeError`] if data is truncated, or is not a valid string.
+    #[inline]
+    pub fn read_str_in(&mut self) -> Result<&'a str, DecodeError> {
+        let tagged_len =
+            usize::try_from(self.read_u64()?).map_err(|_| DecodeError::InvalidValue)?;
+        let len = tagged_len >> 1;
+        if tagged_len & 1 == 1 {
+            let start = self.read_position()? as usize;
+            let end = start.checked_add(len).ok_or(DecodeError::InvalidValue)?;
+            return self.source_text.get(start..end).ok_or(DecodeError::InvalidValue);
+        }
+        let bytes = self.read_raw(len)?;
+        let s = std::str::from_utf8(bytes).map_err(|_| DecodeError::InvalidValue)?;
+        Ok(self.allocator.alloc_str(s))
+    }
+}
+
+impl<'a, T: BinaryCodec<'a>> BinaryCodec<'a> for Option<T> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            None => encoder.write_u8(0),
+            Some(value) => {
+                encoder.write_u8(1);
+                value.encode(encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u8()? {
+            0 => Ok(None),
+            1 => Ok(Some(T::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a, T: BinaryCodec<'a>> BinaryCodec<'a> for Box<'a, T> {
+    fn encode(&self, encoder: &mut Encoder) {
+        self.as_ref().encode(encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        let value = T::decode(decoder)?;
+        Ok(Box::new_in(value, decoder.allocator()))
+    }
+}
+
+impl<'a, T: BinaryCodec<'a>> BinaryCodec<'a> for Vec<'a, T> {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_len(self.len());
+        for value in self {
+            value.encode(encoder);
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        let len = decoder.read_len()?;
+        let mut vec = Vec::with_capacity_in(len, decoder.allocator());
+        for _ in 0..len {
+            vec.push(T::decode(decoder)?);
+        }
+        Ok(vec)
+    }
+}
+
+impl<'a, T: BinaryCodec<'a>> BinaryCodec<'a> for std::vec::Vec<T> {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_len(self.len());
+        for value in self {
+            value.encode(encoder);
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        let len = decoder.read_len()?;
+        (0..len).map(|_| T::decode(decoder)).collect()
+    }
+}
+
+impl<'a, T: BinaryCodec<'a> + Copy> BinaryCodec<'a> for Cell<T> {
+    fn encode(&self, encoder: &mut Encoder) {
+        self.get().encode(encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Cell::new(T::decode(decoder)?))
+    }
+}
+
+impl<'a> BinaryCodec<'a> for &'a str {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_str(self);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        decoder.read_str_in()
+    }
+}
+
+impl<'a> BinaryCodec<'a> for bool {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_u8(u8::from(*self));
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for u8 {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_u8(*self);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        decoder.read_u8()
+    }
+}
+
+impl<'a> BinaryCodec<'a> for u32 {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_u32(*self);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        decoder.read_u32()
+    }
+}
+
+impl<'a> BinaryCodec<'a> for u64 {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_u64(*self);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        decoder.read_u64()
+    }
+}
+
+impl<'a> BinaryCodec<'a> for f64 {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_raw(&self.to_le_bytes());
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        let bytes = decoder.read_raw(8)?;
+        Ok(f64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{BinaryCodec, DecodeError, Decoder, Encoder};
+    use crate::{Allocator, Box, Vec};
+
+    fn round_trip<'a, T: BinaryCodec<'a>>(allocator: &'a Allocator, value: &T) -> T {
+        let mut encoder = Encoder::new();
+        value.encode(&mut encoder);
+        let bytes = encoder.into_bytes();
+        let mut decoder = Decoder::new(allocator, &bytes);
+        let value = T::decode(&mut decoder).unwrap();
+        assert!(decoder.is_finished());
+        value
+    }
+
+    #[test]
+    fn primitives() {
+        let allocator = Allocator::default();
+        for value in [0, 1, 127, 128, 300, u64::from(u32::MAX), u64::MAX] {
+            assert_eq!(round_trip(&allocator, &value), value);
+        }
+        assert_eq!(round_trip(&allocator, &u32::MAX), u32::MAX);
+        assert_eq!(round_trip(&allocator, &-0.5f64).to_bits(), (-0.5f64).to_bits());
+        assert!(round_trip(&allocator, &f64::NAN).is_nan());
+        assert_eq!(round_trip(&allocator, &Some(true)), Some(true));
+        assert_eq!(round_trip(&allocator, &"héllo"), "héllo");
+    }
+
+    #[test]
+    fn containers() {
+        let allocator = Allocator::default();
+        let mut vec = Vec::new_in(&allocator);
+        vec.push(Box::new_in(1u32, &allocator));
+        vec.push(Box::new_in(2u32, &allocator));
+        let decoded = round_trip(&allocator, &vec);
+        assert_eq!(decoded.iter().map(|it| **it).collect::<std::vec::Vec<_>>(), [1, 2]);
+    }
+
+    #[test]
+    fn source_text() {
+        let allocator = Allocator::default();
+        let source_text = "let foo = bar;";
+        let strs = [&source_text[4..7], "other", &source_text[10..13], &source_text[0..0]];
+
+        let mut encoder = Encoder::new();
+        encoder.set_source_text(source_text);
+        for position in [5, 2, 60, 0] {
+            encoder.write_position(position);
+        }
+        for s in strs {
+            s.encode(&mut encoder);
+        }
+        let bytes = encoder.into_bytes();
+        // Small positions take a byte, and slices of the source text a byte for length and position
+        assert_eq!(bytes.len(), 4 + 2 * 3 + 1 + "other".len());
+
+        let source_text_copy = allocator.alloc_str(source_text);
+        let mut decoder = Decoder::new(&allocator, &bytes);
+        decoder.set_source_text(source_text_copy);
+        for position in [5, 2, 60, 0] {
+            assert_eq!(decoder.read_position(), Ok(position));
+        }
+        for s in strs {
+            let string = <&str>::decode(&mut decoder).unwrap();
+            assert_eq!(string, s);
+            if s != "other" {
+                assert!(source_text_copy.as_bytes().as_ptr_range().contains(&string.as_ptr()));
+            }
+        }
+        assert!(decoder.is_finished());
+
+        // Slice out of range of source text
+        let mut decoder = Decoder::new(&allocator, &bytes[4..]);
+        decoder.set_source_text(&source_text_copy[..5]);
+        assert_eq!(<&str>::decode(&mut decoder), Err(DecodeError::InvalidValue));
+    }
+
+    #[test]
+    fn invalid() {
+        let allocator = Allocator::default();
+        let decode = |bytes: &[u8]| {
+            let mut decoder = Decoder::new(&allocator, bytes);
+            <Option<std::vec::Vec<u32>>>::decode(&mut decoder)
+        };
+        assert_eq!(decode(&[]), Err(DecodeError::UnexpectedEnd));
+        assert_eq!(decode(&[2]), Err(DecodeError::InvalidValue));
+        // Length longer than data
+        assert_eq!(decode(&[1, 100, 1]), Err(DecodeError::UnexpectedEnd));
+        // Value out of range
+        assert_eq!(decode(&[1, 1, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F]), Err(DecodeError::InvalidValue));
+    }
+}
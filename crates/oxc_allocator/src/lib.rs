@@ -47,11 +47,13 @@ use std::{
 pub use bumpalo::collections::String;
 use bumpalo::Bump;
 
+mod binary_codec;
 mod boxed;
 mod clone_in;
 mod convert;
 mod vec;
 
+pub use binary_codec::{BinaryCodec, DecodeError, Decoder, Encoder};
 pub use boxed::{Address, Box};
 pub use clone_in::CloneIn;
 pub use convert::{FromIn, IntoIn};
@@ -1,9 +1,9 @@
-use oxc_allocator::CloneIn;
+use oxc_allocator::{BinaryCodec, CloneIn};
 use oxc_ast_macros::ast;
-use oxc_span::{cmp::ContentEq, hash::ContentHash, Span};
+use oxc_span::{cmp::ContentEq, hash::ContentHash, ForEachSpanMut, Span};
 
 #[ast]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
 pub enum CommentKind {
     #[default]
@@ -12,7 +12,7 @@ pub enum CommentKind {
 }
 
 #[ast]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
 pub enum CommentPosition {
     /// Comments prior to a token until another token or trailing comment.
@@ -34,7 +34,7 @@ pub enum CommentPosition {
 }
 
 #[ast]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
 pub struct Comment {
     /// The span of the comment text (without leading/trailing delimiters).
@@ -10,8 +10,10 @@ use std::{
 };
 
 use oxc_allocator::CloneIn;
+#[cfg(feature = "binary_codec")]
+use oxc_allocator::{BinaryCodec, DecodeError, Decoder, Encoder};
 use oxc_regular_expression::ast::Pattern;
-use oxc_span::{cmp::ContentEq, hash::ContentHash, Atom, Span};
+use oxc_span::{cmp::ContentEq, hash::ContentHash, Atom, ForEachSpanMut, Span};
 use oxc_syntax::number::NumberBase;
 
 use crate::ast::*;
@@ -185,6 +187,10 @@ impl ContentHash for RegExpFlags {
     }
 }
 
+impl ForEachSpanMut for RegExpFlags {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
 impl<'alloc> CloneIn<'alloc> for RegExpFlags {
     type Cloned = Self;
 
@@ -193,6 +199,17 @@ impl<'alloc> CloneIn<'alloc> for RegExpFlags {
     }
 }
 
+#[cfg(feature = "binary_codec")]
+impl<'a> BinaryCodec<'a> for RegExpFlags {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_u8(self.bits());
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Self::from_bits(decoder.read_u8()?).ok_or(DecodeError::InvalidValue)
+    }
+}
+
 impl TryFrom<char> for RegExpFlags {
     type Error = char;
 
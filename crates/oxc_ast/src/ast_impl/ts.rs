@@ -271,8 +271,13 @@ impl ImportOrExportKind {
         matches!(self, Self::Value)
     }
 
+    /// `type` or Flow's `typeof`
     pub fn is_type(&self) -> bool {
-        matches!(self, Self::Type)
+        matches!(self, Self::Type | Self::Typeof)
+    }
+
+    pub fn is_typeof(&self) -> bool {
+        matches!(self, Self::Typeof)
     }
 }
 
@@ -11,7 +11,7 @@ impl<'a> AstKind<'a> {
                     | Self::DebuggerStatement(_) | Self::EmptyStatement(_) | Self::ExpressionStatement(_)
                     | Self::LabeledStatement(_) | Self::ReturnStatement(_) | Self::SwitchStatement(_)
                     | Self::ThrowStatement(_) | Self::TryStatement(_) | Self::WithStatement(_)
-                    | Self::IfStatement(_) | Self::VariableDeclaration(_))
+                    | Self::IfStatement(_) | Self::VariableDeclaration(_) | Self::ErrorStatement(_))
     }
 
     #[rustfmt::skip]
@@ -214,6 +214,7 @@ impl<'a> AstKind<'a> {
             Self::TryStatement(_) => "TryStatement".into(),
             Self::WhileStatement(_) => "WhileStatement".into(),
             Self::WithStatement(_) => "WithStatement".into(),
+            Self::ErrorStatement(_) => "ErrorStatement".into(),
 
             Self::SwitchCase(_) => "SwitchCase".into(),
             Self::CatchClause(_) => "CatchClause".into(),
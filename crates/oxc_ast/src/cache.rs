@@ -0,0 +1,113 @@
+//! Binary cache format for ASTs.
+//!
+//! A [`Program`] (including its source text and comments) can be encoded to bytes, written to
+//! disk, and later decoded back into an [`Allocator`] instead of re-parsing the source text.
+//!
+//! The source text is stored once, and strings in the AST which are slices of it (e.g. identifier
+//! names) are stored as their position in it. Decoding them does not copy them, it just slices
+//! the decoded source text, as the parser does.
+//!
+//! Encoded data starts with a header containing [`FORMAT_VERSION`] and a hash of the AST type
+//! definitions. Data encoded by a different version of the AST fails to decode with
+//! [`DecodeError::Incompatible`], so a stale cache can be detected and discarded.
+//!
+//! ```ignore
+//! let bytes = oxc_ast::cache::encode_program(&program);
+//! std::fs::write(cache_path, &bytes)?;
+//!
+//! let allocator = Allocator::default();
+//! let bytes = std::fs::read(cache_path)?;
+//! let program = match oxc_ast::cache::decode_program(&allocator, &bytes) {
+//!     Ok(program) => program,
+//!     Err(_) => Parser::new(&allocator, source_text, source_type).parse().program,
+//! };
+//! ```
+//!
+//! Other data which relates to the AST (e.g. semantic analysis results) can be stored alongside
+//! it with [`encode_program_with`] and [`decode_program_with`].
+
+use oxc_allocator::{Allocator, BinaryCodec, DecodeError, Decoder, Encoder};
+
+use crate::{ast::Program, generated::binary_codec_schema::AST_SCHEMA_HASH};
+
+/// Version of the binary format, excluding AST type definitions.
+///
+/// Incremented whenever encoding of primitive types or the header changes.
+pub const FORMAT_VERSION: u32 = 2;
+
+const MAGIC: &[u8; 4] = b"OXCA";
+
+/// Encode `program` in binary cache format.
+pub fn encode_program(program: &Program) -> Vec<u8> {
+    encode_program_with(program, "", |_| {})
+}
+
+/// Encode `program` in binary cache format, followed by extra data written by `encode_extension`.
+///
+/// `extension` identifies the format of the extra data, and must be passed to
+/// [`decode_program_with`] when decoding.
+pub fn encode_program_with<F: FnOnce(&mut Encoder)>(
+    program: &Program,
+    extension: &str,
+    encode_extension: F,
+) -> Vec<u8> {
+    let mut encoder = Encoder::new();
+    encoder.write_raw(MAGIC);
+    encoder.write_u32(FORMAT_VERSION);
+    encoder.write_u64(AST_SCHEMA_HASH);
+    encoder.write_bytes(extension.as_bytes());
+    encoder.write_bytes(program.source_text.as_bytes());
+    encoder.set_source_text(program.source_text);
+    program.encode(&mut encoder);
+    encode_extension(&mut encoder);
+    encoder.into_bytes()
+}
+
+/// Decode a [`Program`] from `bytes` encoded by [`encode_program`], allocating it in `allocator`.
+///
+/// # Errors
+/// Returns [`DecodeError::Incompatible`] if `bytes` were encoded by an incompatible version,
+/// or another [`DecodeError`] if `bytes` are truncated or corrupt.
+pub fn decode_program<'a>(
+    allocator: &'a Allocator,
+    bytes: &[u8],
+) -> Result<Program<'a>, DecodeError> {
+    decode_program_with(allocator, bytes, "", |_| Ok(())).map(|(program, ())| program)
+}
+
+/// Decode a [`Program`] and extra data from `bytes` encoded by [`encode_program_with`],
+/// allocating the program in `allocator`.
+///
+/// `decode_extension` must read all of the extra data.
+///
+/// # Errors
+/// Returns [`DecodeError::Incompatible`] if `bytes` were encoded by an incompatible version,
+/// or with a different `extension`. Returns another [`DecodeError`] if `bytes` are truncated or
+/// corrupt, or `decode_extension` returns an error.
+pub fn decode_program_with<'a, T, F>(
+    allocator: &'a Allocator,
+    bytes: &[u8],
+    extension: &str,
+    decode_extension: F,
+) -> Result<(Program<'a>, T), DecodeError>
+where
+    F: FnOnce(&mut Decoder<'a, '_>) -> Result<T, DecodeError>,
+{
+    let mut decoder = Decoder::new(allocator, bytes);
+    let is_compatible = decoder.read_raw(MAGIC.len()).is_ok_and(|magic| magic == MAGIC)
+        && decoder.read_u32().is_ok_and(|version| version == FORMAT_VERSION)
+        && decoder.read_u64().is_ok_and(|hash| hash == AST_SCHEMA_HASH)
+        && decoder.read_bytes().is_ok_and(|ext| ext == extension.as_bytes());
+    if !is_compatible {
+        return Err(DecodeError::Incompatible);
+    }
+    let source_text = allocator.alloc_str(decoder.read_str()?);
+    decoder.set_source_text(source_text);
+
+    let program = Program::decode(&mut decoder)?;
+    let extension = decode_extension(&mut decoder)?;
+    if !decoder.is_finished() {
+        return Err(DecodeError::TrailingData);
+    }
+    Ok((program, extension))
+}
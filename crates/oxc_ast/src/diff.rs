@@ -0,0 +1,342 @@
+//! Structural diff of two ASTs.
+//!
+//! Compares the node trees of two [`Program`]s, ignoring spans, comments and formatting,
+//! and reports which nodes were inserted, removed or modified.
+//!
+//! ```ignore
+//! let changes = diff(&old_program, &new_program);
+//! if changes.is_empty() {
+//!     println!("Only formatting changed");
+//! }
+//! for change in &changes {
+//!     println!("{change}");
+//! }
+//! ```
+
+use std::{fmt, mem::discriminant};
+
+use oxc_span::{cmp::ContentEq, GetSpan, Span};
+
+#[allow(clippy::wildcard_imports)]
+use crate::{ast::*, AstKind, Visit};
+
+/// A change between two ASTs, reported by [`diff`].
+#[derive(Debug, Clone, Copy)]
+pub enum AstChange<'a> {
+    /// Node only exists in new AST.
+    Inserted(AstKind<'a>),
+    /// Node only exists in old AST.
+    Removed(AstKind<'a>),
+    /// Node exists in both ASTs, but differs.
+    ///
+    /// Either node is of a different type, or the node's own fields (e.g. an operator or
+    /// identifier name) differ. Changes to descendants of the node are reported separately.
+    Modified { old: AstKind<'a>, new: AstKind<'a> },
+}
+
+impl<'a> AstChange<'a> {
+    /// Span of the node in old source text, if it exists in old AST.
+    pub fn old_span(&self) -> Option<Span> {
+        match self {
+            Self::Inserted(_) => None,
+            Self::Removed(old) | Self::Modified { old, .. } => Some(old.span()),
+        }
+    }
+
+    /// Span of the node in new source text, if it exists in new AST.
+    pub fn new_span(&self) -> Option<Span> {
+        match self {
+            Self::Removed(_) => None,
+            Self::Inserted(new) | Self::Modified { new, .. } => Some(new.span()),
+        }
+    }
+}
+
+impl<'a> fmt::Display for AstChange<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let describe = |kind: &AstKind<'a>| {
+            let span = kind.span();
+            format!("{} at {}..{}", kind.debug_name(), span.start, span.end)
+        };
+        match self {
+            Self::Inserted(new) => write!(f, "inserted {}", describe(new)),
+            Self::Removed(old) => write!(f, "removed {}", describe(old)),
+            Self::Modified { old, new } => {
+                write!(f, "modified {} -> {}", describe(old), describe(new))
+            }
+        }
+    }
+}
+
+/// Compute structural differences between two programs.
+///
+/// Spans, comments and formatting are ignored, so an empty result means the programs are
+/// equivalent. Changes are in source order of the old program.
+pub fn diff<'a>(old: &'a Program<'a>, new: &'a Program<'a>) -> Vec<AstChange<'a>> {
+    let old_tree = NodeTree::build(old);
+    let new_tree = NodeTree::build(new);
+    let mut differ = Differ { old: &old_tree, new: &new_tree, changes: vec![] };
+    differ.diff_nodes(0, 0);
+    differ.changes
+}
+
+struct Node<'a> {
+    kind: AstKind<'a>,
+    children: Vec<usize>,
+}
+
+/// Tree of the nodes of a program which have an [`AstKind`]. Root node is the `Program`.
+struct NodeTree<'a> {
+    nodes: Vec<Node<'a>>,
+    stack: Vec<usize>,
+}
+
+impl<'a> NodeTree<'a> {
+    fn build(program: &'a Program<'a>) -> Self {
+        let mut tree = Self { nodes: vec![], stack: vec![] };
+        tree.visit_program(program);
+        tree
+    }
+}
+
+impl<'a> Visit<'a> for NodeTree<'a> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let index = self.nodes.len();
+        if let Some(&parent_index) = self.stack.last() {
+            self.nodes[parent_index].children.push(index);
+        }
+        self.nodes.push(Node { kind, children: vec![] });
+        self.stack.push(index);
+    }
+
+    fn leave_node(&mut self, _kind: AstKind<'a>) {
+        self.stack.pop();
+    }
+}
+
+struct Differ<'t, 'a> {
+    old: &'t NodeTree<'a>,
+    new: &'t NodeTree<'a>,
+    changes: Vec<AstChange<'a>>,
+}
+
+impl<'t, 'a> Differ<'t, 'a> {
+    fn diff_nodes(&mut self, old_index: usize, new_index: usize) {
+        let (old, new) = (&self.old.nodes[old_index], &self.new.nodes[new_index]);
+        // `Program` contains source text and comments, which are not compared
+        if !matches!(old.kind, AstKind::Program(_)) && old.kind.content_eq(&new.kind) {
+            return;
+        }
+        if discriminant(&old.kind) != discriminant(&new.kind) {
+            self.changes.push(AstChange::Modified { old: old.kind, new: new.kind });
+            return;
+        }
+
+        let own_fields_eq = own_fields_eq(old.kind, new.kind);
+        if !own_fields_eq {
+            self.changes.push(AstChange::Modified { old: old.kind, new: new.kind });
+        }
+
+        let changes_len = self.changes.len();
+        self.diff_children(&old.children, &new.children);
+
+        // Node differs, but neither children nor the fields checked by `own_fields_eq` do.
+        // Some other field of the node itself differs.
+        if own_fields_eq
+            && self.changes.len() == changes_len
+            && !matches!(old.kind, AstKind::Program(_))
+        {
+            self.changes.push(AstChange::Modified { old: old.kind, new: new.kind });
+        }
+    }
+
+    fn diff_children(&mut self, old_children: &[usize], new_children: &[usize]) {
+        let eq = |old_index: usize, new_index: usize| {
+            self.old.nodes[old_index].kind.content_eq(&self.new.nodes[new_index].kind)
+        };
+
+        // Skip common prefix and suffix
+        let prefix_len =
+            old_children.iter().zip(new_children).take_while(|(&o, &n)| eq(o, n)).count();
+        let old_children = &old_children[prefix_len..];
+        let new_children = &new_children[prefix_len..];
+        let suffix_len = old_children
+            .iter()
+            .rev()
+            .zip(new_children.iter().rev())
+            .take_while(|(&o, &n)| eq(o, n))
+            .count();
+        let old_children = &old_children[..old_children.len() - suffix_len];
+        let new_children = &new_children[..new_children.len() - suffix_len];
+
+        // Pair up unchanged children. If the lists differ too much, pair up by position instead.
+        let Some(common) = common_subsequence(old_children.len(), new_children.len(), |i, j| {
+            eq(old_children[i], new_children[j])
+        }) else {
+            self.diff_unmatched(old_children, new_children);
+            return;
+        };
+
+        let (mut old_start, mut new_start) = (0, 0);
+        for (i, j) in common {
+            self.diff_unmatched(&old_children[old_start..i], &new_children[new_start..j]);
+            (old_start, new_start) = (i + 1, j + 1);
+        }
+        self.diff_unmatched(&old_children[old_start..], &new_children[new_start..]);
+    }
+
+    /// Diff runs of children which have no unchanged counterpart.
+    ///
+    /// If both runs are the same length, children are paired up by position and diffed.
+    /// Otherwise children of the same type are paired up in order and diffed,
+    /// and the rest are removed or inserted.
+    fn diff_unmatched(&mut self, old_children: &[usize], new_children: &[usize]) {
+        if old_children.len() == new_children.len() {
+            for (&old_index, &new_index) in old_children.iter().zip(new_children) {
+                self.diff_nodes(old_index, new_index);
+            }
+            return;
+        }
+
+        let mut new_start = 0;
+        for &old_index in old_children {
+            let old_kind = self.old.nodes[old_index].kind;
+            let paired = new_children[new_start..].iter().position(|&new_index| {
+                discriminant(&self.new.nodes[new_index].kind) == discriminant(&old_kind)
+            });
+            if let Some(offset) = paired {
+                for &new_index in &new_children[new_start..new_start + offset] {
+                    self.changes.push(AstChange::Inserted(self.new.nodes[new_index].kind));
+                }
+                self.diff_nodes(old_index, new_children[new_start + offset]);
+                new_start += offset + 1;
+            } else {
+                self.changes.push(AstChange::Removed(old_kind));
+            }
+        }
+        for &new_index in &new_children[new_start..] {
+            self.changes.push(AstChange::Inserted(self.new.nodes[new_index].kind));
+        }
+    }
+}
+
+/// Maximum number of insertions and removals [`common_subsequence`] searches for.
+///
+/// Limits memory use to `O(MAX_EDIT_DISTANCE²)`, and time to `O((old_len + new_len) * MAX_EDIT_DISTANCE)`.
+const MAX_EDIT_DISTANCE: usize = 1000;
+
+/// Find a longest common subsequence of 2 sequences of lengths `old_len` and `new_len`, using
+/// Myers' diff algorithm.
+///
+/// Returns pairs of indexes of equal elements, in order. Returns `None` if the sequences differ
+/// by more than [`MAX_EDIT_DISTANCE`] insertions and removals.
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+fn common_subsequence<F: Fn(usize, usize) -> bool>(
+    old_len: usize,
+    new_len: usize,
+    eq: F,
+) -> Option<Vec<(usize, usize)>> {
+    let max_d = (old_len + new_len).min(MAX_EDIT_DISTANCE) as isize;
+    // Furthest reaching `x` on each diagonal `k = x - y`, at index `k + max_d`
+    let mut furthest = vec![0usize; 2 * max_d as usize + 2];
+    // Diagonals `-d..=d` of `furthest` before each step `d`
+    let mut trace = vec![];
+    let index = |k: isize| (k + max_d) as usize;
+
+    for d in 0..=max_d {
+        trace.push(furthest[index(-d)..=index(d)].to_vec());
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && furthest[index(k - 1)] < furthest[index(k + 1)]) {
+                furthest[index(k + 1)]
+            } else {
+                furthest[index(k - 1)] + 1
+            };
+            let mut y = (x as isize - k) as usize;
+            while x < old_len && y < new_len && eq(x, y) {
+                x += 1;
+                y += 1;
+            }
+            furthest[index(k)] = x;
+            if x >= old_len && y >= new_len {
+                return Some(backtrack_common_subsequence(&trace, old_len, new_len));
+            }
+        }
+    }
+    None
+}
+
+/// Follow the furthest reaching paths recorded by [`common_subsequence`] back from the end.
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+fn backtrack_common_subsequence(
+    trace: &[Vec<usize>],
+    old_len: usize,
+    new_len: usize,
+) -> Vec<(usize, usize)> {
+    let mut common = vec![];
+    let (mut x, mut y) = (old_len, new_len);
+    for (d, furthest) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let (prev_x, prev_y) = if d == 0 {
+            (0, 0)
+        } else {
+            let k = x as isize - y as isize;
+            let index = |k: isize| (k + d) as usize;
+            let prev_k = if k == -d || (k != d && furthest[index(k - 1)] < furthest[index(k + 1)]) {
+                k + 1
+            } else {
+                k - 1
+            };
+            let prev_x = furthest[index(prev_k)];
+            (prev_x, (prev_x as isize - prev_k) as usize)
+        };
+        // Diagonal moves are equal elements
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            common.push((x, y));
+        }
+        (x, y) = (prev_x, prev_y);
+    }
+    common.reverse();
+    common
+}
+
+/// Compare fields of 2 nodes of same type which are not child nodes, for common node types.
+///
+/// Returns `true` for other node types.
+fn own_fields_eq(old: AstKind, new: AstKind) -> bool {
+    match (old, new) {
+        (AstKind::Program(old), AstKind::Program(new)) => old.source_type == new.source_type,
+        (AstKind::IdentifierName(old), AstKind::IdentifierName(new)) => old.name == new.name,
+        (AstKind::IdentifierReference(old), AstKind::IdentifierReference(new)) => {
+            old.name == new.name
+        }
+        (AstKind::BindingIdentifier(old), AstKind::BindingIdentifier(new)) => old.name == new.name,
+        (AstKind::LabelIdentifier(old), AstKind::LabelIdentifier(new)) => old.name == new.name,
+        (AstKind::PrivateIdentifier(old), AstKind::PrivateIdentifier(new)) => old.name == new.name,
+        (AstKind::StringLiteral(old), AstKind::StringLiteral(new)) => old.value == new.value,
+        (AstKind::NumericLiteral(old), AstKind::NumericLiteral(new)) => old.content_eq(new),
+        (AstKind::BigIntLiteral(old), AstKind::BigIntLiteral(new)) => old.content_eq(new),
+        (AstKind::BooleanLiteral(old), AstKind::BooleanLiteral(new)) => old.value == new.value,
+        (AstKind::RegExpLiteral(old), AstKind::RegExpLiteral(new)) => old.content_eq(new),
+        (AstKind::BinaryExpression(old), AstKind::BinaryExpression(new)) => {
+            old.operator == new.operator
+        }
+        (AstKind::LogicalExpression(old), AstKind::LogicalExpression(new)) => {
+            old.operator == new.operator
+        }
+        (AstKind::UnaryExpression(old), AstKind::UnaryExpression(new)) => {
+            old.operator == new.operator
+        }
+        (AstKind::UpdateExpression(old), AstKind::UpdateExpression(new)) => {
+            old.operator == new.operator && old.prefix == new.prefix
+        }
+        (AstKind::AssignmentExpression(old), AstKind::AssignmentExpression(new)) => {
+            old.operator == new.operator
+        }
+        (AstKind::VariableDeclaration(old), AstKind::VariableDeclaration(new)) => {
+            old.kind == new.kind
+        }
+        _ => true,
+    }
+}
@@ -0,0 +1,455 @@
+//! [ESTree] and [TS-ESTree] conformant serialization.
+//!
+//! The `serialize` feature serializes the AST as it is, which is close to ESTree but deviates from
+//! it wherever the Oxc AST is more specific, e.g. `StaticMemberExpression`, `StringLiteral` and
+//! `FormalParameters`. [`Program::to_estree`] converts that output to the node shapes produced by
+//! [acorn] and [typescript-estree], so that it can be consumed by ESLint-ecosystem tooling.
+//!
+//! Spans are converted from UTF-8 byte offsets to UTF-16 code unit offsets, as used by JavaScript.
+//!
+//! [ESTree]: https://github.com/estree/estree
+//! [TS-ESTree]: https://typescript-eslint.io/packages/typescript-estree/ast-spec
+//! [acorn]: https://github.com/acornjs/acorn
+//! [typescript-estree]: https://github.com/typescript-eslint/typescript-eslint/tree/main/packages/typescript-estree
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{ast::Program, serialize::EcmaFormatter};
+
+/// Flavor of the ESTree output.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EstreeFlavor {
+    /// ESTree as produced by acorn. TypeScript specific properties are omitted.
+    #[default]
+    JavaScript,
+    /// TS-ESTree as produced by `@typescript-eslint/typescript-estree`.
+    /// Nodes also have a `range` property.
+    TypeScript,
+}
+
+/// Properties which only exist on TS-ESTree nodes.
+const TYPESCRIPT_PROPERTIES: [&str; 14] = [
+    "typeAnnotation",
+    "typeParameters",
+    "typeArguments",
+    "returnType",
+    "superTypeArguments",
+    "implements",
+    "abstract",
+    "declare",
+    "accessibility",
+    "override",
+    "readonly",
+    "definite",
+    "importKind",
+    "exportKind",
+];
+
+/// Nodes on which `typeParameters` are type arguments.
+const TYPE_ARGUMENTS_NODES: [&str; 11] = [
+    "CallExpression",
+    "NewExpression",
+    "TaggedTemplateExpression",
+    "JSXOpeningElement",
+    "TSTypeReference",
+    "TSTypeQuery",
+    "TSImportType",
+    "TSClassImplements",
+    "TSInterfaceHeritage",
+    "TSInstantiationExpression",
+    "TSExpressionWithTypeArguments",
+];
+
+impl<'a> Program<'a> {
+    /// Serialize to an ESTree conformant JSON value.
+    ///
+    /// # Panics
+    pub fn to_estree(&self, flavor: EstreeFlavor) -> Value {
+        let value = serde_json::to_value(self).unwrap();
+        EstreeConverter::new(self.source_text, flavor).convert(value)
+    }
+
+    /// Serialize to an ESTree conformant JSON string.
+    ///
+    /// # Panics
+    pub fn to_estree_json(&self, flavor: EstreeFlavor) -> String {
+        let mut ser = serde_json::Serializer::with_formatter(std::vec::Vec::new(), EcmaFormatter);
+        self.to_estree(flavor).serialize(&mut ser).unwrap();
+        String::from_utf8(ser.into_inner()).unwrap()
+    }
+}
+
+struct EstreeConverter<'a> {
+    source_text: &'a str,
+    flavor: EstreeFlavor,
+    /// `(end of a non-ASCII character, accumulated difference of UTF-8 and UTF-16 lengths)`
+    utf16_offsets: Vec<(u32, u32)>,
+}
+
+impl<'a> EstreeConverter<'a> {
+    #[allow(clippy::cast_possible_truncation)]
+    fn new(source_text: &'a str, flavor: EstreeFlavor) -> Self {
+        let mut utf16_offsets = vec![];
+        if !source_text.is_ascii() {
+            let mut diff = 0;
+            for (offset, c) in source_text.char_indices() {
+                if !c.is_ascii() {
+                    diff += (c.len_utf8() - c.len_utf16()) as u32;
+                    utf16_offsets.push(((offset + c.len_utf8()) as u32, diff));
+                }
+            }
+        }
+        Self { source_text, flavor, utf16_offsets }
+    }
+
+    fn is_typescript(&self) -> bool {
+        self.flavor == EstreeFlavor::TypeScript
+    }
+
+    fn utf16_offset(&self, offset: u64) -> u64 {
+        let index = self.utf16_offsets.partition_point(|&(end, _)| u64::from(end) <= offset);
+        let diff = if index == 0 { 0 } else { self.utf16_offsets[index - 1].1 };
+        offset - u64::from(diff)
+    }
+
+    fn convert(&self, value: Value) -> Value {
+        match value {
+            Value::Object(node) => self.convert_object(node),
+            Value::Array(items) => {
+                Value::Array(items.into_iter().map(|v| self.convert(v)).collect())
+            }
+            value => value,
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn convert_object(&self, node: Map<String, Value>) -> Value {
+        let mut node: Map<String, Value> =
+            node.into_iter().map(|(key, value)| (key, self.convert(value))).collect();
+        let Some(Value::String(ty)) = node.get("type") else {
+            return Value::Object(node);
+        };
+        let ty = ty.clone();
+
+        let (start, end) = match (node.get("start"), node.get("end")) {
+            (Some(Value::Number(start)), Some(Value::Number(end))) => {
+                (start.as_u64().unwrap_or(0), end.as_u64().unwrap_or(0))
+            }
+            _ => (0, 0),
+        };
+        let raw = self.source_text.get(start as usize..end as usize).unwrap_or_default();
+        let (start, end) = (self.utf16_offset(start), self.utf16_offset(end));
+        node.insert("start".into(), start.into());
+        node.insert("end".into(), end.into());
+        if self.is_typescript() {
+            node.insert("range".into(), Value::Array(vec![start.into(), end.into()]));
+        }
+
+        let node = match ty.as_str() {
+            "Program" => Self::convert_program(node),
+            "Directive" => {
+                set_type(&mut node, "ExpressionStatement");
+                Value::Object(node)
+            }
+            "Hashbang" => Value::Null,
+            "BooleanLiteral" | "NullLiteral" | "NumericLiteral" | "StringLiteral" => {
+                set_type(&mut node, "Literal");
+                node.entry("value").or_insert(Value::Null);
+                node.insert("raw".into(), raw.into());
+                Value::Object(node)
+            }
+            "BigIntLiteral" => {
+                set_type(&mut node, "Literal");
+                let bigint =
+                    raw.trim_end_matches('n').chars().filter(|c| *c != '_').collect::<String>();
+                node.insert("value".into(), Value::Null);
+                node.insert("raw".into(), raw.into());
+                node.insert("bigint".into(), bigint.into());
+                Value::Object(node)
+            }
+            "RegExpLiteral" => {
+                set_type(&mut node, "Literal");
+                let pattern_end = raw.rfind('/').unwrap_or(raw.len());
+                let flags = raw.get(pattern_end + 1..).unwrap_or_default();
+                let pattern = raw.get(1..pattern_end).unwrap_or_default();
+                let mut regex = Map::new();
+                regex.insert("pattern".into(), pattern.into());
+                regex.insert("flags".into(), flags.into());
+                node.insert("value".into(), Value::Null);
+                node.insert("raw".into(), raw.into());
+                node.insert("regex".into(), Value::Object(regex));
+                Value::Object(node)
+            }
+            "JSXText" => {
+                node.insert("raw".into(), raw.into());
+                Value::Object(node)
+            }
+            "ParenthesizedExpression" => take(&mut node, "expression"),
+            "StaticMemberExpression" => {
+                set_type(&mut node, "MemberExpression");
+                node.insert("computed".into(), false.into());
+                Value::Object(node)
+            }
+            "ComputedMemberExpression" => {
+                set_type(&mut node, "MemberExpression");
+                rename(&mut node, "expression", "property");
+                node.insert("computed".into(), true.into());
+                Value::Object(node)
+            }
+            "PrivateFieldExpression" => {
+                set_type(&mut node, "MemberExpression");
+                rename(&mut node, "field", "property");
+                node.insert("computed".into(), false.into());
+                Value::Object(node)
+            }
+            "PrivateInExpression" => {
+                set_type(&mut node, "BinaryExpression");
+                Value::Object(node)
+            }
+            "UnaryExpression" => {
+                node.insert("prefix".into(), true.into());
+                Value::Object(node)
+            }
+            "ObjectProperty" => {
+                set_type(&mut node, "Property");
+                node.remove("init");
+                Value::Object(node)
+            }
+            "BindingProperty" => {
+                set_type(&mut node, "Property");
+                node.insert("kind".into(), "init".into());
+                node.insert("method".into(), false.into());
+                Value::Object(node)
+            }
+            "AssignmentTargetPropertyIdentifier" => {
+                let key = take(&mut node, "binding");
+                let value = match take(&mut node, "init") {
+                    Value::Null => key.clone(),
+                    init => {
+                        let mut pattern = Map::new();
+                        pattern.insert("type".into(), "AssignmentPattern".into());
+                        copy_span(&node, &mut pattern);
+                        pattern.insert("left".into(), key.clone());
+                        pattern.insert("right".into(), init);
+                        Value::Object(pattern)
+                    }
+                };
+                property(node, key, value, /* shorthand */ true, /* computed */ false)
+            }
+            "AssignmentTargetPropertyProperty" => {
+                let key = take(&mut node, "name");
+                let value = take(&mut node, "binding");
+                // `{ [a]: b } = c`
+                let computed = raw.starts_with('[');
+                property(node, key, value, /* shorthand */ false, computed)
+            }
+            "AssignmentTargetWithDefault" => {
+                set_type(&mut node, "AssignmentPattern");
+                rename(&mut node, "binding", "left");
+                rename(&mut node, "init", "right");
+                Value::Object(node)
+            }
+            "ArrayAssignmentTarget" => {
+                set_type(&mut node, "ArrayPattern");
+                Value::Object(node)
+            }
+            "ObjectAssignmentTarget" => {
+                set_type(&mut node, "ObjectPattern");
+                Value::Object(node)
+            }
+            "CatchParameter" => take(&mut node, "pattern"),
+            "FormalParameters" => take(&mut node, "items"),
+            "FormalParameter" => self.convert_formal_parameter(node),
+            "TSThisParameter" => {
+                set_type(&mut node, "Identifier");
+                node.remove("thisSpan");
+                node.insert("name".into(), "this".into());
+                Value::Object(node)
+            }
+            "FunctionBody" => {
+                set_type(&mut node, "BlockStatement");
+                let mut body = into_array(take(&mut node, "directives"));
+                body.extend(into_array(take(&mut node, "statements")));
+                node.insert("body".into(), Value::Array(body));
+                Value::Object(node)
+            }
+            "ArrowFunctionExpression" => {
+                if node.get("expression") == Some(&Value::Bool(true)) {
+                    if let Some(Value::Array(body)) =
+                        node.get_mut("body").and_then(|body| body.get_mut("body"))
+                    {
+                        if let Some(Value::Object(mut statement)) = body.pop() {
+                            node.insert("body".into(), take(&mut statement, "expression"));
+                        }
+                    }
+                }
+                node.insert("id".into(), Value::Null);
+                node.insert("generator".into(), false.into());
+                self.convert_function(node)
+            }
+            "FunctionDeclaration"
+            | "FunctionExpression"
+            | "TSDeclareFunction"
+            | "TSEmptyBodyFunctionExpression" => {
+                node.insert("expression".into(), false.into());
+                self.convert_function(node)
+            }
+            "TSMethodSignature"
+            | "TSCallSignatureDeclaration"
+            | "TSConstructSignatureDeclaration"
+            | "TSFunctionType"
+            | "TSConstructorType" => self.convert_function(node),
+            "ImportExpression" => {
+                let options = match take(&mut node, "arguments") {
+                    Value::Array(mut arguments) if !arguments.is_empty() => arguments.remove(0),
+                    _ => Value::Null,
+                };
+                node.insert("options".into(), options);
+                Value::Object(node)
+            }
+            "ImportDeclaration" | "ExportNamedDeclaration" | "ExportAllDeclaration" => {
+                let attributes = match take(&mut node, "withClause") {
+                    Value::Object(mut with_clause) => take(&mut with_clause, "withEntries"),
+                    _ => Value::Array(vec![]),
+                };
+                node.insert("attributes".into(), attributes);
+                self.strip_typescript_properties(&ty, node)
+            }
+            "ExportDefaultDeclaration" => {
+                node.remove("exported");
+                Value::Object(node)
+            }
+            "ClassDeclaration" | "ClassExpression" => {
+                rename(&mut node, "superTypeParameters", "superTypeArguments");
+                self.strip_typescript_properties(&ty, node)
+            }
+            "TSInterfaceDeclaration" => {
+                if node.get("extends") == Some(&Value::Null) {
+                    node.insert("extends".into(), Value::Array(vec![]));
+                }
+                Value::Object(node)
+            }
+            _ => {
+                if TYPE_ARGUMENTS_NODES.contains(&ty.as_str()) {
+                    rename(&mut node, "typeParameters", "typeArguments");
+                }
+                self.strip_typescript_properties(&ty, node)
+            }
+        };
+        node
+    }
+
+    fn convert_program(mut node: Map<String, Value>) -> Value {
+        let source_type = match node.get("sourceType").and_then(|t| t.get("moduleKind")) {
+            Some(Value::String(kind)) if kind == "script" => "script",
+            _ => "module",
+        };
+        node.insert("sourceType".into(), source_type.into());
+        node.remove("hashbang");
+        let mut body = into_array(take(&mut node, "directives"));
+        body.extend(into_array(take(&mut node, "body")));
+        node.insert("body".into(), Value::Array(body));
+        Value::Object(node)
+    }
+
+    /// `FormalParameter` is its binding pattern, or a `TSParameterProperty` if it has modifiers.
+    fn convert_formal_parameter(&self, mut node: Map<String, Value>) -> Value {
+        let mut pattern = take(&mut node, "pattern");
+        let decorators = take(&mut node, "decorators");
+        if !self.is_typescript() {
+            return pattern;
+        }
+        let is_parameter_property = node.get("accessibility").is_some_and(|a| !a.is_null())
+            || node.get("readonly") == Some(&Value::Bool(true))
+            || node.get("override") == Some(&Value::Bool(true));
+        if is_parameter_property {
+            set_type(&mut node, "TSParameterProperty");
+            node.insert("static".into(), false.into());
+            node.insert("parameter".into(), pattern);
+            node.insert("decorators".into(), decorators);
+            Value::Object(node)
+        } else {
+            if let Value::Object(pattern) = &mut pattern {
+                pattern.insert("decorators".into(), decorators);
+            }
+            pattern
+        }
+    }
+
+    /// Prepend `this` parameter to `params`.
+    fn convert_function(&self, mut node: Map<String, Value>) -> Value {
+        let this_param = take(&mut node, "thisParam");
+        if self.is_typescript() && !this_param.is_null() {
+            let mut params = into_array(take(&mut node, "params"));
+            params.insert(0, this_param);
+            node.insert("params".into(), Value::Array(params));
+        }
+        let ty = node.get("type").and_then(Value::as_str).unwrap_or_default().to_string();
+        self.strip_typescript_properties(&ty, node)
+    }
+
+    fn strip_typescript_properties(&self, ty: &str, mut node: Map<String, Value>) -> Value {
+        if self.is_typescript() {
+            return Value::Object(node);
+        }
+        for key in TYPESCRIPT_PROPERTIES {
+            node.remove(key);
+        }
+        // `optional` is only part of ESTree for optional chaining
+        if !matches!(ty, "MemberExpression" | "CallExpression") {
+            node.remove("optional");
+        }
+        if node.get("decorators").is_some_and(|d| d.as_array().is_some_and(Vec::is_empty)) {
+            node.remove("decorators");
+        }
+        Value::Object(node)
+    }
+}
+
+fn set_type(node: &mut Map<String, Value>, ty: &str) {
+    node.insert("type".into(), ty.into());
+}
+
+fn take(node: &mut Map<String, Value>, key: &str) -> Value {
+    node.remove(key).unwrap_or(Value::Null)
+}
+
+fn rename(node: &mut Map<String, Value>, from: &str, to: &str) {
+    if let Some(value) = node.remove(from) {
+        node.insert(to.into(), value);
+    }
+}
+
+fn copy_span(from: &Map<String, Value>, to: &mut Map<String, Value>) {
+    for key in ["start", "end", "range"] {
+        if let Some(value) = from.get(key) {
+            to.insert(key.into(), value.clone());
+        }
+    }
+}
+
+fn into_array(value: Value) -> Vec<Value> {
+    match value {
+        Value::Array(items) => items,
+        Value::Null => vec![],
+        value => vec![value],
+    }
+}
+
+fn property(
+    mut node: Map<String, Value>,
+    key: Value,
+    value: Value,
+    shorthand: bool,
+    computed: bool,
+) -> Value {
+    set_type(&mut node, "Property");
+    node.insert("key".into(), key);
+    node.insert("value".into(), value);
+    node.insert("kind".into(), "init".into());
+    node.insert("method".into(), false.into());
+    node.insert("shorthand".into(), shorthand.into());
+    node.insert("computed".into(), computed.into());
+    Value::Object(node)
+}
@@ -506,6 +506,10 @@ const _: () = {
     assert!(align_of::<DebuggerStatement>() == 4usize);
     assert!(offset_of!(DebuggerStatement, span) == 0usize);
 
+    assert!(size_of::<ErrorStatement>() == 8usize);
+    assert!(align_of::<ErrorStatement>() == 4usize);
+    assert!(offset_of!(ErrorStatement, span) == 0usize);
+
     assert!(size_of::<BindingPattern>() == 32usize);
     assert!(align_of::<BindingPattern>() == 8usize);
     assert!(offset_of!(BindingPattern, kind) == 0usize);
@@ -1117,10 +1121,11 @@ const _: () = {
     assert!(offset_of!(TSModuleBlock, directives) == 8usize);
     assert!(offset_of!(TSModuleBlock, body) == 40usize);
 
-    assert!(size_of::<TSTypeLiteral>() == 40usize);
+    assert!(size_of::<TSTypeLiteral>() == 48usize);
     assert!(align_of::<TSTypeLiteral>() == 8usize);
     assert!(offset_of!(TSTypeLiteral, span) == 0usize);
     assert!(offset_of!(TSTypeLiteral, members) == 8usize);
+    assert!(offset_of!(TSTypeLiteral, flow_exact) == 40usize);
 
     assert!(size_of::<TSInferType>() == 16usize);
     assert!(align_of::<TSInferType>() == 8usize);
@@ -1195,11 +1200,12 @@ const _: () = {
     assert!(offset_of!(TSTemplateLiteralType, quasis) == 8usize);
     assert!(offset_of!(TSTemplateLiteralType, types) == 40usize);
 
-    assert!(size_of::<TSAsExpression>() == 40usize);
+    assert!(size_of::<TSAsExpression>() == 48usize);
     assert!(align_of::<TSAsExpression>() == 8usize);
     assert!(offset_of!(TSAsExpression, span) == 0usize);
     assert!(offset_of!(TSAsExpression, expression) == 8usize);
     assert!(offset_of!(TSAsExpression, type_annotation) == 24usize);
+    assert!(offset_of!(TSAsExpression, flow_type_cast) == 40usize);
 
     assert!(size_of::<TSSatisfiesExpression>() == 40usize);
     assert!(align_of::<TSSatisfiesExpression>() == 8usize);
@@ -2065,6 +2071,10 @@ const _: () = {
     assert!(align_of::<DebuggerStatement>() == 4usize);
     assert!(offset_of!(DebuggerStatement, span) == 0usize);
 
+    assert!(size_of::<ErrorStatement>() == 8usize);
+    assert!(align_of::<ErrorStatement>() == 4usize);
+    assert!(offset_of!(ErrorStatement, span) == 0usize);
+
     assert!(size_of::<BindingPattern>() == 16usize);
     assert!(align_of::<BindingPattern>() == 4usize);
     assert!(offset_of!(BindingPattern, kind) == 0usize);
@@ -2676,10 +2686,11 @@ const _: () = {
     assert!(offset_of!(TSModuleBlock, directives) == 8usize);
     assert!(offset_of!(TSModuleBlock, body) == 24usize);
 
-    assert!(size_of::<TSTypeLiteral>() == 24usize);
+    assert!(size_of::<TSTypeLiteral>() == 28usize);
     assert!(align_of::<TSTypeLiteral>() == 4usize);
     assert!(offset_of!(TSTypeLiteral, span) == 0usize);
     assert!(offset_of!(TSTypeLiteral, members) == 8usize);
+    assert!(offset_of!(TSTypeLiteral, flow_exact) == 24usize);
 
     assert!(size_of::<TSInferType>() == 12usize);
     assert!(align_of::<TSInferType>() == 4usize);
@@ -2754,11 +2765,12 @@ const _: () = {
     assert!(offset_of!(TSTemplateLiteralType, quasis) == 8usize);
     assert!(offset_of!(TSTemplateLiteralType, types) == 24usize);
 
-    assert!(size_of::<TSAsExpression>() == 24usize);
+    assert!(size_of::<TSAsExpression>() == 28usize);
     assert!(align_of::<TSAsExpression>() == 4usize);
     assert!(offset_of!(TSAsExpression, span) == 0usize);
     assert!(offset_of!(TSAsExpression, expression) == 8usize);
     assert!(offset_of!(TSAsExpression, type_annotation) == 16usize);
+    assert!(offset_of!(TSAsExpression, flow_type_cast) == 24usize);
 
     assert!(size_of::<TSSatisfiesExpression>() == 24usize);
     assert!(align_of::<TSSatisfiesExpression>() == 4usize);
@@ -1344,17 +1344,20 @@ impl<'a> AstBuilder<'a> {
     /// - span: The [`Span`] covering this node
     /// - expression
     /// - type_annotation
+    /// - flow_type_cast: Was it a Flow type cast `(expression: Type)`?
     #[inline]
     pub fn expression_ts_as(
         self,
         span: Span,
         expression: Expression<'a>,
         type_annotation: TSType<'a>,
+        flow_type_cast: bool,
     ) -> Expression<'a> {
         Expression::TSAsExpression(self.alloc(self.ts_as_expression(
             span,
             expression,
             type_annotation,
+            flow_type_cast,
         )))
     }
 
@@ -2847,17 +2850,20 @@ impl<'a> AstBuilder<'a> {
     /// - span: The [`Span`] covering this node
     /// - expression
     /// - type_annotation
+    /// - flow_type_cast: Was it a Flow type cast `(expression: Type)`?
     #[inline]
     pub fn simple_assignment_target_ts_as_expression(
         self,
         span: Span,
         expression: Expression<'a>,
         type_annotation: TSType<'a>,
+        flow_type_cast: bool,
     ) -> SimpleAssignmentTarget<'a> {
         SimpleAssignmentTarget::TSAsExpression(self.alloc(self.ts_as_expression(
             span,
             expression,
             type_annotation,
+            flow_type_cast,
         )))
     }
 
@@ -4054,6 +4060,26 @@ impl<'a> AstBuilder<'a> {
         Statement::WithStatement(inner.into_in(self.allocator))
     }
 
+    /// Build a [`Statement::ErrorStatement`]
+    ///
+    /// This node contains a [`ErrorStatement`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// - span: The [`Span`] covering this node
+    #[inline]
+    pub fn statement_error(self, span: Span) -> Statement<'a> {
+        Statement::ErrorStatement(self.alloc(self.error_statement(span)))
+    }
+
+    /// Convert a [`ErrorStatement`] into a [`Statement::ErrorStatement`]
+    #[inline]
+    pub fn statement_from_error<T>(self, inner: T) -> Statement<'a>
+    where
+        T: IntoIn<'a, Box<'a, ErrorStatement>>,
+    {
+        Statement::ErrorStatement(inner.into_in(self.allocator))
+    }
+
     #[inline]
     pub fn statement_declaration(self, inner: Declaration<'a>) -> Statement<'a> {
         Statement::from(inner)
@@ -5383,6 +5409,28 @@ impl<'a> AstBuilder<'a> {
         Box::new_in(self.debugger_statement(span), self.allocator)
     }
 
+    /// Builds a [`ErrorStatement`]
+    ///
+    /// If you want the built node to be allocated in the memory arena, use [`AstBuilder::alloc_error_statement`] instead.
+    ///
+    /// ## Parameters
+    /// - span: The [`Span`] covering this node
+    #[inline]
+    pub fn error_statement(self, span: Span) -> ErrorStatement {
+        ErrorStatement { span }
+    }
+
+    /// Builds a [`ErrorStatement`] and stores it in the memory arena.
+    ///
+    /// Returns a [`Box`] containing the newly-allocated node. If you want a stack-allocated node, use [`AstBuilder::error_statement`] instead.
+    ///
+    /// ## Parameters
+    /// - span: The [`Span`] covering this node
+    #[inline]
+    pub fn alloc_error_statement(self, span: Span) -> Box<'a, ErrorStatement> {
+        Box::new_in(self.error_statement(span), self.allocator)
+    }
+
     /// Builds a [`BindingPattern`]
     ///
     /// If you want the built node to be allocated in the memory arena, use [`AstBuilder::alloc_binding_pattern`] instead.
@@ -9185,9 +9233,15 @@ impl<'a> AstBuilder<'a> {
     /// ## Parameters
     /// - span: The [`Span`] covering this node
     /// - members
+    /// - flow_exact: Is it a Flow exact object type `{| a: A |}`?
     #[inline]
-    pub fn ts_type_type_literal(self, span: Span, members: Vec<'a, TSSignature<'a>>) -> TSType<'a> {
-        TSType::TSTypeLiteral(self.alloc(self.ts_type_literal(span, members)))
+    pub fn ts_type_type_literal(
+        self,
+        span: Span,
+        members: Vec<'a, TSSignature<'a>>,
+        flow_exact: bool,
+    ) -> TSType<'a> {
+        TSType::TSTypeLiteral(self.alloc(self.ts_type_literal(span, members, flow_exact)))
     }
 
     /// Convert a [`TSTypeLiteral`] into a [`TSType::TSTypeLiteral`]
@@ -11614,13 +11668,15 @@ impl<'a> AstBuilder<'a> {
     /// ## Parameters
     /// - span: The [`Span`] covering this node
     /// - members
+    /// - flow_exact: Is it a Flow exact object type `{| a: A |}`?
     #[inline]
     pub fn ts_type_literal(
         self,
         span: Span,
         members: Vec<'a, TSSignature<'a>>,
+        flow_exact: bool,
     ) -> TSTypeLiteral<'a> {
-        TSTypeLiteral { span, members }
+        TSTypeLiteral { span, members, flow_exact }
     }
 
     /// Builds a [`TSTypeLiteral`] and stores it in the memory arena.
@@ -11630,13 +11686,15 @@ impl<'a> AstBuilder<'a> {
     /// ## Parameters
     /// - span: The [`Span`] covering this node
     /// - members
+    /// - flow_exact: Is it a Flow exact object type `{| a: A |}`?
     #[inline]
     pub fn alloc_ts_type_literal(
         self,
         span: Span,
         members: Vec<'a, TSSignature<'a>>,
+        flow_exact: bool,
     ) -> Box<'a, TSTypeLiteral<'a>> {
-        Box::new_in(self.ts_type_literal(span, members), self.allocator)
+        Box::new_in(self.ts_type_literal(span, members, flow_exact), self.allocator)
     }
 
     /// Builds a [`TSInferType`]
@@ -12210,14 +12268,16 @@ impl<'a> AstBuilder<'a> {
     /// - span: The [`Span`] covering this node
     /// - expression
     /// - type_annotation
+    /// - flow_type_cast: Was it a Flow type cast `(expression: Type)`?
     #[inline]
     pub fn ts_as_expression(
         self,
         span: Span,
         expression: Expression<'a>,
         type_annotation: TSType<'a>,
+        flow_type_cast: bool,
     ) -> TSAsExpression<'a> {
-        TSAsExpression { span, expression, type_annotation }
+        TSAsExpression { span, expression, type_annotation, flow_type_cast }
     }
 
     /// Builds a [`TSAsExpression`] and stores it in the memory arena.
@@ -12228,14 +12288,19 @@ impl<'a> AstBuilder<'a> {
     /// - span: The [`Span`] covering this node
     /// - expression
     /// - type_annotation
+    /// - flow_type_cast: Was it a Flow type cast `(expression: Type)`?
     #[inline]
     pub fn alloc_ts_as_expression(
         self,
         span: Span,
         expression: Expression<'a>,
         type_annotation: TSType<'a>,
+        flow_type_cast: bool,
     ) -> Box<'a, TSAsExpression<'a>> {
-        Box::new_in(self.ts_as_expression(span, expression, type_annotation), self.allocator)
+        Box::new_in(
+            self.ts_as_expression(span, expression, type_annotation, flow_type_cast),
+            self.allocator,
+        )
     }
 
     /// Builds a [`TSSatisfiesExpression`]
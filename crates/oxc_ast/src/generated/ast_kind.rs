@@ -1,7 +1,7 @@
 // Auto-generated code, DO NOT EDIT DIRECTLY!
 // To edit this generated file you have to edit `tasks/ast_tools/src/generators/ast_kind.rs`
 
-use oxc_span::{GetSpan, Span};
+use oxc_span::{cmp::ContentEq, GetSpan, Span};
 
 #[allow(clippy::wildcard_imports)]
 use crate::ast::*;
@@ -79,6 +79,7 @@ pub enum AstType {
     CatchClause,
     CatchParameter,
     DebuggerStatement,
+    ErrorStatement,
     AssignmentPattern,
     ObjectPattern,
     ArrayPattern,
@@ -252,6 +253,7 @@ pub enum AstKind<'a> {
     CatchClause(&'a CatchClause<'a>),
     CatchParameter(&'a CatchParameter<'a>),
     DebuggerStatement(&'a DebuggerStatement),
+    ErrorStatement(&'a ErrorStatement),
     AssignmentPattern(&'a AssignmentPattern<'a>),
     ObjectPattern(&'a ObjectPattern<'a>),
     ArrayPattern(&'a ArrayPattern<'a>),
@@ -426,6 +428,7 @@ impl<'a> GetSpan for AstKind<'a> {
             Self::CatchClause(it) => it.span(),
             Self::CatchParameter(it) => it.span(),
             Self::DebuggerStatement(it) => it.span(),
+            Self::ErrorStatement(it) => it.span(),
             Self::AssignmentPattern(it) => it.span(),
             Self::ObjectPattern(it) => it.span(),
             Self::ArrayPattern(it) => it.span(),
@@ -527,6 +530,207 @@ impl<'a> GetSpan for AstKind<'a> {
     }
 }
 
+impl<'a> ContentEq for AstKind<'a> {
+    #[allow(clippy::match_same_arms)]
+    fn content_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::BooleanLiteral(a), Self::BooleanLiteral(b)) => a.content_eq(b),
+            (Self::NullLiteral(a), Self::NullLiteral(b)) => a.content_eq(b),
+            (Self::NumericLiteral(a), Self::NumericLiteral(b)) => a.content_eq(b),
+            (Self::BigIntLiteral(a), Self::BigIntLiteral(b)) => a.content_eq(b),
+            (Self::RegExpLiteral(a), Self::RegExpLiteral(b)) => a.content_eq(b),
+            (Self::StringLiteral(a), Self::StringLiteral(b)) => a.content_eq(b),
+            (Self::Program(a), Self::Program(b)) => a.content_eq(b),
+            (Self::IdentifierName(a), Self::IdentifierName(b)) => a.content_eq(b),
+            (Self::IdentifierReference(a), Self::IdentifierReference(b)) => a.content_eq(b),
+            (Self::BindingIdentifier(a), Self::BindingIdentifier(b)) => a.content_eq(b),
+            (Self::LabelIdentifier(a), Self::LabelIdentifier(b)) => a.content_eq(b),
+            (Self::ThisExpression(a), Self::ThisExpression(b)) => a.content_eq(b),
+            (Self::ArrayExpression(a), Self::ArrayExpression(b)) => a.content_eq(b),
+            (Self::ArrayExpressionElement(a), Self::ArrayExpressionElement(b)) => a.content_eq(b),
+            (Self::Elision(a), Self::Elision(b)) => a.content_eq(b),
+            (Self::ObjectExpression(a), Self::ObjectExpression(b)) => a.content_eq(b),
+            (Self::ObjectProperty(a), Self::ObjectProperty(b)) => a.content_eq(b),
+            (Self::PropertyKey(a), Self::PropertyKey(b)) => a.content_eq(b),
+            (Self::TemplateLiteral(a), Self::TemplateLiteral(b)) => a.content_eq(b),
+            (Self::TaggedTemplateExpression(a), Self::TaggedTemplateExpression(b)) => {
+                a.content_eq(b)
+            }
+            (Self::MemberExpression(a), Self::MemberExpression(b)) => a.content_eq(b),
+            (Self::CallExpression(a), Self::CallExpression(b)) => a.content_eq(b),
+            (Self::NewExpression(a), Self::NewExpression(b)) => a.content_eq(b),
+            (Self::MetaProperty(a), Self::MetaProperty(b)) => a.content_eq(b),
+            (Self::SpreadElement(a), Self::SpreadElement(b)) => a.content_eq(b),
+            (Self::Argument(a), Self::Argument(b)) => a.content_eq(b),
+            (Self::UpdateExpression(a), Self::UpdateExpression(b)) => a.content_eq(b),
+            (Self::UnaryExpression(a), Self::UnaryExpression(b)) => a.content_eq(b),
+            (Self::BinaryExpression(a), Self::BinaryExpression(b)) => a.content_eq(b),
+            (Self::PrivateInExpression(a), Self::PrivateInExpression(b)) => a.content_eq(b),
+            (Self::LogicalExpression(a), Self::LogicalExpression(b)) => a.content_eq(b),
+            (Self::ConditionalExpression(a), Self::ConditionalExpression(b)) => a.content_eq(b),
+            (Self::AssignmentExpression(a), Self::AssignmentExpression(b)) => a.content_eq(b),
+            (Self::AssignmentTarget(a), Self::AssignmentTarget(b)) => a.content_eq(b),
+            (Self::SimpleAssignmentTarget(a), Self::SimpleAssignmentTarget(b)) => a.content_eq(b),
+            (Self::AssignmentTargetPattern(a), Self::AssignmentTargetPattern(b)) => a.content_eq(b),
+            (Self::ArrayAssignmentTarget(a), Self::ArrayAssignmentTarget(b)) => a.content_eq(b),
+            (Self::ObjectAssignmentTarget(a), Self::ObjectAssignmentTarget(b)) => a.content_eq(b),
+            (Self::AssignmentTargetWithDefault(a), Self::AssignmentTargetWithDefault(b)) => {
+                a.content_eq(b)
+            }
+            (Self::SequenceExpression(a), Self::SequenceExpression(b)) => a.content_eq(b),
+            (Self::Super(a), Self::Super(b)) => a.content_eq(b),
+            (Self::AwaitExpression(a), Self::AwaitExpression(b)) => a.content_eq(b),
+            (Self::ChainExpression(a), Self::ChainExpression(b)) => a.content_eq(b),
+            (Self::ParenthesizedExpression(a), Self::ParenthesizedExpression(b)) => a.content_eq(b),
+            (Self::Directive(a), Self::Directive(b)) => a.content_eq(b),
+            (Self::Hashbang(a), Self::Hashbang(b)) => a.content_eq(b),
+            (Self::BlockStatement(a), Self::BlockStatement(b)) => a.content_eq(b),
+            (Self::VariableDeclaration(a), Self::VariableDeclaration(b)) => a.content_eq(b),
+            (Self::VariableDeclarator(a), Self::VariableDeclarator(b)) => a.content_eq(b),
+            (Self::EmptyStatement(a), Self::EmptyStatement(b)) => a.content_eq(b),
+            (Self::ExpressionStatement(a), Self::ExpressionStatement(b)) => a.content_eq(b),
+            (Self::IfStatement(a), Self::IfStatement(b)) => a.content_eq(b),
+            (Self::DoWhileStatement(a), Self::DoWhileStatement(b)) => a.content_eq(b),
+            (Self::WhileStatement(a), Self::WhileStatement(b)) => a.content_eq(b),
+            (Self::ForStatement(a), Self::ForStatement(b)) => a.content_eq(b),
+            (Self::ForStatementInit(a), Self::ForStatementInit(b)) => a.content_eq(b),
+            (Self::ForInStatement(a), Self::ForInStatement(b)) => a.content_eq(b),
+            (Self::ForOfStatement(a), Self::ForOfStatement(b)) => a.content_eq(b),
+            (Self::ContinueStatement(a), Self::ContinueStatement(b)) => a.content_eq(b),
+            (Self::BreakStatement(a), Self::BreakStatement(b)) => a.content_eq(b),
+            (Self::ReturnStatement(a), Self::ReturnStatement(b)) => a.content_eq(b),
+            (Self::WithStatement(a), Self::WithStatement(b)) => a.content_eq(b),
+            (Self::SwitchStatement(a), Self::SwitchStatement(b)) => a.content_eq(b),
+            (Self::SwitchCase(a), Self::SwitchCase(b)) => a.content_eq(b),
+            (Self::LabeledStatement(a), Self::LabeledStatement(b)) => a.content_eq(b),
+            (Self::ThrowStatement(a), Self::ThrowStatement(b)) => a.content_eq(b),
+            (Self::TryStatement(a), Self::TryStatement(b)) => a.content_eq(b),
+            (Self::FinallyClause(a), Self::FinallyClause(b)) => a.content_eq(b),
+            (Self::CatchClause(a), Self::CatchClause(b)) => a.content_eq(b),
+            (Self::CatchParameter(a), Self::CatchParameter(b)) => a.content_eq(b),
+            (Self::DebuggerStatement(a), Self::DebuggerStatement(b)) => a.content_eq(b),
+            (Self::ErrorStatement(a), Self::ErrorStatement(b)) => a.content_eq(b),
+            (Self::AssignmentPattern(a), Self::AssignmentPattern(b)) => a.content_eq(b),
+            (Self::ObjectPattern(a), Self::ObjectPattern(b)) => a.content_eq(b),
+            (Self::ArrayPattern(a), Self::ArrayPattern(b)) => a.content_eq(b),
+            (Self::BindingRestElement(a), Self::BindingRestElement(b)) => a.content_eq(b),
+            (Self::Function(a), Self::Function(b)) => a.content_eq(b),
+            (Self::FormalParameters(a), Self::FormalParameters(b)) => a.content_eq(b),
+            (Self::FormalParameter(a), Self::FormalParameter(b)) => a.content_eq(b),
+            (Self::FunctionBody(a), Self::FunctionBody(b)) => a.content_eq(b),
+            (Self::ArrowFunctionExpression(a), Self::ArrowFunctionExpression(b)) => a.content_eq(b),
+            (Self::YieldExpression(a), Self::YieldExpression(b)) => a.content_eq(b),
+            (Self::Class(a), Self::Class(b)) => a.content_eq(b),
+            (Self::ClassHeritage(a), Self::ClassHeritage(b)) => a.content_eq(b),
+            (Self::ClassBody(a), Self::ClassBody(b)) => a.content_eq(b),
+            (Self::MethodDefinition(a), Self::MethodDefinition(b)) => a.content_eq(b),
+            (Self::PropertyDefinition(a), Self::PropertyDefinition(b)) => a.content_eq(b),
+            (Self::PrivateIdentifier(a), Self::PrivateIdentifier(b)) => a.content_eq(b),
+            (Self::StaticBlock(a), Self::StaticBlock(b)) => a.content_eq(b),
+            (Self::ModuleDeclaration(a), Self::ModuleDeclaration(b)) => a.content_eq(b),
+            (Self::ImportExpression(a), Self::ImportExpression(b)) => a.content_eq(b),
+            (Self::ImportDeclaration(a), Self::ImportDeclaration(b)) => a.content_eq(b),
+            (Self::ImportSpecifier(a), Self::ImportSpecifier(b)) => a.content_eq(b),
+            (Self::ImportDefaultSpecifier(a), Self::ImportDefaultSpecifier(b)) => a.content_eq(b),
+            (Self::ImportNamespaceSpecifier(a), Self::ImportNamespaceSpecifier(b)) => {
+                a.content_eq(b)
+            }
+            (Self::ExportNamedDeclaration(a), Self::ExportNamedDeclaration(b)) => a.content_eq(b),
+            (Self::ExportDefaultDeclaration(a), Self::ExportDefaultDeclaration(b)) => {
+                a.content_eq(b)
+            }
+            (Self::ExportAllDeclaration(a), Self::ExportAllDeclaration(b)) => a.content_eq(b),
+            (Self::ExportSpecifier(a), Self::ExportSpecifier(b)) => a.content_eq(b),
+            (Self::TSThisParameter(a), Self::TSThisParameter(b)) => a.content_eq(b),
+            (Self::TSEnumDeclaration(a), Self::TSEnumDeclaration(b)) => a.content_eq(b),
+            (Self::TSEnumMember(a), Self::TSEnumMember(b)) => a.content_eq(b),
+            (Self::TSTypeAnnotation(a), Self::TSTypeAnnotation(b)) => a.content_eq(b),
+            (Self::TSLiteralType(a), Self::TSLiteralType(b)) => a.content_eq(b),
+            (Self::TSConditionalType(a), Self::TSConditionalType(b)) => a.content_eq(b),
+            (Self::TSUnionType(a), Self::TSUnionType(b)) => a.content_eq(b),
+            (Self::TSIntersectionType(a), Self::TSIntersectionType(b)) => a.content_eq(b),
+            (Self::TSParenthesizedType(a), Self::TSParenthesizedType(b)) => a.content_eq(b),
+            (Self::TSIndexedAccessType(a), Self::TSIndexedAccessType(b)) => a.content_eq(b),
+            (Self::TSNamedTupleMember(a), Self::TSNamedTupleMember(b)) => a.content_eq(b),
+            (Self::TSAnyKeyword(a), Self::TSAnyKeyword(b)) => a.content_eq(b),
+            (Self::TSStringKeyword(a), Self::TSStringKeyword(b)) => a.content_eq(b),
+            (Self::TSBooleanKeyword(a), Self::TSBooleanKeyword(b)) => a.content_eq(b),
+            (Self::TSNumberKeyword(a), Self::TSNumberKeyword(b)) => a.content_eq(b),
+            (Self::TSNeverKeyword(a), Self::TSNeverKeyword(b)) => a.content_eq(b),
+            (Self::TSIntrinsicKeyword(a), Self::TSIntrinsicKeyword(b)) => a.content_eq(b),
+            (Self::TSUnknownKeyword(a), Self::TSUnknownKeyword(b)) => a.content_eq(b),
+            (Self::TSNullKeyword(a), Self::TSNullKeyword(b)) => a.content_eq(b),
+            (Self::TSUndefinedKeyword(a), Self::TSUndefinedKeyword(b)) => a.content_eq(b),
+            (Self::TSVoidKeyword(a), Self::TSVoidKeyword(b)) => a.content_eq(b),
+            (Self::TSSymbolKeyword(a), Self::TSSymbolKeyword(b)) => a.content_eq(b),
+            (Self::TSThisType(a), Self::TSThisType(b)) => a.content_eq(b),
+            (Self::TSObjectKeyword(a), Self::TSObjectKeyword(b)) => a.content_eq(b),
+            (Self::TSBigIntKeyword(a), Self::TSBigIntKeyword(b)) => a.content_eq(b),
+            (Self::TSTypeReference(a), Self::TSTypeReference(b)) => a.content_eq(b),
+            (Self::TSTypeName(a), Self::TSTypeName(b)) => a.content_eq(b),
+            (Self::TSQualifiedName(a), Self::TSQualifiedName(b)) => a.content_eq(b),
+            (Self::TSTypeParameterInstantiation(a), Self::TSTypeParameterInstantiation(b)) => {
+                a.content_eq(b)
+            }
+            (Self::TSTypeParameter(a), Self::TSTypeParameter(b)) => a.content_eq(b),
+            (Self::TSTypeParameterDeclaration(a), Self::TSTypeParameterDeclaration(b)) => {
+                a.content_eq(b)
+            }
+            (Self::TSTypeAliasDeclaration(a), Self::TSTypeAliasDeclaration(b)) => a.content_eq(b),
+            (Self::TSClassImplements(a), Self::TSClassImplements(b)) => a.content_eq(b),
+            (Self::TSInterfaceDeclaration(a), Self::TSInterfaceDeclaration(b)) => a.content_eq(b),
+            (Self::TSPropertySignature(a), Self::TSPropertySignature(b)) => a.content_eq(b),
+            (Self::TSMethodSignature(a), Self::TSMethodSignature(b)) => a.content_eq(b),
+            (
+                Self::TSConstructSignatureDeclaration(a),
+                Self::TSConstructSignatureDeclaration(b),
+            ) => a.content_eq(b),
+            (Self::TSInterfaceHeritage(a), Self::TSInterfaceHeritage(b)) => a.content_eq(b),
+            (Self::TSModuleDeclaration(a), Self::TSModuleDeclaration(b)) => a.content_eq(b),
+            (Self::TSModuleBlock(a), Self::TSModuleBlock(b)) => a.content_eq(b),
+            (Self::TSTypeLiteral(a), Self::TSTypeLiteral(b)) => a.content_eq(b),
+            (Self::TSInferType(a), Self::TSInferType(b)) => a.content_eq(b),
+            (Self::TSTypeQuery(a), Self::TSTypeQuery(b)) => a.content_eq(b),
+            (Self::TSImportType(a), Self::TSImportType(b)) => a.content_eq(b),
+            (Self::TSMappedType(a), Self::TSMappedType(b)) => a.content_eq(b),
+            (Self::TSTemplateLiteralType(a), Self::TSTemplateLiteralType(b)) => a.content_eq(b),
+            (Self::TSAsExpression(a), Self::TSAsExpression(b)) => a.content_eq(b),
+            (Self::TSSatisfiesExpression(a), Self::TSSatisfiesExpression(b)) => a.content_eq(b),
+            (Self::TSTypeAssertion(a), Self::TSTypeAssertion(b)) => a.content_eq(b),
+            (Self::TSImportEqualsDeclaration(a), Self::TSImportEqualsDeclaration(b)) => {
+                a.content_eq(b)
+            }
+            (Self::TSModuleReference(a), Self::TSModuleReference(b)) => a.content_eq(b),
+            (Self::TSExternalModuleReference(a), Self::TSExternalModuleReference(b)) => {
+                a.content_eq(b)
+            }
+            (Self::TSNonNullExpression(a), Self::TSNonNullExpression(b)) => a.content_eq(b),
+            (Self::Decorator(a), Self::Decorator(b)) => a.content_eq(b),
+            (Self::TSExportAssignment(a), Self::TSExportAssignment(b)) => a.content_eq(b),
+            (Self::TSInstantiationExpression(a), Self::TSInstantiationExpression(b)) => {
+                a.content_eq(b)
+            }
+            (Self::JSXElement(a), Self::JSXElement(b)) => a.content_eq(b),
+            (Self::JSXOpeningElement(a), Self::JSXOpeningElement(b)) => a.content_eq(b),
+            (Self::JSXClosingElement(a), Self::JSXClosingElement(b)) => a.content_eq(b),
+            (Self::JSXFragment(a), Self::JSXFragment(b)) => a.content_eq(b),
+            (Self::JSXElementName(a), Self::JSXElementName(b)) => a.content_eq(b),
+            (Self::JSXNamespacedName(a), Self::JSXNamespacedName(b)) => a.content_eq(b),
+            (Self::JSXMemberExpression(a), Self::JSXMemberExpression(b)) => a.content_eq(b),
+            (Self::JSXMemberExpressionObject(a), Self::JSXMemberExpressionObject(b)) => {
+                a.content_eq(b)
+            }
+            (Self::JSXExpressionContainer(a), Self::JSXExpressionContainer(b)) => a.content_eq(b),
+            (Self::JSXAttributeItem(a), Self::JSXAttributeItem(b)) => a.content_eq(b),
+            (Self::JSXSpreadAttribute(a), Self::JSXSpreadAttribute(b)) => a.content_eq(b),
+            (Self::JSXIdentifier(a), Self::JSXIdentifier(b)) => a.content_eq(b),
+            (Self::JSXText(a), Self::JSXText(b)) => a.content_eq(b),
+            (Self::ExpressionArrayElement(a), Self::ExpressionArrayElement(b)) => a.content_eq(b),
+            _ => false,
+        }
+    }
+}
+
 impl<'a> AstKind<'a> {
     #[inline]
     pub fn as_boolean_literal(&self) -> Option<&'a BooleanLiteral> {
@@ -1167,6 +1371,15 @@ impl<'a> AstKind<'a> {
         }
     }
 
+    #[inline]
+    pub fn as_error_statement(&self) -> Option<&'a ErrorStatement> {
+        if let Self::ErrorStatement(v) = self {
+            Some(*v)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     pub fn as_assignment_pattern(&self) -> Option<&'a AssignmentPattern<'a>> {
         if let Self::AssignmentPattern(v) = self {
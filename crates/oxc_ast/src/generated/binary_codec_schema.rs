@@ -0,0 +1,9 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `tasks/ast_tools/src/generators/binary_codec_schema.rs`
+
+/// Hash of definitions of all AST types which implement `BinaryCodec`,
+/// and of hand-written `BinaryCodec` implementations for semantic data
+/// (`SymbolTable`, `ScopeTree`, and the types they contain).
+///
+/// Changes whenever the binary format of the AST changes.
+pub const AST_SCHEMA_HASH: u64 = 0xb6ac_2186_3c47_4f94;
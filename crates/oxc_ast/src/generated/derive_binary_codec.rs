@@ -0,0 +1,6914 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `tasks/ast_tools/src/derives/binary_codec.rs`
+
+#![allow(clippy::match_same_arms, clippy::semicolon_if_nothing_returned)]
+
+use oxc_allocator::{BinaryCodec, DecodeError, Decoder, Encoder};
+
+#[allow(clippy::wildcard_imports)]
+use crate::ast::comment::*;
+
+#[allow(clippy::wildcard_imports)]
+use crate::ast::js::*;
+
+#[allow(clippy::wildcard_imports)]
+use crate::ast::jsx::*;
+
+#[allow(clippy::wildcard_imports)]
+use crate::ast::literal::*;
+
+#[allow(clippy::wildcard_imports)]
+use crate::ast::ts::*;
+
+impl<'a> BinaryCodec<'a> for BooleanLiteral {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.value, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, value: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for NullLiteral {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for NumericLiteral<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.value, encoder);
+        BinaryCodec::encode(&self.raw, encoder);
+        BinaryCodec::encode(&self.base, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            value: BinaryCodec::decode(decoder)?,
+            raw: BinaryCodec::decode(decoder)?,
+            base: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for BigIntLiteral<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.raw, encoder);
+        BinaryCodec::encode(&self.base, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            raw: BinaryCodec::decode(decoder)?,
+            base: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for RegExpLiteral<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.value, encoder);
+        BinaryCodec::encode(&self.regex, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            value: BinaryCodec::decode(decoder)?,
+            regex: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for RegExp<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.pattern, encoder);
+        BinaryCodec::encode(&self.flags, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { pattern: BinaryCodec::decode(decoder)?, flags: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for RegExpPattern<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Raw(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Invalid(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Pattern(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Raw(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::Invalid(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::Pattern(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for EmptyObject {
+    fn encode(&self, _: &mut Encoder) {}
+
+    fn decode(_: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {})
+    }
+}
+
+impl<'a> BinaryCodec<'a> for StringLiteral<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.value, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, value: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Program<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.source_type, encoder);
+        BinaryCodec::encode(&self.source_text, encoder);
+        BinaryCodec::encode(&self.comments, encoder);
+        BinaryCodec::encode(&self.hashbang, encoder);
+        BinaryCodec::encode(&self.directives, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+        BinaryCodec::encode(&self.scope_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            source_type: BinaryCodec::decode(decoder)?,
+            source_text: BinaryCodec::decode(decoder)?,
+            comments: BinaryCodec::decode(decoder)?,
+            hashbang: BinaryCodec::decode(decoder)?,
+            directives: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+            scope_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Expression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::BooleanLiteral(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NullLiteral(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NumericLiteral(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BigIntLiteral(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::RegExpLiteral(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StringLiteral(it) => {
+                encoder.write_u32(5u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TemplateLiteral(it) => {
+                encoder.write_u32(6u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Identifier(it) => {
+                encoder.write_u32(7u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::MetaProperty(it) => {
+                encoder.write_u32(8u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Super(it) => {
+                encoder.write_u32(9u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ArrayExpression(it) => {
+                encoder.write_u32(10u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ArrowFunctionExpression(it) => {
+                encoder.write_u32(11u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AssignmentExpression(it) => {
+                encoder.write_u32(12u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AwaitExpression(it) => {
+                encoder.write_u32(13u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BinaryExpression(it) => {
+                encoder.write_u32(14u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::CallExpression(it) => {
+                encoder.write_u32(15u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ChainExpression(it) => {
+                encoder.write_u32(16u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ClassExpression(it) => {
+                encoder.write_u32(17u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ConditionalExpression(it) => {
+                encoder.write_u32(18u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::FunctionExpression(it) => {
+                encoder.write_u32(19u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ImportExpression(it) => {
+                encoder.write_u32(20u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::LogicalExpression(it) => {
+                encoder.write_u32(21u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NewExpression(it) => {
+                encoder.write_u32(22u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ObjectExpression(it) => {
+                encoder.write_u32(23u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ParenthesizedExpression(it) => {
+                encoder.write_u32(24u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::SequenceExpression(it) => {
+                encoder.write_u32(25u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TaggedTemplateExpression(it) => {
+                encoder.write_u32(26u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ThisExpression(it) => {
+                encoder.write_u32(27u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::UnaryExpression(it) => {
+                encoder.write_u32(28u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::UpdateExpression(it) => {
+                encoder.write_u32(29u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::YieldExpression(it) => {
+                encoder.write_u32(30u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateInExpression(it) => {
+                encoder.write_u32(31u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSXElement(it) => {
+                encoder.write_u32(32u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSXFragment(it) => {
+                encoder.write_u32(33u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSAsExpression(it) => {
+                encoder.write_u32(34u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                encoder.write_u32(35u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeAssertion(it) => {
+                encoder.write_u32(36u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNonNullExpression(it) => {
+                encoder.write_u32(37u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSInstantiationExpression(it) => {
+                encoder.write_u32(38u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ComputedMemberExpression(it) => {
+                encoder.write_u32(39u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StaticMemberExpression(it) => {
+                encoder.write_u32(40u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateFieldExpression(it) => {
+                encoder.write_u32(41u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::BooleanLiteral(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::NullLiteral(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::NumericLiteral(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::BigIntLiteral(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::RegExpLiteral(BinaryCodec::decode(decoder)?)),
+            5u32 => Ok(Self::StringLiteral(BinaryCodec::decode(decoder)?)),
+            6u32 => Ok(Self::TemplateLiteral(BinaryCodec::decode(decoder)?)),
+            7u32 => Ok(Self::Identifier(BinaryCodec::decode(decoder)?)),
+            8u32 => Ok(Self::MetaProperty(BinaryCodec::decode(decoder)?)),
+            9u32 => Ok(Self::Super(BinaryCodec::decode(decoder)?)),
+            10u32 => Ok(Self::ArrayExpression(BinaryCodec::decode(decoder)?)),
+            11u32 => Ok(Self::ArrowFunctionExpression(BinaryCodec::decode(decoder)?)),
+            12u32 => Ok(Self::AssignmentExpression(BinaryCodec::decode(decoder)?)),
+            13u32 => Ok(Self::AwaitExpression(BinaryCodec::decode(decoder)?)),
+            14u32 => Ok(Self::BinaryExpression(BinaryCodec::decode(decoder)?)),
+            15u32 => Ok(Self::CallExpression(BinaryCodec::decode(decoder)?)),
+            16u32 => Ok(Self::ChainExpression(BinaryCodec::decode(decoder)?)),
+            17u32 => Ok(Self::ClassExpression(BinaryCodec::decode(decoder)?)),
+            18u32 => Ok(Self::ConditionalExpression(BinaryCodec::decode(decoder)?)),
+            19u32 => Ok(Self::FunctionExpression(BinaryCodec::decode(decoder)?)),
+            20u32 => Ok(Self::ImportExpression(BinaryCodec::decode(decoder)?)),
+            21u32 => Ok(Self::LogicalExpression(BinaryCodec::decode(decoder)?)),
+            22u32 => Ok(Self::NewExpression(BinaryCodec::decode(decoder)?)),
+            23u32 => Ok(Self::ObjectExpression(BinaryCodec::decode(decoder)?)),
+            24u32 => Ok(Self::ParenthesizedExpression(BinaryCodec::decode(decoder)?)),
+            25u32 => Ok(Self::SequenceExpression(BinaryCodec::decode(decoder)?)),
+            26u32 => Ok(Self::TaggedTemplateExpression(BinaryCodec::decode(decoder)?)),
+            27u32 => Ok(Self::ThisExpression(BinaryCodec::decode(decoder)?)),
+            28u32 => Ok(Self::UnaryExpression(BinaryCodec::decode(decoder)?)),
+            29u32 => Ok(Self::UpdateExpression(BinaryCodec::decode(decoder)?)),
+            30u32 => Ok(Self::YieldExpression(BinaryCodec::decode(decoder)?)),
+            31u32 => Ok(Self::PrivateInExpression(BinaryCodec::decode(decoder)?)),
+            32u32 => Ok(Self::JSXElement(BinaryCodec::decode(decoder)?)),
+            33u32 => Ok(Self::JSXFragment(BinaryCodec::decode(decoder)?)),
+            34u32 => Ok(Self::TSAsExpression(BinaryCodec::decode(decoder)?)),
+            35u32 => Ok(Self::TSSatisfiesExpression(BinaryCodec::decode(decoder)?)),
+            36u32 => Ok(Self::TSTypeAssertion(BinaryCodec::decode(decoder)?)),
+            37u32 => Ok(Self::TSNonNullExpression(BinaryCodec::decode(decoder)?)),
+            38u32 => Ok(Self::TSInstantiationExpression(BinaryCodec::decode(decoder)?)),
+            39u32 => Ok(Self::ComputedMemberExpression(BinaryCodec::decode(decoder)?)),
+            40u32 => Ok(Self::StaticMemberExpression(BinaryCodec::decode(decoder)?)),
+            41u32 => Ok(Self::PrivateFieldExpression(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for IdentifierName<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.name, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, name: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for IdentifierReference<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.name, encoder);
+        BinaryCodec::encode(&self.reference_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            name: BinaryCodec::decode(decoder)?,
+            reference_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for BindingIdentifier<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.name, encoder);
+        BinaryCodec::encode(&self.symbol_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            name: BinaryCodec::decode(decoder)?,
+            symbol_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for LabelIdentifier<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.name, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, name: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ThisExpression {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ArrayExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.elements, encoder);
+        BinaryCodec::encode(&self.trailing_comma, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            elements: BinaryCodec::decode(decoder)?,
+            trailing_comma: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ArrayExpressionElement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::SpreadElement(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Elision(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BooleanLiteral(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NullLiteral(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NumericLiteral(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BigIntLiteral(it) => {
+                encoder.write_u32(5u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::RegExpLiteral(it) => {
+                encoder.write_u32(6u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StringLiteral(it) => {
+                encoder.write_u32(7u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TemplateLiteral(it) => {
+                encoder.write_u32(8u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Identifier(it) => {
+                encoder.write_u32(9u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::MetaProperty(it) => {
+                encoder.write_u32(10u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Super(it) => {
+                encoder.write_u32(11u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ArrayExpression(it) => {
+                encoder.write_u32(12u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ArrowFunctionExpression(it) => {
+                encoder.write_u32(13u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AssignmentExpression(it) => {
+                encoder.write_u32(14u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AwaitExpression(it) => {
+                encoder.write_u32(15u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BinaryExpression(it) => {
+                encoder.write_u32(16u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::CallExpression(it) => {
+                encoder.write_u32(17u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ChainExpression(it) => {
+                encoder.write_u32(18u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ClassExpression(it) => {
+                encoder.write_u32(19u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ConditionalExpression(it) => {
+                encoder.write_u32(20u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::FunctionExpression(it) => {
+                encoder.write_u32(21u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ImportExpression(it) => {
+                encoder.write_u32(22u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::LogicalExpression(it) => {
+                encoder.write_u32(23u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NewExpression(it) => {
+                encoder.write_u32(24u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ObjectExpression(it) => {
+                encoder.write_u32(25u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ParenthesizedExpression(it) => {
+                encoder.write_u32(26u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::SequenceExpression(it) => {
+                encoder.write_u32(27u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TaggedTemplateExpression(it) => {
+                encoder.write_u32(28u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ThisExpression(it) => {
+                encoder.write_u32(29u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::UnaryExpression(it) => {
+                encoder.write_u32(30u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::UpdateExpression(it) => {
+                encoder.write_u32(31u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::YieldExpression(it) => {
+                encoder.write_u32(32u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateInExpression(it) => {
+                encoder.write_u32(33u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSXElement(it) => {
+                encoder.write_u32(34u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSXFragment(it) => {
+                encoder.write_u32(35u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSAsExpression(it) => {
+                encoder.write_u32(36u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                encoder.write_u32(37u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeAssertion(it) => {
+                encoder.write_u32(38u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNonNullExpression(it) => {
+                encoder.write_u32(39u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSInstantiationExpression(it) => {
+                encoder.write_u32(40u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ComputedMemberExpression(it) => {
+                encoder.write_u32(41u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StaticMemberExpression(it) => {
+                encoder.write_u32(42u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateFieldExpression(it) => {
+                encoder.write_u32(43u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::SpreadElement(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::Elision(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::BooleanLiteral(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::NullLiteral(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::NumericLiteral(BinaryCodec::decode(decoder)?)),
+            5u32 => Ok(Self::BigIntLiteral(BinaryCodec::decode(decoder)?)),
+            6u32 => Ok(Self::RegExpLiteral(BinaryCodec::decode(decoder)?)),
+            7u32 => Ok(Self::StringLiteral(BinaryCodec::decode(decoder)?)),
+            8u32 => Ok(Self::TemplateLiteral(BinaryCodec::decode(decoder)?)),
+            9u32 => Ok(Self::Identifier(BinaryCodec::decode(decoder)?)),
+            10u32 => Ok(Self::MetaProperty(BinaryCodec::decode(decoder)?)),
+            11u32 => Ok(Self::Super(BinaryCodec::decode(decoder)?)),
+            12u32 => Ok(Self::ArrayExpression(BinaryCodec::decode(decoder)?)),
+            13u32 => Ok(Self::ArrowFunctionExpression(BinaryCodec::decode(decoder)?)),
+            14u32 => Ok(Self::AssignmentExpression(BinaryCodec::decode(decoder)?)),
+            15u32 => Ok(Self::AwaitExpression(BinaryCodec::decode(decoder)?)),
+            16u32 => Ok(Self::BinaryExpression(BinaryCodec::decode(decoder)?)),
+            17u32 => Ok(Self::CallExpression(BinaryCodec::decode(decoder)?)),
+            18u32 => Ok(Self::ChainExpression(BinaryCodec::decode(decoder)?)),
+            19u32 => Ok(Self::ClassExpression(BinaryCodec::decode(decoder)?)),
+            20u32 => Ok(Self::ConditionalExpression(BinaryCodec::decode(decoder)?)),
+            21u32 => Ok(Self::FunctionExpression(BinaryCodec::decode(decoder)?)),
+            22u32 => Ok(Self::ImportExpression(BinaryCodec::decode(decoder)?)),
+            23u32 => Ok(Self::LogicalExpression(BinaryCodec::decode(decoder)?)),
+            24u32 => Ok(Self::NewExpression(BinaryCodec::decode(decoder)?)),
+            25u32 => Ok(Self::ObjectExpression(BinaryCodec::decode(decoder)?)),
+            26u32 => Ok(Self::ParenthesizedExpression(BinaryCodec::decode(decoder)?)),
+            27u32 => Ok(Self::SequenceExpression(BinaryCodec::decode(decoder)?)),
+            28u32 => Ok(Self::TaggedTemplateExpression(BinaryCodec::decode(decoder)?)),
+            29u32 => Ok(Self::ThisExpression(BinaryCodec::decode(decoder)?)),
+            30u32 => Ok(Self::UnaryExpression(BinaryCodec::decode(decoder)?)),
+            31u32 => Ok(Self::UpdateExpression(BinaryCodec::decode(decoder)?)),
+            32u32 => Ok(Self::YieldExpression(BinaryCodec::decode(decoder)?)),
+            33u32 => Ok(Self::PrivateInExpression(BinaryCodec::decode(decoder)?)),
+            34u32 => Ok(Self::JSXElement(BinaryCodec::decode(decoder)?)),
+            35u32 => Ok(Self::JSXFragment(BinaryCodec::decode(decoder)?)),
+            36u32 => Ok(Self::TSAsExpression(BinaryCodec::decode(decoder)?)),
+            37u32 => Ok(Self::TSSatisfiesExpression(BinaryCodec::decode(decoder)?)),
+            38u32 => Ok(Self::TSTypeAssertion(BinaryCodec::decode(decoder)?)),
+            39u32 => Ok(Self::TSNonNullExpression(BinaryCodec::decode(decoder)?)),
+            40u32 => Ok(Self::TSInstantiationExpression(BinaryCodec::decode(decoder)?)),
+            41u32 => Ok(Self::ComputedMemberExpression(BinaryCodec::decode(decoder)?)),
+            42u32 => Ok(Self::StaticMemberExpression(BinaryCodec::decode(decoder)?)),
+            43u32 => Ok(Self::PrivateFieldExpression(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Elision {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ObjectExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.properties, encoder);
+        BinaryCodec::encode(&self.trailing_comma, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            properties: BinaryCodec::decode(decoder)?,
+            trailing_comma: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ObjectPropertyKind<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::ObjectProperty(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::SpreadProperty(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::ObjectProperty(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::SpreadProperty(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ObjectProperty<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.kind, encoder);
+        BinaryCodec::encode(&self.key, encoder);
+        BinaryCodec::encode(&self.value, encoder);
+        BinaryCodec::encode(&self.init, encoder);
+        BinaryCodec::encode(&self.method, encoder);
+        BinaryCodec::encode(&self.shorthand, encoder);
+        BinaryCodec::encode(&self.computed, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            kind: BinaryCodec::decode(decoder)?,
+            key: BinaryCodec::decode(decoder)?,
+            value: BinaryCodec::decode(decoder)?,
+            init: BinaryCodec::decode(decoder)?,
+            method: BinaryCodec::decode(decoder)?,
+            shorthand: BinaryCodec::decode(decoder)?,
+            computed: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for PropertyKey<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::StaticIdentifier(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateIdentifier(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BooleanLiteral(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NullLiteral(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NumericLiteral(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BigIntLiteral(it) => {
+                encoder.write_u32(5u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::RegExpLiteral(it) => {
+                encoder.write_u32(6u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StringLiteral(it) => {
+                encoder.write_u32(7u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TemplateLiteral(it) => {
+                encoder.write_u32(8u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Identifier(it) => {
+                encoder.write_u32(9u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::MetaProperty(it) => {
+                encoder.write_u32(10u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Super(it) => {
+                encoder.write_u32(11u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ArrayExpression(it) => {
+                encoder.write_u32(12u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ArrowFunctionExpression(it) => {
+                encoder.write_u32(13u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AssignmentExpression(it) => {
+                encoder.write_u32(14u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AwaitExpression(it) => {
+                encoder.write_u32(15u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BinaryExpression(it) => {
+                encoder.write_u32(16u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::CallExpression(it) => {
+                encoder.write_u32(17u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ChainExpression(it) => {
+                encoder.write_u32(18u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ClassExpression(it) => {
+                encoder.write_u32(19u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ConditionalExpression(it) => {
+                encoder.write_u32(20u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::FunctionExpression(it) => {
+                encoder.write_u32(21u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ImportExpression(it) => {
+                encoder.write_u32(22u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::LogicalExpression(it) => {
+                encoder.write_u32(23u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NewExpression(it) => {
+                encoder.write_u32(24u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ObjectExpression(it) => {
+                encoder.write_u32(25u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ParenthesizedExpression(it) => {
+                encoder.write_u32(26u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::SequenceExpression(it) => {
+                encoder.write_u32(27u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TaggedTemplateExpression(it) => {
+                encoder.write_u32(28u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ThisExpression(it) => {
+                encoder.write_u32(29u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::UnaryExpression(it) => {
+                encoder.write_u32(30u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::UpdateExpression(it) => {
+                encoder.write_u32(31u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::YieldExpression(it) => {
+                encoder.write_u32(32u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateInExpression(it) => {
+                encoder.write_u32(33u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSXElement(it) => {
+                encoder.write_u32(34u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSXFragment(it) => {
+                encoder.write_u32(35u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSAsExpression(it) => {
+                encoder.write_u32(36u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                encoder.write_u32(37u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeAssertion(it) => {
+                encoder.write_u32(38u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNonNullExpression(it) => {
+                encoder.write_u32(39u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSInstantiationExpression(it) => {
+                encoder.write_u32(40u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ComputedMemberExpression(it) => {
+                encoder.write_u32(41u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StaticMemberExpression(it) => {
+                encoder.write_u32(42u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateFieldExpression(it) => {
+                encoder.write_u32(43u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::StaticIdentifier(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::PrivateIdentifier(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::BooleanLiteral(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::NullLiteral(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::NumericLiteral(BinaryCodec::decode(decoder)?)),
+            5u32 => Ok(Self::BigIntLiteral(BinaryCodec::decode(decoder)?)),
+            6u32 => Ok(Self::RegExpLiteral(BinaryCodec::decode(decoder)?)),
+            7u32 => Ok(Self::StringLiteral(BinaryCodec::decode(decoder)?)),
+            8u32 => Ok(Self::TemplateLiteral(BinaryCodec::decode(decoder)?)),
+            9u32 => Ok(Self::Identifier(BinaryCodec::decode(decoder)?)),
+            10u32 => Ok(Self::MetaProperty(BinaryCodec::decode(decoder)?)),
+            11u32 => Ok(Self::Super(BinaryCodec::decode(decoder)?)),
+            12u32 => Ok(Self::ArrayExpression(BinaryCodec::decode(decoder)?)),
+            13u32 => Ok(Self::ArrowFunctionExpression(BinaryCodec::decode(decoder)?)),
+            14u32 => Ok(Self::AssignmentExpression(BinaryCodec::decode(decoder)?)),
+            15u32 => Ok(Self::AwaitExpression(BinaryCodec::decode(decoder)?)),
+            16u32 => Ok(Self::BinaryExpression(BinaryCodec::decode(decoder)?)),
+            17u32 => Ok(Self::CallExpression(BinaryCodec::decode(decoder)?)),
+            18u32 => Ok(Self::ChainExpression(BinaryCodec::decode(decoder)?)),
+            19u32 => Ok(Self::ClassExpression(BinaryCodec::decode(decoder)?)),
+            20u32 => Ok(Self::ConditionalExpression(BinaryCodec::decode(decoder)?)),
+            21u32 => Ok(Self::FunctionExpression(BinaryCodec::decode(decoder)?)),
+            22u32 => Ok(Self::ImportExpression(BinaryCodec::decode(decoder)?)),
+            23u32 => Ok(Self::LogicalExpression(BinaryCodec::decode(decoder)?)),
+            24u32 => Ok(Self::NewExpression(BinaryCodec::decode(decoder)?)),
+            25u32 => Ok(Self::ObjectExpression(BinaryCodec::decode(decoder)?)),
+            26u32 => Ok(Self::ParenthesizedExpression(BinaryCodec::decode(decoder)?)),
+            27u32 => Ok(Self::SequenceExpression(BinaryCodec::decode(decoder)?)),
+            28u32 => Ok(Self::TaggedTemplateExpression(BinaryCodec::decode(decoder)?)),
+            29u32 => Ok(Self::ThisExpression(BinaryCodec::decode(decoder)?)),
+            30u32 => Ok(Self::UnaryExpression(BinaryCodec::decode(decoder)?)),
+            31u32 => Ok(Self::UpdateExpression(BinaryCodec::decode(decoder)?)),
+            32u32 => Ok(Self::YieldExpression(BinaryCodec::decode(decoder)?)),
+            33u32 => Ok(Self::PrivateInExpression(BinaryCodec::decode(decoder)?)),
+            34u32 => Ok(Self::JSXElement(BinaryCodec::decode(decoder)?)),
+            35u32 => Ok(Self::JSXFragment(BinaryCodec::decode(decoder)?)),
+            36u32 => Ok(Self::TSAsExpression(BinaryCodec::decode(decoder)?)),
+            37u32 => Ok(Self::TSSatisfiesExpression(BinaryCodec::decode(decoder)?)),
+            38u32 => Ok(Self::TSTypeAssertion(BinaryCodec::decode(decoder)?)),
+            39u32 => Ok(Self::TSNonNullExpression(BinaryCodec::decode(decoder)?)),
+            40u32 => Ok(Self::TSInstantiationExpression(BinaryCodec::decode(decoder)?)),
+            41u32 => Ok(Self::ComputedMemberExpression(BinaryCodec::decode(decoder)?)),
+            42u32 => Ok(Self::StaticMemberExpression(BinaryCodec::decode(decoder)?)),
+            43u32 => Ok(Self::PrivateFieldExpression(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for PropertyKind {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Init => encoder.write_u32(0u32),
+            Self::Get => encoder.write_u32(1u32),
+            Self::Set => encoder.write_u32(2u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Init),
+            1u32 => Ok(Self::Get),
+            2u32 => Ok(Self::Set),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TemplateLiteral<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.quasis, encoder);
+        BinaryCodec::encode(&self.expressions, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            quasis: BinaryCodec::decode(decoder)?,
+            expressions: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TaggedTemplateExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.tag, encoder);
+        BinaryCodec::encode(&self.quasi, encoder);
+        BinaryCodec::encode(&self.type_parameters, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            tag: BinaryCodec::decode(decoder)?,
+            quasi: BinaryCodec::decode(decoder)?,
+            type_parameters: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TemplateElement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.tail, encoder);
+        BinaryCodec::encode(&self.value, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            tail: BinaryCodec::decode(decoder)?,
+            value: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TemplateElementValue<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.raw, encoder);
+        BinaryCodec::encode(&self.cooked, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { raw: BinaryCodec::decode(decoder)?, cooked: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for MemberExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::ComputedMemberExpression(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StaticMemberExpression(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateFieldExpression(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::ComputedMemberExpression(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::StaticMemberExpression(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::PrivateFieldExpression(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ComputedMemberExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.object, encoder);
+        BinaryCodec::encode(&self.expression, encoder);
+        BinaryCodec::encode(&self.optional, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            object: BinaryCodec::decode(decoder)?,
+            expression: BinaryCodec::decode(decoder)?,
+            optional: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for StaticMemberExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.object, encoder);
+        BinaryCodec::encode(&self.property, encoder);
+        BinaryCodec::encode(&self.optional, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            object: BinaryCodec::decode(decoder)?,
+            property: BinaryCodec::decode(decoder)?,
+            optional: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for PrivateFieldExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.object, encoder);
+        BinaryCodec::encode(&self.field, encoder);
+        BinaryCodec::encode(&self.optional, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            object: BinaryCodec::decode(decoder)?,
+            field: BinaryCodec::decode(decoder)?,
+            optional: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for CallExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.callee, encoder);
+        BinaryCodec::encode(&self.type_parameters, encoder);
+        BinaryCodec::encode(&self.arguments, encoder);
+        BinaryCodec::encode(&self.optional, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            callee: BinaryCodec::decode(decoder)?,
+            type_parameters: BinaryCodec::decode(decoder)?,
+            arguments: BinaryCodec::decode(decoder)?,
+            optional: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for NewExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.callee, encoder);
+        BinaryCodec::encode(&self.arguments, encoder);
+        BinaryCodec::encode(&self.type_parameters, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            callee: BinaryCodec::decode(decoder)?,
+            arguments: BinaryCodec::decode(decoder)?,
+            type_parameters: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for MetaProperty<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.meta, encoder);
+        BinaryCodec::encode(&self.property, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            meta: BinaryCodec::decode(decoder)?,
+            property: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for SpreadElement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.argument, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, argument: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Argument<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::SpreadElement(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BooleanLiteral(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NullLiteral(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NumericLiteral(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BigIntLiteral(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::RegExpLiteral(it) => {
+                encoder.write_u32(5u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StringLiteral(it) => {
+                encoder.write_u32(6u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TemplateLiteral(it) => {
+                encoder.write_u32(7u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Identifier(it) => {
+                encoder.write_u32(8u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::MetaProperty(it) => {
+                encoder.write_u32(9u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Super(it) => {
+                encoder.write_u32(10u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ArrayExpression(it) => {
+                encoder.write_u32(11u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ArrowFunctionExpression(it) => {
+                encoder.write_u32(12u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AssignmentExpression(it) => {
+                encoder.write_u32(13u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AwaitExpression(it) => {
+                encoder.write_u32(14u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BinaryExpression(it) => {
+                encoder.write_u32(15u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::CallExpression(it) => {
+                encoder.write_u32(16u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ChainExpression(it) => {
+                encoder.write_u32(17u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ClassExpression(it) => {
+                encoder.write_u32(18u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ConditionalExpression(it) => {
+                encoder.write_u32(19u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::FunctionExpression(it) => {
+                encoder.write_u32(20u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ImportExpression(it) => {
+                encoder.write_u32(21u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::LogicalExpression(it) => {
+                encoder.write_u32(22u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NewExpression(it) => {
+                encoder.write_u32(23u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ObjectExpression(it) => {
+                encoder.write_u32(24u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ParenthesizedExpression(it) => {
+                encoder.write_u32(25u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::SequenceExpression(it) => {
+                encoder.write_u32(26u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TaggedTemplateExpression(it) => {
+                encoder.write_u32(27u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ThisExpression(it) => {
+                encoder.write_u32(28u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::UnaryExpression(it) => {
+                encoder.write_u32(29u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::UpdateExpression(it) => {
+                encoder.write_u32(30u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::YieldExpression(it) => {
+                encoder.write_u32(31u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateInExpression(it) => {
+                encoder.write_u32(32u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSXElement(it) => {
+                encoder.write_u32(33u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSXFragment(it) => {
+                encoder.write_u32(34u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSAsExpression(it) => {
+                encoder.write_u32(35u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                encoder.write_u32(36u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeAssertion(it) => {
+                encoder.write_u32(37u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNonNullExpression(it) => {
+                encoder.write_u32(38u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSInstantiationExpression(it) => {
+                encoder.write_u32(39u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ComputedMemberExpression(it) => {
+                encoder.write_u32(40u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StaticMemberExpression(it) => {
+                encoder.write_u32(41u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateFieldExpression(it) => {
+                encoder.write_u32(42u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::SpreadElement(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::BooleanLiteral(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::NullLiteral(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::NumericLiteral(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::BigIntLiteral(BinaryCodec::decode(decoder)?)),
+            5u32 => Ok(Self::RegExpLiteral(BinaryCodec::decode(decoder)?)),
+            6u32 => Ok(Self::StringLiteral(BinaryCodec::decode(decoder)?)),
+            7u32 => Ok(Self::TemplateLiteral(BinaryCodec::decode(decoder)?)),
+            8u32 => Ok(Self::Identifier(BinaryCodec::decode(decoder)?)),
+            9u32 => Ok(Self::MetaProperty(BinaryCodec::decode(decoder)?)),
+            10u32 => Ok(Self::Super(BinaryCodec::decode(decoder)?)),
+            11u32 => Ok(Self::ArrayExpression(BinaryCodec::decode(decoder)?)),
+            12u32 => Ok(Self::ArrowFunctionExpression(BinaryCodec::decode(decoder)?)),
+            13u32 => Ok(Self::AssignmentExpression(BinaryCodec::decode(decoder)?)),
+            14u32 => Ok(Self::AwaitExpression(BinaryCodec::decode(decoder)?)),
+            15u32 => Ok(Self::BinaryExpression(BinaryCodec::decode(decoder)?)),
+            16u32 => Ok(Self::CallExpression(BinaryCodec::decode(decoder)?)),
+            17u32 => Ok(Self::ChainExpression(BinaryCodec::decode(decoder)?)),
+            18u32 => Ok(Self::ClassExpression(BinaryCodec::decode(decoder)?)),
+            19u32 => Ok(Self::ConditionalExpression(BinaryCodec::decode(decoder)?)),
+            20u32 => Ok(Self::FunctionExpression(BinaryCodec::decode(decoder)?)),
+            21u32 => Ok(Self::ImportExpression(BinaryCodec::decode(decoder)?)),
+            22u32 => Ok(Self::LogicalExpression(BinaryCodec::decode(decoder)?)),
+            23u32 => Ok(Self::NewExpression(BinaryCodec::decode(decoder)?)),
+            24u32 => Ok(Self::ObjectExpression(BinaryCodec::decode(decoder)?)),
+            25u32 => Ok(Self::ParenthesizedExpression(BinaryCodec::decode(decoder)?)),
+            26u32 => Ok(Self::SequenceExpression(BinaryCodec::decode(decoder)?)),
+            27u32 => Ok(Self::TaggedTemplateExpression(BinaryCodec::decode(decoder)?)),
+            28u32 => Ok(Self::ThisExpression(BinaryCodec::decode(decoder)?)),
+            29u32 => Ok(Self::UnaryExpression(BinaryCodec::decode(decoder)?)),
+            30u32 => Ok(Self::UpdateExpression(BinaryCodec::decode(decoder)?)),
+            31u32 => Ok(Self::YieldExpression(BinaryCodec::decode(decoder)?)),
+            32u32 => Ok(Self::PrivateInExpression(BinaryCodec::decode(decoder)?)),
+            33u32 => Ok(Self::JSXElement(BinaryCodec::decode(decoder)?)),
+            34u32 => Ok(Self::JSXFragment(BinaryCodec::decode(decoder)?)),
+            35u32 => Ok(Self::TSAsExpression(BinaryCodec::decode(decoder)?)),
+            36u32 => Ok(Self::TSSatisfiesExpression(BinaryCodec::decode(decoder)?)),
+            37u32 => Ok(Self::TSTypeAssertion(BinaryCodec::decode(decoder)?)),
+            38u32 => Ok(Self::TSNonNullExpression(BinaryCodec::decode(decoder)?)),
+            39u32 => Ok(Self::TSInstantiationExpression(BinaryCodec::decode(decoder)?)),
+            40u32 => Ok(Self::ComputedMemberExpression(BinaryCodec::decode(decoder)?)),
+            41u32 => Ok(Self::StaticMemberExpression(BinaryCodec::decode(decoder)?)),
+            42u32 => Ok(Self::PrivateFieldExpression(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for UpdateExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.operator, encoder);
+        BinaryCodec::encode(&self.prefix, encoder);
+        BinaryCodec::encode(&self.argument, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            operator: BinaryCodec::decode(decoder)?,
+            prefix: BinaryCodec::decode(decoder)?,
+            argument: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for UnaryExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.operator, encoder);
+        BinaryCodec::encode(&self.argument, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            operator: BinaryCodec::decode(decoder)?,
+            argument: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for BinaryExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.left, encoder);
+        BinaryCodec::encode(&self.operator, encoder);
+        BinaryCodec::encode(&self.right, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            left: BinaryCodec::decode(decoder)?,
+            operator: BinaryCodec::decode(decoder)?,
+            right: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for PrivateInExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.left, encoder);
+        BinaryCodec::encode(&self.operator, encoder);
+        BinaryCodec::encode(&self.right, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            left: BinaryCodec::decode(decoder)?,
+            operator: BinaryCodec::decode(decoder)?,
+            right: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for LogicalExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.left, encoder);
+        BinaryCodec::encode(&self.operator, encoder);
+        BinaryCodec::encode(&self.right, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            left: BinaryCodec::decode(decoder)?,
+            operator: BinaryCodec::decode(decoder)?,
+            right: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ConditionalExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.test, encoder);
+        BinaryCodec::encode(&self.consequent, encoder);
+        BinaryCodec::encode(&self.alternate, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            test: BinaryCodec::decode(decoder)?,
+            consequent: BinaryCodec::decode(decoder)?,
+            alternate: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for AssignmentExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.operator, encoder);
+        BinaryCodec::encode(&self.left, encoder);
+        BinaryCodec::encode(&self.right, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            operator: BinaryCodec::decode(decoder)?,
+            left: BinaryCodec::decode(decoder)?,
+            right: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for AssignmentTarget<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::AssignmentTargetIdentifier(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSAsExpression(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNonNullExpression(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeAssertion(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSInstantiationExpression(it) => {
+                encoder.write_u32(5u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ComputedMemberExpression(it) => {
+                encoder.write_u32(6u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StaticMemberExpression(it) => {
+                encoder.write_u32(7u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateFieldExpression(it) => {
+                encoder.write_u32(8u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ArrayAssignmentTarget(it) => {
+                encoder.write_u32(9u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ObjectAssignmentTarget(it) => {
+                encoder.write_u32(10u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::AssignmentTargetIdentifier(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::TSAsExpression(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::TSSatisfiesExpression(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::TSNonNullExpression(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::TSTypeAssertion(BinaryCodec::decode(decoder)?)),
+            5u32 => Ok(Self::TSInstantiationExpression(BinaryCodec::decode(decoder)?)),
+            6u32 => Ok(Self::ComputedMemberExpression(BinaryCodec::decode(decoder)?)),
+            7u32 => Ok(Self::StaticMemberExpression(BinaryCodec::decode(decoder)?)),
+            8u32 => Ok(Self::PrivateFieldExpression(BinaryCodec::decode(decoder)?)),
+            9u32 => Ok(Self::ArrayAssignmentTarget(BinaryCodec::decode(decoder)?)),
+            10u32 => Ok(Self::ObjectAssignmentTarget(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for SimpleAssignmentTarget<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::AssignmentTargetIdentifier(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSAsExpression(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNonNullExpression(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeAssertion(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSInstantiationExpression(it) => {
+                encoder.write_u32(5u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ComputedMemberExpression(it) => {
+                encoder.write_u32(6u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StaticMemberExpression(it) => {
+                encoder.write_u32(7u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateFieldExpression(it) => {
+                encoder.write_u32(8u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::AssignmentTargetIdentifier(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::TSAsExpression(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::TSSatisfiesExpression(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::TSNonNullExpression(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::TSTypeAssertion(BinaryCodec::decode(decoder)?)),
+            5u32 => Ok(Self::TSInstantiationExpression(BinaryCodec::decode(decoder)?)),
+            6u32 => Ok(Self::ComputedMemberExpression(BinaryCodec::decode(decoder)?)),
+            7u32 => Ok(Self::StaticMemberExpression(BinaryCodec::decode(decoder)?)),
+            8u32 => Ok(Self::PrivateFieldExpression(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for AssignmentTargetPattern<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::ArrayAssignmentTarget(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ObjectAssignmentTarget(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::ArrayAssignmentTarget(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::ObjectAssignmentTarget(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ArrayAssignmentTarget<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.elements, encoder);
+        BinaryCodec::encode(&self.rest, encoder);
+        BinaryCodec::encode(&self.trailing_comma, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            elements: BinaryCodec::decode(decoder)?,
+            rest: BinaryCodec::decode(decoder)?,
+            trailing_comma: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ObjectAssignmentTarget<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.properties, encoder);
+        BinaryCodec::encode(&self.rest, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            properties: BinaryCodec::decode(decoder)?,
+            rest: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for AssignmentTargetRest<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.target, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, target: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for AssignmentTargetMaybeDefault<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::AssignmentTargetWithDefault(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AssignmentTargetIdentifier(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSAsExpression(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNonNullExpression(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeAssertion(it) => {
+                encoder.write_u32(5u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSInstantiationExpression(it) => {
+                encoder.write_u32(6u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ComputedMemberExpression(it) => {
+                encoder.write_u32(7u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StaticMemberExpression(it) => {
+                encoder.write_u32(8u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateFieldExpression(it) => {
+                encoder.write_u32(9u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ArrayAssignmentTarget(it) => {
+                encoder.write_u32(10u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ObjectAssignmentTarget(it) => {
+                encoder.write_u32(11u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::AssignmentTargetWithDefault(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::AssignmentTargetIdentifier(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::TSAsExpression(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::TSSatisfiesExpression(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::TSNonNullExpression(BinaryCodec::decode(decoder)?)),
+            5u32 => Ok(Self::TSTypeAssertion(BinaryCodec::decode(decoder)?)),
+            6u32 => Ok(Self::TSInstantiationExpression(BinaryCodec::decode(decoder)?)),
+            7u32 => Ok(Self::ComputedMemberExpression(BinaryCodec::decode(decoder)?)),
+            8u32 => Ok(Self::StaticMemberExpression(BinaryCodec::decode(decoder)?)),
+            9u32 => Ok(Self::PrivateFieldExpression(BinaryCodec::decode(decoder)?)),
+            10u32 => Ok(Self::ArrayAssignmentTarget(BinaryCodec::decode(decoder)?)),
+            11u32 => Ok(Self::ObjectAssignmentTarget(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for AssignmentTargetWithDefault<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.binding, encoder);
+        BinaryCodec::encode(&self.init, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            binding: BinaryCodec::decode(decoder)?,
+            init: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for AssignmentTargetProperty<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::AssignmentTargetPropertyIdentifier(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AssignmentTargetPropertyProperty(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::AssignmentTargetPropertyIdentifier(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::AssignmentTargetPropertyProperty(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for AssignmentTargetPropertyIdentifier<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.binding, encoder);
+        BinaryCodec::encode(&self.init, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            binding: BinaryCodec::decode(decoder)?,
+            init: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for AssignmentTargetPropertyProperty<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.name, encoder);
+        BinaryCodec::encode(&self.binding, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            name: BinaryCodec::decode(decoder)?,
+            binding: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for SequenceExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.expressions, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, expressions: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Super {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for AwaitExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.argument, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, argument: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ChainExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.expression, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, expression: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ChainElement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::CallExpression(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ComputedMemberExpression(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StaticMemberExpression(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateFieldExpression(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::CallExpression(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::ComputedMemberExpression(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::StaticMemberExpression(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::PrivateFieldExpression(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ParenthesizedExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.expression, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, expression: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Statement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::BlockStatement(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BreakStatement(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ContinueStatement(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::DebuggerStatement(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::DoWhileStatement(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::EmptyStatement(it) => {
+                encoder.write_u32(5u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ExpressionStatement(it) => {
+                encoder.write_u32(6u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ForInStatement(it) => {
+                encoder.write_u32(7u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ForOfStatement(it) => {
+                encoder.write_u32(8u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ForStatement(it) => {
+                encoder.write_u32(9u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::IfStatement(it) => {
+                encoder.write_u32(10u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::LabeledStatement(it) => {
+                encoder.write_u32(11u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ReturnStatement(it) => {
+                encoder.write_u32(12u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::SwitchStatement(it) => {
+                encoder.write_u32(13u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ThrowStatement(it) => {
+                encoder.write_u32(14u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TryStatement(it) => {
+                encoder.write_u32(15u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::WhileStatement(it) => {
+                encoder.write_u32(16u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::WithStatement(it) => {
+                encoder.write_u32(17u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ErrorStatement(it) => {
+                encoder.write_u32(18u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::VariableDeclaration(it) => {
+                encoder.write_u32(19u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::FunctionDeclaration(it) => {
+                encoder.write_u32(20u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ClassDeclaration(it) => {
+                encoder.write_u32(21u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeAliasDeclaration(it) => {
+                encoder.write_u32(22u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSInterfaceDeclaration(it) => {
+                encoder.write_u32(23u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSEnumDeclaration(it) => {
+                encoder.write_u32(24u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSModuleDeclaration(it) => {
+                encoder.write_u32(25u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSImportEqualsDeclaration(it) => {
+                encoder.write_u32(26u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ImportDeclaration(it) => {
+                encoder.write_u32(27u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ExportAllDeclaration(it) => {
+                encoder.write_u32(28u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ExportDefaultDeclaration(it) => {
+                encoder.write_u32(29u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ExportNamedDeclaration(it) => {
+                encoder.write_u32(30u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSExportAssignment(it) => {
+                encoder.write_u32(31u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNamespaceExportDeclaration(it) => {
+                encoder.write_u32(32u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::BlockStatement(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::BreakStatement(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::ContinueStatement(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::DebuggerStatement(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::DoWhileStatement(BinaryCodec::decode(decoder)?)),
+            5u32 => Ok(Self::EmptyStatement(BinaryCodec::decode(decoder)?)),
+            6u32 => Ok(Self::ExpressionStatement(BinaryCodec::decode(decoder)?)),
+            7u32 => Ok(Self::ForInStatement(BinaryCodec::decode(decoder)?)),
+            8u32 => Ok(Self::ForOfStatement(BinaryCodec::decode(decoder)?)),
+            9u32 => Ok(Self::ForStatement(BinaryCodec::decode(decoder)?)),
+            10u32 => Ok(Self::IfStatement(BinaryCodec::decode(decoder)?)),
+            11u32 => Ok(Self::LabeledStatement(BinaryCodec::decode(decoder)?)),
+            12u32 => Ok(Self::ReturnStatement(BinaryCodec::decode(decoder)?)),
+            13u32 => Ok(Self::SwitchStatement(BinaryCodec::decode(decoder)?)),
+            14u32 => Ok(Self::ThrowStatement(BinaryCodec::decode(decoder)?)),
+            15u32 => Ok(Self::TryStatement(BinaryCodec::decode(decoder)?)),
+            16u32 => Ok(Self::WhileStatement(BinaryCodec::decode(decoder)?)),
+            17u32 => Ok(Self::WithStatement(BinaryCodec::decode(decoder)?)),
+            18u32 => Ok(Self::ErrorStatement(BinaryCodec::decode(decoder)?)),
+            19u32 => Ok(Self::VariableDeclaration(BinaryCodec::decode(decoder)?)),
+            20u32 => Ok(Self::FunctionDeclaration(BinaryCodec::decode(decoder)?)),
+            21u32 => Ok(Self::ClassDeclaration(BinaryCodec::decode(decoder)?)),
+            22u32 => Ok(Self::TSTypeAliasDeclaration(BinaryCodec::decode(decoder)?)),
+            23u32 => Ok(Self::TSInterfaceDeclaration(BinaryCodec::decode(decoder)?)),
+            24u32 => Ok(Self::TSEnumDeclaration(BinaryCodec::decode(decoder)?)),
+            25u32 => Ok(Self::TSModuleDeclaration(BinaryCodec::decode(decoder)?)),
+            26u32 => Ok(Self::TSImportEqualsDeclaration(BinaryCodec::decode(decoder)?)),
+            27u32 => Ok(Self::ImportDeclaration(BinaryCodec::decode(decoder)?)),
+            28u32 => Ok(Self::ExportAllDeclaration(BinaryCodec::decode(decoder)?)),
+            29u32 => Ok(Self::ExportDefaultDeclaration(BinaryCodec::decode(decoder)?)),
+            30u32 => Ok(Self::ExportNamedDeclaration(BinaryCodec::decode(decoder)?)),
+            31u32 => Ok(Self::TSExportAssignment(BinaryCodec::decode(decoder)?)),
+            32u32 => Ok(Self::TSNamespaceExportDeclaration(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Directive<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.expression, encoder);
+        BinaryCodec::encode(&self.directive, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            expression: BinaryCodec::decode(decoder)?,
+            directive: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Hashbang<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.value, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, value: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for BlockStatement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+        BinaryCodec::encode(&self.scope_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+            scope_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Declaration<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::VariableDeclaration(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::FunctionDeclaration(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ClassDeclaration(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeAliasDeclaration(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSInterfaceDeclaration(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSEnumDeclaration(it) => {
+                encoder.write_u32(5u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSModuleDeclaration(it) => {
+                encoder.write_u32(6u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSImportEqualsDeclaration(it) => {
+                encoder.write_u32(7u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::VariableDeclaration(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::FunctionDeclaration(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::ClassDeclaration(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::TSTypeAliasDeclaration(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::TSInterfaceDeclaration(BinaryCodec::decode(decoder)?)),
+            5u32 => Ok(Self::TSEnumDeclaration(BinaryCodec::decode(decoder)?)),
+            6u32 => Ok(Self::TSModuleDeclaration(BinaryCodec::decode(decoder)?)),
+            7u32 => Ok(Self::TSImportEqualsDeclaration(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for VariableDeclaration<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.kind, encoder);
+        BinaryCodec::encode(&self.declarations, encoder);
+        BinaryCodec::encode(&self.declare, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            kind: BinaryCodec::decode(decoder)?,
+            declarations: BinaryCodec::decode(decoder)?,
+            declare: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for VariableDeclarationKind {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Var => encoder.write_u32(0u32),
+            Self::Const => encoder.write_u32(1u32),
+            Self::Let => encoder.write_u32(2u32),
+            Self::Using => encoder.write_u32(3u32),
+            Self::AwaitUsing => encoder.write_u32(4u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Var),
+            1u32 => Ok(Self::Const),
+            2u32 => Ok(Self::Let),
+            3u32 => Ok(Self::Using),
+            4u32 => Ok(Self::AwaitUsing),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for VariableDeclarator<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.kind, encoder);
+        BinaryCodec::encode(&self.id, encoder);
+        BinaryCodec::encode(&self.init, encoder);
+        BinaryCodec::encode(&self.definite, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            kind: BinaryCodec::decode(decoder)?,
+            id: BinaryCodec::decode(decoder)?,
+            init: BinaryCodec::decode(decoder)?,
+            definite: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for EmptyStatement {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ExpressionStatement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.expression, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, expression: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for IfStatement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.test, encoder);
+        BinaryCodec::encode(&self.consequent, encoder);
+        BinaryCodec::encode(&self.alternate, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            test: BinaryCodec::decode(decoder)?,
+            consequent: BinaryCodec::decode(decoder)?,
+            alternate: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for DoWhileStatement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+        BinaryCodec::encode(&self.test, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+            test: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for WhileStatement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.test, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            test: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ForStatement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.init, encoder);
+        BinaryCodec::encode(&self.test, encoder);
+        BinaryCodec::encode(&self.update, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+        BinaryCodec::encode(&self.scope_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            init: BinaryCodec::decode(decoder)?,
+            test: BinaryCodec::decode(decoder)?,
+            update: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+            scope_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ForStatementInit<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::VariableDeclaration(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BooleanLiteral(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NullLiteral(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NumericLiteral(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BigIntLiteral(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::RegExpLiteral(it) => {
+                encoder.write_u32(5u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StringLiteral(it) => {
+                encoder.write_u32(6u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TemplateLiteral(it) => {
+                encoder.write_u32(7u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Identifier(it) => {
+                encoder.write_u32(8u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::MetaProperty(it) => {
+                encoder.write_u32(9u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Super(it) => {
+                encoder.write_u32(10u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ArrayExpression(it) => {
+                encoder.write_u32(11u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ArrowFunctionExpression(it) => {
+                encoder.write_u32(12u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AssignmentExpression(it) => {
+                encoder.write_u32(13u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AwaitExpression(it) => {
+                encoder.write_u32(14u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BinaryExpression(it) => {
+                encoder.write_u32(15u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::CallExpression(it) => {
+                encoder.write_u32(16u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ChainExpression(it) => {
+                encoder.write_u32(17u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ClassExpression(it) => {
+                encoder.write_u32(18u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ConditionalExpression(it) => {
+                encoder.write_u32(19u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::FunctionExpression(it) => {
+                encoder.write_u32(20u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ImportExpression(it) => {
+                encoder.write_u32(21u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::LogicalExpression(it) => {
+                encoder.write_u32(22u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NewExpression(it) => {
+                encoder.write_u32(23u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ObjectExpression(it) => {
+                encoder.write_u32(24u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ParenthesizedExpression(it) => {
+                encoder.write_u32(25u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::SequenceExpression(it) => {
+                encoder.write_u32(26u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TaggedTemplateExpression(it) => {
+                encoder.write_u32(27u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ThisExpression(it) => {
+                encoder.write_u32(28u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::UnaryExpression(it) => {
+                encoder.write_u32(29u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::UpdateExpression(it) => {
+                encoder.write_u32(30u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::YieldExpression(it) => {
+                encoder.write_u32(31u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateInExpression(it) => {
+                encoder.write_u32(32u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSXElement(it) => {
+                encoder.write_u32(33u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSXFragment(it) => {
+                encoder.write_u32(34u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSAsExpression(it) => {
+                encoder.write_u32(35u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                encoder.write_u32(36u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeAssertion(it) => {
+                encoder.write_u32(37u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNonNullExpression(it) => {
+                encoder.write_u32(38u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSInstantiationExpression(it) => {
+                encoder.write_u32(39u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ComputedMemberExpression(it) => {
+                encoder.write_u32(40u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StaticMemberExpression(it) => {
+                encoder.write_u32(41u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateFieldExpression(it) => {
+                encoder.write_u32(42u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::VariableDeclaration(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::BooleanLiteral(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::NullLiteral(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::NumericLiteral(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::BigIntLiteral(BinaryCodec::decode(decoder)?)),
+            5u32 => Ok(Self::RegExpLiteral(BinaryCodec::decode(decoder)?)),
+            6u32 => Ok(Self::StringLiteral(BinaryCodec::decode(decoder)?)),
+            7u32 => Ok(Self::TemplateLiteral(BinaryCodec::decode(decoder)?)),
+            8u32 => Ok(Self::Identifier(BinaryCodec::decode(decoder)?)),
+            9u32 => Ok(Self::MetaProperty(BinaryCodec::decode(decoder)?)),
+            10u32 => Ok(Self::Super(BinaryCodec::decode(decoder)?)),
+            11u32 => Ok(Self::ArrayExpression(BinaryCodec::decode(decoder)?)),
+            12u32 => Ok(Self::ArrowFunctionExpression(BinaryCodec::decode(decoder)?)),
+            13u32 => Ok(Self::AssignmentExpression(BinaryCodec::decode(decoder)?)),
+            14u32 => Ok(Self::AwaitExpression(BinaryCodec::decode(decoder)?)),
+            15u32 => Ok(Self::BinaryExpression(BinaryCodec::decode(decoder)?)),
+            16u32 => Ok(Self::CallExpression(BinaryCodec::decode(decoder)?)),
+            17u32 => Ok(Self::ChainExpression(BinaryCodec::decode(decoder)?)),
+            18u32 => Ok(Self::ClassExpression(BinaryCodec::decode(decoder)?)),
+            19u32 => Ok(Self::ConditionalExpression(BinaryCodec::decode(decoder)?)),
+            20u32 => Ok(Self::FunctionExpression(BinaryCodec::decode(decoder)?)),
+            21u32 => Ok(Self::ImportExpression(BinaryCodec::decode(decoder)?)),
+            22u32 => Ok(Self::LogicalExpression(BinaryCodec::decode(decoder)?)),
+            23u32 => Ok(Self::NewExpression(BinaryCodec::decode(decoder)?)),
+            24u32 => Ok(Self::ObjectExpression(BinaryCodec::decode(decoder)?)),
+            25u32 => Ok(Self::ParenthesizedExpression(BinaryCodec::decode(decoder)?)),
+            26u32 => Ok(Self::SequenceExpression(BinaryCodec::decode(decoder)?)),
+            27u32 => Ok(Self::TaggedTemplateExpression(BinaryCodec::decode(decoder)?)),
+            28u32 => Ok(Self::ThisExpression(BinaryCodec::decode(decoder)?)),
+            29u32 => Ok(Self::UnaryExpression(BinaryCodec::decode(decoder)?)),
+            30u32 => Ok(Self::UpdateExpression(BinaryCodec::decode(decoder)?)),
+            31u32 => Ok(Self::YieldExpression(BinaryCodec::decode(decoder)?)),
+            32u32 => Ok(Self::PrivateInExpression(BinaryCodec::decode(decoder)?)),
+            33u32 => Ok(Self::JSXElement(BinaryCodec::decode(decoder)?)),
+            34u32 => Ok(Self::JSXFragment(BinaryCodec::decode(decoder)?)),
+            35u32 => Ok(Self::TSAsExpression(BinaryCodec::decode(decoder)?)),
+            36u32 => Ok(Self::TSSatisfiesExpression(BinaryCodec::decode(decoder)?)),
+            37u32 => Ok(Self::TSTypeAssertion(BinaryCodec::decode(decoder)?)),
+            38u32 => Ok(Self::TSNonNullExpression(BinaryCodec::decode(decoder)?)),
+            39u32 => Ok(Self::TSInstantiationExpression(BinaryCodec::decode(decoder)?)),
+            40u32 => Ok(Self::ComputedMemberExpression(BinaryCodec::decode(decoder)?)),
+            41u32 => Ok(Self::StaticMemberExpression(BinaryCodec::decode(decoder)?)),
+            42u32 => Ok(Self::PrivateFieldExpression(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ForInStatement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.left, encoder);
+        BinaryCodec::encode(&self.right, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+        BinaryCodec::encode(&self.scope_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            left: BinaryCodec::decode(decoder)?,
+            right: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+            scope_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ForStatementLeft<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::VariableDeclaration(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AssignmentTargetIdentifier(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSAsExpression(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNonNullExpression(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeAssertion(it) => {
+                encoder.write_u32(5u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSInstantiationExpression(it) => {
+                encoder.write_u32(6u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ComputedMemberExpression(it) => {
+                encoder.write_u32(7u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StaticMemberExpression(it) => {
+                encoder.write_u32(8u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateFieldExpression(it) => {
+                encoder.write_u32(9u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ArrayAssignmentTarget(it) => {
+                encoder.write_u32(10u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ObjectAssignmentTarget(it) => {
+                encoder.write_u32(11u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::VariableDeclaration(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::AssignmentTargetIdentifier(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::TSAsExpression(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::TSSatisfiesExpression(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::TSNonNullExpression(BinaryCodec::decode(decoder)?)),
+            5u32 => Ok(Self::TSTypeAssertion(BinaryCodec::decode(decoder)?)),
+            6u32 => Ok(Self::TSInstantiationExpression(BinaryCodec::decode(decoder)?)),
+            7u32 => Ok(Self::ComputedMemberExpression(BinaryCodec::decode(decoder)?)),
+            8u32 => Ok(Self::StaticMemberExpression(BinaryCodec::decode(decoder)?)),
+            9u32 => Ok(Self::PrivateFieldExpression(BinaryCodec::decode(decoder)?)),
+            10u32 => Ok(Self::ArrayAssignmentTarget(BinaryCodec::decode(decoder)?)),
+            11u32 => Ok(Self::ObjectAssignmentTarget(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ForOfStatement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.r#await, encoder);
+        BinaryCodec::encode(&self.left, encoder);
+        BinaryCodec::encode(&self.right, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+        BinaryCodec::encode(&self.scope_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            r#await: BinaryCodec::decode(decoder)?,
+            left: BinaryCodec::decode(decoder)?,
+            right: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+            scope_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ContinueStatement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.label, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, label: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for BreakStatement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.label, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, label: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ReturnStatement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.argument, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, argument: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for WithStatement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.object, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            object: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for SwitchStatement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.discriminant, encoder);
+        BinaryCodec::encode(&self.cases, encoder);
+        BinaryCodec::encode(&self.scope_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            discriminant: BinaryCodec::decode(decoder)?,
+            cases: BinaryCodec::decode(decoder)?,
+            scope_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for SwitchCase<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.test, encoder);
+        BinaryCodec::encode(&self.consequent, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            test: BinaryCodec::decode(decoder)?,
+            consequent: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for LabeledStatement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.label, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            label: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ThrowStatement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.argument, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, argument: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TryStatement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.block, encoder);
+        BinaryCodec::encode(&self.handler, encoder);
+        BinaryCodec::encode(&self.finalizer, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            block: BinaryCodec::decode(decoder)?,
+            handler: BinaryCodec::decode(decoder)?,
+            finalizer: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for CatchClause<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.param, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+        BinaryCodec::encode(&self.scope_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            param: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+            scope_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for CatchParameter<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.pattern, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, pattern: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for DebuggerStatement {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ErrorStatement {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for BindingPattern<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.kind, encoder);
+        BinaryCodec::encode(&self.type_annotation, encoder);
+        BinaryCodec::encode(&self.optional, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            kind: BinaryCodec::decode(decoder)?,
+            type_annotation: BinaryCodec::decode(decoder)?,
+            optional: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for BindingPatternKind<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::BindingIdentifier(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ObjectPattern(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ArrayPattern(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AssignmentPattern(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::BindingIdentifier(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::ObjectPattern(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::ArrayPattern(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::AssignmentPattern(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for AssignmentPattern<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.left, encoder);
+        BinaryCodec::encode(&self.right, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            left: BinaryCodec::decode(decoder)?,
+            right: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ObjectPattern<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.properties, encoder);
+        BinaryCodec::encode(&self.rest, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            properties: BinaryCodec::decode(decoder)?,
+            rest: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for BindingProperty<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.key, encoder);
+        BinaryCodec::encode(&self.value, encoder);
+        BinaryCodec::encode(&self.shorthand, encoder);
+        BinaryCodec::encode(&self.computed, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            key: BinaryCodec::decode(decoder)?,
+            value: BinaryCodec::decode(decoder)?,
+            shorthand: BinaryCodec::decode(decoder)?,
+            computed: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ArrayPattern<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.elements, encoder);
+        BinaryCodec::encode(&self.rest, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            elements: BinaryCodec::decode(decoder)?,
+            rest: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for BindingRestElement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.argument, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, argument: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Function<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.r#type, encoder);
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.id, encoder);
+        BinaryCodec::encode(&self.generator, encoder);
+        BinaryCodec::encode(&self.r#async, encoder);
+        BinaryCodec::encode(&self.declare, encoder);
+        BinaryCodec::encode(&self.type_parameters, encoder);
+        BinaryCodec::encode(&self.this_param, encoder);
+        BinaryCodec::encode(&self.params, encoder);
+        BinaryCodec::encode(&self.return_type, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+        BinaryCodec::encode(&self.scope_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            r#type: BinaryCodec::decode(decoder)?,
+            span: BinaryCodec::decode(decoder)?,
+            id: BinaryCodec::decode(decoder)?,
+            generator: BinaryCodec::decode(decoder)?,
+            r#async: BinaryCodec::decode(decoder)?,
+            declare: BinaryCodec::decode(decoder)?,
+            type_parameters: BinaryCodec::decode(decoder)?,
+            this_param: BinaryCodec::decode(decoder)?,
+            params: BinaryCodec::decode(decoder)?,
+            return_type: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+            scope_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for FunctionType {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::FunctionDeclaration => encoder.write_u32(0u32),
+            Self::FunctionExpression => encoder.write_u32(1u32),
+            Self::TSDeclareFunction => encoder.write_u32(2u32),
+            Self::TSEmptyBodyFunctionExpression => encoder.write_u32(3u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::FunctionDeclaration),
+            1u32 => Ok(Self::FunctionExpression),
+            2u32 => Ok(Self::TSDeclareFunction),
+            3u32 => Ok(Self::TSEmptyBodyFunctionExpression),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for FormalParameters<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.kind, encoder);
+        BinaryCodec::encode(&self.items, encoder);
+        BinaryCodec::encode(&self.rest, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            kind: BinaryCodec::decode(decoder)?,
+            items: BinaryCodec::decode(decoder)?,
+            rest: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for FormalParameter<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.decorators, encoder);
+        BinaryCodec::encode(&self.pattern, encoder);
+        BinaryCodec::encode(&self.accessibility, encoder);
+        BinaryCodec::encode(&self.readonly, encoder);
+        BinaryCodec::encode(&self.r#override, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            decorators: BinaryCodec::decode(decoder)?,
+            pattern: BinaryCodec::decode(decoder)?,
+            accessibility: BinaryCodec::decode(decoder)?,
+            readonly: BinaryCodec::decode(decoder)?,
+            r#override: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for FormalParameterKind {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::FormalParameter => encoder.write_u32(0u32),
+            Self::UniqueFormalParameters => encoder.write_u32(1u32),
+            Self::ArrowFormalParameters => encoder.write_u32(2u32),
+            Self::Signature => encoder.write_u32(3u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::FormalParameter),
+            1u32 => Ok(Self::UniqueFormalParameters),
+            2u32 => Ok(Self::ArrowFormalParameters),
+            3u32 => Ok(Self::Signature),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for FunctionBody<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.directives, encoder);
+        BinaryCodec::encode(&self.statements, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            directives: BinaryCodec::decode(decoder)?,
+            statements: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ArrowFunctionExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.expression, encoder);
+        BinaryCodec::encode(&self.r#async, encoder);
+        BinaryCodec::encode(&self.type_parameters, encoder);
+        BinaryCodec::encode(&self.params, encoder);
+        BinaryCodec::encode(&self.return_type, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+        BinaryCodec::encode(&self.scope_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            expression: BinaryCodec::decode(decoder)?,
+            r#async: BinaryCodec::decode(decoder)?,
+            type_parameters: BinaryCodec::decode(decoder)?,
+            params: BinaryCodec::decode(decoder)?,
+            return_type: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+            scope_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for YieldExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.delegate, encoder);
+        BinaryCodec::encode(&self.argument, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            delegate: BinaryCodec::decode(decoder)?,
+            argument: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Class<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.r#type, encoder);
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.decorators, encoder);
+        BinaryCodec::encode(&self.id, encoder);
+        BinaryCodec::encode(&self.type_parameters, encoder);
+        BinaryCodec::encode(&self.super_class, encoder);
+        BinaryCodec::encode(&self.super_type_parameters, encoder);
+        BinaryCodec::encode(&self.implements, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+        BinaryCodec::encode(&self.r#abstract, encoder);
+        BinaryCodec::encode(&self.declare, encoder);
+        BinaryCodec::encode(&self.scope_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            r#type: BinaryCodec::decode(decoder)?,
+            span: BinaryCodec::decode(decoder)?,
+            decorators: BinaryCodec::decode(decoder)?,
+            id: BinaryCodec::decode(decoder)?,
+            type_parameters: BinaryCodec::decode(decoder)?,
+            super_class: BinaryCodec::decode(decoder)?,
+            super_type_parameters: BinaryCodec::decode(decoder)?,
+            implements: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+            r#abstract: BinaryCodec::decode(decoder)?,
+            declare: BinaryCodec::decode(decoder)?,
+            scope_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ClassType {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::ClassDeclaration => encoder.write_u32(0u32),
+            Self::ClassExpression => encoder.write_u32(1u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::ClassDeclaration),
+            1u32 => Ok(Self::ClassExpression),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ClassBody<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, body: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ClassElement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::StaticBlock(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::MethodDefinition(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PropertyDefinition(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AccessorProperty(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSIndexSignature(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::StaticBlock(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::MethodDefinition(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::PropertyDefinition(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::AccessorProperty(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::TSIndexSignature(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for MethodDefinition<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.r#type, encoder);
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.decorators, encoder);
+        BinaryCodec::encode(&self.key, encoder);
+        BinaryCodec::encode(&self.value, encoder);
+        BinaryCodec::encode(&self.kind, encoder);
+        BinaryCodec::encode(&self.computed, encoder);
+        BinaryCodec::encode(&self.r#static, encoder);
+        BinaryCodec::encode(&self.r#override, encoder);
+        BinaryCodec::encode(&self.optional, encoder);
+        BinaryCodec::encode(&self.accessibility, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            r#type: BinaryCodec::decode(decoder)?,
+            span: BinaryCodec::decode(decoder)?,
+            decorators: BinaryCodec::decode(decoder)?,
+            key: BinaryCodec::decode(decoder)?,
+            value: BinaryCodec::decode(decoder)?,
+            kind: BinaryCodec::decode(decoder)?,
+            computed: BinaryCodec::decode(decoder)?,
+            r#static: BinaryCodec::decode(decoder)?,
+            r#override: BinaryCodec::decode(decoder)?,
+            optional: BinaryCodec::decode(decoder)?,
+            accessibility: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for MethodDefinitionType {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::MethodDefinition => encoder.write_u32(0u32),
+            Self::TSAbstractMethodDefinition => encoder.write_u32(1u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::MethodDefinition),
+            1u32 => Ok(Self::TSAbstractMethodDefinition),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for PropertyDefinition<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.r#type, encoder);
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.decorators, encoder);
+        BinaryCodec::encode(&self.key, encoder);
+        BinaryCodec::encode(&self.value, encoder);
+        BinaryCodec::encode(&self.computed, encoder);
+        BinaryCodec::encode(&self.r#static, encoder);
+        BinaryCodec::encode(&self.declare, encoder);
+        BinaryCodec::encode(&self.r#override, encoder);
+        BinaryCodec::encode(&self.optional, encoder);
+        BinaryCodec::encode(&self.definite, encoder);
+        BinaryCodec::encode(&self.readonly, encoder);
+        BinaryCodec::encode(&self.type_annotation, encoder);
+        BinaryCodec::encode(&self.accessibility, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            r#type: BinaryCodec::decode(decoder)?,
+            span: BinaryCodec::decode(decoder)?,
+            decorators: BinaryCodec::decode(decoder)?,
+            key: BinaryCodec::decode(decoder)?,
+            value: BinaryCodec::decode(decoder)?,
+            computed: BinaryCodec::decode(decoder)?,
+            r#static: BinaryCodec::decode(decoder)?,
+            declare: BinaryCodec::decode(decoder)?,
+            r#override: BinaryCodec::decode(decoder)?,
+            optional: BinaryCodec::decode(decoder)?,
+            definite: BinaryCodec::decode(decoder)?,
+            readonly: BinaryCodec::decode(decoder)?,
+            type_annotation: BinaryCodec::decode(decoder)?,
+            accessibility: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for PropertyDefinitionType {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::PropertyDefinition => encoder.write_u32(0u32),
+            Self::TSAbstractPropertyDefinition => encoder.write_u32(1u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::PropertyDefinition),
+            1u32 => Ok(Self::TSAbstractPropertyDefinition),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for MethodDefinitionKind {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Constructor => encoder.write_u32(0u32),
+            Self::Method => encoder.write_u32(1u32),
+            Self::Get => encoder.write_u32(2u32),
+            Self::Set => encoder.write_u32(3u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Constructor),
+            1u32 => Ok(Self::Method),
+            2u32 => Ok(Self::Get),
+            3u32 => Ok(Self::Set),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for PrivateIdentifier<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.name, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, name: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for StaticBlock<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+        BinaryCodec::encode(&self.scope_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+            scope_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ModuleDeclaration<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::ImportDeclaration(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ExportAllDeclaration(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ExportDefaultDeclaration(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ExportNamedDeclaration(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSExportAssignment(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNamespaceExportDeclaration(it) => {
+                encoder.write_u32(5u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::ImportDeclaration(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::ExportAllDeclaration(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::ExportDefaultDeclaration(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::ExportNamedDeclaration(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::TSExportAssignment(BinaryCodec::decode(decoder)?)),
+            5u32 => Ok(Self::TSNamespaceExportDeclaration(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for AccessorPropertyType {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::AccessorProperty => encoder.write_u32(0u32),
+            Self::TSAbstractAccessorProperty => encoder.write_u32(1u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::AccessorProperty),
+            1u32 => Ok(Self::TSAbstractAccessorProperty),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for AccessorProperty<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.r#type, encoder);
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.decorators, encoder);
+        BinaryCodec::encode(&self.key, encoder);
+        BinaryCodec::encode(&self.value, encoder);
+        BinaryCodec::encode(&self.computed, encoder);
+        BinaryCodec::encode(&self.r#static, encoder);
+        BinaryCodec::encode(&self.definite, encoder);
+        BinaryCodec::encode(&self.type_annotation, encoder);
+        BinaryCodec::encode(&self.accessibility, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            r#type: BinaryCodec::decode(decoder)?,
+            span: BinaryCodec::decode(decoder)?,
+            decorators: BinaryCodec::decode(decoder)?,
+            key: BinaryCodec::decode(decoder)?,
+            value: BinaryCodec::decode(decoder)?,
+            computed: BinaryCodec::decode(decoder)?,
+            r#static: BinaryCodec::decode(decoder)?,
+            definite: BinaryCodec::decode(decoder)?,
+            type_annotation: BinaryCodec::decode(decoder)?,
+            accessibility: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ImportExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.source, encoder);
+        BinaryCodec::encode(&self.arguments, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            source: BinaryCodec::decode(decoder)?,
+            arguments: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ImportDeclaration<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.specifiers, encoder);
+        BinaryCodec::encode(&self.source, encoder);
+        BinaryCodec::encode(&self.with_clause, encoder);
+        BinaryCodec::encode(&self.import_kind, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            specifiers: BinaryCodec::decode(decoder)?,
+            source: BinaryCodec::decode(decoder)?,
+            with_clause: BinaryCodec::decode(decoder)?,
+            import_kind: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ImportDeclarationSpecifier<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::ImportSpecifier(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ImportDefaultSpecifier(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ImportNamespaceSpecifier(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::ImportSpecifier(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::ImportDefaultSpecifier(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::ImportNamespaceSpecifier(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ImportSpecifier<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.imported, encoder);
+        BinaryCodec::encode(&self.local, encoder);
+        BinaryCodec::encode(&self.import_kind, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            imported: BinaryCodec::decode(decoder)?,
+            local: BinaryCodec::decode(decoder)?,
+            import_kind: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ImportDefaultSpecifier<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.local, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, local: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ImportNamespaceSpecifier<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.local, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, local: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for WithClause<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.attributes_keyword, encoder);
+        BinaryCodec::encode(&self.with_entries, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            attributes_keyword: BinaryCodec::decode(decoder)?,
+            with_entries: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ImportAttribute<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.key, encoder);
+        BinaryCodec::encode(&self.value, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            key: BinaryCodec::decode(decoder)?,
+            value: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ImportAttributeKey<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Identifier(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StringLiteral(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Identifier(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::StringLiteral(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ExportNamedDeclaration<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.declaration, encoder);
+        BinaryCodec::encode(&self.specifiers, encoder);
+        BinaryCodec::encode(&self.source, encoder);
+        BinaryCodec::encode(&self.export_kind, encoder);
+        BinaryCodec::encode(&self.with_clause, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            declaration: BinaryCodec::decode(decoder)?,
+            specifiers: BinaryCodec::decode(decoder)?,
+            source: BinaryCodec::decode(decoder)?,
+            export_kind: BinaryCodec::decode(decoder)?,
+            with_clause: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ExportDefaultDeclaration<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.declaration, encoder);
+        BinaryCodec::encode(&self.exported, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            declaration: BinaryCodec::decode(decoder)?,
+            exported: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ExportAllDeclaration<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.exported, encoder);
+        BinaryCodec::encode(&self.source, encoder);
+        BinaryCodec::encode(&self.with_clause, encoder);
+        BinaryCodec::encode(&self.export_kind, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            exported: BinaryCodec::decode(decoder)?,
+            source: BinaryCodec::decode(decoder)?,
+            with_clause: BinaryCodec::decode(decoder)?,
+            export_kind: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ExportSpecifier<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.local, encoder);
+        BinaryCodec::encode(&self.exported, encoder);
+        BinaryCodec::encode(&self.export_kind, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            local: BinaryCodec::decode(decoder)?,
+            exported: BinaryCodec::decode(decoder)?,
+            export_kind: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ExportDefaultDeclarationKind<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::FunctionDeclaration(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ClassDeclaration(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSInterfaceDeclaration(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BooleanLiteral(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NullLiteral(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NumericLiteral(it) => {
+                encoder.write_u32(5u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BigIntLiteral(it) => {
+                encoder.write_u32(6u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::RegExpLiteral(it) => {
+                encoder.write_u32(7u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StringLiteral(it) => {
+                encoder.write_u32(8u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TemplateLiteral(it) => {
+                encoder.write_u32(9u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Identifier(it) => {
+                encoder.write_u32(10u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::MetaProperty(it) => {
+                encoder.write_u32(11u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Super(it) => {
+                encoder.write_u32(12u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ArrayExpression(it) => {
+                encoder.write_u32(13u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ArrowFunctionExpression(it) => {
+                encoder.write_u32(14u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AssignmentExpression(it) => {
+                encoder.write_u32(15u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AwaitExpression(it) => {
+                encoder.write_u32(16u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BinaryExpression(it) => {
+                encoder.write_u32(17u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::CallExpression(it) => {
+                encoder.write_u32(18u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ChainExpression(it) => {
+                encoder.write_u32(19u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ClassExpression(it) => {
+                encoder.write_u32(20u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ConditionalExpression(it) => {
+                encoder.write_u32(21u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::FunctionExpression(it) => {
+                encoder.write_u32(22u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ImportExpression(it) => {
+                encoder.write_u32(23u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::LogicalExpression(it) => {
+                encoder.write_u32(24u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NewExpression(it) => {
+                encoder.write_u32(25u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ObjectExpression(it) => {
+                encoder.write_u32(26u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ParenthesizedExpression(it) => {
+                encoder.write_u32(27u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::SequenceExpression(it) => {
+                encoder.write_u32(28u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TaggedTemplateExpression(it) => {
+                encoder.write_u32(29u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ThisExpression(it) => {
+                encoder.write_u32(30u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::UnaryExpression(it) => {
+                encoder.write_u32(31u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::UpdateExpression(it) => {
+                encoder.write_u32(32u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::YieldExpression(it) => {
+                encoder.write_u32(33u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateInExpression(it) => {
+                encoder.write_u32(34u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSXElement(it) => {
+                encoder.write_u32(35u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSXFragment(it) => {
+                encoder.write_u32(36u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSAsExpression(it) => {
+                encoder.write_u32(37u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                encoder.write_u32(38u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeAssertion(it) => {
+                encoder.write_u32(39u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNonNullExpression(it) => {
+                encoder.write_u32(40u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSInstantiationExpression(it) => {
+                encoder.write_u32(41u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ComputedMemberExpression(it) => {
+                encoder.write_u32(42u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StaticMemberExpression(it) => {
+                encoder.write_u32(43u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateFieldExpression(it) => {
+                encoder.write_u32(44u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::FunctionDeclaration(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::ClassDeclaration(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::TSInterfaceDeclaration(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::BooleanLiteral(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::NullLiteral(BinaryCodec::decode(decoder)?)),
+            5u32 => Ok(Self::NumericLiteral(BinaryCodec::decode(decoder)?)),
+            6u32 => Ok(Self::BigIntLiteral(BinaryCodec::decode(decoder)?)),
+            7u32 => Ok(Self::RegExpLiteral(BinaryCodec::decode(decoder)?)),
+            8u32 => Ok(Self::StringLiteral(BinaryCodec::decode(decoder)?)),
+            9u32 => Ok(Self::TemplateLiteral(BinaryCodec::decode(decoder)?)),
+            10u32 => Ok(Self::Identifier(BinaryCodec::decode(decoder)?)),
+            11u32 => Ok(Self::MetaProperty(BinaryCodec::decode(decoder)?)),
+            12u32 => Ok(Self::Super(BinaryCodec::decode(decoder)?)),
+            13u32 => Ok(Self::ArrayExpression(BinaryCodec::decode(decoder)?)),
+            14u32 => Ok(Self::ArrowFunctionExpression(BinaryCodec::decode(decoder)?)),
+            15u32 => Ok(Self::AssignmentExpression(BinaryCodec::decode(decoder)?)),
+            16u32 => Ok(Self::AwaitExpression(BinaryCodec::decode(decoder)?)),
+            17u32 => Ok(Self::BinaryExpression(BinaryCodec::decode(decoder)?)),
+            18u32 => Ok(Self::CallExpression(BinaryCodec::decode(decoder)?)),
+            19u32 => Ok(Self::ChainExpression(BinaryCodec::decode(decoder)?)),
+            20u32 => Ok(Self::ClassExpression(BinaryCodec::decode(decoder)?)),
+            21u32 => Ok(Self::ConditionalExpression(BinaryCodec::decode(decoder)?)),
+            22u32 => Ok(Self::FunctionExpression(BinaryCodec::decode(decoder)?)),
+            23u32 => Ok(Self::ImportExpression(BinaryCodec::decode(decoder)?)),
+            24u32 => Ok(Self::LogicalExpression(BinaryCodec::decode(decoder)?)),
+            25u32 => Ok(Self::NewExpression(BinaryCodec::decode(decoder)?)),
+            26u32 => Ok(Self::ObjectExpression(BinaryCodec::decode(decoder)?)),
+            27u32 => Ok(Self::ParenthesizedExpression(BinaryCodec::decode(decoder)?)),
+            28u32 => Ok(Self::SequenceExpression(BinaryCodec::decode(decoder)?)),
+            29u32 => Ok(Self::TaggedTemplateExpression(BinaryCodec::decode(decoder)?)),
+            30u32 => Ok(Self::ThisExpression(BinaryCodec::decode(decoder)?)),
+            31u32 => Ok(Self::UnaryExpression(BinaryCodec::decode(decoder)?)),
+            32u32 => Ok(Self::UpdateExpression(BinaryCodec::decode(decoder)?)),
+            33u32 => Ok(Self::YieldExpression(BinaryCodec::decode(decoder)?)),
+            34u32 => Ok(Self::PrivateInExpression(BinaryCodec::decode(decoder)?)),
+            35u32 => Ok(Self::JSXElement(BinaryCodec::decode(decoder)?)),
+            36u32 => Ok(Self::JSXFragment(BinaryCodec::decode(decoder)?)),
+            37u32 => Ok(Self::TSAsExpression(BinaryCodec::decode(decoder)?)),
+            38u32 => Ok(Self::TSSatisfiesExpression(BinaryCodec::decode(decoder)?)),
+            39u32 => Ok(Self::TSTypeAssertion(BinaryCodec::decode(decoder)?)),
+            40u32 => Ok(Self::TSNonNullExpression(BinaryCodec::decode(decoder)?)),
+            41u32 => Ok(Self::TSInstantiationExpression(BinaryCodec::decode(decoder)?)),
+            42u32 => Ok(Self::ComputedMemberExpression(BinaryCodec::decode(decoder)?)),
+            43u32 => Ok(Self::StaticMemberExpression(BinaryCodec::decode(decoder)?)),
+            44u32 => Ok(Self::PrivateFieldExpression(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ModuleExportName<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::IdentifierName(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::IdentifierReference(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StringLiteral(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::IdentifierName(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::IdentifierReference(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::StringLiteral(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSThisParameter<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.this_span, encoder);
+        BinaryCodec::encode(&self.type_annotation, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            this_span: BinaryCodec::decode(decoder)?,
+            type_annotation: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSEnumDeclaration<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.id, encoder);
+        BinaryCodec::encode(&self.members, encoder);
+        BinaryCodec::encode(&self.r#const, encoder);
+        BinaryCodec::encode(&self.declare, encoder);
+        BinaryCodec::encode(&self.scope_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            id: BinaryCodec::decode(decoder)?,
+            members: BinaryCodec::decode(decoder)?,
+            r#const: BinaryCodec::decode(decoder)?,
+            declare: BinaryCodec::decode(decoder)?,
+            scope_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSEnumMember<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.id, encoder);
+        BinaryCodec::encode(&self.initializer, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            id: BinaryCodec::decode(decoder)?,
+            initializer: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSEnumMemberName<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::StaticIdentifier(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StaticStringLiteral(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StaticTemplateLiteral(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StaticNumericLiteral(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BooleanLiteral(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NullLiteral(it) => {
+                encoder.write_u32(5u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NumericLiteral(it) => {
+                encoder.write_u32(6u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BigIntLiteral(it) => {
+                encoder.write_u32(7u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::RegExpLiteral(it) => {
+                encoder.write_u32(8u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StringLiteral(it) => {
+                encoder.write_u32(9u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TemplateLiteral(it) => {
+                encoder.write_u32(10u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Identifier(it) => {
+                encoder.write_u32(11u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::MetaProperty(it) => {
+                encoder.write_u32(12u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Super(it) => {
+                encoder.write_u32(13u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ArrayExpression(it) => {
+                encoder.write_u32(14u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ArrowFunctionExpression(it) => {
+                encoder.write_u32(15u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AssignmentExpression(it) => {
+                encoder.write_u32(16u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AwaitExpression(it) => {
+                encoder.write_u32(17u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BinaryExpression(it) => {
+                encoder.write_u32(18u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::CallExpression(it) => {
+                encoder.write_u32(19u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ChainExpression(it) => {
+                encoder.write_u32(20u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ClassExpression(it) => {
+                encoder.write_u32(21u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ConditionalExpression(it) => {
+                encoder.write_u32(22u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::FunctionExpression(it) => {
+                encoder.write_u32(23u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ImportExpression(it) => {
+                encoder.write_u32(24u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::LogicalExpression(it) => {
+                encoder.write_u32(25u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NewExpression(it) => {
+                encoder.write_u32(26u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ObjectExpression(it) => {
+                encoder.write_u32(27u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ParenthesizedExpression(it) => {
+                encoder.write_u32(28u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::SequenceExpression(it) => {
+                encoder.write_u32(29u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TaggedTemplateExpression(it) => {
+                encoder.write_u32(30u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ThisExpression(it) => {
+                encoder.write_u32(31u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::UnaryExpression(it) => {
+                encoder.write_u32(32u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::UpdateExpression(it) => {
+                encoder.write_u32(33u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::YieldExpression(it) => {
+                encoder.write_u32(34u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateInExpression(it) => {
+                encoder.write_u32(35u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSXElement(it) => {
+                encoder.write_u32(36u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSXFragment(it) => {
+                encoder.write_u32(37u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSAsExpression(it) => {
+                encoder.write_u32(38u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                encoder.write_u32(39u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeAssertion(it) => {
+                encoder.write_u32(40u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNonNullExpression(it) => {
+                encoder.write_u32(41u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSInstantiationExpression(it) => {
+                encoder.write_u32(42u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ComputedMemberExpression(it) => {
+                encoder.write_u32(43u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StaticMemberExpression(it) => {
+                encoder.write_u32(44u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateFieldExpression(it) => {
+                encoder.write_u32(45u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::StaticIdentifier(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::StaticStringLiteral(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::StaticTemplateLiteral(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::StaticNumericLiteral(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::BooleanLiteral(BinaryCodec::decode(decoder)?)),
+            5u32 => Ok(Self::NullLiteral(BinaryCodec::decode(decoder)?)),
+            6u32 => Ok(Self::NumericLiteral(BinaryCodec::decode(decoder)?)),
+            7u32 => Ok(Self::BigIntLiteral(BinaryCodec::decode(decoder)?)),
+            8u32 => Ok(Self::RegExpLiteral(BinaryCodec::decode(decoder)?)),
+            9u32 => Ok(Self::StringLiteral(BinaryCodec::decode(decoder)?)),
+            10u32 => Ok(Self::TemplateLiteral(BinaryCodec::decode(decoder)?)),
+            11u32 => Ok(Self::Identifier(BinaryCodec::decode(decoder)?)),
+            12u32 => Ok(Self::MetaProperty(BinaryCodec::decode(decoder)?)),
+            13u32 => Ok(Self::Super(BinaryCodec::decode(decoder)?)),
+            14u32 => Ok(Self::ArrayExpression(BinaryCodec::decode(decoder)?)),
+            15u32 => Ok(Self::ArrowFunctionExpression(BinaryCodec::decode(decoder)?)),
+            16u32 => Ok(Self::AssignmentExpression(BinaryCodec::decode(decoder)?)),
+            17u32 => Ok(Self::AwaitExpression(BinaryCodec::decode(decoder)?)),
+            18u32 => Ok(Self::BinaryExpression(BinaryCodec::decode(decoder)?)),
+            19u32 => Ok(Self::CallExpression(BinaryCodec::decode(decoder)?)),
+            20u32 => Ok(Self::ChainExpression(BinaryCodec::decode(decoder)?)),
+            21u32 => Ok(Self::ClassExpression(BinaryCodec::decode(decoder)?)),
+            22u32 => Ok(Self::ConditionalExpression(BinaryCodec::decode(decoder)?)),
+            23u32 => Ok(Self::FunctionExpression(BinaryCodec::decode(decoder)?)),
+            24u32 => Ok(Self::ImportExpression(BinaryCodec::decode(decoder)?)),
+            25u32 => Ok(Self::LogicalExpression(BinaryCodec::decode(decoder)?)),
+            26u32 => Ok(Self::NewExpression(BinaryCodec::decode(decoder)?)),
+            27u32 => Ok(Self::ObjectExpression(BinaryCodec::decode(decoder)?)),
+            28u32 => Ok(Self::ParenthesizedExpression(BinaryCodec::decode(decoder)?)),
+            29u32 => Ok(Self::SequenceExpression(BinaryCodec::decode(decoder)?)),
+            30u32 => Ok(Self::TaggedTemplateExpression(BinaryCodec::decode(decoder)?)),
+            31u32 => Ok(Self::ThisExpression(BinaryCodec::decode(decoder)?)),
+            32u32 => Ok(Self::UnaryExpression(BinaryCodec::decode(decoder)?)),
+            33u32 => Ok(Self::UpdateExpression(BinaryCodec::decode(decoder)?)),
+            34u32 => Ok(Self::YieldExpression(BinaryCodec::decode(decoder)?)),
+            35u32 => Ok(Self::PrivateInExpression(BinaryCodec::decode(decoder)?)),
+            36u32 => Ok(Self::JSXElement(BinaryCodec::decode(decoder)?)),
+            37u32 => Ok(Self::JSXFragment(BinaryCodec::decode(decoder)?)),
+            38u32 => Ok(Self::TSAsExpression(BinaryCodec::decode(decoder)?)),
+            39u32 => Ok(Self::TSSatisfiesExpression(BinaryCodec::decode(decoder)?)),
+            40u32 => Ok(Self::TSTypeAssertion(BinaryCodec::decode(decoder)?)),
+            41u32 => Ok(Self::TSNonNullExpression(BinaryCodec::decode(decoder)?)),
+            42u32 => Ok(Self::TSInstantiationExpression(BinaryCodec::decode(decoder)?)),
+            43u32 => Ok(Self::ComputedMemberExpression(BinaryCodec::decode(decoder)?)),
+            44u32 => Ok(Self::StaticMemberExpression(BinaryCodec::decode(decoder)?)),
+            45u32 => Ok(Self::PrivateFieldExpression(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSTypeAnnotation<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.type_annotation, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            type_annotation: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSLiteralType<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.literal, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, literal: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSLiteral<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::BooleanLiteral(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NullLiteral(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NumericLiteral(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BigIntLiteral(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::RegExpLiteral(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StringLiteral(it) => {
+                encoder.write_u32(5u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TemplateLiteral(it) => {
+                encoder.write_u32(6u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::UnaryExpression(it) => {
+                encoder.write_u32(7u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::BooleanLiteral(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::NullLiteral(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::NumericLiteral(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::BigIntLiteral(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::RegExpLiteral(BinaryCodec::decode(decoder)?)),
+            5u32 => Ok(Self::StringLiteral(BinaryCodec::decode(decoder)?)),
+            6u32 => Ok(Self::TemplateLiteral(BinaryCodec::decode(decoder)?)),
+            7u32 => Ok(Self::UnaryExpression(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSType<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::TSAnyKeyword(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSBigIntKeyword(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSBooleanKeyword(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSIntrinsicKeyword(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNeverKeyword(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNullKeyword(it) => {
+                encoder.write_u32(5u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNumberKeyword(it) => {
+                encoder.write_u32(6u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSObjectKeyword(it) => {
+                encoder.write_u32(7u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSStringKeyword(it) => {
+                encoder.write_u32(8u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSSymbolKeyword(it) => {
+                encoder.write_u32(9u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSUndefinedKeyword(it) => {
+                encoder.write_u32(10u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSUnknownKeyword(it) => {
+                encoder.write_u32(11u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSVoidKeyword(it) => {
+                encoder.write_u32(12u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSArrayType(it) => {
+                encoder.write_u32(13u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSConditionalType(it) => {
+                encoder.write_u32(14u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSConstructorType(it) => {
+                encoder.write_u32(15u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSFunctionType(it) => {
+                encoder.write_u32(16u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSImportType(it) => {
+                encoder.write_u32(17u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSIndexedAccessType(it) => {
+                encoder.write_u32(18u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSInferType(it) => {
+                encoder.write_u32(19u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSIntersectionType(it) => {
+                encoder.write_u32(20u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSLiteralType(it) => {
+                encoder.write_u32(21u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSMappedType(it) => {
+                encoder.write_u32(22u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNamedTupleMember(it) => {
+                encoder.write_u32(23u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSQualifiedName(it) => {
+                encoder.write_u32(24u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTemplateLiteralType(it) => {
+                encoder.write_u32(25u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSThisType(it) => {
+                encoder.write_u32(26u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTupleType(it) => {
+                encoder.write_u32(27u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeLiteral(it) => {
+                encoder.write_u32(28u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeOperatorType(it) => {
+                encoder.write_u32(29u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypePredicate(it) => {
+                encoder.write_u32(30u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeQuery(it) => {
+                encoder.write_u32(31u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeReference(it) => {
+                encoder.write_u32(32u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSUnionType(it) => {
+                encoder.write_u32(33u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSParenthesizedType(it) => {
+                encoder.write_u32(34u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSDocNullableType(it) => {
+                encoder.write_u32(35u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSDocNonNullableType(it) => {
+                encoder.write_u32(36u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSDocUnknownType(it) => {
+                encoder.write_u32(37u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::TSAnyKeyword(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::TSBigIntKeyword(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::TSBooleanKeyword(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::TSIntrinsicKeyword(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::TSNeverKeyword(BinaryCodec::decode(decoder)?)),
+            5u32 => Ok(Self::TSNullKeyword(BinaryCodec::decode(decoder)?)),
+            6u32 => Ok(Self::TSNumberKeyword(BinaryCodec::decode(decoder)?)),
+            7u32 => Ok(Self::TSObjectKeyword(BinaryCodec::decode(decoder)?)),
+            8u32 => Ok(Self::TSStringKeyword(BinaryCodec::decode(decoder)?)),
+            9u32 => Ok(Self::TSSymbolKeyword(BinaryCodec::decode(decoder)?)),
+            10u32 => Ok(Self::TSUndefinedKeyword(BinaryCodec::decode(decoder)?)),
+            11u32 => Ok(Self::TSUnknownKeyword(BinaryCodec::decode(decoder)?)),
+            12u32 => Ok(Self::TSVoidKeyword(BinaryCodec::decode(decoder)?)),
+            13u32 => Ok(Self::TSArrayType(BinaryCodec::decode(decoder)?)),
+            14u32 => Ok(Self::TSConditionalType(BinaryCodec::decode(decoder)?)),
+            15u32 => Ok(Self::TSConstructorType(BinaryCodec::decode(decoder)?)),
+            16u32 => Ok(Self::TSFunctionType(BinaryCodec::decode(decoder)?)),
+            17u32 => Ok(Self::TSImportType(BinaryCodec::decode(decoder)?)),
+            18u32 => Ok(Self::TSIndexedAccessType(BinaryCodec::decode(decoder)?)),
+            19u32 => Ok(Self::TSInferType(BinaryCodec::decode(decoder)?)),
+            20u32 => Ok(Self::TSIntersectionType(BinaryCodec::decode(decoder)?)),
+            21u32 => Ok(Self::TSLiteralType(BinaryCodec::decode(decoder)?)),
+            22u32 => Ok(Self::TSMappedType(BinaryCodec::decode(decoder)?)),
+            23u32 => Ok(Self::TSNamedTupleMember(BinaryCodec::decode(decoder)?)),
+            24u32 => Ok(Self::TSQualifiedName(BinaryCodec::decode(decoder)?)),
+            25u32 => Ok(Self::TSTemplateLiteralType(BinaryCodec::decode(decoder)?)),
+            26u32 => Ok(Self::TSThisType(BinaryCodec::decode(decoder)?)),
+            27u32 => Ok(Self::TSTupleType(BinaryCodec::decode(decoder)?)),
+            28u32 => Ok(Self::TSTypeLiteral(BinaryCodec::decode(decoder)?)),
+            29u32 => Ok(Self::TSTypeOperatorType(BinaryCodec::decode(decoder)?)),
+            30u32 => Ok(Self::TSTypePredicate(BinaryCodec::decode(decoder)?)),
+            31u32 => Ok(Self::TSTypeQuery(BinaryCodec::decode(decoder)?)),
+            32u32 => Ok(Self::TSTypeReference(BinaryCodec::decode(decoder)?)),
+            33u32 => Ok(Self::TSUnionType(BinaryCodec::decode(decoder)?)),
+            34u32 => Ok(Self::TSParenthesizedType(BinaryCodec::decode(decoder)?)),
+            35u32 => Ok(Self::JSDocNullableType(BinaryCodec::decode(decoder)?)),
+            36u32 => Ok(Self::JSDocNonNullableType(BinaryCodec::decode(decoder)?)),
+            37u32 => Ok(Self::JSDocUnknownType(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSConditionalType<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.check_type, encoder);
+        BinaryCodec::encode(&self.extends_type, encoder);
+        BinaryCodec::encode(&self.true_type, encoder);
+        BinaryCodec::encode(&self.false_type, encoder);
+        BinaryCodec::encode(&self.scope_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            check_type: BinaryCodec::decode(decoder)?,
+            extends_type: BinaryCodec::decode(decoder)?,
+            true_type: BinaryCodec::decode(decoder)?,
+            false_type: BinaryCodec::decode(decoder)?,
+            scope_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSUnionType<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.types, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, types: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSIntersectionType<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.types, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, types: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSParenthesizedType<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.type_annotation, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            type_annotation: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSTypeOperator<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.operator, encoder);
+        BinaryCodec::encode(&self.type_annotation, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            operator: BinaryCodec::decode(decoder)?,
+            type_annotation: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSTypeOperatorOperator {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Keyof => encoder.write_u32(0u32),
+            Self::Unique => encoder.write_u32(1u32),
+            Self::Readonly => encoder.write_u32(2u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Keyof),
+            1u32 => Ok(Self::Unique),
+            2u32 => Ok(Self::Readonly),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSArrayType<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.element_type, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            element_type: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSIndexedAccessType<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.object_type, encoder);
+        BinaryCodec::encode(&self.index_type, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            object_type: BinaryCodec::decode(decoder)?,
+            index_type: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSTupleType<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.element_types, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            element_types: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSNamedTupleMember<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.element_type, encoder);
+        BinaryCodec::encode(&self.label, encoder);
+        BinaryCodec::encode(&self.optional, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            element_type: BinaryCodec::decode(decoder)?,
+            label: BinaryCodec::decode(decoder)?,
+            optional: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSOptionalType<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.type_annotation, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            type_annotation: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSRestType<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.type_annotation, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            type_annotation: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSTupleElement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::TSOptionalType(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSRestType(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSAnyKeyword(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSBigIntKeyword(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSBooleanKeyword(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSIntrinsicKeyword(it) => {
+                encoder.write_u32(5u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNeverKeyword(it) => {
+                encoder.write_u32(6u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNullKeyword(it) => {
+                encoder.write_u32(7u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNumberKeyword(it) => {
+                encoder.write_u32(8u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSObjectKeyword(it) => {
+                encoder.write_u32(9u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSStringKeyword(it) => {
+                encoder.write_u32(10u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSSymbolKeyword(it) => {
+                encoder.write_u32(11u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSUndefinedKeyword(it) => {
+                encoder.write_u32(12u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSUnknownKeyword(it) => {
+                encoder.write_u32(13u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSVoidKeyword(it) => {
+                encoder.write_u32(14u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSArrayType(it) => {
+                encoder.write_u32(15u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSConditionalType(it) => {
+                encoder.write_u32(16u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSConstructorType(it) => {
+                encoder.write_u32(17u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSFunctionType(it) => {
+                encoder.write_u32(18u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSImportType(it) => {
+                encoder.write_u32(19u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSIndexedAccessType(it) => {
+                encoder.write_u32(20u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSInferType(it) => {
+                encoder.write_u32(21u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSIntersectionType(it) => {
+                encoder.write_u32(22u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSLiteralType(it) => {
+                encoder.write_u32(23u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSMappedType(it) => {
+                encoder.write_u32(24u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNamedTupleMember(it) => {
+                encoder.write_u32(25u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSQualifiedName(it) => {
+                encoder.write_u32(26u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTemplateLiteralType(it) => {
+                encoder.write_u32(27u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSThisType(it) => {
+                encoder.write_u32(28u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTupleType(it) => {
+                encoder.write_u32(29u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeLiteral(it) => {
+                encoder.write_u32(30u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeOperatorType(it) => {
+                encoder.write_u32(31u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypePredicate(it) => {
+                encoder.write_u32(32u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeQuery(it) => {
+                encoder.write_u32(33u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeReference(it) => {
+                encoder.write_u32(34u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSUnionType(it) => {
+                encoder.write_u32(35u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSParenthesizedType(it) => {
+                encoder.write_u32(36u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSDocNullableType(it) => {
+                encoder.write_u32(37u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSDocNonNullableType(it) => {
+                encoder.write_u32(38u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSDocUnknownType(it) => {
+                encoder.write_u32(39u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::TSOptionalType(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::TSRestType(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::TSAnyKeyword(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::TSBigIntKeyword(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::TSBooleanKeyword(BinaryCodec::decode(decoder)?)),
+            5u32 => Ok(Self::TSIntrinsicKeyword(BinaryCodec::decode(decoder)?)),
+            6u32 => Ok(Self::TSNeverKeyword(BinaryCodec::decode(decoder)?)),
+            7u32 => Ok(Self::TSNullKeyword(BinaryCodec::decode(decoder)?)),
+            8u32 => Ok(Self::TSNumberKeyword(BinaryCodec::decode(decoder)?)),
+            9u32 => Ok(Self::TSObjectKeyword(BinaryCodec::decode(decoder)?)),
+            10u32 => Ok(Self::TSStringKeyword(BinaryCodec::decode(decoder)?)),
+            11u32 => Ok(Self::TSSymbolKeyword(BinaryCodec::decode(decoder)?)),
+            12u32 => Ok(Self::TSUndefinedKeyword(BinaryCodec::decode(decoder)?)),
+            13u32 => Ok(Self::TSUnknownKeyword(BinaryCodec::decode(decoder)?)),
+            14u32 => Ok(Self::TSVoidKeyword(BinaryCodec::decode(decoder)?)),
+            15u32 => Ok(Self::TSArrayType(BinaryCodec::decode(decoder)?)),
+            16u32 => Ok(Self::TSConditionalType(BinaryCodec::decode(decoder)?)),
+            17u32 => Ok(Self::TSConstructorType(BinaryCodec::decode(decoder)?)),
+            18u32 => Ok(Self::TSFunctionType(BinaryCodec::decode(decoder)?)),
+            19u32 => Ok(Self::TSImportType(BinaryCodec::decode(decoder)?)),
+            20u32 => Ok(Self::TSIndexedAccessType(BinaryCodec::decode(decoder)?)),
+            21u32 => Ok(Self::TSInferType(BinaryCodec::decode(decoder)?)),
+            22u32 => Ok(Self::TSIntersectionType(BinaryCodec::decode(decoder)?)),
+            23u32 => Ok(Self::TSLiteralType(BinaryCodec::decode(decoder)?)),
+            24u32 => Ok(Self::TSMappedType(BinaryCodec::decode(decoder)?)),
+            25u32 => Ok(Self::TSNamedTupleMember(BinaryCodec::decode(decoder)?)),
+            26u32 => Ok(Self::TSQualifiedName(BinaryCodec::decode(decoder)?)),
+            27u32 => Ok(Self::TSTemplateLiteralType(BinaryCodec::decode(decoder)?)),
+            28u32 => Ok(Self::TSThisType(BinaryCodec::decode(decoder)?)),
+            29u32 => Ok(Self::TSTupleType(BinaryCodec::decode(decoder)?)),
+            30u32 => Ok(Self::TSTypeLiteral(BinaryCodec::decode(decoder)?)),
+            31u32 => Ok(Self::TSTypeOperatorType(BinaryCodec::decode(decoder)?)),
+            32u32 => Ok(Self::TSTypePredicate(BinaryCodec::decode(decoder)?)),
+            33u32 => Ok(Self::TSTypeQuery(BinaryCodec::decode(decoder)?)),
+            34u32 => Ok(Self::TSTypeReference(BinaryCodec::decode(decoder)?)),
+            35u32 => Ok(Self::TSUnionType(BinaryCodec::decode(decoder)?)),
+            36u32 => Ok(Self::TSParenthesizedType(BinaryCodec::decode(decoder)?)),
+            37u32 => Ok(Self::JSDocNullableType(BinaryCodec::decode(decoder)?)),
+            38u32 => Ok(Self::JSDocNonNullableType(BinaryCodec::decode(decoder)?)),
+            39u32 => Ok(Self::JSDocUnknownType(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSAnyKeyword {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSStringKeyword {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSBooleanKeyword {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSNumberKeyword {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSNeverKeyword {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSIntrinsicKeyword {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSUnknownKeyword {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSNullKeyword {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSUndefinedKeyword {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSVoidKeyword {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSSymbolKeyword {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSThisType {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSObjectKeyword {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSBigIntKeyword {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSTypeReference<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.type_name, encoder);
+        BinaryCodec::encode(&self.type_parameters, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            type_name: BinaryCodec::decode(decoder)?,
+            type_parameters: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSTypeName<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::IdentifierReference(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::QualifiedName(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::IdentifierReference(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::QualifiedName(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSQualifiedName<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.left, encoder);
+        BinaryCodec::encode(&self.right, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            left: BinaryCodec::decode(decoder)?,
+            right: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSTypeParameterInstantiation<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.params, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, params: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSTypeParameter<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.name, encoder);
+        BinaryCodec::encode(&self.constraint, encoder);
+        BinaryCodec::encode(&self.default, encoder);
+        BinaryCodec::encode(&self.r#in, encoder);
+        BinaryCodec::encode(&self.out, encoder);
+        BinaryCodec::encode(&self.r#const, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            name: BinaryCodec::decode(decoder)?,
+            constraint: BinaryCodec::decode(decoder)?,
+            default: BinaryCodec::decode(decoder)?,
+            r#in: BinaryCodec::decode(decoder)?,
+            out: BinaryCodec::decode(decoder)?,
+            r#const: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSTypeParameterDeclaration<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.params, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, params: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSTypeAliasDeclaration<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.id, encoder);
+        BinaryCodec::encode(&self.type_parameters, encoder);
+        BinaryCodec::encode(&self.type_annotation, encoder);
+        BinaryCodec::encode(&self.declare, encoder);
+        BinaryCodec::encode(&self.scope_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            id: BinaryCodec::decode(decoder)?,
+            type_parameters: BinaryCodec::decode(decoder)?,
+            type_annotation: BinaryCodec::decode(decoder)?,
+            declare: BinaryCodec::decode(decoder)?,
+            scope_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSAccessibility {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Private => encoder.write_u32(0u32),
+            Self::Protected => encoder.write_u32(1u32),
+            Self::Public => encoder.write_u32(2u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Private),
+            1u32 => Ok(Self::Protected),
+            2u32 => Ok(Self::Public),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSClassImplements<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.expression, encoder);
+        BinaryCodec::encode(&self.type_parameters, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            expression: BinaryCodec::decode(decoder)?,
+            type_parameters: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSInterfaceDeclaration<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.id, encoder);
+        BinaryCodec::encode(&self.extends, encoder);
+        BinaryCodec::encode(&self.type_parameters, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+        BinaryCodec::encode(&self.declare, encoder);
+        BinaryCodec::encode(&self.scope_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            id: BinaryCodec::decode(decoder)?,
+            extends: BinaryCodec::decode(decoder)?,
+            type_parameters: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+            declare: BinaryCodec::decode(decoder)?,
+            scope_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSInterfaceBody<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, body: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSPropertySignature<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.computed, encoder);
+        BinaryCodec::encode(&self.optional, encoder);
+        BinaryCodec::encode(&self.readonly, encoder);
+        BinaryCodec::encode(&self.key, encoder);
+        BinaryCodec::encode(&self.type_annotation, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            computed: BinaryCodec::decode(decoder)?,
+            optional: BinaryCodec::decode(decoder)?,
+            readonly: BinaryCodec::decode(decoder)?,
+            key: BinaryCodec::decode(decoder)?,
+            type_annotation: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSSignature<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::TSIndexSignature(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSPropertySignature(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSCallSignatureDeclaration(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSConstructSignatureDeclaration(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSMethodSignature(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::TSIndexSignature(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::TSPropertySignature(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::TSCallSignatureDeclaration(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::TSConstructSignatureDeclaration(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::TSMethodSignature(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSIndexSignature<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.parameters, encoder);
+        BinaryCodec::encode(&self.type_annotation, encoder);
+        BinaryCodec::encode(&self.readonly, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            parameters: BinaryCodec::decode(decoder)?,
+            type_annotation: BinaryCodec::decode(decoder)?,
+            readonly: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSCallSignatureDeclaration<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.type_parameters, encoder);
+        BinaryCodec::encode(&self.this_param, encoder);
+        BinaryCodec::encode(&self.params, encoder);
+        BinaryCodec::encode(&self.return_type, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            type_parameters: BinaryCodec::decode(decoder)?,
+            this_param: BinaryCodec::decode(decoder)?,
+            params: BinaryCodec::decode(decoder)?,
+            return_type: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSMethodSignatureKind {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Method => encoder.write_u32(0u32),
+            Self::Get => encoder.write_u32(1u32),
+            Self::Set => encoder.write_u32(2u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Method),
+            1u32 => Ok(Self::Get),
+            2u32 => Ok(Self::Set),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSMethodSignature<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.key, encoder);
+        BinaryCodec::encode(&self.computed, encoder);
+        BinaryCodec::encode(&self.optional, encoder);
+        BinaryCodec::encode(&self.kind, encoder);
+        BinaryCodec::encode(&self.type_parameters, encoder);
+        BinaryCodec::encode(&self.this_param, encoder);
+        BinaryCodec::encode(&self.params, encoder);
+        BinaryCodec::encode(&self.return_type, encoder);
+        BinaryCodec::encode(&self.scope_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            key: BinaryCodec::decode(decoder)?,
+            computed: BinaryCodec::decode(decoder)?,
+            optional: BinaryCodec::decode(decoder)?,
+            kind: BinaryCodec::decode(decoder)?,
+            type_parameters: BinaryCodec::decode(decoder)?,
+            this_param: BinaryCodec::decode(decoder)?,
+            params: BinaryCodec::decode(decoder)?,
+            return_type: BinaryCodec::decode(decoder)?,
+            scope_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSConstructSignatureDeclaration<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.type_parameters, encoder);
+        BinaryCodec::encode(&self.params, encoder);
+        BinaryCodec::encode(&self.return_type, encoder);
+        BinaryCodec::encode(&self.scope_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            type_parameters: BinaryCodec::decode(decoder)?,
+            params: BinaryCodec::decode(decoder)?,
+            return_type: BinaryCodec::decode(decoder)?,
+            scope_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSIndexSignatureName<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.name, encoder);
+        BinaryCodec::encode(&self.type_annotation, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            name: BinaryCodec::decode(decoder)?,
+            type_annotation: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSInterfaceHeritage<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.expression, encoder);
+        BinaryCodec::encode(&self.type_parameters, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            expression: BinaryCodec::decode(decoder)?,
+            type_parameters: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSTypePredicate<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.parameter_name, encoder);
+        BinaryCodec::encode(&self.asserts, encoder);
+        BinaryCodec::encode(&self.type_annotation, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            parameter_name: BinaryCodec::decode(decoder)?,
+            asserts: BinaryCodec::decode(decoder)?,
+            type_annotation: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSTypePredicateName<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Identifier(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::This(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Identifier(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::This(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSModuleDeclaration<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.id, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+        BinaryCodec::encode(&self.kind, encoder);
+        BinaryCodec::encode(&self.declare, encoder);
+        BinaryCodec::encode(&self.scope_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            id: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+            kind: BinaryCodec::decode(decoder)?,
+            declare: BinaryCodec::decode(decoder)?,
+            scope_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSModuleDeclarationKind {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Global => encoder.write_u32(0u32),
+            Self::Module => encoder.write_u32(1u32),
+            Self::Namespace => encoder.write_u32(2u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Global),
+            1u32 => Ok(Self::Module),
+            2u32 => Ok(Self::Namespace),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSModuleDeclarationName<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Identifier(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StringLiteral(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Identifier(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::StringLiteral(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSModuleDeclarationBody<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::TSModuleDeclaration(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSModuleBlock(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::TSModuleDeclaration(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::TSModuleBlock(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSModuleBlock<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.directives, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            directives: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSTypeLiteral<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.members, encoder);
+        BinaryCodec::encode(&self.flow_exact, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            members: BinaryCodec::decode(decoder)?,
+            flow_exact: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSInferType<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.type_parameter, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            type_parameter: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSTypeQuery<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.expr_name, encoder);
+        BinaryCodec::encode(&self.type_parameters, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            expr_name: BinaryCodec::decode(decoder)?,
+            type_parameters: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSTypeQueryExprName<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::TSImportType(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::IdentifierReference(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::QualifiedName(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::TSImportType(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::IdentifierReference(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::QualifiedName(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSImportType<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.is_type_of, encoder);
+        BinaryCodec::encode(&self.parameter, encoder);
+        BinaryCodec::encode(&self.qualifier, encoder);
+        BinaryCodec::encode(&self.attributes, encoder);
+        BinaryCodec::encode(&self.type_parameters, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            is_type_of: BinaryCodec::decode(decoder)?,
+            parameter: BinaryCodec::decode(decoder)?,
+            qualifier: BinaryCodec::decode(decoder)?,
+            attributes: BinaryCodec::decode(decoder)?,
+            type_parameters: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSImportAttributes<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.attributes_keyword, encoder);
+        BinaryCodec::encode(&self.elements, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            attributes_keyword: BinaryCodec::decode(decoder)?,
+            elements: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSImportAttribute<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.name, encoder);
+        BinaryCodec::encode(&self.value, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            name: BinaryCodec::decode(decoder)?,
+            value: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSImportAttributeName<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Identifier(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StringLiteral(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Identifier(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::StringLiteral(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSFunctionType<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.type_parameters, encoder);
+        BinaryCodec::encode(&self.this_param, encoder);
+        BinaryCodec::encode(&self.params, encoder);
+        BinaryCodec::encode(&self.return_type, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            type_parameters: BinaryCodec::decode(decoder)?,
+            this_param: BinaryCodec::decode(decoder)?,
+            params: BinaryCodec::decode(decoder)?,
+            return_type: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSConstructorType<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.r#abstract, encoder);
+        BinaryCodec::encode(&self.type_parameters, encoder);
+        BinaryCodec::encode(&self.params, encoder);
+        BinaryCodec::encode(&self.return_type, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            r#abstract: BinaryCodec::decode(decoder)?,
+            type_parameters: BinaryCodec::decode(decoder)?,
+            params: BinaryCodec::decode(decoder)?,
+            return_type: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSMappedType<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.type_parameter, encoder);
+        BinaryCodec::encode(&self.name_type, encoder);
+        BinaryCodec::encode(&self.type_annotation, encoder);
+        BinaryCodec::encode(&self.optional, encoder);
+        BinaryCodec::encode(&self.readonly, encoder);
+        BinaryCodec::encode(&self.scope_id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            type_parameter: BinaryCodec::decode(decoder)?,
+            name_type: BinaryCodec::decode(decoder)?,
+            type_annotation: BinaryCodec::decode(decoder)?,
+            optional: BinaryCodec::decode(decoder)?,
+            readonly: BinaryCodec::decode(decoder)?,
+            scope_id: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSMappedTypeModifierOperator {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::True => encoder.write_u32(0u32),
+            Self::Plus => encoder.write_u32(1u32),
+            Self::Minus => encoder.write_u32(2u32),
+            Self::None => encoder.write_u32(3u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::True),
+            1u32 => Ok(Self::Plus),
+            2u32 => Ok(Self::Minus),
+            3u32 => Ok(Self::None),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSTemplateLiteralType<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.quasis, encoder);
+        BinaryCodec::encode(&self.types, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            quasis: BinaryCodec::decode(decoder)?,
+            types: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSAsExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.expression, encoder);
+        BinaryCodec::encode(&self.type_annotation, encoder);
+        BinaryCodec::encode(&self.flow_type_cast, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            expression: BinaryCodec::decode(decoder)?,
+            type_annotation: BinaryCodec::decode(decoder)?,
+            flow_type_cast: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSSatisfiesExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.expression, encoder);
+        BinaryCodec::encode(&self.type_annotation, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            expression: BinaryCodec::decode(decoder)?,
+            type_annotation: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSTypeAssertion<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.expression, encoder);
+        BinaryCodec::encode(&self.type_annotation, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            expression: BinaryCodec::decode(decoder)?,
+            type_annotation: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSImportEqualsDeclaration<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.id, encoder);
+        BinaryCodec::encode(&self.module_reference, encoder);
+        BinaryCodec::encode(&self.import_kind, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            id: BinaryCodec::decode(decoder)?,
+            module_reference: BinaryCodec::decode(decoder)?,
+            import_kind: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSModuleReference<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::ExternalModuleReference(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::IdentifierReference(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::QualifiedName(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::ExternalModuleReference(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::IdentifierReference(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::QualifiedName(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSExternalModuleReference<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.expression, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, expression: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSNonNullExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.expression, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, expression: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Decorator<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.expression, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, expression: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSExportAssignment<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.expression, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, expression: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSNamespaceExportDeclaration<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.id, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, id: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for TSInstantiationExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.expression, encoder);
+        BinaryCodec::encode(&self.type_parameters, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            expression: BinaryCodec::decode(decoder)?,
+            type_parameters: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ImportOrExportKind {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Value => encoder.write_u32(0u32),
+            Self::Type => encoder.write_u32(1u32),
+            Self::Typeof => encoder.write_u32(2u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Value),
+            1u32 => Ok(Self::Type),
+            2u32 => Ok(Self::Typeof),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSDocNullableType<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.type_annotation, encoder);
+        BinaryCodec::encode(&self.postfix, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            type_annotation: BinaryCodec::decode(decoder)?,
+            postfix: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSDocNonNullableType<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.type_annotation, encoder);
+        BinaryCodec::encode(&self.postfix, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            type_annotation: BinaryCodec::decode(decoder)?,
+            postfix: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSDocUnknownType {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXElement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.opening_element, encoder);
+        BinaryCodec::encode(&self.closing_element, encoder);
+        BinaryCodec::encode(&self.children, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            opening_element: BinaryCodec::decode(decoder)?,
+            closing_element: BinaryCodec::decode(decoder)?,
+            children: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXOpeningElement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.self_closing, encoder);
+        BinaryCodec::encode(&self.name, encoder);
+        BinaryCodec::encode(&self.attributes, encoder);
+        BinaryCodec::encode(&self.type_parameters, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            self_closing: BinaryCodec::decode(decoder)?,
+            name: BinaryCodec::decode(decoder)?,
+            attributes: BinaryCodec::decode(decoder)?,
+            type_parameters: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXClosingElement<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.name, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, name: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXFragment<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.opening_fragment, encoder);
+        BinaryCodec::encode(&self.closing_fragment, encoder);
+        BinaryCodec::encode(&self.children, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            opening_fragment: BinaryCodec::decode(decoder)?,
+            closing_fragment: BinaryCodec::decode(decoder)?,
+            children: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXOpeningFragment {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXClosingFragment {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXElementName<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Identifier(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::IdentifierReference(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NamespacedName(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::MemberExpression(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ThisExpression(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Identifier(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::IdentifierReference(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::NamespacedName(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::MemberExpression(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::ThisExpression(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXNamespacedName<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.namespace, encoder);
+        BinaryCodec::encode(&self.property, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            namespace: BinaryCodec::decode(decoder)?,
+            property: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXMemberExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.object, encoder);
+        BinaryCodec::encode(&self.property, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            object: BinaryCodec::decode(decoder)?,
+            property: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXMemberExpressionObject<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::IdentifierReference(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::MemberExpression(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ThisExpression(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::IdentifierReference(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::MemberExpression(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::ThisExpression(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXExpressionContainer<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.expression, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, expression: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXExpression<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::EmptyExpression(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BooleanLiteral(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NullLiteral(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NumericLiteral(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BigIntLiteral(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::RegExpLiteral(it) => {
+                encoder.write_u32(5u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StringLiteral(it) => {
+                encoder.write_u32(6u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TemplateLiteral(it) => {
+                encoder.write_u32(7u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Identifier(it) => {
+                encoder.write_u32(8u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::MetaProperty(it) => {
+                encoder.write_u32(9u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Super(it) => {
+                encoder.write_u32(10u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ArrayExpression(it) => {
+                encoder.write_u32(11u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ArrowFunctionExpression(it) => {
+                encoder.write_u32(12u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AssignmentExpression(it) => {
+                encoder.write_u32(13u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::AwaitExpression(it) => {
+                encoder.write_u32(14u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::BinaryExpression(it) => {
+                encoder.write_u32(15u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::CallExpression(it) => {
+                encoder.write_u32(16u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ChainExpression(it) => {
+                encoder.write_u32(17u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ClassExpression(it) => {
+                encoder.write_u32(18u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ConditionalExpression(it) => {
+                encoder.write_u32(19u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::FunctionExpression(it) => {
+                encoder.write_u32(20u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ImportExpression(it) => {
+                encoder.write_u32(21u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::LogicalExpression(it) => {
+                encoder.write_u32(22u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NewExpression(it) => {
+                encoder.write_u32(23u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ObjectExpression(it) => {
+                encoder.write_u32(24u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ParenthesizedExpression(it) => {
+                encoder.write_u32(25u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::SequenceExpression(it) => {
+                encoder.write_u32(26u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TaggedTemplateExpression(it) => {
+                encoder.write_u32(27u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ThisExpression(it) => {
+                encoder.write_u32(28u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::UnaryExpression(it) => {
+                encoder.write_u32(29u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::UpdateExpression(it) => {
+                encoder.write_u32(30u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::YieldExpression(it) => {
+                encoder.write_u32(31u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateInExpression(it) => {
+                encoder.write_u32(32u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSXElement(it) => {
+                encoder.write_u32(33u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::JSXFragment(it) => {
+                encoder.write_u32(34u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSAsExpression(it) => {
+                encoder.write_u32(35u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSSatisfiesExpression(it) => {
+                encoder.write_u32(36u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSTypeAssertion(it) => {
+                encoder.write_u32(37u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSNonNullExpression(it) => {
+                encoder.write_u32(38u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::TSInstantiationExpression(it) => {
+                encoder.write_u32(39u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ComputedMemberExpression(it) => {
+                encoder.write_u32(40u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::StaticMemberExpression(it) => {
+                encoder.write_u32(41u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::PrivateFieldExpression(it) => {
+                encoder.write_u32(42u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::EmptyExpression(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::BooleanLiteral(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::NullLiteral(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::NumericLiteral(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::BigIntLiteral(BinaryCodec::decode(decoder)?)),
+            5u32 => Ok(Self::RegExpLiteral(BinaryCodec::decode(decoder)?)),
+            6u32 => Ok(Self::StringLiteral(BinaryCodec::decode(decoder)?)),
+            7u32 => Ok(Self::TemplateLiteral(BinaryCodec::decode(decoder)?)),
+            8u32 => Ok(Self::Identifier(BinaryCodec::decode(decoder)?)),
+            9u32 => Ok(Self::MetaProperty(BinaryCodec::decode(decoder)?)),
+            10u32 => Ok(Self::Super(BinaryCodec::decode(decoder)?)),
+            11u32 => Ok(Self::ArrayExpression(BinaryCodec::decode(decoder)?)),
+            12u32 => Ok(Self::ArrowFunctionExpression(BinaryCodec::decode(decoder)?)),
+            13u32 => Ok(Self::AssignmentExpression(BinaryCodec::decode(decoder)?)),
+            14u32 => Ok(Self::AwaitExpression(BinaryCodec::decode(decoder)?)),
+            15u32 => Ok(Self::BinaryExpression(BinaryCodec::decode(decoder)?)),
+            16u32 => Ok(Self::CallExpression(BinaryCodec::decode(decoder)?)),
+            17u32 => Ok(Self::ChainExpression(BinaryCodec::decode(decoder)?)),
+            18u32 => Ok(Self::ClassExpression(BinaryCodec::decode(decoder)?)),
+            19u32 => Ok(Self::ConditionalExpression(BinaryCodec::decode(decoder)?)),
+            20u32 => Ok(Self::FunctionExpression(BinaryCodec::decode(decoder)?)),
+            21u32 => Ok(Self::ImportExpression(BinaryCodec::decode(decoder)?)),
+            22u32 => Ok(Self::LogicalExpression(BinaryCodec::decode(decoder)?)),
+            23u32 => Ok(Self::NewExpression(BinaryCodec::decode(decoder)?)),
+            24u32 => Ok(Self::ObjectExpression(BinaryCodec::decode(decoder)?)),
+            25u32 => Ok(Self::ParenthesizedExpression(BinaryCodec::decode(decoder)?)),
+            26u32 => Ok(Self::SequenceExpression(BinaryCodec::decode(decoder)?)),
+            27u32 => Ok(Self::TaggedTemplateExpression(BinaryCodec::decode(decoder)?)),
+            28u32 => Ok(Self::ThisExpression(BinaryCodec::decode(decoder)?)),
+            29u32 => Ok(Self::UnaryExpression(BinaryCodec::decode(decoder)?)),
+            30u32 => Ok(Self::UpdateExpression(BinaryCodec::decode(decoder)?)),
+            31u32 => Ok(Self::YieldExpression(BinaryCodec::decode(decoder)?)),
+            32u32 => Ok(Self::PrivateInExpression(BinaryCodec::decode(decoder)?)),
+            33u32 => Ok(Self::JSXElement(BinaryCodec::decode(decoder)?)),
+            34u32 => Ok(Self::JSXFragment(BinaryCodec::decode(decoder)?)),
+            35u32 => Ok(Self::TSAsExpression(BinaryCodec::decode(decoder)?)),
+            36u32 => Ok(Self::TSSatisfiesExpression(BinaryCodec::decode(decoder)?)),
+            37u32 => Ok(Self::TSTypeAssertion(BinaryCodec::decode(decoder)?)),
+            38u32 => Ok(Self::TSNonNullExpression(BinaryCodec::decode(decoder)?)),
+            39u32 => Ok(Self::TSInstantiationExpression(BinaryCodec::decode(decoder)?)),
+            40u32 => Ok(Self::ComputedMemberExpression(BinaryCodec::decode(decoder)?)),
+            41u32 => Ok(Self::StaticMemberExpression(BinaryCodec::decode(decoder)?)),
+            42u32 => Ok(Self::PrivateFieldExpression(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXEmptyExpression {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXAttributeItem<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Attribute(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::SpreadAttribute(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Attribute(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::SpreadAttribute(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXAttribute<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.name, encoder);
+        BinaryCodec::encode(&self.value, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            name: BinaryCodec::decode(decoder)?,
+            value: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXSpreadAttribute<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.argument, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, argument: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXAttributeName<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Identifier(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NamespacedName(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Identifier(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::NamespacedName(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXAttributeValue<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::StringLiteral(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ExpressionContainer(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Element(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Fragment(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::StringLiteral(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::ExpressionContainer(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::Element(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::Fragment(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXIdentifier<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.name, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, name: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXChild<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Text(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Element(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Fragment(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ExpressionContainer(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Spread(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Text(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::Element(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::Fragment(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::ExpressionContainer(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::Spread(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXSpreadChild<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.expression, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, expression: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for JSXText<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.value, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, value: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for CommentKind {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Line => encoder.write_u32(0u32),
+            Self::Block => encoder.write_u32(1u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Line),
+            1u32 => Ok(Self::Block),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for CommentPosition {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Leading => encoder.write_u32(0u32),
+            Self::Trailing => encoder.write_u32(1u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Leading),
+            1u32 => Ok(Self::Trailing),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Comment {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.kind, encoder);
+        BinaryCodec::encode(&self.position, encoder);
+        BinaryCodec::encode(&self.attached_to, encoder);
+        BinaryCodec::encode(&self.preceded_by_newline, encoder);
+        BinaryCodec::encode(&self.followed_by_newline, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            kind: BinaryCodec::decode(decoder)?,
+            position: BinaryCodec::decode(decoder)?,
+            attached_to: BinaryCodec::decode(decoder)?,
+            preceded_by_newline: BinaryCodec::decode(decoder)?,
+            followed_by_newline: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
@@ -1282,6 +1282,7 @@ impl<'old_alloc, 'new_alloc> CloneIn<'new_alloc> for Statement<'old_alloc> {
             Self::TryStatement(it) => Statement::TryStatement(CloneIn::clone_in(it, allocator)),
             Self::WhileStatement(it) => Statement::WhileStatement(CloneIn::clone_in(it, allocator)),
             Self::WithStatement(it) => Statement::WithStatement(CloneIn::clone_in(it, allocator)),
+            Self::ErrorStatement(it) => Statement::ErrorStatement(CloneIn::clone_in(it, allocator)),
             Self::VariableDeclaration(it) => {
                 Statement::VariableDeclaration(CloneIn::clone_in(it, allocator))
             }
@@ -1823,6 +1824,13 @@ impl<'alloc> CloneIn<'alloc> for DebuggerStatement {
     }
 }
 
+impl<'alloc> CloneIn<'alloc> for ErrorStatement {
+    type Cloned = ErrorStatement;
+    fn clone_in(&self, allocator: &'alloc Allocator) -> Self::Cloned {
+        ErrorStatement { span: CloneIn::clone_in(&self.span, allocator) }
+    }
+}
+
 impl<'old_alloc, 'new_alloc> CloneIn<'new_alloc> for BindingPattern<'old_alloc> {
     type Cloned = BindingPattern<'new_alloc>;
     fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
@@ -3549,6 +3557,7 @@ impl<'old_alloc, 'new_alloc> CloneIn<'new_alloc> for TSTypeLiteral<'old_alloc> {
         TSTypeLiteral {
             span: CloneIn::clone_in(&self.span, allocator),
             members: CloneIn::clone_in(&self.members, allocator),
+            flow_exact: CloneIn::clone_in(&self.flow_exact, allocator),
         }
     }
 }
@@ -3712,6 +3721,7 @@ impl<'old_alloc, 'new_alloc> CloneIn<'new_alloc> for TSAsExpression<'old_alloc>
             span: CloneIn::clone_in(&self.span, allocator),
             expression: CloneIn::clone_in(&self.expression, allocator),
             type_annotation: CloneIn::clone_in(&self.type_annotation, allocator),
+            flow_type_cast: CloneIn::clone_in(&self.flow_type_cast, allocator),
         }
     }
 }
@@ -3834,6 +3844,7 @@ impl<'alloc> CloneIn<'alloc> for ImportOrExportKind {
         match self {
             Self::Value => ImportOrExportKind::Value,
             Self::Type => ImportOrExportKind::Type,
+            Self::Typeof => ImportOrExportKind::Typeof,
         }
     }
 }
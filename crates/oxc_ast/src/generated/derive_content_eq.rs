@@ -1419,6 +1419,10 @@ impl<'a> ContentEq for Statement<'a> {
                 Self::WithStatement(other) if ContentEq::content_eq(it, other) => true,
                 _ => false,
             },
+            Self::ErrorStatement(it) => match other {
+                Self::ErrorStatement(other) if ContentEq::content_eq(it, other) => true,
+                _ => false,
+            },
             Self::VariableDeclaration(it) => match other {
                 Self::VariableDeclaration(other) if ContentEq::content_eq(it, other) => true,
                 _ => false,
@@ -1935,6 +1939,12 @@ impl ContentEq for DebuggerStatement {
     }
 }
 
+impl ContentEq for ErrorStatement {
+    fn content_eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
 impl<'a> ContentEq for BindingPattern<'a> {
     fn content_eq(&self, other: &Self) -> bool {
         ContentEq::content_eq(&self.kind, &other.kind)
@@ -3586,6 +3596,7 @@ impl<'a> ContentEq for TSModuleBlock<'a> {
 impl<'a> ContentEq for TSTypeLiteral<'a> {
     fn content_eq(&self, other: &Self) -> bool {
         ContentEq::content_eq(&self.members, &other.members)
+            && ContentEq::content_eq(&self.flow_exact, &other.flow_exact)
     }
 }
 
@@ -3705,6 +3716,7 @@ impl<'a> ContentEq for TSAsExpression<'a> {
     fn content_eq(&self, other: &Self) -> bool {
         ContentEq::content_eq(&self.expression, &other.expression)
             && ContentEq::content_eq(&self.type_annotation, &other.type_annotation)
+            && ContentEq::content_eq(&self.flow_type_cast, &other.flow_type_cast)
     }
 }
 
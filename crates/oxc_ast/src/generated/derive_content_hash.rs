@@ -686,6 +686,7 @@ impl<'a> ContentHash for Statement<'a> {
             Self::TryStatement(it) => ContentHash::content_hash(it, state),
             Self::WhileStatement(it) => ContentHash::content_hash(it, state),
             Self::WithStatement(it) => ContentHash::content_hash(it, state),
+            Self::ErrorStatement(it) => ContentHash::content_hash(it, state),
             Self::VariableDeclaration(it) => ContentHash::content_hash(it, state),
             Self::FunctionDeclaration(it) => ContentHash::content_hash(it, state),
             Self::ClassDeclaration(it) => ContentHash::content_hash(it, state),
@@ -968,6 +969,10 @@ impl ContentHash for DebuggerStatement {
     fn content_hash<H: Hasher>(&self, _: &mut H) {}
 }
 
+impl ContentHash for ErrorStatement {
+    fn content_hash<H: Hasher>(&self, _: &mut H) {}
+}
+
 impl<'a> ContentHash for BindingPattern<'a> {
     fn content_hash<H: Hasher>(&self, state: &mut H) {
         ContentHash::content_hash(&self.kind, state);
@@ -1962,6 +1967,7 @@ impl<'a> ContentHash for TSModuleBlock<'a> {
 impl<'a> ContentHash for TSTypeLiteral<'a> {
     fn content_hash<H: Hasher>(&self, state: &mut H) {
         ContentHash::content_hash(&self.members, state);
+        ContentHash::content_hash(&self.flow_exact, state);
     }
 }
 
@@ -2068,6 +2074,7 @@ impl<'a> ContentHash for TSAsExpression<'a> {
     fn content_hash<H: Hasher>(&self, state: &mut H) {
         ContentHash::content_hash(&self.expression, state);
         ContentHash::content_hash(&self.type_annotation, state);
+        ContentHash::content_hash(&self.flow_type_cast, state);
     }
 }
 
@@ -0,0 +1,2560 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `tasks/ast_tools/src/derives/for_each_span_mut.rs`
+
+#![allow(clippy::match_same_arms, clippy::semicolon_if_nothing_returned)]
+
+use oxc_span::{ForEachSpanMut, Span};
+
+#[allow(clippy::wildcard_imports)]
+use crate::ast::comment::*;
+
+#[allow(clippy::wildcard_imports)]
+use crate::ast::js::*;
+
+#[allow(clippy::wildcard_imports)]
+use crate::ast::jsx::*;
+
+#[allow(clippy::wildcard_imports)]
+use crate::ast::literal::*;
+
+#[allow(clippy::wildcard_imports)]
+use crate::ast::ts::*;
+
+impl ForEachSpanMut for BooleanLiteral {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.value, f);
+    }
+}
+
+impl ForEachSpanMut for NullLiteral {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for NumericLiteral<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.value, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.raw, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.base, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for BigIntLiteral<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.raw, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.base, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for RegExpLiteral<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.value, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.regex, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for RegExp<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.pattern, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.flags, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for RegExpPattern<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::Raw(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Invalid(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Pattern(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl ForEachSpanMut for EmptyObject {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl<'a> ForEachSpanMut for StringLiteral<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.value, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for Program<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.source_type, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.source_text, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.comments, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.hashbang, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.directives, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for Expression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::BooleanLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NullLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NumericLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BigIntLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::RegExpLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StringLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TemplateLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Identifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::MetaProperty(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Super(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ArrayExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ArrowFunctionExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::AssignmentExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::AwaitExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BinaryExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::CallExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ChainExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ClassExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ConditionalExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::FunctionExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ImportExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::LogicalExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NewExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ObjectExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ParenthesizedExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::SequenceExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TaggedTemplateExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ThisExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::UnaryExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::UpdateExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::YieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateInExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSXElement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSXFragment(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSAsExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSSatisfiesExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeAssertion(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNonNullExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSInstantiationExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ComputedMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StaticMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateFieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for IdentifierName<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.name, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for IdentifierReference<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.name, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for BindingIdentifier<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.name, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for LabelIdentifier<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.name, f);
+    }
+}
+
+impl ForEachSpanMut for ThisExpression {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ArrayExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.elements, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.trailing_comma, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ArrayExpressionElement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::SpreadElement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Elision(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BooleanLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NullLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NumericLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BigIntLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::RegExpLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StringLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TemplateLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Identifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::MetaProperty(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Super(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ArrayExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ArrowFunctionExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::AssignmentExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::AwaitExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BinaryExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::CallExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ChainExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ClassExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ConditionalExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::FunctionExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ImportExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::LogicalExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NewExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ObjectExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ParenthesizedExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::SequenceExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TaggedTemplateExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ThisExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::UnaryExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::UpdateExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::YieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateInExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSXElement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSXFragment(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSAsExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSSatisfiesExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeAssertion(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNonNullExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSInstantiationExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ComputedMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StaticMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateFieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl ForEachSpanMut for Elision {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ObjectExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.properties, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.trailing_comma, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ObjectPropertyKind<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::ObjectProperty(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::SpreadProperty(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for ObjectProperty<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.kind, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.key, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.value, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.init, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.method, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.shorthand, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.computed, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for PropertyKey<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::StaticIdentifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateIdentifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BooleanLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NullLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NumericLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BigIntLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::RegExpLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StringLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TemplateLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Identifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::MetaProperty(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Super(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ArrayExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ArrowFunctionExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::AssignmentExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::AwaitExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BinaryExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::CallExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ChainExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ClassExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ConditionalExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::FunctionExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ImportExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::LogicalExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NewExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ObjectExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ParenthesizedExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::SequenceExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TaggedTemplateExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ThisExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::UnaryExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::UpdateExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::YieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateInExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSXElement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSXFragment(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSAsExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSSatisfiesExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeAssertion(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNonNullExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSInstantiationExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ComputedMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StaticMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateFieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl ForEachSpanMut for PropertyKind {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl<'a> ForEachSpanMut for TemplateLiteral<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.quasis, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expressions, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TaggedTemplateExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.tag, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.quasi, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameters, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TemplateElement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.tail, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.value, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TemplateElementValue<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.raw, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.cooked, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for MemberExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::ComputedMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StaticMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateFieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for ComputedMemberExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.object, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expression, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.optional, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for StaticMemberExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.object, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.property, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.optional, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for PrivateFieldExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.object, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.field, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.optional, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for CallExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.callee, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameters, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.arguments, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.optional, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for NewExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.callee, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.arguments, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameters, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for MetaProperty<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.meta, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.property, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for SpreadElement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.argument, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for Argument<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::SpreadElement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BooleanLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NullLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NumericLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BigIntLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::RegExpLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StringLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TemplateLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Identifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::MetaProperty(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Super(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ArrayExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ArrowFunctionExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::AssignmentExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::AwaitExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BinaryExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::CallExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ChainExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ClassExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ConditionalExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::FunctionExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ImportExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::LogicalExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NewExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ObjectExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ParenthesizedExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::SequenceExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TaggedTemplateExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ThisExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::UnaryExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::UpdateExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::YieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateInExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSXElement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSXFragment(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSAsExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSSatisfiesExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeAssertion(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNonNullExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSInstantiationExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ComputedMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StaticMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateFieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for UpdateExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.operator, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.prefix, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.argument, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for UnaryExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.operator, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.argument, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for BinaryExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.left, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.operator, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.right, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for PrivateInExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.left, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.operator, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.right, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for LogicalExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.left, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.operator, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.right, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ConditionalExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.test, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.consequent, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.alternate, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for AssignmentExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.operator, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.left, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.right, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for AssignmentTarget<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::AssignmentTargetIdentifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSAsExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSSatisfiesExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNonNullExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeAssertion(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSInstantiationExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ComputedMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StaticMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateFieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ArrayAssignmentTarget(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ObjectAssignmentTarget(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for SimpleAssignmentTarget<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::AssignmentTargetIdentifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSAsExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSSatisfiesExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNonNullExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeAssertion(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSInstantiationExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ComputedMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StaticMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateFieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for AssignmentTargetPattern<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::ArrayAssignmentTarget(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ObjectAssignmentTarget(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for ArrayAssignmentTarget<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.elements, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.rest, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.trailing_comma, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ObjectAssignmentTarget<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.properties, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.rest, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for AssignmentTargetRest<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.target, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for AssignmentTargetMaybeDefault<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::AssignmentTargetWithDefault(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::AssignmentTargetIdentifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSAsExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSSatisfiesExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNonNullExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeAssertion(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSInstantiationExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ComputedMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StaticMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateFieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ArrayAssignmentTarget(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ObjectAssignmentTarget(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for AssignmentTargetWithDefault<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.binding, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.init, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for AssignmentTargetProperty<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::AssignmentTargetPropertyIdentifier(it) => {
+                ForEachSpanMut::for_each_span_mut(it, f)
+            }
+            Self::AssignmentTargetPropertyProperty(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for AssignmentTargetPropertyIdentifier<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.binding, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.init, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for AssignmentTargetPropertyProperty<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.name, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.binding, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for SequenceExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expressions, f);
+    }
+}
+
+impl ForEachSpanMut for Super {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for AwaitExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.argument, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ChainExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expression, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ChainElement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::CallExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ComputedMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StaticMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateFieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for ParenthesizedExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expression, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for Statement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::BlockStatement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BreakStatement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ContinueStatement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::DebuggerStatement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::DoWhileStatement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::EmptyStatement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ExpressionStatement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ForInStatement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ForOfStatement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ForStatement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::IfStatement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::LabeledStatement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ReturnStatement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::SwitchStatement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ThrowStatement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TryStatement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::WhileStatement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::WithStatement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ErrorStatement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::VariableDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::FunctionDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ClassDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeAliasDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSInterfaceDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSEnumDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSModuleDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSImportEqualsDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ImportDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ExportAllDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ExportDefaultDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ExportNamedDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSExportAssignment(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNamespaceExportDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for Directive<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expression, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.directive, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for Hashbang<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.value, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for BlockStatement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for Declaration<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::VariableDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::FunctionDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ClassDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeAliasDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSInterfaceDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSEnumDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSModuleDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSImportEqualsDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for VariableDeclaration<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.kind, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.declarations, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.declare, f);
+    }
+}
+
+impl ForEachSpanMut for VariableDeclarationKind {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl<'a> ForEachSpanMut for VariableDeclarator<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.kind, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.id, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.init, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.definite, f);
+    }
+}
+
+impl ForEachSpanMut for EmptyStatement {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ExpressionStatement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expression, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for IfStatement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.test, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.consequent, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.alternate, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for DoWhileStatement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.test, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for WhileStatement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.test, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ForStatement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.init, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.test, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.update, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ForStatementInit<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::VariableDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BooleanLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NullLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NumericLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BigIntLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::RegExpLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StringLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TemplateLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Identifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::MetaProperty(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Super(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ArrayExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ArrowFunctionExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::AssignmentExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::AwaitExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BinaryExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::CallExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ChainExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ClassExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ConditionalExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::FunctionExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ImportExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::LogicalExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NewExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ObjectExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ParenthesizedExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::SequenceExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TaggedTemplateExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ThisExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::UnaryExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::UpdateExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::YieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateInExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSXElement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSXFragment(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSAsExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSSatisfiesExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeAssertion(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNonNullExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSInstantiationExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ComputedMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StaticMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateFieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for ForInStatement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.left, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.right, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ForStatementLeft<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::VariableDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::AssignmentTargetIdentifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSAsExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSSatisfiesExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNonNullExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeAssertion(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSInstantiationExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ComputedMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StaticMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateFieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ArrayAssignmentTarget(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ObjectAssignmentTarget(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for ForOfStatement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.r#await, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.left, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.right, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ContinueStatement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.label, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for BreakStatement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.label, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ReturnStatement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.argument, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for WithStatement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.object, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for SwitchStatement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.discriminant, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.cases, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for SwitchCase<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.test, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.consequent, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for LabeledStatement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.label, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ThrowStatement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.argument, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TryStatement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.block, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.handler, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.finalizer, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for CatchClause<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.param, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for CatchParameter<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.pattern, f);
+    }
+}
+
+impl ForEachSpanMut for DebuggerStatement {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl ForEachSpanMut for ErrorStatement {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for BindingPattern<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.kind, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_annotation, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.optional, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for BindingPatternKind<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::BindingIdentifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ObjectPattern(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ArrayPattern(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::AssignmentPattern(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for AssignmentPattern<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.left, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.right, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ObjectPattern<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.properties, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.rest, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for BindingProperty<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.key, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.value, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.shorthand, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.computed, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ArrayPattern<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.elements, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.rest, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for BindingRestElement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.argument, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for Function<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.r#type, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.id, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.generator, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.r#async, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.declare, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameters, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.this_param, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.params, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.return_type, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl ForEachSpanMut for FunctionType {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl<'a> ForEachSpanMut for FormalParameters<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.kind, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.items, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.rest, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for FormalParameter<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.decorators, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.pattern, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.accessibility, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.readonly, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.r#override, f);
+    }
+}
+
+impl ForEachSpanMut for FormalParameterKind {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl<'a> ForEachSpanMut for FunctionBody<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.directives, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.statements, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ArrowFunctionExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expression, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.r#async, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameters, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.params, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.return_type, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for YieldExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.delegate, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.argument, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for Class<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.r#type, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.decorators, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.id, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameters, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.super_class, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.super_type_parameters, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.implements, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.r#abstract, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.declare, f);
+    }
+}
+
+impl ForEachSpanMut for ClassType {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl<'a> ForEachSpanMut for ClassBody<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ClassElement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::StaticBlock(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::MethodDefinition(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PropertyDefinition(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::AccessorProperty(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSIndexSignature(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for MethodDefinition<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.r#type, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.decorators, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.key, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.value, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.kind, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.computed, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.r#static, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.r#override, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.optional, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.accessibility, f);
+    }
+}
+
+impl ForEachSpanMut for MethodDefinitionType {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl<'a> ForEachSpanMut for PropertyDefinition<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.r#type, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.decorators, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.key, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.value, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.computed, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.r#static, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.declare, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.r#override, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.optional, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.definite, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.readonly, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_annotation, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.accessibility, f);
+    }
+}
+
+impl ForEachSpanMut for PropertyDefinitionType {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl ForEachSpanMut for MethodDefinitionKind {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl<'a> ForEachSpanMut for PrivateIdentifier<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.name, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for StaticBlock<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ModuleDeclaration<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::ImportDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ExportAllDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ExportDefaultDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ExportNamedDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSExportAssignment(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNamespaceExportDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl ForEachSpanMut for AccessorPropertyType {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl<'a> ForEachSpanMut for AccessorProperty<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.r#type, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.decorators, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.key, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.value, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.computed, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.r#static, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.definite, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_annotation, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.accessibility, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ImportExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.source, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.arguments, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ImportDeclaration<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.specifiers, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.source, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.with_clause, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.import_kind, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ImportDeclarationSpecifier<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::ImportSpecifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ImportDefaultSpecifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ImportNamespaceSpecifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for ImportSpecifier<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.imported, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.local, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.import_kind, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ImportDefaultSpecifier<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.local, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ImportNamespaceSpecifier<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.local, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for WithClause<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.attributes_keyword, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.with_entries, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ImportAttribute<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.key, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.value, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ImportAttributeKey<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::Identifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StringLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for ExportNamedDeclaration<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.declaration, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.specifiers, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.source, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.export_kind, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.with_clause, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ExportDefaultDeclaration<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.declaration, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.exported, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ExportAllDeclaration<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.exported, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.source, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.with_clause, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.export_kind, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ExportSpecifier<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.local, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.exported, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.export_kind, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ExportDefaultDeclarationKind<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::FunctionDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ClassDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSInterfaceDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BooleanLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NullLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NumericLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BigIntLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::RegExpLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StringLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TemplateLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Identifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::MetaProperty(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Super(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ArrayExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ArrowFunctionExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::AssignmentExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::AwaitExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BinaryExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::CallExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ChainExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ClassExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ConditionalExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::FunctionExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ImportExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::LogicalExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NewExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ObjectExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ParenthesizedExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::SequenceExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TaggedTemplateExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ThisExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::UnaryExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::UpdateExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::YieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateInExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSXElement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSXFragment(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSAsExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSSatisfiesExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeAssertion(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNonNullExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSInstantiationExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ComputedMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StaticMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateFieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for ModuleExportName<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::IdentifierName(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::IdentifierReference(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StringLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for TSThisParameter<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.this_span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_annotation, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSEnumDeclaration<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.id, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.members, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.r#const, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.declare, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSEnumMember<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.id, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.initializer, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSEnumMemberName<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::StaticIdentifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StaticStringLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StaticTemplateLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StaticNumericLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BooleanLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NullLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NumericLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BigIntLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::RegExpLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StringLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TemplateLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Identifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::MetaProperty(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Super(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ArrayExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ArrowFunctionExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::AssignmentExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::AwaitExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BinaryExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::CallExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ChainExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ClassExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ConditionalExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::FunctionExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ImportExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::LogicalExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NewExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ObjectExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ParenthesizedExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::SequenceExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TaggedTemplateExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ThisExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::UnaryExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::UpdateExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::YieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateInExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSXElement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSXFragment(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSAsExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSSatisfiesExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeAssertion(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNonNullExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSInstantiationExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ComputedMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StaticMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateFieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for TSTypeAnnotation<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_annotation, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSLiteralType<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.literal, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSLiteral<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::BooleanLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NullLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NumericLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BigIntLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::RegExpLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StringLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TemplateLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::UnaryExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for TSType<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::TSAnyKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSBigIntKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSBooleanKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSIntrinsicKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNeverKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNullKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNumberKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSObjectKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSStringKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSSymbolKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSUndefinedKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSUnknownKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSVoidKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSArrayType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSConditionalType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSConstructorType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSFunctionType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSImportType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSIndexedAccessType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSInferType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSIntersectionType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSLiteralType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSMappedType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNamedTupleMember(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSQualifiedName(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTemplateLiteralType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSThisType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTupleType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeOperatorType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypePredicate(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeQuery(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeReference(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSUnionType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSParenthesizedType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSDocNullableType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSDocNonNullableType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSDocUnknownType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for TSConditionalType<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.check_type, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.extends_type, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.true_type, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.false_type, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSUnionType<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.types, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSIntersectionType<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.types, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSParenthesizedType<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_annotation, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSTypeOperator<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.operator, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_annotation, f);
+    }
+}
+
+impl ForEachSpanMut for TSTypeOperatorOperator {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl<'a> ForEachSpanMut for TSArrayType<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.element_type, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSIndexedAccessType<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.object_type, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.index_type, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSTupleType<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.element_types, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSNamedTupleMember<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.element_type, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.label, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.optional, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSOptionalType<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_annotation, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSRestType<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_annotation, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSTupleElement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::TSOptionalType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSRestType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSAnyKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSBigIntKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSBooleanKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSIntrinsicKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNeverKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNullKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNumberKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSObjectKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSStringKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSSymbolKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSUndefinedKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSUnknownKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSVoidKeyword(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSArrayType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSConditionalType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSConstructorType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSFunctionType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSImportType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSIndexedAccessType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSInferType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSIntersectionType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSLiteralType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSMappedType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNamedTupleMember(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSQualifiedName(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTemplateLiteralType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSThisType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTupleType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeOperatorType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypePredicate(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeQuery(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeReference(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSUnionType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSParenthesizedType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSDocNullableType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSDocNonNullableType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSDocUnknownType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl ForEachSpanMut for TSAnyKeyword {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl ForEachSpanMut for TSStringKeyword {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl ForEachSpanMut for TSBooleanKeyword {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl ForEachSpanMut for TSNumberKeyword {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl ForEachSpanMut for TSNeverKeyword {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl ForEachSpanMut for TSIntrinsicKeyword {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl ForEachSpanMut for TSUnknownKeyword {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl ForEachSpanMut for TSNullKeyword {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl ForEachSpanMut for TSUndefinedKeyword {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl ForEachSpanMut for TSVoidKeyword {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl ForEachSpanMut for TSSymbolKeyword {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl ForEachSpanMut for TSThisType {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl ForEachSpanMut for TSObjectKeyword {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl ForEachSpanMut for TSBigIntKeyword {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSTypeReference<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_name, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameters, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSTypeName<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::IdentifierReference(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::QualifiedName(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for TSQualifiedName<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.left, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.right, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSTypeParameterInstantiation<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.params, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSTypeParameter<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.name, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.constraint, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.default, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.r#in, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.out, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.r#const, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSTypeParameterDeclaration<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.params, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSTypeAliasDeclaration<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.id, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameters, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_annotation, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.declare, f);
+    }
+}
+
+impl ForEachSpanMut for TSAccessibility {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl<'a> ForEachSpanMut for TSClassImplements<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expression, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameters, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSInterfaceDeclaration<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.id, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.extends, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameters, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.declare, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSInterfaceBody<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSPropertySignature<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.computed, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.optional, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.readonly, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.key, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_annotation, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSSignature<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::TSIndexSignature(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSPropertySignature(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSCallSignatureDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSConstructSignatureDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSMethodSignature(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for TSIndexSignature<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.parameters, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_annotation, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.readonly, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSCallSignatureDeclaration<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameters, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.this_param, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.params, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.return_type, f);
+    }
+}
+
+impl ForEachSpanMut for TSMethodSignatureKind {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl<'a> ForEachSpanMut for TSMethodSignature<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.key, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.computed, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.optional, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.kind, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameters, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.this_param, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.params, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.return_type, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSConstructSignatureDeclaration<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameters, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.params, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.return_type, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSIndexSignatureName<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.name, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_annotation, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSInterfaceHeritage<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expression, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameters, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSTypePredicate<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.parameter_name, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.asserts, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_annotation, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSTypePredicateName<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::Identifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::This(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for TSModuleDeclaration<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.id, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.kind, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.declare, f);
+    }
+}
+
+impl ForEachSpanMut for TSModuleDeclarationKind {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl<'a> ForEachSpanMut for TSModuleDeclarationName<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::Identifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StringLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for TSModuleDeclarationBody<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::TSModuleDeclaration(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSModuleBlock(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for TSModuleBlock<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.directives, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSTypeLiteral<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.members, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.flow_exact, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSInferType<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameter, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSTypeQuery<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expr_name, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameters, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSTypeQueryExprName<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::TSImportType(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::IdentifierReference(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::QualifiedName(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for TSImportType<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.is_type_of, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.parameter, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.qualifier, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.attributes, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameters, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSImportAttributes<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.attributes_keyword, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.elements, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSImportAttribute<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.name, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.value, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSImportAttributeName<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::Identifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StringLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for TSFunctionType<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameters, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.this_param, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.params, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.return_type, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSConstructorType<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.r#abstract, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameters, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.params, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.return_type, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSMappedType<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameter, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.name_type, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_annotation, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.optional, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.readonly, f);
+    }
+}
+
+impl ForEachSpanMut for TSMappedTypeModifierOperator {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl<'a> ForEachSpanMut for TSTemplateLiteralType<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.quasis, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.types, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSAsExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expression, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_annotation, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.flow_type_cast, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSSatisfiesExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expression, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_annotation, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSTypeAssertion<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expression, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_annotation, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSImportEqualsDeclaration<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.id, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.module_reference, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.import_kind, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSModuleReference<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::ExternalModuleReference(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::IdentifierReference(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::QualifiedName(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for TSExternalModuleReference<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expression, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSNonNullExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expression, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for Decorator<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expression, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSExportAssignment<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expression, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSNamespaceExportDeclaration<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.id, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for TSInstantiationExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expression, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameters, f);
+    }
+}
+
+impl ForEachSpanMut for ImportOrExportKind {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl<'a> ForEachSpanMut for JSDocNullableType<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_annotation, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.postfix, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for JSDocNonNullableType<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_annotation, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.postfix, f);
+    }
+}
+
+impl ForEachSpanMut for JSDocUnknownType {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for JSXElement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.opening_element, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.closing_element, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.children, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for JSXOpeningElement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.self_closing, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.name, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.attributes, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.type_parameters, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for JSXClosingElement<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.name, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for JSXFragment<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.opening_fragment, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.closing_fragment, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.children, f);
+    }
+}
+
+impl ForEachSpanMut for JSXOpeningFragment {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl ForEachSpanMut for JSXClosingFragment {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for JSXElementName<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::Identifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::IdentifierReference(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NamespacedName(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::MemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ThisExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for JSXNamespacedName<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.namespace, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.property, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for JSXMemberExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.object, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.property, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for JSXMemberExpressionObject<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::IdentifierReference(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::MemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ThisExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for JSXExpressionContainer<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expression, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for JSXExpression<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::EmptyExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BooleanLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NullLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NumericLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BigIntLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::RegExpLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StringLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TemplateLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Identifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::MetaProperty(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Super(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ArrayExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ArrowFunctionExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::AssignmentExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::AwaitExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::BinaryExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::CallExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ChainExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ClassExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ConditionalExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::FunctionExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ImportExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::LogicalExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NewExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ObjectExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ParenthesizedExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::SequenceExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TaggedTemplateExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ThisExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::UnaryExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::UpdateExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::YieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateInExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSXElement(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::JSXFragment(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSAsExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSSatisfiesExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSTypeAssertion(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSNonNullExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::TSInstantiationExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ComputedMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::StaticMemberExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::PrivateFieldExpression(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl ForEachSpanMut for JSXEmptyExpression {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for JSXAttributeItem<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::Attribute(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::SpreadAttribute(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for JSXAttribute<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.name, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.value, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for JSXSpreadAttribute<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.argument, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for JSXAttributeName<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::Identifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NamespacedName(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for JSXAttributeValue<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::StringLiteral(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ExpressionContainer(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Element(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Fragment(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for JSXIdentifier<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.name, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for JSXChild<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::Text(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Element(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Fragment(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ExpressionContainer(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Spread(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl<'a> ForEachSpanMut for JSXSpreadChild<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.expression, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for JSXText<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.value, f);
+    }
+}
+
+impl ForEachSpanMut for CommentKind {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl ForEachSpanMut for CommentPosition {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl ForEachSpanMut for Comment {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.kind, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.position, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.attached_to, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.preceded_by_newline, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.followed_by_newline, f);
+    }
+}
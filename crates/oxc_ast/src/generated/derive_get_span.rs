@@ -648,6 +648,7 @@ impl<'a> GetSpan for Statement<'a> {
             Self::TryStatement(it) => GetSpan::span(it.as_ref()),
             Self::WhileStatement(it) => GetSpan::span(it.as_ref()),
             Self::WithStatement(it) => GetSpan::span(it.as_ref()),
+            Self::ErrorStatement(it) => GetSpan::span(it.as_ref()),
             Self::VariableDeclaration(it) => GetSpan::span(it.as_ref()),
             Self::FunctionDeclaration(it) => GetSpan::span(it.as_ref()),
             Self::ClassDeclaration(it) => GetSpan::span(it.as_ref()),
@@ -925,6 +926,13 @@ impl GetSpan for DebuggerStatement {
     }
 }
 
+impl GetSpan for ErrorStatement {
+    #[inline]
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
 impl<'a> GetSpan for BindingPattern<'a> {
     #[inline]
     fn span(&self) -> Span {
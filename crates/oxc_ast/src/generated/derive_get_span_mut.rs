@@ -648,6 +648,7 @@ impl<'a> GetSpanMut for Statement<'a> {
             Self::TryStatement(it) => GetSpanMut::span_mut(&mut **it),
             Self::WhileStatement(it) => GetSpanMut::span_mut(&mut **it),
             Self::WithStatement(it) => GetSpanMut::span_mut(&mut **it),
+            Self::ErrorStatement(it) => GetSpanMut::span_mut(&mut **it),
             Self::VariableDeclaration(it) => GetSpanMut::span_mut(&mut **it),
             Self::FunctionDeclaration(it) => GetSpanMut::span_mut(&mut **it),
             Self::ClassDeclaration(it) => GetSpanMut::span_mut(&mut **it),
@@ -925,6 +926,13 @@ impl GetSpanMut for DebuggerStatement {
     }
 }
 
+impl GetSpanMut for ErrorStatement {
+    #[inline]
+    fn span_mut(&mut self) -> &mut Span {
+        &mut self.span
+    }
+}
+
 impl<'a> GetSpanMut for BindingPattern<'a> {
     #[inline]
     fn span_mut(&mut self) -> &mut Span {
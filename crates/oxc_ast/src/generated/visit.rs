@@ -1156,6 +1156,11 @@ pub trait Visit<'a>: Sized {
         walk_with_statement(self, it);
     }
 
+    #[inline]
+    fn visit_error_statement(&mut self, it: &ErrorStatement) {
+        walk_error_statement(self, it);
+    }
+
     #[inline]
     fn visit_declaration(&mut self, it: &Declaration<'a>) {
         walk_declaration(self, it);
@@ -1428,6 +1433,7 @@ pub mod walk {
             Statement::TryStatement(it) => visitor.visit_try_statement(it),
             Statement::WhileStatement(it) => visitor.visit_while_statement(it),
             Statement::WithStatement(it) => visitor.visit_with_statement(it),
+            Statement::ErrorStatement(it) => visitor.visit_error_statement(it),
             match_declaration!(Statement) => visitor.visit_declaration(it.to_declaration()),
             match_module_declaration!(Statement) => {
                 visitor.visit_module_declaration(it.to_module_declaration())
@@ -3732,6 +3738,13 @@ pub mod walk {
         visitor.leave_node(kind);
     }
 
+    #[inline]
+    pub fn walk_error_statement<'a, V: Visit<'a>>(visitor: &mut V, it: &ErrorStatement) {
+        let kind = AstKind::ErrorStatement(visitor.alloc(it));
+        visitor.enter_node(kind);
+        visitor.leave_node(kind);
+    }
+
     pub fn walk_declaration<'a, V: Visit<'a>>(visitor: &mut V, it: &Declaration<'a>) {
         match it {
             Declaration::VariableDeclaration(it) => visitor.visit_variable_declaration(it),
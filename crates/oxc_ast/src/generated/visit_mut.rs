@@ -1151,6 +1151,11 @@ pub trait VisitMut<'a>: Sized {
         walk_with_statement(self, it);
     }
 
+    #[inline]
+    fn visit_error_statement(&mut self, it: &mut ErrorStatement) {
+        walk_error_statement(self, it);
+    }
+
     #[inline]
     fn visit_declaration(&mut self, it: &mut Declaration<'a>) {
         walk_declaration(self, it);
@@ -1423,6 +1428,7 @@ pub mod walk_mut {
             Statement::TryStatement(it) => visitor.visit_try_statement(it),
             Statement::WhileStatement(it) => visitor.visit_while_statement(it),
             Statement::WithStatement(it) => visitor.visit_with_statement(it),
+            Statement::ErrorStatement(it) => visitor.visit_error_statement(it),
             match_declaration!(Statement) => visitor.visit_declaration(it.to_declaration_mut()),
             match_module_declaration!(Statement) => {
                 visitor.visit_module_declaration(it.to_module_declaration_mut())
@@ -3941,6 +3947,13 @@ pub mod walk_mut {
         visitor.leave_node(kind);
     }
 
+    #[inline]
+    pub fn walk_error_statement<'a, V: VisitMut<'a>>(visitor: &mut V, it: &mut ErrorStatement) {
+        let kind = AstType::ErrorStatement;
+        visitor.enter_node(kind);
+        visitor.leave_node(kind);
+    }
+
     pub fn walk_declaration<'a, V: VisitMut<'a>>(visitor: &mut V, it: &mut Declaration<'a>) {
         match it {
             Declaration::VariableDeclaration(it) => visitor.visit_variable_declaration(it),
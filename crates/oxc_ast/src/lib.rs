@@ -17,6 +17,7 @@
 //!
 //! ## Cargo Features
 //! * `"serde"` enables support for serde serialization
+//! * `"binary_codec"` enables encoding ASTs in a binary format, and caching them with the `cache` module
 //!
 //! [`BindingIdentifier`]: ast::BindingIdentifier
 //! [`IdentifierReference`]: ast::IdentifierReference
@@ -25,6 +26,8 @@
 //! [`oxc_parser`]: <https://docs.rs/oxc_parser>
 //! [`Parser`]: <https://docs.rs/oxc_parser/latest/oxc_parser/struct.Parser.html>
 
+#[cfg(feature = "serialize")]
+mod estree;
 #[cfg(feature = "serialize")]
 mod serialize;
 
@@ -32,6 +35,9 @@ pub mod ast;
 mod ast_builder_impl;
 mod ast_impl;
 mod ast_kind_impl;
+#[cfg(feature = "binary_codec")]
+pub mod cache;
+mod diff;
 pub mod precedence;
 mod trivia;
 
@@ -40,9 +46,14 @@ mod generated {
     pub mod assert_layouts;
     pub mod ast_builder;
     pub mod ast_kind;
+    #[cfg(feature = "binary_codec")]
+    pub mod binary_codec_schema;
+    #[cfg(feature = "binary_codec")]
+    pub mod derive_binary_codec;
     pub mod derive_clone_in;
     pub mod derive_content_eq;
     pub mod derive_content_hash;
+    pub mod derive_for_each_span_mut;
     pub mod derive_get_span;
     pub mod derive_get_span_mut;
     pub mod visit;
@@ -56,11 +67,15 @@ pub mod visit {
 pub use generated::{ast_builder, ast_kind};
 pub use num_bigint::BigUint;
 
+#[cfg(feature = "serialize")]
+pub use crate::estree::EstreeFlavor;
+
 pub use crate::{
     ast::comment::{Comment, CommentKind, CommentPosition},
     ast_builder::AstBuilder,
     ast_builder_impl::NONE,
     ast_kind::{AstKind, AstType},
+    diff::{diff, AstChange},
     trivia::{comments_range, has_comments_between, CommentsRange},
     visit::{Visit, VisitMut},
 };
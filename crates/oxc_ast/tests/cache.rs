@@ -0,0 +1,108 @@
+#![cfg(feature = "binary_codec")]
+
+use oxc_allocator::{Allocator, BinaryCodec, DecodeError};
+use oxc_ast::cache::{decode_program, decode_program_with, encode_program, encode_program_with};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+const SOURCE: &str = r#"#!/usr/bin/env node
+"use strict";
+// Comment
+import React, { useState as useS } from "react";
+export * as ns from "./ns";
+
+/** Doc comment */
+export default class Foo<T extends object = {}> extends Bar<T> implements Baz {
+    #priv = 1n;
+    static { this.x ??= /ab+c/giu; }
+    @dec() accessor y: string | number = `a${1.5e3}b${0x1f}`;
+    constructor(private readonly z?: T) { super(); }
+    async *gen(...args: Array<[a: number, b?: string]>): AsyncGenerator<void> {
+        for await (const { a, b: [c, ...d] = [] } of args) yield* d;
+    }
+}
+
+enum E { A = 1, B = A << 2 }
+declare module "m" { export type U<K> = K extends `x${infer R}` ? R : never; }
+label: while (true) { try { break label; } catch { continue; } finally { debugger; } }
+const el = <div className="a" {...props}>{cond ? <Foo.Bar x={1} /> : <>text &amp;</>}</div>;
+let v = -(a?.b!.c?.[d] as any) ** 2, w = typeof x === "undefined" || void 0, { u = 1 } = o;
+"#;
+
+#[test]
+fn round_trip() {
+    let allocator = Allocator::default();
+    let source_type = SourceType::tsx();
+    let ret = Parser::new(&allocator, SOURCE, source_type).parse();
+    assert!(ret.errors.is_empty());
+    let program = ret.program;
+
+    let bytes = encode_program(&program);
+    let decode_allocator = Allocator::default();
+    let decoded = decode_program(&decode_allocator, &bytes).unwrap();
+
+    // `Debug` output includes spans and all other fields
+    assert_eq!(format!("{program:?}"), format!("{decoded:?}"));
+    assert_eq!(decoded.source_text, SOURCE);
+    assert_eq!(decoded.comments.len(), program.comments.len());
+
+    // Encoding is deterministic
+    assert_eq!(encode_program(&decoded), bytes);
+}
+
+#[test]
+fn extension() {
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, "let x = 1;", SourceType::mjs()).parse().program;
+
+    let bytes = encode_program_with(&program, "test", |encoder| encoder.write_u32(123));
+    let (_, value) = decode_program_with(&allocator, &bytes, "test", u32::decode).unwrap();
+    assert_eq!(value, 123);
+
+    // Extension must match
+    assert_eq!(decode_program(&allocator, &bytes).unwrap_err(), DecodeError::Incompatible);
+    assert_eq!(
+        decode_program_with(&allocator, &bytes, "other", u32::decode).unwrap_err(),
+        DecodeError::Incompatible
+    );
+    // Extension data must be read in full
+    assert_eq!(
+        decode_program_with(&allocator, &bytes, "test", |_| Ok(())).unwrap_err(),
+        DecodeError::TrailingData
+    );
+}
+
+#[test]
+fn invalid() {
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, SOURCE, SourceType::tsx()).parse().program;
+    let bytes = encode_program(&program);
+
+    assert_eq!(decode_program(&allocator, &[]).unwrap_err(), DecodeError::Incompatible);
+    assert_eq!(decode_program(&allocator, b"not a cache").unwrap_err(), DecodeError::Incompatible);
+
+    // Different format version
+    let mut other_version = bytes.clone();
+    other_version[4] += 1;
+    assert_eq!(decode_program(&allocator, &other_version).unwrap_err(), DecodeError::Incompatible);
+
+    // Truncated data
+    for len in [bytes.len() / 2, bytes.len() - 1] {
+        assert_eq!(
+            decode_program(&allocator, &bytes[..len]).unwrap_err(),
+            DecodeError::UnexpectedEnd
+        );
+    }
+
+    // Trailing data
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(decode_program(&allocator, &trailing).unwrap_err(), DecodeError::TrailingData);
+
+    // Corrupt data must not panic
+    for i in 0..bytes.len() {
+        let mut corrupt = bytes.clone();
+        corrupt[i] ^= 0xff;
+        let _ = decode_program(&allocator, &corrupt);
+    }
+}
@@ -0,0 +1,72 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{diff, AstChange};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+/// Diff 2 source texts, and return changes formatted as strings.
+fn test(old: &str, new: &str) -> Vec<String> {
+    let allocator = Allocator::default();
+    let source_type = SourceType::mjs();
+    let old = Parser::new(&allocator, old, source_type).parse();
+    let new = Parser::new(&allocator, new, source_type).parse();
+    assert!(old.errors.is_empty() && new.errors.is_empty());
+    let old = allocator.alloc(old.program);
+    let new = allocator.alloc(new.program);
+    diff(old, new).iter().map(AstChange::to_string).collect()
+}
+
+#[test]
+fn formatting_only() {
+    assert!(test("let a=1;f(a)", "// comment\nlet a = 1;\n\nf( a );\n").is_empty());
+}
+
+#[test]
+fn modified() {
+    assert_eq!(
+        test("let a = 1 + b;", "let a = 1 - b;"),
+        ["modified BinaryExpression(+) at 8..13 -> BinaryExpression(-) at 8..13"]
+    );
+    assert_eq!(
+        test("f(a, b);", "f(a, c);"),
+        ["modified IdentifierReference(b) at 5..6 -> IdentifierReference(c) at 5..6"]
+    );
+    assert_eq!(
+        test("f(a);", "f(1);"),
+        ["modified IdentifierReference(a) at 2..3 -> NumericLiteral(1) at 2..3"]
+    );
+    // Change to a field which is not a child node
+    assert_eq!(
+        test("async function f() {}", "function f() {}"),
+        ["modified Function(f) at 0..21 -> Function(f) at 0..15"]
+    );
+}
+
+#[test]
+fn inserted_and_removed() {
+    assert_eq!(
+        test("a(); b(); c();", "a(); c(); d();"),
+        ["removed ExpressionStatement at 5..9", "inserted ExpressionStatement at 10..14"]
+    );
+    assert_eq!(test("f(a);", "f(a, b);"), ["inserted Argument at 5..6"]);
+}
+
+/// `f0();f1();...` for each of `indexes`.
+fn calls<I: Iterator<Item = usize>>(callee: &str, indexes: I) -> String {
+    indexes.map(|i| format!("{callee}{i}();")).collect::<Vec<_>>().concat()
+}
+
+#[test]
+fn long_lists() {
+    // Single insertion and removal in a long list
+    let old = calls("f", 0..5000);
+    let new = calls("f", 0..1000) + "g();" + &calls("f", (1000..5000).filter(|&i| i != 4000));
+    let changes = test(&old, &new);
+    assert_eq!(changes.len(), 2);
+    assert!(changes[0].starts_with("inserted ExpressionStatement"));
+    assert!(changes[1].starts_with("removed ExpressionStatement"));
+
+    // Lists which differ too much are paired up by position
+    let changes = test(&calls("f", 0..20000), &calls("g", 0..20000));
+    assert_eq!(changes.len(), 20000);
+    assert!(changes[0].starts_with("modified IdentifierReference(f0)"));
+}
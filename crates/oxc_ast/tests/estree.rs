@@ -0,0 +1,253 @@
+#![cfg(feature = "serialize")]
+
+use oxc_allocator::Allocator;
+use oxc_ast::EstreeFlavor;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use serde_json::{json, Value};
+
+/// Convert `source_text` to ESTree, and return the expressions of its expression statements.
+fn expressions(source_text: &str, source_type: SourceType, flavor: EstreeFlavor) -> Vec<Value> {
+    statements(source_text, source_type, flavor)
+        .into_iter()
+        .map(|mut statement| statement["expression"].take())
+        .collect()
+}
+
+fn statements(source_text: &str, source_type: SourceType, flavor: EstreeFlavor) -> Vec<Value> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty(), "{source_text}: {:?}", ret.errors);
+    let mut program = ret.program.to_estree(flavor);
+    assert_eq!(program["type"], "Program");
+    match program["body"].take() {
+        Value::Array(body) => body,
+        body => panic!("{body}"),
+    }
+}
+
+#[test]
+fn literals() {
+    let source = "1; 'a'; null; true; 1_0n; /a/g;";
+    let [number, string, null, boolean, bigint, regex] =
+        <[Value; 6]>::try_from(expressions(source, SourceType::mjs(), EstreeFlavor::JavaScript))
+            .unwrap();
+    assert_eq!(
+        number,
+        json!({ "type": "Literal", "start": 0, "end": 1, "value": 1.0, "raw": "1" })
+    );
+    assert_eq!(
+        string,
+        json!({ "type": "Literal", "start": 3, "end": 6, "value": "a", "raw": "'a'" })
+    );
+    assert_eq!(
+        null,
+        json!({ "type": "Literal", "start": 8, "end": 12, "value": null, "raw": "null" })
+    );
+    assert_eq!(
+        boolean,
+        json!({ "type": "Literal", "start": 14, "end": 18, "value": true, "raw": "true" })
+    );
+    assert_eq!(
+        bigint,
+        json!({
+            "type": "Literal", "start": 20, "end": 24,
+            "value": null, "raw": "1_0n", "bigint": "10"
+        })
+    );
+    assert_eq!(
+        regex,
+        json!({
+            "type": "Literal", "start": 26, "end": 30,
+            "value": null, "raw": "/a/g", "regex": { "pattern": "a", "flags": "g" }
+        })
+    );
+}
+
+#[test]
+fn directives() {
+    let [directive, statement] = <[Value; 2]>::try_from(statements(
+        "'use strict'; a",
+        SourceType::cjs(),
+        EstreeFlavor::JavaScript,
+    ))
+    .unwrap();
+    assert_eq!(directive["type"], "ExpressionStatement");
+    assert_eq!(directive["directive"], "use strict");
+    assert_eq!(directive["expression"]["type"], "Literal");
+    assert_eq!(statement["type"], "ExpressionStatement");
+}
+
+#[test]
+fn chain_expressions() {
+    let [chain, parenthesized] = <[Value; 2]>::try_from(expressions(
+        "a?.b[c](); (a?.b)();",
+        SourceType::mjs(),
+        EstreeFlavor::JavaScript,
+    ))
+    .unwrap();
+
+    assert_eq!(chain["type"], "ChainExpression");
+    let call = &chain["expression"];
+    assert_eq!(call["type"], "CallExpression");
+    assert_eq!(call["optional"], false);
+    let computed = &call["callee"];
+    assert_eq!(computed["type"], "MemberExpression");
+    assert_eq!(computed["computed"], true);
+    assert_eq!(computed["optional"], false);
+    assert_eq!(computed["property"]["name"], "c");
+    let member = &computed["object"];
+    assert_eq!(member["type"], "MemberExpression");
+    assert_eq!(member["computed"], false);
+    assert_eq!(member["optional"], true);
+
+    // Parentheses end the chain
+    assert_eq!(parenthesized["type"], "CallExpression");
+    assert_eq!(parenthesized["callee"]["type"], "ChainExpression");
+    assert_eq!(parenthesized["callee"]["expression"]["type"], "MemberExpression");
+}
+
+#[test]
+fn template_literals() {
+    let [template, tagged] = <[Value; 2]>::try_from(expressions(
+        "`a${b}c`; t`\\u`;",
+        SourceType::mjs(),
+        EstreeFlavor::JavaScript,
+    ))
+    .unwrap();
+
+    assert_eq!(template["type"], "TemplateLiteral");
+    assert_eq!(
+        template["quasis"],
+        json!([
+            {
+                "type": "TemplateElement", "start": 1, "end": 2,
+                "value": { "raw": "a", "cooked": "a" }, "tail": false
+            },
+            {
+                "type": "TemplateElement", "start": 6, "end": 7,
+                "value": { "raw": "c", "cooked": "c" }, "tail": true
+            },
+        ])
+    );
+    assert_eq!(template["expressions"][0]["name"], "b");
+
+    // Invalid escapes are allowed in tagged templates, and have no cooked value
+    assert_eq!(tagged["type"], "TaggedTemplateExpression");
+    assert_eq!(tagged["quasi"]["quasis"][0]["value"], json!({ "raw": "\\u", "cooked": null }));
+}
+
+#[test]
+fn patterns_and_properties() {
+    let [object, assignment] = <[Value; 2]>::try_from(expressions(
+        "({ a, b: 1 }); ({ a = 1, [b]: c } = d);",
+        SourceType::mjs(),
+        EstreeFlavor::JavaScript,
+    ))
+    .unwrap();
+
+    assert_eq!(object["type"], "ObjectExpression");
+    assert_eq!(object["start"], 1);
+    assert_eq!(object["properties"][0]["type"], "Property");
+    assert_eq!(object["properties"][0]["shorthand"], true);
+    assert_eq!(object["properties"][1]["value"]["type"], "Literal");
+
+    let pattern = &assignment["left"];
+    assert_eq!(pattern["type"], "ObjectPattern");
+    let shorthand = &pattern["properties"][0];
+    assert_eq!(shorthand["type"], "Property");
+    assert_eq!(shorthand["shorthand"], true);
+    assert_eq!(shorthand["value"]["type"], "AssignmentPattern");
+    let computed = &pattern["properties"][1];
+    assert_eq!(computed["type"], "Property");
+    assert_eq!(computed["computed"], true);
+    assert_eq!(computed["value"]["name"], "c");
+}
+
+#[test]
+fn functions() {
+    let [function] = <[Value; 1]>::try_from(statements(
+        "function f(a, b = 1) { 'use strict'; }",
+        SourceType::mjs(),
+        EstreeFlavor::JavaScript,
+    ))
+    .unwrap();
+    assert_eq!(function["type"], "FunctionDeclaration");
+    assert_eq!(function["expression"], false);
+    assert_eq!(function["params"][0]["type"], "Identifier");
+    assert_eq!(function["params"][1]["type"], "AssignmentPattern");
+    assert_eq!(function["body"]["type"], "BlockStatement");
+    assert_eq!(function["body"]["body"][0]["directive"], "use strict");
+
+    let [arrow] = <[Value; 1]>::try_from(expressions(
+        "(a) => a",
+        SourceType::mjs(),
+        EstreeFlavor::JavaScript,
+    ))
+    .unwrap();
+    assert_eq!(arrow["type"], "ArrowFunctionExpression");
+    assert_eq!(arrow["expression"], true);
+    assert_eq!(arrow["id"], Value::Null);
+    assert_eq!(arrow["body"]["type"], "Identifier");
+}
+
+#[test]
+fn typescript_nodes() {
+    let source = "class A { constructor(private a: T, b?: U) {} } f<T>(x); function g(this: X) {}";
+    let [class, call, function] =
+        <[Value; 3]>::try_from(statements(source, SourceType::ts(), EstreeFlavor::TypeScript))
+            .unwrap();
+
+    assert_eq!(class["range"], json!([0, 47]));
+    let params = &class["body"]["body"][0]["value"]["params"];
+    assert_eq!(params[0]["type"], "TSParameterProperty");
+    assert_eq!(params[0]["accessibility"], "private");
+    assert_eq!(params[0]["parameter"]["name"], "a");
+    assert_eq!(params[0]["parameter"]["typeAnnotation"]["type"], "TSTypeAnnotation");
+    assert_eq!(params[1]["type"], "Identifier");
+    assert_eq!(params[1]["optional"], true);
+
+    let call = &call["expression"];
+    assert_eq!(call["typeArguments"]["type"], "TSTypeParameterInstantiation");
+    assert!(call.get("typeParameters").is_none());
+
+    assert_eq!(function["params"][0]["type"], "Identifier");
+    assert_eq!(function["params"][0]["name"], "this");
+    assert_eq!(
+        function["params"][0]["typeAnnotation"]["typeAnnotation"]["type"],
+        "TSTypeReference"
+    );
+}
+
+#[test]
+fn typescript_properties_are_removed_from_estree() {
+    let [function] = <[Value; 1]>::try_from(statements(
+        "function f<T>(this: X, a?: T): R {}",
+        SourceType::ts(),
+        EstreeFlavor::JavaScript,
+    ))
+    .unwrap();
+    assert!(function.get("range").is_none());
+    assert!(function.get("typeParameters").is_none());
+    assert!(function.get("returnType").is_none());
+    // `this` parameter is omitted
+    assert_eq!(function["params"].as_array().unwrap().len(), 1);
+    let param = &function["params"][0];
+    assert_eq!(param["name"], "a");
+    assert!(param.get("typeAnnotation").is_none());
+    assert!(param.get("optional").is_none());
+}
+
+#[test]
+fn utf16_offsets() {
+    let [string, identifier] =
+        <[Value; 2]>::try_from(expressions("'😀'; a", SourceType::cjs(), EstreeFlavor::TypeScript))
+            .unwrap();
+    // `😀` is 4 bytes in UTF-8, and 2 code units in UTF-16
+    assert_eq!(string["start"], 0);
+    assert_eq!(string["end"], 4);
+    assert_eq!(string["raw"], "'😀'");
+    assert_eq!(identifier["start"], 6);
+    assert_eq!(identifier["end"], 7);
+    assert_eq!(identifier["range"], json!([6, 7]));
+}
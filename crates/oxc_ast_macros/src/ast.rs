@@ -81,6 +81,10 @@ fn abs_trait(
         (quote!(::oxc_span::cmp::ContentEq), TokenStream::default())
     } else if ident == "ContentHash" {
         (quote!(::oxc_span::hash::ContentHash), TokenStream::default())
+    } else if ident == "ForEachSpanMut" {
+        (quote!(::oxc_span::ForEachSpanMut), TokenStream::default())
+    } else if ident == "BinaryCodec" {
+        (quote!(::oxc_allocator::BinaryCodec), quote!(<'static>))
     } else {
         invalid_derive(ident)
     }
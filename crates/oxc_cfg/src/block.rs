@@ -53,6 +53,8 @@ pub enum InstructionKind {
     Throw,
     Condition,
     Iteration(IterationInstructionKind),
+    /// `yield` or `await`, the last instruction of its basic block
+    Suspend(SuspendInstructionKind),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -72,3 +74,9 @@ pub enum IterationInstructionKind {
     Of,
     In,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuspendInstructionKind {
+    Yield,
+    Await,
+}
@@ -8,7 +8,7 @@ use petgraph::Direction;
 
 use super::{
     BasicBlock, BlockNodeId, ControlFlowGraph, EdgeType, ErrorEdgeKind, Graph, Instruction,
-    InstructionKind, IterationInstructionKind, LabeledInstruction,
+    InstructionKind, IterationInstructionKind, LabeledInstruction, SuspendInstructionKind,
 };
 use crate::{BasicBlockId, ReturnInstructionKind};
 
@@ -179,6 +179,35 @@ impl<'a> ControlFlowGraphBuilder<'a> {
         self.append_unreachable();
     }
 
+    /// Whether an exception thrown at the current position is caught by a `catch` clause, or runs
+    /// a `finally` block, in the current function.
+    pub fn is_in_try(&self) -> bool {
+        matches!(self.error_path.last(), Some(ErrorHarness(ErrorEdgeKind::Explicit, _)))
+            || matches!(self.finalizers.last(), Some(Some(_)))
+    }
+
+    /// End the current basic block after an expression which may throw, e.g. a call.
+    ///
+    /// The error edge of the current basic block then only carries the effects before the throw.
+    pub fn append_throw_point(&mut self) {
+        let before_throw_point_ix = self.current_node_ix;
+        let after_throw_point_ix = self.new_basic_block_normal();
+        self.add_edge(before_throw_point_ix, after_throw_point_ix, EdgeType::Normal);
+    }
+
+    /// Suspend at a `yield` or `await` expression.
+    ///
+    /// The function continues in a new basic block when it is resumed. That block has the usual
+    /// error and finalize edges, so resuming with `generator.throw()` or a rejected promise reaches
+    /// the enclosing `catch` and `finally` blocks. Resuming with `generator.return()`, which
+    /// completes the function after its `finally` blocks, is not modeled.
+    pub fn append_suspend(&mut self, node: NodeId, kind: SuspendInstructionKind) {
+        self.push_instruction(InstructionKind::Suspend(kind), Some(node));
+        let suspend_ix = self.current_node_ix;
+        let resume_ix = self.new_basic_block_normal();
+        self.add_edge(suspend_ix, resume_ix, EdgeType::Normal);
+    }
+
     pub fn append_break(&mut self, node: NodeId, label: Option<&'a str>) {
         let kind = match label {
             Some(_) => LabeledInstruction::Labeled,
@@ -0,0 +1,173 @@
+//! A generic worklist solver for dataflow analyses on the [`ControlFlowGraph`].
+//!
+//! An [`Analysis`] describes the lattice of its states and the effect of each basic block.
+//! [`ControlFlowGraph::dataflow`] iterates until a fixed point is reached, and returns the states
+//! at the entry and exit of every basic block.
+//!
+//! Function bodies are analyzed independently: [`EdgeType::NewFunction`] edges are not followed.
+
+use std::collections::VecDeque;
+
+use petgraph::{visit::EdgeRef, Direction};
+
+use crate::{BlockNodeId, ControlFlowGraph, EdgeType};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataflowDirection {
+    /// States flow from the entry of a function to its exits, e.g. reaching definitions.
+    Forward,
+    /// States flow from the exits of a function to its entry, e.g. live variables.
+    Backward,
+}
+
+pub trait Analysis {
+    type Domain: Clone + PartialEq;
+
+    const DIRECTION: DataflowDirection;
+
+    /// The initial state of every basic block.
+    fn bottom(&self) -> Self::Domain;
+
+    /// Merge `other` into `state`, at a point where control flow paths meet.
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain);
+
+    /// Apply the effect of `block` on `state`, in the direction of the analysis.
+    fn transfer(&self, block: BlockNodeId, state: &mut Self::Domain);
+
+    /// Apply the effect of `block` on `state` for the [`EdgeType::Error`] path.
+    ///
+    /// An exception can be thrown at any point of the block, so this should only
+    /// contain the effects which may happen before the exception, without killing anything.
+    fn transfer_error(&self, block: BlockNodeId, state: &mut Self::Domain);
+}
+
+/// States at the entry and exit of every basic block, in program order.
+///
+/// For a [`DataflowDirection::Backward`] analysis, the exit state is the input of
+/// [`Analysis::transfer`] and the entry state is its output.
+#[derive(Debug)]
+pub struct DataflowResults<D> {
+    entry: Vec<D>,
+    exit: Vec<D>,
+}
+
+impl<D> DataflowResults<D> {
+    /// # Panics
+    /// if `block` is not in the graph.
+    pub fn entry(&self, block: BlockNodeId) -> &D {
+        &self.entry[block.index()]
+    }
+
+    /// # Panics
+    /// if `block` is not in the graph.
+    pub fn exit(&self, block: BlockNodeId) -> &D {
+        &self.exit[block.index()]
+    }
+}
+
+impl ControlFlowGraph {
+    /// Solve `analysis` to a fixed point.
+    pub fn dataflow<A: Analysis>(&self, analysis: &A) -> DataflowResults<A::Domain> {
+        let count = self.graph.node_count();
+        let mut results = DataflowResults {
+            entry: vec![analysis.bottom(); count],
+            exit: vec![analysis.bottom(); count],
+        };
+
+        let mut worklist = self.graph.node_indices().collect::<VecDeque<_>>();
+        if A::DIRECTION == DataflowDirection::Backward {
+            worklist.make_contiguous().reverse();
+        }
+        let mut queued = vec![true; count];
+
+        while let Some(block) = worklist.pop_front() {
+            queued[block.index()] = false;
+            let changed = match A::DIRECTION {
+                DataflowDirection::Forward => self.update_forward(analysis, block, &mut results),
+                DataflowDirection::Backward => self.update_backward(analysis, block, &mut results),
+            };
+            if !changed {
+                continue;
+            }
+            let direction = match A::DIRECTION {
+                DataflowDirection::Forward => Direction::Outgoing,
+                DataflowDirection::Backward => Direction::Incoming,
+            };
+            for edge in self.graph.edges_directed(block, direction) {
+                if matches!(edge.weight(), EdgeType::NewFunction) {
+                    continue;
+                }
+                let neighbour =
+                    if direction == Direction::Outgoing { edge.target() } else { edge.source() };
+                if !queued[neighbour.index()] {
+                    queued[neighbour.index()] = true;
+                    worklist.push_back(neighbour);
+                }
+            }
+        }
+
+        results
+    }
+
+    /// Returns `true` if the entry or exit state of `block` changed.
+    fn update_forward<A: Analysis>(
+        &self,
+        analysis: &A,
+        block: BlockNodeId,
+        results: &mut DataflowResults<A::Domain>,
+    ) -> bool {
+        let mut entry = analysis.bottom();
+        for edge in self.graph.edges_directed(block, Direction::Incoming) {
+            let source = edge.source();
+            match edge.weight() {
+                EdgeType::NewFunction => {}
+                // Error edges leave from anywhere inside the source block
+                EdgeType::Error(_) => {
+                    let mut state = results.entry[source.index()].clone();
+                    analysis.transfer_error(source, &mut state);
+                    analysis.join(&mut entry, &state);
+                }
+                _ => analysis.join(&mut entry, &results.exit[source.index()]),
+            }
+        }
+        let mut exit = entry.clone();
+        analysis.transfer(block, &mut exit);
+
+        let changed = results.entry[block.index()] != entry || results.exit[block.index()] != exit;
+        results.entry[block.index()] = entry;
+        results.exit[block.index()] = exit;
+        changed
+    }
+
+    /// Returns `true` if the entry state of `block` changed.
+    fn update_backward<A: Analysis>(
+        &self,
+        analysis: &A,
+        block: BlockNodeId,
+        results: &mut DataflowResults<A::Domain>,
+    ) -> bool {
+        let mut normal = analysis.bottom();
+        let mut error = analysis.bottom();
+        for edge in self.graph.edges_directed(block, Direction::Outgoing) {
+            let target_entry = &results.entry[edge.target().index()];
+            match edge.weight() {
+                EdgeType::NewFunction => {}
+                EdgeType::Error(_) => analysis.join(&mut error, target_entry),
+                _ => analysis.join(&mut normal, target_entry),
+            }
+        }
+
+        let mut exit = normal.clone();
+        analysis.join(&mut exit, &error);
+        let mut entry = normal;
+        analysis.transfer(block, &mut entry);
+        // Error edges leave from anywhere inside the block
+        analysis.transfer_error(block, &mut error);
+        analysis.join(&mut entry, &error);
+
+        let changed = results.entry[block.index()] != entry;
+        results.entry[block.index()] = entry;
+        results.exit[block.index()] = exit;
+        changed
+    }
+}
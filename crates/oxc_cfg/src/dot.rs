@@ -10,7 +10,7 @@ use rustc_hash::FxHashMap;
 use super::IterationInstructionKind;
 use crate::{
     BasicBlock, ControlFlowGraph, EdgeType, Instruction, InstructionKind, LabeledInstruction,
-    ReturnInstructionKind,
+    ReturnInstructionKind, SuspendInstructionKind,
 };
 
 pub trait DisplayDot {
@@ -81,6 +81,8 @@ impl DisplayDot for Instruction {
             InstructionKind::Return(ReturnInstructionKind::NotImplicitUndefined) => {
                 "return <value>"
             }
+            InstructionKind::Suspend(SuspendInstructionKind::Yield) => "suspend <yield>",
+            InstructionKind::Suspend(SuspendInstructionKind::Await) => "suspend <await>",
         }
         .to_string()
     }
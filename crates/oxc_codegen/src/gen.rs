@@ -33,6 +33,17 @@ pub trait GenExpr: GetSpan {
 
 impl<'a> Gen for Program<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
+        // A JSON document is a single value, printed without parentheses or semicolon.
+        if self.source_type.is_json() {
+            if let Some(Statement::ExpressionStatement(stmt)) = self.body.first() {
+                // Not at the start of a statement, so that objects are not wrapped.
+                p.start_of_stmt = usize::MAX;
+                p.start_of_arrow_expr = usize::MAX;
+                p.print_expression(&stmt.expression);
+                p.print_soft_newline();
+            }
+            return;
+        }
         if let Some(hashbang) = &self.hashbang {
             hashbang.print(p, ctx);
         }
@@ -71,6 +82,9 @@ impl<'a> Gen for Directive<'a> {
 
 impl<'a> Gen for Statement<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
+        if !p.enter_nesting(self.span()) {
+            return;
+        }
         p.print_leading_comments(self.span().start);
         match self {
             Self::BlockStatement(stmt) => stmt.print(p, ctx),
@@ -91,6 +105,7 @@ impl<'a> Gen for Statement<'a> {
             Self::TryStatement(stmt) => stmt.print(p, ctx),
             Self::WhileStatement(stmt) => stmt.print(p, ctx),
             Self::WithStatement(stmt) => stmt.print(p, ctx),
+            Self::ErrorStatement(stmt) => stmt.print(p, ctx),
 
             Self::ImportDeclaration(decl) => decl.print(p, ctx),
             Self::ExportAllDeclaration(decl) => decl.print(p, ctx),
@@ -140,6 +155,7 @@ impl<'a> Gen for Statement<'a> {
                 p.print_semicolon_after_statement();
             }
         }
+        p.leave_nesting();
     }
 }
 
@@ -388,6 +404,16 @@ impl Gen for EmptyStatement {
     }
 }
 
+impl Gen for ErrorStatement {
+    fn gen(&self, p: &mut Codegen, _ctx: Context) {
+        // Unparsable source text is printed as is.
+        p.add_source_mapping(self.span.start);
+        p.print_indent();
+        p.print_str(self.span.source_text(p.source_text));
+        p.print_soft_newline();
+    }
+}
+
 impl<'a> Gen for ContinueStatement<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
         p.add_source_mapping(self.span.start);
@@ -705,7 +731,9 @@ impl<'a> Gen for ImportDeclaration<'a> {
         p.add_source_mapping(self.span.start);
         p.print_indent();
         p.print_str("import ");
-        if self.import_kind.is_type() {
+        if self.import_kind.is_typeof() {
+            p.print_str("typeof ");
+        } else if self.import_kind.is_type() {
             p.print_str("type ");
         }
         if let Some(specifiers) = &self.specifiers {
@@ -765,7 +793,9 @@ impl<'a> Gen for ImportDeclaration<'a> {
                             p.print_soft_space();
                         }
 
-                        if spec.import_kind.is_type() {
+                        if spec.import_kind.is_typeof() {
+                            p.print_str("typeof ");
+                        } else if spec.import_kind.is_type() {
                             p.print_str("type ");
                         }
 
@@ -1002,6 +1032,9 @@ impl<'a> Gen for ExportDefaultDeclarationKind<'a> {
 
 impl<'a> GenExpr for Expression<'a> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
+        if !p.enter_nesting(self.span()) {
+            return;
+        }
         match self {
             Self::BooleanLiteral(lit) => lit.print(p, ctx),
             Self::NullLiteral(lit) => lit.print(p, ctx),
@@ -1046,6 +1079,7 @@ impl<'a> GenExpr for Expression<'a> {
             Self::TSNonNullExpression(e) => e.print_expr(p, precedence, ctx),
             Self::TSInstantiationExpression(e) => e.print_expr(p, precedence, ctx),
         }
+        p.leave_nesting();
     }
 }
 
@@ -2089,6 +2123,15 @@ impl<'a> GenExpr for NewExpression<'a> {
 
 impl<'a> GenExpr for TSAsExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
+        if self.flow_type_cast {
+            p.print_char(b'(');
+            self.expression.print_expr(p, Precedence::Comma, ctx);
+            p.print_colon();
+            p.print_soft_space();
+            self.type_annotation.print(p, ctx);
+            p.print_char(b')');
+            return;
+        }
         let wrap = precedence >= Precedence::Shift;
 
         p.wrap(wrap, |p| {
@@ -3120,7 +3163,7 @@ impl<'a> Gen for TSTemplateLiteralType<'a> {
 impl<'a> Gen for TSTypeLiteral<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
         let single_line = self.members.len() <= 1;
-        p.print_curly_braces(self.span, single_line, |p| {
+        let print_members = |p: &mut Codegen| {
             for item in &self.members {
                 if single_line {
                     p.print_soft_space();
@@ -3135,7 +3178,25 @@ impl<'a> Gen for TSTypeLiteral<'a> {
                     p.print_soft_newline();
                 }
             }
-        });
+        };
+        if !self.flow_exact {
+            p.print_curly_braces(self.span, single_line, print_members);
+            return;
+        }
+        // Flow exact object type `{| a: A |}`
+        p.add_source_mapping(self.span.start);
+        p.print_str("{|");
+        if !single_line {
+            p.print_soft_newline();
+            p.indent();
+        }
+        print_members(p);
+        if !single_line {
+            p.dedent();
+            p.print_indent();
+        }
+        p.add_source_mapping(self.span.end);
+        p.print_str("|}");
     }
 }
 
@@ -15,6 +15,7 @@ use std::{borrow::Cow, path::PathBuf};
 use oxc_ast::ast::{
     BindingIdentifier, BlockStatement, Expression, IdentifierReference, Program, Statement,
 };
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_mangler::Mangler;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::{
@@ -60,6 +61,15 @@ pub struct CodegenOptions {
     pub annotation_comments: bool,
 
     pub source_map_path: Option<PathBuf>,
+
+    /// Maximum nesting depth of statements and expressions.
+    ///
+    /// Code generation is recursive, so a deeply nested AST can overflow the stack.
+    /// Statements and expressions nested deeper than this limit are not printed,
+    /// and an error is reported in [`CodegenReturn::errors`] instead.
+    ///
+    /// Default is `5000`.
+    pub max_nesting_depth: u32,
 }
 
 impl Default for CodegenOptions {
@@ -70,6 +80,7 @@ impl Default for CodegenOptions {
             comments: true,
             annotation_comments: false,
             source_map_path: None,
+            max_nesting_depth: 5000,
         }
     }
 }
@@ -89,6 +100,9 @@ pub struct CodegenReturn {
     ///
     /// You must set [`CodegenOptions::source_map_path`] for this to be [`Some`].
     pub map: Option<oxc_sourcemap::SourceMap>,
+
+    /// Errors encountered while generating code, the generated code is incomplete if not empty.
+    pub errors: Vec<OxcDiagnostic>,
 }
 
 pub struct Codegen<'a> {
@@ -136,6 +150,11 @@ pub struct Codegen<'a> {
     /// Track the current indentation level
     indent: u32,
 
+    /// Track the current nesting depth, see [`CodegenOptions::max_nesting_depth`]
+    depth: u32,
+
+    errors: Vec<OxcDiagnostic>,
+
     /// Fast path for [CodegenOptions::single_quote]
     quote: u8,
 
@@ -183,6 +202,8 @@ impl<'a> Codegen<'a> {
             start_of_arrow_expr: 0,
             start_of_default_export: 0,
             indent: 0,
+            depth: 0,
+            errors: vec![],
             quote: b'"',
             sourcemap_builder: None,
         }
@@ -216,7 +237,7 @@ impl<'a> Codegen<'a> {
         program.print(&mut self, Context::default());
         let code = self.into_source_text();
         let map = self.sourcemap_builder.map(SourcemapBuilder::into_sourcemap);
-        CodegenReturn { code, map }
+        CodegenReturn { code, map, errors: self.errors }
     }
 
     #[must_use]
@@ -253,6 +274,32 @@ impl<'a> Codegen<'a> {
         self.code().len()
     }
 
+    /// Enter a nested statement or expression.
+    ///
+    /// Returns `false` if [`CodegenOptions::max_nesting_depth`] is exceeded,
+    /// in which case the node must be skipped without calling [`Codegen::leave_nesting`].
+    fn enter_nesting(&mut self, span: Span) -> bool {
+        if self.depth >= self.options.max_nesting_depth {
+            // Only report the outermost node, all of its siblings are skipped as well.
+            if self.errors.is_empty() {
+                self.errors.push(
+                    OxcDiagnostic::error(format!(
+                        "Maximum nesting depth of {} exceeded",
+                        self.options.max_nesting_depth
+                    ))
+                    .with_label(span),
+                );
+            }
+            return false;
+        }
+        self.depth += 1;
+        true
+    }
+
+    fn leave_nesting(&mut self) {
+        self.depth -= 1;
+    }
+
     #[inline]
     fn print_soft_space(&mut self) {
         if !self.options.minify {
@@ -1,3 +1,8 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_span::SourceType;
+
 use crate::tester::{test, test_minify, test_without_source};
 
 #[test]
@@ -271,3 +276,46 @@ fn vite_special_comments() {
         "import(\n\t/* @vite-ignore */\n\tmodule1Url\n).then((module1) => {\n\tself.postMessage(module.default + module1.msg1 + import.meta.env.BASE_URL);\n});\n",
     );
 }
+
+#[test]
+fn max_nesting_depth() {
+    let allocator = Allocator::default();
+    let source_text = format!("x = {}{};", "[".repeat(100), "]".repeat(100));
+    let program = Parser::new(&allocator, &source_text, SourceType::mjs()).parse().program;
+
+    let options = CodegenOptions { minify: true, ..CodegenOptions::default() };
+    let ret = CodeGenerator::new().with_options(options).build(&program);
+    assert!(ret.errors.is_empty());
+    assert_eq!(ret.code, format!("x={}{};", "[".repeat(100), "]".repeat(100)));
+
+    let options = CodegenOptions { max_nesting_depth: 50, ..CodegenOptions::default() };
+    let ret = CodeGenerator::new().with_options(options).build(&program);
+    assert_eq!(ret.errors.len(), 1);
+    assert_eq!(ret.errors[0].to_string(), "Maximum nesting depth of 50 exceeded");
+}
+
+#[test]
+fn json() {
+    let allocator = Allocator::default();
+    let source_text = "// config\n{ \"a\": [1, -2.5, true, null], \"b\": { \"c\": \"d\" }, }";
+    let program = Parser::new(&allocator, source_text, SourceType::jsonc()).parse().program;
+    let ret = CodeGenerator::new().build(&program);
+    assert_eq!(ret.code, "{\n\t\"a\": [\n\t\t1,\n\t\t-2.5,\n\t\ttrue,\n\t\tnull\n\t],\n\t\"b\": { \"c\": \"d\" }\n}\n");
+}
+
+#[test]
+fn flow() {
+    let allocator = Allocator::default();
+    let source_text =
+        "import typeof T from 'm';\ntype O = {| a: string |};\ntype E = {||};\nconst y = (x: any);\n";
+    let options = ParseOptions { parse_flow: true, ..ParseOptions::default() };
+    let program = Parser::new(&allocator, source_text, SourceType::mjs())
+        .with_options(options)
+        .parse()
+        .program;
+    let ret = CodeGenerator::new().build(&program);
+    assert_eq!(
+        ret.code,
+        "import typeof T from \"m\";\ntype O = {| a: string |};\ntype E = {||};\nconst y = (x: any);\n"
+    );
+}
@@ -145,7 +145,7 @@ impl<'a> IsolatedDeclarations<'a> {
                     None
                 }
             }));
-        self.ast.ts_type_type_literal(SPAN, members)
+        self.ast.ts_type_type_literal(SPAN, members, false)
     }
 
     pub fn transform_array_expression_to_ts_type(
@@ -7,7 +7,7 @@ use futures::future::join_all;
 use globset::Glob;
 use ignore::gitignore::Gitignore;
 use log::{debug, error, info};
-use oxc_linter::{FixKind, LinterBuilder, Oxlintrc};
+use oxc_linter::{FixKind, IgnorePatterns, LinterBuilder, Oxlintrc};
 use serde::{Deserialize, Serialize};
 use tokio::sync::{Mutex, OnceCell, RwLock, SetError};
 use tower_lsp::{
@@ -33,6 +33,8 @@ struct Backend {
     diagnostics_report_map: DashMap<String, Vec<DiagnosticReport>>,
     options: Mutex<Options>,
     gitignore_glob: Mutex<Option<Gitignore>>,
+    /// `ignorePatterns` of the configuration file
+    ignore_patterns: Mutex<IgnorePatterns>,
 }
 #[derive(Debug, Serialize, Deserialize, Default, PartialEq, PartialOrd, Clone, Copy)]
 #[serde(rename_all = "camelCase")]
@@ -343,15 +345,12 @@ impl Backend {
             config_path = Some(config);
         }
         if let Some(config_path) = config_path {
+            let oxlintrc = Oxlintrc::from_file(&config_path)
+                .expect("should have initialized linter with new options");
+            *self.ignore_patterns.lock().await = oxlintrc.ignore_patterns.clone();
             let mut linter = self.server_linter.write().await;
             *linter = ServerLinter::new_with_linter(
-                LinterBuilder::from_oxlintrc(
-                    true,
-                    Oxlintrc::from_file(&config_path)
-                        .expect("should have initialized linter with new options"),
-                )
-                .with_fix(FixKind::SafeFix)
-                .build(),
+                LinterBuilder::from_oxlintrc(true, oxlintrc).with_fix(FixKind::SafeFix).build(),
             );
         }
     }
@@ -381,11 +380,10 @@ impl Backend {
         if !uri.path().starts_with(root_uri.path()) {
             return false;
         }
-        let Some(ref gitignore_globs) = *self.gitignore_glob.lock().await else {
-            return false;
-        };
         let path = PathBuf::from(uri.path());
-        let ignored = gitignore_globs.matched_path_or_any_parents(&path, path.is_dir()).is_ignore();
+        let ignored = self.gitignore_glob.lock().await.as_ref().is_some_and(|gitignore_globs| {
+            gitignore_globs.matched_path_or_any_parents(&path, path.is_dir()).is_ignore()
+        }) || self.ignore_patterns.lock().await.is_ignored(&path, path.is_dir());
         if ignored {
             debug!("ignored: {uri}");
         }
@@ -410,6 +408,7 @@ async fn main() {
         diagnostics_report_map,
         options: Mutex::new(Options::default()),
         gitignore_glob: Mutex::new(None),
+        ignore_patterns: Mutex::new(IgnorePatterns::default()),
     })
     .finish();
 
@@ -0,0 +1,2 @@
+import d from './default-export';
+export { d };
@@ -1,14 +1,17 @@
 use std::{
     cell::{Ref, RefCell},
     fmt,
+    path::{Path, PathBuf},
 };
 
 use rustc_hash::FxHashSet;
 
 use crate::{
-    options::LintPlugins, rules::RULES, AllowWarnDeny, FixKind, FrameworkFlags, LintConfig,
-    LintFilter, LintFilterKind, LintOptions, Linter, Oxlintrc, RuleCategory, RuleEnum,
-    RuleWithSeverity,
+    config::{ConfigStore, OxlintOverrides},
+    options::LintPlugins,
+    rules::RULES,
+    AllowWarnDeny, FixKind, FrameworkFlags, LintConfig, LintFilter, LintFilterKind, LintOptions,
+    Linter, Oxlintrc, RuleCategory, RuleEnum, RuleWithSeverity,
 };
 
 #[must_use = "You dropped your builder without building a Linter! Did you mean to call .build()?"]
@@ -16,6 +19,9 @@ pub struct LinterBuilder {
     pub(super) rules: FxHashSet<RuleWithSeverity>,
     options: LintOptions,
     config: LintConfig,
+    overrides: OxlintOverrides,
+    /// Directory containing the configuration file, which globs in `overrides` are relative to.
+    config_dir: PathBuf,
     cache: RulesCache,
 }
 
@@ -33,7 +39,14 @@ impl LinterBuilder {
     pub fn empty() -> Self {
         let options = LintOptions::default();
         let cache = RulesCache::new(options.plugins);
-        Self { rules: FxHashSet::default(), options, config: LintConfig::default(), cache }
+        Self {
+            rules: FxHashSet::default(),
+            options,
+            config: LintConfig::default(),
+            overrides: OxlintOverrides::default(),
+            config_dir: PathBuf::new(),
+            cache,
+        }
     }
 
     /// Warn on all rules in all plugins and categories, including those in `nursery`.
@@ -50,6 +63,8 @@ impl LinterBuilder {
                 .collect(),
             options,
             config: LintConfig::default(),
+            overrides: OxlintOverrides::default(),
+            config_dir: PathBuf::new(),
             cache,
         }
     }
@@ -70,15 +85,27 @@ impl LinterBuilder {
     /// ```
     pub fn from_oxlintrc(start_empty: bool, oxlintrc: Oxlintrc) -> Self {
         // TODO: monorepo config merging, plugin-based extends, etc.
-        let Oxlintrc { plugins, settings, env, globals, categories, rules: oxlintrc_rules } =
-            oxlintrc;
+        let Oxlintrc {
+            extends: _,
+            root: _,
+            plugins,
+            settings,
+            env,
+            globals,
+            categories,
+            rules: oxlintrc_rules,
+            overrides,
+            ignore_patterns: _,
+            path,
+        } = oxlintrc;
 
         let config = LintConfig { settings, env, globals };
         let options = LintOptions { plugins, ..Default::default() };
         let rules =
             if start_empty { FxHashSet::default() } else { Self::warn_correctness(plugins) };
         let cache = RulesCache::new(options.plugins);
-        let mut builder = Self { rules, options, config, cache };
+        let config_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        let mut builder = Self { rules, options, config, overrides, config_dir, cache };
 
         if !categories.is_empty() {
             builder = builder.with_filters(categories.filters());
@@ -146,6 +173,14 @@ impl LinterBuilder {
         self.options.plugins
     }
 
+    /// Plugins enabled by the top-level configuration or by any of its `overrides`.
+    pub fn plugins_with_overrides(&self) -> LintPlugins {
+        self.overrides
+            .iter()
+            .filter_map(|override_config| override_config.plugins)
+            .fold(self.plugins(), |plugins, override_plugins| plugins | override_plugins)
+    }
+
     #[cfg(test)]
     pub(crate) fn with_rule(mut self, rule: RuleWithSeverity) -> Self {
         self.rules.insert(rule);
@@ -228,7 +263,9 @@ impl LinterBuilder {
             self.rules.into_iter().collect::<Vec<_>>()
         };
         rules.sort_unstable_by_key(|r| r.id());
-        Linter::new(rules, self.options, self.config)
+        let config =
+            ConfigStore::new(rules, self.config, plugins, self.overrides, &self.config_dir);
+        Linter::new(self.options, config)
     }
 
     /// Warn for all correctness rules in the given set of plugins.
@@ -259,6 +296,7 @@ impl fmt::Debug for LinterBuilder {
             .field("rules", &self.rules)
             .field("options", &self.options)
             .field("config", &self.config)
+            .field("overrides", &self.overrides)
             .finish_non_exhaustive()
     }
 }
@@ -23,6 +23,11 @@ impl OxlintEnv {
         self.0.get(key).is_some_and(|v| *v)
     }
 
+    /// Enable or disable environments set in `other`, overriding environments set in `self`.
+    pub(crate) fn override_envs(&mut self, other: &Self) {
+        self.0.extend(other.0.iter().map(|(k, v)| (k.clone(), *v)));
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &str> + '_ {
         // Filter out false values
         self.0.iter().filter_map(|(k, v)| (*v).then_some(k.as_str()))
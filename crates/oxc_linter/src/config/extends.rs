@@ -0,0 +1,404 @@
+use std::path::{Path, PathBuf};
+
+use globset::Glob;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_resolver::{ResolveOptions, Resolver};
+use serde_json::{Map, Value};
+
+use super::Oxlintrc;
+
+/// Loads configuration files and the files they `extends`, merging them into a single JSON value.
+pub(super) struct ExtendsLoader {
+    resolver: Resolver,
+    /// Whether to also merge configuration files in parent directories.
+    with_parents: bool,
+    /// Configuration files currently being loaded, used to detect cycles.
+    stack: Vec<PathBuf>,
+}
+
+impl ExtendsLoader {
+    pub(super) fn new(with_parents: bool) -> Self {
+        let resolver = Resolver::new(ResolveOptions {
+            extensions: vec![".json".into()],
+            ..ResolveOptions::default()
+        });
+        Self { resolver, with_parents, stack: vec![] }
+    }
+
+    /// Load the configuration file at `path`, with the files it extends merged into it.
+    ///
+    /// `read` reads and parses a single configuration file.
+    pub(super) fn load<F>(&mut self, path: &Path, read: &F) -> Result<Value, OxcDiagnostic>
+    where
+        F: Fn(&Path) -> Result<Value, OxcDiagnostic>,
+    {
+        self.load_file(path, read, self.with_parents)
+    }
+
+    fn load_file<F>(
+        &mut self,
+        path: &Path,
+        read: &F,
+        with_parents: bool,
+    ) -> Result<Value, OxcDiagnostic>
+    where
+        F: Fn(&Path) -> Result<Value, OxcDiagnostic>,
+    {
+        let canonical_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        if let Some(start) = self.stack.iter().position(|p| *p == canonical_path) {
+            let cycle = self.stack[start..]
+                .iter()
+                .chain([&canonical_path])
+                .map(|p| p.display().to_string())
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            return Err(OxcDiagnostic::error(format!("Circular `extends` in config: {cycle}"))
+                .with_help("Remove one of the `extends` entries to break the cycle."));
+        }
+
+        let mut json = read(path)?;
+        let extends = match json.as_object_mut().and_then(|object| object.get("extends")) {
+            None => vec![],
+            Some(Value::String(specifier)) => vec![specifier.clone()],
+            Some(Value::Array(specifiers)) => specifiers
+                .iter()
+                .map(|specifier| specifier.as_str().map(ToString::to_string))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| invalid_extends(path))?,
+            Some(_) => return Err(invalid_extends(path)),
+        };
+        let is_root = json.get("root").and_then(Value::as_bool).unwrap_or(false);
+        let parent = if with_parents && !is_root {
+            canonical_path.parent().and_then(Path::parent).and_then(Oxlintrc::find_nearest)
+        } else {
+            None
+        };
+        if extends.is_empty() && parent.is_none() {
+            return Ok(json);
+        }
+
+        let mut merged = Value::Object(Map::new());
+        if let Some(parent) = parent {
+            let mut base = self.load_file(&parent, read, true)?;
+            let prefix = canonical_path
+                .parent()
+                .zip(parent.parent())
+                .and_then(|(dir, parent_dir)| dir.strip_prefix(parent_dir).ok());
+            if let Some(prefix) = prefix {
+                rebase(&mut base, prefix);
+            }
+            merge_base(&mut merged, base);
+        }
+
+        self.stack.push(canonical_path);
+        let directory = path.parent().unwrap_or_else(|| Path::new("."));
+        for specifier in &extends {
+            let resolved = self.resolver.resolve(directory, specifier).map_err(|err| {
+                OxcDiagnostic::error(format!(
+                    "Failed to resolve `extends` entry {specifier:?} in config {path:?}: {err}"
+                ))
+            })?;
+            let base = self.load_file(resolved.path(), read, false)?;
+            merge_base(&mut merged, base);
+        }
+        self.stack.pop();
+
+        // Keep `extends` of this file as written, normalized to an array.
+        if let Some(object) = json.as_object_mut() {
+            object.insert("extends".into(), extends.into_iter().map(Value::String).collect());
+        }
+        merge(&mut merged, json);
+        Ok(merged)
+    }
+}
+
+fn invalid_extends(path: &Path) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "Failed to parse config {path:?}: `extends` must be a string or an array of strings"
+    ))
+}
+
+/// Merge an extended or parent configuration into `merged`. `extends` and `root` only apply to
+/// the file they are written in.
+fn merge_base(merged: &mut Value, mut base: Value) {
+    if let Some(object) = base.as_object_mut() {
+        object.remove("extends");
+        object.remove("root");
+    }
+    merge(merged, base);
+}
+
+/// Rewrite globs in `overrides` and `ignorePatterns` of a configuration file, which are relative
+/// to its directory, to be relative to its subdirectory `prefix`.
+///
+/// Patterns which can only match files outside of `prefix` are removed, along with overrides
+/// left without any `files`.
+fn rebase(config: &mut Value, prefix: &Path) {
+    let components = prefix
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>();
+    let prefix = components.iter().map(AsRef::as_ref).collect::<Vec<&str>>();
+    if prefix.is_empty() {
+        return;
+    }
+
+    if let Some(Value::Array(patterns)) = config.get_mut("ignorePatterns") {
+        *patterns = patterns
+            .iter()
+            .filter_map(Value::as_str)
+            .flat_map(|pattern| rebase_ignore_pattern(pattern, &prefix))
+            .map(Value::String)
+            .collect();
+    }
+
+    let Some(Value::Array(overrides)) = config.get_mut("overrides") else {
+        return;
+    };
+    let rebase_globs = |patterns: &Value| -> Vec<Value> {
+        let patterns = match patterns {
+            Value::String(_) => std::slice::from_ref(patterns),
+            Value::Array(patterns) => patterns.as_slice(),
+            _ => &[],
+        };
+        patterns
+            .iter()
+            .filter_map(Value::as_str)
+            .flat_map(|pattern| {
+                let pattern = pattern.strip_prefix("./").unwrap_or(pattern);
+                if pattern.contains('/') {
+                    rebase_glob(&pattern.split('/').collect::<Vec<_>>(), &prefix)
+                } else {
+                    vec![pattern.to_string()]
+                }
+            })
+            // Matches `prefix` itself or one of its ancestors, which are directories, not files
+            .filter(|pattern| !pattern.is_empty())
+            .map(Value::String)
+            .collect()
+    };
+    overrides.retain_mut(|override_config| {
+        let Some(object) = override_config.as_object_mut() else {
+            return true;
+        };
+        for key in ["files", "excludedFiles"] {
+            if let Some(patterns) = object.get(key) {
+                let patterns = rebase_globs(patterns);
+                object.insert(key.into(), Value::Array(patterns));
+            }
+        }
+        object.get("files").and_then(Value::as_array).map_or(true, |files| !files.is_empty())
+    });
+}
+
+/// `.gitignore` patterns only match relative to their directory if they contain a `/` other than
+/// a trailing one.
+fn rebase_ignore_pattern(pattern: &str, prefix: &[&str]) -> Vec<String> {
+    let (negation, pattern) =
+        pattern.strip_prefix('!').map_or(("", pattern), |pattern| ("!", pattern));
+    let (trimmed, trailing_slash) =
+        pattern.strip_suffix('/').map_or((pattern, ""), |trimmed| (trimmed, "/"));
+    if !trimmed.contains('/') {
+        return vec![format!("{negation}{pattern}")];
+    }
+    let trimmed = trimmed.strip_prefix('/').unwrap_or(trimmed);
+    rebase_glob(&trimmed.split('/').collect::<Vec<_>>(), prefix)
+        .into_iter()
+        .map(|rebased| {
+            if rebased.is_empty() {
+                // The whole directory
+                format!("{negation}/**")
+            } else {
+                format!("{negation}/{rebased}{trailing_slash}")
+            }
+        })
+        .fold(vec![], |mut patterns, pattern| {
+            if !patterns.contains(&pattern) {
+                patterns.push(pattern);
+            }
+            patterns
+        })
+}
+
+/// Rebase the glob with components `pattern` onto the directory with components `prefix`,
+/// by matching them component by component.
+///
+/// Returns the globs, relative to `prefix`, which match the same paths inside `prefix`.
+/// `**` can match any number of components of `prefix`, so there may be several of them. An empty
+/// glob means that `pattern` matches `prefix` itself or one of its ancestors.
+fn rebase_glob(pattern: &[&str], prefix: &[&str]) -> Vec<String> {
+    let Some((dir, prefix_rest)) = prefix.split_first() else {
+        return vec![pattern.join("/")];
+    };
+    match pattern.split_first() {
+        None => vec![String::new()],
+        Some((&"**", pattern_rest)) => {
+            let mut globs = rebase_glob(pattern_rest, prefix);
+            for glob in rebase_glob(pattern, prefix_rest) {
+                if !globs.contains(&glob) {
+                    globs.push(glob);
+                }
+            }
+            globs
+        }
+        Some((component, pattern_rest)) if component_matches(component, dir) => {
+            rebase_glob(pattern_rest, prefix_rest)
+        }
+        Some(_) => vec![],
+    }
+}
+
+/// Whether a glob without `/` matches the file name `name`.
+fn component_matches(glob: &str, name: &str) -> bool {
+    Glob::new(glob).map_or(glob == name, |glob| glob.compile_matcher().is_match(name))
+}
+
+/// Merge `config` into `base`, with values in `config` taking precedence.
+///
+/// * `plugins` are combined.
+/// * `overrides` and `ignorePatterns` of `config` are appended to those of `base`.
+/// * `rules` are merged by rule. A rule configured only with a severity in `config` keeps
+///   its options from `base`.
+/// * Other objects (`env`, `globals`, `settings`, `categories`) are merged recursively.
+fn merge(base: &mut Value, config: Value) {
+    let (Value::Object(base), Value::Object(config)) = (base, config) else {
+        return;
+    };
+    for (key, value) in config {
+        let Some(base_value) = base.get_mut(&key) else {
+            base.insert(key, value);
+            continue;
+        };
+        match (key.as_str(), base_value, value) {
+            ("plugins", Value::Array(base_plugins), Value::Array(plugins)) => {
+                for plugin in plugins {
+                    if !base_plugins.contains(&plugin) {
+                        base_plugins.push(plugin);
+                    }
+                }
+            }
+            ("overrides" | "ignorePatterns", Value::Array(base_values), Value::Array(values)) => {
+                base_values.extend(values);
+            }
+            ("rules", Value::Object(base_rules), Value::Object(rules)) => {
+                for (name, rule) in rules {
+                    match (base_rules.get_mut(&name), rule) {
+                        (
+                            Some(Value::Array(base_rule)),
+                            severity @ (Value::String(_) | Value::Number(_)),
+                        ) if !base_rule.is_empty() => {
+                            base_rule[0] = severity;
+                        }
+                        (_, rule) => {
+                            base_rules.insert(name, rule);
+                        }
+                    }
+                }
+            }
+            (_, base_value @ Value::Object(_), value @ Value::Object(_)) => {
+                merge(base_value, value);
+            }
+            (_, base_value, value) => *base_value = value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::{merge, rebase};
+
+    #[test]
+    fn test_rebase() {
+        let mut config = json!({
+            "ignorePatterns": [
+                "dist/",
+                "/packages/*/dist/",
+                "!packages/a/dist/keep.js",
+                "packages/{a,b}/coverage",
+                "**/packages/a/tmp/",
+                "packages/*",
+                "packages/c/",
+                "/build/",
+            ],
+            "overrides": [
+                {
+                    "files": ["packages/*/src/**/*.test.js", "*.spec.js", "./packages/?/e2e/*.js"],
+                    "excludedFiles": ["packages/*/src/fixtures/**", "packages/c/**"],
+                },
+                { "files": "**/src/*.js" },
+                { "files": ["packages/c/**", "packages/a"] },
+            ]
+        });
+        rebase(&mut config, Path::new("packages/a"));
+        assert_eq!(
+            config,
+            json!({
+                "ignorePatterns": [
+                    "dist/",
+                    "/dist/",
+                    "!/dist/keep.js",
+                    "/coverage",
+                    "/tmp/",
+                    "/**/packages/a/tmp/",
+                    "/**",
+                ],
+                "overrides": [
+                    {
+                        "files": ["src/**/*.test.js", "*.spec.js", "e2e/*.js"],
+                        "excludedFiles": ["src/fixtures/**"],
+                    },
+                    { "files": ["**/src/*.js"] },
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut base = json!({
+            "plugins": ["import", "jest"],
+            "env": { "browser": true },
+            "settings": { "jsx-a11y": { "polymorphicPropName": "as", "components": { "A": "a" } } },
+            "rules": {
+                "no-console": "error",
+                "eqeqeq": ["warn", "smart"],
+                "no-debugger": ["error"]
+            },
+            "overrides": [{ "files": "*.test.js" }]
+        });
+        merge(
+            &mut base,
+            json!({
+                "plugins": ["jest", "react"],
+                "env": { "browser": false, "node": true },
+                "settings": { "jsx-a11y": { "components": { "B": "b" } } },
+                "rules": {
+                    "no-console": "off",
+                    "eqeqeq": "error",
+                    "no-debugger": ["warn", { "foo": true }]
+                },
+                "overrides": [{ "files": "*.ts" }]
+            }),
+        );
+        assert_eq!(
+            base,
+            json!({
+                "plugins": ["import", "jest", "react"],
+                "env": { "browser": false, "node": true },
+                "settings": {
+                    "jsx-a11y": { "polymorphicPropName": "as", "components": { "A": "a", "B": "b" } }
+                },
+                "rules": {
+                    "no-console": "off",
+                    "eqeqeq": ["error", "smart"],
+                    "no-debugger": ["warn", { "foo": true }]
+                },
+                "overrides": [{ "files": "*.test.js" }, { "files": "*.ts" }]
+            })
+        );
+    }
+}
@@ -29,7 +29,7 @@ use serde::{de::Visitor, Deserialize, Serialize};
 /// You may also use `"readable"` or `false` to represent `"readonly"`, and
 /// `"writeable"` or `true` to represent `"writable"`.
 // <https://eslint.org/docs/v8.x/use/configure/language-options#using-configuration-files-1>
-#[derive(Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct OxlintGlobals(FxHashMap<String, GlobalValue>);
 impl OxlintGlobals {
     pub fn is_enabled<Q>(&self, name: &Q) -> bool
@@ -39,6 +39,11 @@ impl OxlintGlobals {
     {
         self.0.get(name).is_some_and(|value| *value != GlobalValue::Off)
     }
+
+    /// Add or remove globals set in `other`, overriding globals set in `self`.
+    pub(crate) fn override_globals(&mut self, other: &Self) {
+        self.0.extend(other.0.iter().map(|(k, v)| (k.clone(), *v)));
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
@@ -0,0 +1,147 @@
+use std::{
+    borrow::Cow,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::{de, ser::SerializeSeq, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Patterns of files to ignore, in `.gitignore` format.
+///
+/// Patterns are relative to `root`, the directory containing the configuration file.
+#[derive(Clone)]
+pub struct IgnorePatterns {
+    patterns: Vec<String>,
+    matcher: Gitignore,
+    /// Resolved against `cwd`.
+    root: PathBuf,
+    /// Current working directory when the patterns were created, relative paths are resolved
+    /// against it.
+    cwd: PathBuf,
+}
+
+impl Default for IgnorePatterns {
+    fn default() -> Self {
+        Self {
+            patterns: vec![],
+            matcher: Gitignore::empty(),
+            root: PathBuf::new(),
+            cwd: PathBuf::new(),
+        }
+    }
+}
+
+impl IgnorePatterns {
+    /// # Errors
+    /// Returns an error if any pattern is not a valid glob.
+    pub fn new<S: AsRef<str>, I: IntoIterator<Item = S>>(
+        patterns: I,
+    ) -> Result<Self, ignore::Error> {
+        let patterns: Vec<String> =
+            patterns.into_iter().map(|pattern| pattern.as_ref().to_string()).collect();
+        // Paths are made relative to `root` before matching
+        let mut builder = GitignoreBuilder::new("");
+        for pattern in &patterns {
+            builder.add_line(None, pattern)?;
+        }
+        let cwd = std::env::current_dir().unwrap_or_default();
+        Ok(Self { patterns, matcher: builder.build()?, root: cwd.clone(), cwd })
+    }
+
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    pub(crate) fn set_root(&mut self, root: PathBuf) {
+        self.root = self.cwd.join(root);
+    }
+
+    /// Whether `path`, or any of its parent directories, is ignored. Relative paths are resolved
+    /// against the current working directory at the time the patterns were created. Paths outside
+    /// of the root are never ignored.
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        let path = self.cwd.join(path);
+        path.strip_prefix(&self.root).is_ok_and(|path| {
+            !path.as_os_str().is_empty()
+                && self.matcher.matched_path_or_any_parents(path, is_dir).is_ignore()
+        })
+    }
+}
+
+impl fmt::Debug for IgnorePatterns {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IgnorePatterns").field(&self.patterns).finish()
+    }
+}
+
+impl<'de> Deserialize<'de> for IgnorePatterns {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let patterns = Vec::<String>::deserialize(deserializer)?;
+        Self::new(patterns).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for IgnorePatterns {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.patterns.len()))?;
+        for pattern in &self.patterns {
+            seq.serialize_element(pattern)?;
+        }
+        seq.end()
+    }
+}
+
+impl JsonSchema for IgnorePatterns {
+    fn schema_name() -> String {
+        "IgnorePatterns".to_string()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("IgnorePatterns")
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        gen.subschema_for::<Vec<String>>()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_is_ignored() {
+        let mut ignore_patterns = IgnorePatterns::deserialize(&json!([
+            "dist/",
+            "*.min.js",
+            "/scripts/*.js",
+            "!scripts/keep.js"
+        ]))
+        .unwrap();
+        ignore_patterns.set_root(PathBuf::from("project"));
+
+        assert!(ignore_patterns.is_ignored(Path::new("project/dist"), true));
+        assert!(ignore_patterns.is_ignored(Path::new("project/packages/a/dist/index.js"), false));
+        assert!(ignore_patterns.is_ignored(Path::new("project/a/b.min.js"), false));
+        assert!(ignore_patterns.is_ignored(Path::new("project/scripts/build.js"), false));
+        // Negated
+        assert!(!ignore_patterns.is_ignored(Path::new("project/scripts/keep.js"), false));
+        // Anchored to the root
+        assert!(!ignore_patterns.is_ignored(Path::new("project/src/scripts/build.js"), false));
+        assert!(!ignore_patterns.is_ignored(Path::new("project/src/index.js"), false));
+        // Outside of the root
+        assert!(!ignore_patterns.is_ignored(Path::new("dist/index.js"), false));
+        assert!(!ignore_patterns.is_ignored(Path::new("project"), true));
+    }
+}
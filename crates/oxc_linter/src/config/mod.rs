@@ -1,18 +1,25 @@
 mod categories;
 mod env;
+mod extends;
 mod globals;
+mod ignore_patterns;
+mod overrides;
 mod oxlintrc;
 mod rules;
 mod settings;
+mod store;
 
+pub(crate) use self::store::{ConfigStore, ResolvedLinterState};
 pub use self::{
     env::OxlintEnv,
     globals::OxlintGlobals,
+    ignore_patterns::IgnorePatterns,
+    overrides::{GlobSet, OxlintOverride, OxlintOverrides},
     oxlintrc::Oxlintrc,
     settings::{jsdoc::JSDocPluginSettings, OxlintSettings},
 };
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub(crate) struct LintConfig {
     pub(crate) settings: OxlintSettings,
     /// Environments enable and disable collections of global variables.
@@ -36,7 +43,7 @@ mod test {
     use serde::Deserialize;
 
     use super::Oxlintrc;
-    use crate::rules::RULES;
+    use crate::{rules::RULES, AllowWarnDeny};
 
     #[test]
     fn test_from_file() {
@@ -45,6 +52,70 @@ mod test {
         assert!(!config.rules.is_empty());
     }
 
+    #[test]
+    fn test_from_file_extends() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/.oxlintrc.json");
+        let config = Oxlintrc::from_file(&fixture_path).unwrap();
+        assert_eq!(config.extends, ["./base.json", "oxlint-config-preset"]);
+        assert!(config.plugins.has_import());
+        assert!(config.globals.is_enabled("foo"));
+
+        let rule = |name: &str| config.rules.iter().find(|rule| rule.rule_name == name).unwrap();
+        // Later files take precedence
+        assert_eq!(rule("no-console").severity, AllowWarnDeny::Warn);
+        assert_eq!(rule("no-debugger").severity, AllowWarnDeny::Deny);
+        // Severity is overridden, options are kept
+        assert_eq!(rule("eqeqeq").severity, AllowWarnDeny::Deny);
+        assert_eq!(rule("eqeqeq").config, Some(serde_json::json!(["smart"])));
+    }
+
+    #[test]
+    fn test_from_file_extends_errors() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/cycle/a.json");
+        let err = Oxlintrc::from_file(&fixture_path).unwrap_err();
+        let message = err.to_string();
+        assert!(message.starts_with("Circular `extends` in config"), "{message}");
+        assert!(message.contains("a.json -> ") && message.contains("b.json -> "), "{message}");
+
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/unresolved.json");
+        let err = Oxlintrc::from_file(&fixture_path).unwrap_err();
+        assert!(err.to_string().starts_with("Failed to resolve `extends`"), "{err}");
+    }
+
+    #[test]
+    fn test_from_file_with_parents() {
+        let fixtures = env::current_dir().unwrap().join("fixtures/parents/packages");
+        let rule = |config: &Oxlintrc, name: &str| {
+            config.rules.iter().find(|rule| rule.rule_name == name).map(|rule| rule.severity)
+        };
+
+        let config = Oxlintrc::from_file_with_parents(&fixtures.join("a/.oxlintrc.json")).unwrap();
+        assert_eq!(rule(&config, "no-console"), Some(AllowWarnDeny::Deny));
+        assert_eq!(rule(&config, "no-debugger"), Some(AllowWarnDeny::Warn));
+        // Globs are relative to `packages/a`, overrides which only apply elsewhere are removed
+        assert_eq!(config.overrides.len(), 2);
+        assert_eq!(config.overrides[0].files.patterns(), ["**/*.test.js", "*.spec.js"]);
+        // Wildcard directories match `packages/a`
+        assert_eq!(
+            config.overrides[1].files.patterns(),
+            ["src/**/*.js", "*.stories.js", "**/a/*.stories.js"]
+        );
+        assert_eq!(config.overrides[1].excluded_files.patterns(), ["src/fixtures/**"]);
+        assert_eq!(config.ignore_patterns.patterns(), ["*.min.js", "/dist/", "/coverage/"]);
+        assert!(config.ignore_patterns.is_ignored(&fixtures.join("a/dist/index.js"), false));
+        assert!(config.ignore_patterns.is_ignored(&fixtures.join("a/coverage/index.js"), false));
+
+        let config = Oxlintrc::from_file(&fixtures.join("a/.oxlintrc.json")).unwrap();
+        assert_eq!(rule(&config, "no-console"), None);
+
+        // `"root": true`
+        let config = Oxlintrc::from_file_with_parents(&fixtures.join("b/.oxlintrc.json")).unwrap();
+        assert!(config.root);
+        assert!(config.rules.is_empty());
+        assert!(config.overrides.is_empty());
+        assert!(config.ignore_patterns.is_empty());
+    }
+
     #[test]
     fn test_deserialize() {
         let config = Oxlintrc::deserialize(&serde_json::json!({
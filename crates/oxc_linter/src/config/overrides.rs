@@ -0,0 +1,215 @@
+use std::{borrow::Cow, fmt, ops::Deref, path::Path};
+
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::{de, ser::SerializeSeq, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{env::OxlintEnv, globals::OxlintGlobals, rules::OxlintRules};
+use crate::options::LintPlugins;
+
+/// Apply configuration to files matching glob patterns.
+///
+/// Overrides are applied in order, on top of the top-level configuration.
+/// Later overrides take precedence over earlier ones.
+///
+/// ```json
+/// {
+///   "rules": { "no-console": "error" },
+///   "overrides": [
+///     {
+///       "files": ["**/__tests__/**", "*.test.ts"],
+///       "excludedFiles": ["**/fixtures/**"],
+///       "plugins": ["jest"],
+///       "env": { "jest": true },
+///       "rules": { "no-console": "off", "jest/no-focused-tests": "error" }
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct OxlintOverrides(Vec<OxlintOverride>);
+
+impl Deref for OxlintOverrides {
+    type Target = Vec<OxlintOverride>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl OxlintOverrides {
+    /// Iterate over the indices of overrides which apply to `path`, in order.
+    ///
+    /// `path` should be relative to the directory containing the configuration file.
+    pub(crate) fn matching<'s>(&'s self, path: &'s Path) -> impl Iterator<Item = usize> + 's {
+        self.0.iter().enumerate().filter(|(_, it)| it.matches(path)).map(|(index, _)| index)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OxlintOverride {
+    /// Glob patterns of files this override applies to.
+    ///
+    /// Patterns are relative to the directory containing the configuration file.
+    /// Patterns without a `/` (e.g. `*.test.js`) match files in any directory.
+    pub files: GlobSet,
+    /// Glob patterns of files this override does not apply to, even if they match `files`.
+    #[serde(default)]
+    pub excluded_files: GlobSet,
+    /// Plugins to enable for matching files, in addition to top-level `plugins`.
+    ///
+    /// Enabling a plugin does not turn on any of its rules. Configure them in `rules`.
+    #[serde(default)]
+    pub plugins: Option<LintPlugins>,
+    /// Rules to configure for matching files. See [Oxlint Rules](https://oxc.rs/docs/guide/usage/linter/rules.html).
+    #[serde(default)]
+    pub rules: OxlintRules,
+    /// Environments to enable or disable for matching files.
+    #[serde(default)]
+    pub env: Option<OxlintEnv>,
+    /// Global variables to add or remove for matching files.
+    #[serde(default)]
+    pub globals: Option<OxlintGlobals>,
+}
+
+impl OxlintOverride {
+    fn matches(&self, path: &Path) -> bool {
+        self.files.is_match(path) && !self.excluded_files.is_match(path)
+    }
+}
+
+/// A set of glob patterns, which can be written as a single pattern or an array of patterns.
+#[derive(Clone, Default)]
+pub struct GlobSet {
+    patterns: Vec<String>,
+    set: globset::GlobSet,
+}
+
+impl GlobSet {
+    /// # Errors
+    /// Returns an error if any pattern is not a valid glob.
+    pub fn new<S: AsRef<str>, I: IntoIterator<Item = S>>(
+        patterns: I,
+    ) -> Result<Self, globset::Error> {
+        let patterns: Vec<String> =
+            patterns.into_iter().map(|pattern| pattern.as_ref().to_string()).collect();
+        let mut builder = globset::GlobSetBuilder::new();
+        for pattern in &patterns {
+            let pattern = pattern.strip_prefix("./").unwrap_or(pattern);
+            // Like ESLint, patterns without a slash match the file name in any directory
+            let pattern: Cow<str> = if pattern.contains('/') {
+                Cow::Borrowed(pattern)
+            } else {
+                Cow::Owned(format!("**/{pattern}"))
+            };
+            builder.add(globset::GlobBuilder::new(&pattern).literal_separator(true).build()?);
+        }
+        Ok(Self { patterns, set: builder.build()? })
+    }
+
+    pub fn is_match<P: AsRef<Path>>(&self, path: P) -> bool {
+        self.set.is_match(path)
+    }
+
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+}
+
+impl fmt::Debug for GlobSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("GlobSet").field(&self.patterns).finish()
+    }
+}
+
+impl<'de> Deserialize<'de> for GlobSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Patterns {
+            One(String),
+            Many(Vec<String>),
+        }
+
+        let patterns = match Patterns::deserialize(deserializer)? {
+            Patterns::One(pattern) => vec![pattern],
+            Patterns::Many(patterns) => patterns,
+        };
+        Self::new(patterns).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for GlobSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.patterns.len()))?;
+        for pattern in &self.patterns {
+            seq.serialize_element(pattern)?;
+        }
+        seq.end()
+    }
+}
+
+impl JsonSchema for GlobSet {
+    fn schema_name() -> String {
+        "GlobSet".to_string()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("GlobSet")
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        gen.subschema_for::<Vec<String>>()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let overrides = OxlintOverrides::deserialize(&json!([
+            { "files": "*.test.js", "rules": { "no-console": "off" } },
+            {
+                "files": ["src/**/*.ts"],
+                "excludedFiles": "src/generated/**",
+                "plugins": ["jest"],
+                "env": { "jest": true },
+                "globals": { "foo": "readonly" }
+            }
+        ]))
+        .unwrap();
+        assert_eq!(overrides.len(), 2);
+        assert_eq!(overrides[0].files.patterns(), ["*.test.js"]);
+        assert_eq!(overrides[0].rules.len(), 1);
+        assert!(overrides[0].plugins.is_none());
+        assert!(overrides[1].plugins.is_some_and(LintPlugins::has_jest));
+        assert!(overrides[1].env.as_ref().is_some_and(|env| env.contains("jest")));
+        assert!(overrides[1].globals.as_ref().is_some_and(|globals| globals.is_enabled("foo")));
+
+        assert!(OxlintOverrides::deserialize(&json!([{ "rules": {} }])).is_err());
+        assert!(OxlintOverrides::deserialize(&json!([{ "files": "a[" }])).is_err());
+    }
+
+    #[test]
+    fn test_matching() {
+        let overrides = OxlintOverrides::deserialize(&json!([
+            { "files": ["*.test.js", "**/__tests__/**"] },
+            { "files": "src/*.js", "excludedFiles": ["*.test.js"] },
+        ]))
+        .unwrap();
+        let matching = |path: &str| overrides.matching(Path::new(path)).collect::<Vec<_>>();
+
+        assert_eq!(matching("foo.test.js"), [0]);
+        assert_eq!(matching("a/b/foo.test.js"), [0]);
+        assert_eq!(matching("a/__tests__/b/foo.js"), [0]);
+        assert_eq!(matching("src/foo.js"), [1]);
+        assert_eq!(matching("src/foo.test.js"), [0]);
+        // `*` does not match `/`
+        assert!(matching("src/a/foo.js").is_empty());
+        assert!(matching("foo.js").is_empty());
+    }
+}
@@ -1,11 +1,12 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use oxc_diagnostics::OxcDiagnostic;
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 use super::{
-    categories::OxlintCategories, env::OxlintEnv, globals::OxlintGlobals, rules::OxlintRules,
+    categories::OxlintCategories, env::OxlintEnv, extends::ExtendsLoader, globals::OxlintGlobals,
+    ignore_patterns::IgnorePatterns, overrides::OxlintOverrides, rules::OxlintRules,
     settings::OxlintSettings,
 };
 
@@ -47,6 +48,23 @@ use crate::{options::LintPlugins, utils::read_to_string};
 #[serde(default)]
 #[non_exhaustive]
 pub struct Oxlintrc {
+    /// Paths of configuration files to extend.
+    ///
+    /// Relative paths are resolved from the directory containing this file. Other values are
+    /// resolved as packages (e.g. `"@company/oxlint-config"`), like `require` in Node.js.
+    ///
+    /// Extended files are merged in order, and this file's configuration takes precedence.
+    /// Rules are merged by rule: a rule configured only with a severity keeps the options
+    /// configured by an extended file. `overrides` in extended files are relative to the
+    /// directory containing this file.
+    #[serde(deserialize_with = "deserialize_extends")]
+    pub extends: Vec<String>,
+    /// Stop looking for configuration files in parent directories.
+    ///
+    /// When oxlint is run without `-c`, each file is linted with the nearest `.oxlintrc.json`,
+    /// merged with configuration files in parent directories up to the first one with
+    /// `"root": true`.
+    pub root: bool,
     pub plugins: LintPlugins,
     pub categories: OxlintCategories,
     /// See [Oxlint Rules](https://oxc.rs/docs/guide/usage/linter/rules.html).
@@ -56,13 +74,75 @@ pub struct Oxlintrc {
     pub env: OxlintEnv,
     /// Enabled or disabled specific global variables.
     pub globals: OxlintGlobals,
+    /// Add, remove, or otherwise reconfigure rules for specific files or groups of files.
+    pub overrides: OxlintOverrides,
+    /// Files to ignore, in `.gitignore` format. Patterns are relative to the directory containing
+    /// this file.
+    ///
+    /// Patterns in extended files and in configuration files of parent directories are included.
+    ///
+    /// ```json
+    /// {
+    ///   "ignorePatterns": ["dist/", "*.min.js", "!dist/keep.js"]
+    /// }
+    /// ```
+    #[serde(rename = "ignorePatterns")]
+    pub ignore_patterns: IgnorePatterns,
+    /// Path to the configuration file, if it was loaded from a file.
+    ///
+    /// Globs in `overrides` are relative to the directory containing it.
+    #[serde(skip)]
+    #[schemars(skip)]
+    pub path: PathBuf,
 }
 
 impl Oxlintrc {
+    /// Name of configuration files discovered in directories.
+    pub const FILE_NAME: &'static str = ".oxlintrc.json";
+
     /// # Errors
     ///
     /// * Parse Failure
+    /// * Failure to resolve or parse a file in `extends`
+    /// * Circular `extends`
     pub fn from_file(path: &Path) -> Result<Self, OxcDiagnostic> {
+        Self::load(path, false)
+    }
+
+    /// Like [`Oxlintrc::from_file`], but also merges configuration files found in parent
+    /// directories of `path`, up to the first one with `"root": true`. The configuration in
+    /// `path` takes precedence.
+    ///
+    /// `overrides` in parent configuration files which cannot match files in the directory
+    /// containing `path` are discarded.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Oxlintrc::from_file`], for any of the files.
+    pub fn from_file_with_parents(path: &Path) -> Result<Self, OxcDiagnostic> {
+        Self::load(path, true)
+    }
+
+    /// Find the nearest [`Oxlintrc::FILE_NAME`] in `dir` or its ancestors.
+    pub fn find_nearest(dir: &Path) -> Option<PathBuf> {
+        dir.ancestors().map(|dir| dir.join(Self::FILE_NAME)).find(|path| path.is_file())
+    }
+
+    fn load(path: &Path, with_parents: bool) -> Result<Self, OxcDiagnostic> {
+        let json = ExtendsLoader::new(with_parents).load(path, &Self::read_json)?;
+
+        let mut config = Self::deserialize(&json).map_err(|err| {
+            OxcDiagnostic::error(format!("Failed to parse config with error {err:?}"))
+        })?;
+        config.path = path.to_path_buf();
+        if let Some(dir) = path.parent() {
+            config.ignore_patterns.set_root(dir.to_path_buf());
+        }
+
+        Ok(config)
+    }
+
+    fn read_json(path: &Path) -> Result<serde_json::Value, OxcDiagnostic> {
         let mut string = read_to_string(path).map_err(|e| {
             OxcDiagnostic::error(format!("Failed to parse config {path:?} with error {e:?}"))
         })?;
@@ -87,12 +167,24 @@ impl Oxlintrc {
             OxcDiagnostic::error(format!("Failed to parse eslint config {path:?}.\n{err}"))
         })?;
 
-        let config = Self::deserialize(&json).map_err(|err| {
-            OxcDiagnostic::error(format!("Failed to parse config with error {err:?}"))
-        })?;
+        Ok(json)
+    }
+}
 
-        Ok(config)
+fn deserialize_extends<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Extends {
+        One(String),
+        Many(Vec<String>),
     }
+
+    Ok(match Extends::deserialize(deserializer)? {
+        Extends::One(specifier) => vec![specifier],
+        Extends::Many(specifiers) => specifiers,
+    })
 }
 
 #[cfg(test)]
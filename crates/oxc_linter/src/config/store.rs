@@ -0,0 +1,199 @@
+use std::{
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::{overrides::OxlintOverrides, LintConfig};
+use crate::{options::LintPlugins, rules::RULES, RuleEnum, RuleWithSeverity};
+
+/// Rules, plugins and configuration which apply to a file.
+#[derive(Debug, Clone)]
+pub(crate) struct ResolvedLinterState {
+    pub(crate) rules: Arc<[RuleWithSeverity]>,
+    pub(crate) config: Arc<LintConfig>,
+    pub(crate) plugins: LintPlugins,
+}
+
+/// Resolves the [`ResolvedLinterState`] for each file, by applying `overrides` which match the
+/// file's path on top of the top-level configuration.
+#[derive(Debug)]
+pub(crate) struct ConfigStore {
+    base: ResolvedLinterState,
+    overrides: OxlintOverrides,
+    /// Directory which globs in `overrides` are relative to.
+    root: PathBuf,
+    /// Directory which relative file paths are relative to.
+    cwd: PathBuf,
+    /// Resolved states, keyed by indices of the overrides which were applied.
+    cache: Mutex<FxHashMap<Vec<usize>, ResolvedLinterState>>,
+}
+
+impl ConfigStore {
+    /// `root` is the directory containing the configuration file. If it is relative, it is
+    /// resolved against the current working directory.
+    pub(crate) fn new(
+        rules: Vec<RuleWithSeverity>,
+        config: LintConfig,
+        plugins: LintPlugins,
+        overrides: OxlintOverrides,
+        root: &Path,
+    ) -> Self {
+        let cwd = std::env::current_dir().unwrap_or_default();
+        let root = cwd.join(root);
+        Self {
+            base: ResolvedLinterState { rules: rules.into(), config: Arc::new(config), plugins },
+            overrides,
+            root,
+            cwd,
+            cache: Mutex::default(),
+        }
+    }
+
+    pub(crate) fn number_of_rules(&self) -> usize {
+        self.base.rules.len()
+    }
+
+    /// Rules enabled by the top-level configuration.
+    pub(crate) fn rules(&self) -> &Arc<[RuleWithSeverity]> {
+        &self.base.rules
+    }
+
+    #[cfg(test)]
+    pub(crate) fn set_rules(&mut self, rules: Vec<RuleWithSeverity>) {
+        self.base.rules = rules.into();
+    }
+
+    /// Feed everything which affects resolved states into `hasher`.
+    ///
+    /// `Debug` output is used because it includes the options of each rule.
+    pub(crate) fn hash_config<H: Hasher>(&self, hasher: &mut H) {
+        let mut rules = self.base.rules.iter().map(|rule| format!("{rule:?}")).collect::<Vec<_>>();
+        rules.sort_unstable();
+        rules.hash(hasher);
+        format!("{:?}", self.base.config).hash(hasher);
+        format!("{:?}", self.base.plugins).hash(hasher);
+        format!("{:?}", self.overrides).hash(hasher);
+        self.root.hash(hasher);
+    }
+
+    /// Get rules, plugins and configuration for the file at `path`.
+    pub(crate) fn resolve(&self, path: &Path) -> ResolvedLinterState {
+        if self.overrides.is_empty() {
+            return self.base.clone();
+        }
+
+        let path = self.cwd.join(path);
+        let relative_path = path.strip_prefix(&self.root).unwrap_or(&path);
+        let matching = self.overrides.matching(relative_path).collect::<Vec<_>>();
+        if matching.is_empty() {
+            return self.base.clone();
+        }
+
+        let mut cache = self.cache.lock().unwrap();
+        if let Some(state) = cache.get(&matching) {
+            return state.clone();
+        }
+        let state = self.apply_overrides(&matching);
+        cache.insert(matching, state.clone());
+        state
+    }
+
+    fn apply_overrides(&self, override_ids: &[usize]) -> ResolvedLinterState {
+        let mut rules = self.base.rules.iter().cloned().collect::<FxHashSet<_>>();
+        let mut config = LintConfig::clone(&self.base.config);
+        let mut plugins = self.base.plugins;
+
+        for &id in override_ids {
+            let override_config = &self.overrides[id];
+            if let Some(override_plugins) = override_config.plugins {
+                plugins |= override_plugins;
+            }
+            if !override_config.rules.is_empty() {
+                let all_rules = RULES
+                    .iter()
+                    .filter(|rule| plugins.contains(LintPlugins::from(rule.plugin_name())))
+                    .cloned()
+                    .collect::<Vec<RuleEnum>>();
+                override_config.rules.override_rules(&mut rules, &all_rules);
+            }
+            if let Some(env) = &override_config.env {
+                config.env.override_envs(env);
+            }
+            if let Some(globals) = &override_config.globals {
+                config.globals.override_globals(globals);
+            }
+        }
+
+        let mut rules = rules.into_iter().collect::<Vec<_>>();
+        rules.sort_unstable_by_key(|rule| rule.id());
+        ResolvedLinterState { rules: rules.into(), config: Arc::new(config), plugins }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use crate::{AllowWarnDeny, LinterBuilder, Oxlintrc};
+
+    fn severity_of(rules: &[crate::RuleWithSeverity], name: &str) -> Option<AllowWarnDeny> {
+        rules.iter().find(|rule| rule.name() == name).map(|rule| rule.severity)
+    }
+
+    #[test]
+    fn test_resolve() {
+        let oxlintrc = Oxlintrc::deserialize(&json!({
+            "rules": { "no-console": "error", "no-debugger": "warn" },
+            "globals": { "foo": "readonly" },
+            "overrides": [
+                {
+                    "files": ["**/__tests__/**", "*.test.js"],
+                    "plugins": ["jest"],
+                    "env": { "jest": true },
+                    "rules": { "no-console": "off", "jest/no-focused-tests": "error" }
+                },
+                {
+                    "files": "*.test.js",
+                    "excludedFiles": "legacy/**",
+                    "globals": { "foo": "off" },
+                    "rules": { "no-debugger": "error" }
+                }
+            ]
+        }))
+        .unwrap();
+        let linter = LinterBuilder::from_oxlintrc(true, oxlintrc).build();
+        let store = &linter.config;
+
+        let base = store.resolve(Path::new("src/index.js"));
+        assert_eq!(severity_of(&base.rules, "no-console"), Some(AllowWarnDeny::Deny));
+        assert_eq!(severity_of(&base.rules, "no-focused-tests"), None);
+        assert!(!base.plugins.has_jest());
+        assert!(!base.config.env.contains("jest"));
+
+        let test = store.resolve(Path::new("src/__tests__/index.js"));
+        assert_eq!(severity_of(&test.rules, "no-console"), None);
+        assert_eq!(severity_of(&test.rules, "no-focused-tests"), Some(AllowWarnDeny::Deny));
+        assert_eq!(severity_of(&test.rules, "no-debugger"), Some(AllowWarnDeny::Warn));
+        assert!(test.plugins.has_jest());
+        assert!(test.config.env.contains("jest"));
+        assert!(test.config.globals.is_enabled("foo"));
+
+        let both = store.resolve(Path::new("./src/index.test.js"));
+        assert_eq!(severity_of(&both.rules, "no-console"), None);
+        assert_eq!(severity_of(&both.rules, "no-debugger"), Some(AllowWarnDeny::Deny));
+        assert!(!both.config.globals.is_enabled("foo"));
+
+        let excluded = store.resolve(Path::new("legacy/index.test.js"));
+        assert_eq!(severity_of(&excluded.rules, "no-debugger"), Some(AllowWarnDeny::Warn));
+
+        // Paths matching the same overrides share resolved state
+        let other_test = store.resolve(Path::new("lib/__tests__/other.js"));
+        assert!(std::sync::Arc::ptr_eq(&test.rules, &other_test.rules));
+    }
+}
@@ -21,22 +21,28 @@ mod utils;
 pub mod loader;
 pub mod table;
 
-use std::{io::Write, path::Path, rc::Rc, sync::Arc};
+use std::{
+    hash::{Hash, Hasher},
+    io::Write,
+    path::Path,
+    rc::Rc,
+};
 
-use config::LintConfig;
+use config::{ConfigStore, LintConfig, ResolvedLinterState};
 use context::ContextHost;
 use options::LintOptions;
 use oxc_semantic::{AstNode, Semantic};
+use rustc_hash::FxHasher;
 
 pub use crate::{
     builder::LinterBuilder,
-    config::Oxlintrc,
+    config::{GlobSet, IgnorePatterns, OxlintOverride, OxlintOverrides, Oxlintrc},
     context::LintContext,
     fixer::FixKind,
     frameworks::FrameworkFlags,
     options::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind, LintPlugins},
     rule::{RuleCategory, RuleFixMeta, RuleMeta, RuleWithSeverity},
-    service::{LintService, LintServiceOptions},
+    service::{LintCache, LintService, LintServiceOptions},
 };
 use crate::{
     config::{OxlintEnv, OxlintGlobals, OxlintSettings},
@@ -56,9 +62,8 @@ fn size_asserts() {
 
 #[derive(Debug)]
 pub struct Linter {
-    rules: Vec<RuleWithSeverity>,
     options: LintOptions,
-    config: Arc<LintConfig>,
+    config: ConfigStore,
 }
 
 impl Default for Linter {
@@ -68,18 +73,14 @@ impl Default for Linter {
 }
 
 impl Linter {
-    pub(crate) fn new(
-        rules: Vec<RuleWithSeverity>,
-        options: LintOptions,
-        config: LintConfig,
-    ) -> Self {
-        Self { rules, options, config: Arc::new(config) }
+    pub(crate) fn new(options: LintOptions, config: ConfigStore) -> Self {
+        Self { options, config }
     }
 
     #[cfg(test)]
     #[must_use]
     pub fn with_rules(mut self, rules: Vec<RuleWithSeverity>) -> Self {
-        self.rules = rules;
+        self.config.set_rules(rules);
         self
     }
 
@@ -103,21 +104,30 @@ impl Linter {
         &self.options
     }
 
+    /// Number of rules enabled by the top-level configuration, ignoring `overrides`.
     pub fn number_of_rules(&self) -> usize {
-        self.rules.len()
+        self.config.number_of_rules()
+    }
+
+    /// Hash of all options and configuration which affect lint results.
+    pub(crate) fn config_hash(&self) -> u64 {
+        let mut hasher = FxHasher::default();
+        format!("{:?}", self.options).hash(&mut hasher);
+        self.config.hash_config(&mut hasher);
+        hasher.finish()
     }
 
     #[cfg(test)]
-    pub(crate) fn rules(&self) -> &Vec<RuleWithSeverity> {
-        &self.rules
+    pub(crate) fn rules(&self) -> &[RuleWithSeverity] {
+        self.config.rules()
     }
 
     pub fn run<'a>(&self, path: &Path, semantic: Rc<Semantic<'a>>) -> Vec<Message<'a>> {
-        let ctx_host =
-            Rc::new(ContextHost::new(path, semantic, self.options).with_config(&self.config));
+        let ResolvedLinterState { rules, config, plugins } = self.config.resolve(path);
+        let options = LintOptions { plugins, ..self.options };
+        let ctx_host = Rc::new(ContextHost::new(path, semantic, options).with_config(&config));
 
-        let rules = self
-            .rules
+        let rules = rules
             .iter()
             .filter(|rule| rule.should_run(&ctx_host))
             .map(|rule| (rule, Rc::clone(&ctx_host).spawn(rule)))
@@ -16,7 +16,8 @@ pub struct Loader;
 impl Loader {
     pub fn can_load<P: AsRef<Path>>(path: P) -> bool {
         let path = path.as_ref();
-        SourceType::from_path(path).is_ok()
+        // JSON files are not linted.
+        SourceType::from_path(path).is_ok_and(|source_type| !source_type.is_json())
             || path
                 .extension()
                 .and_then(std::ffi::OsStr::to_str)
@@ -42,7 +43,9 @@ impl Loader {
         let ext = ext.to_str().ok_or_else(|| LoadError::unsupported(ext))?;
 
         // let source_type = SourceType::from_path(path);
-        if let Ok(source_type) = SourceType::from_path(path) {
+        if let Some(source_type) =
+            SourceType::from_path(path).ok().filter(|source_type| !source_type.is_json())
+        {
             Ok(vec![JavaScriptSource::new(source_text, source_type)])
         } else {
             let partial = PartialLoader::parse(ext, source_text);
@@ -98,10 +101,19 @@ mod test {
             "foo.astro",
             "foo.svelte",
             "foo.vue",
+            "foo.md",
+            "foo.mdx",
         ];
 
         for path in paths {
             assert!(Loader::can_load(path));
         }
     }
+
+    #[test]
+    fn test_cannot_load_json() {
+        for path in ["package.json", "tsconfig.json", "foo.jsonc", "foo.json5"] {
+            assert!(!Loader::can_load(path));
+        }
+    }
 }
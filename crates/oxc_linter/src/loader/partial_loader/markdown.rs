@@ -0,0 +1,240 @@
+use cow_utils::CowUtils;
+use oxc_span::SourceType;
+
+use crate::loader::JavaScriptSource;
+
+/// Extracts fenced code blocks from Markdown, and additionally top-level
+/// `import` / `export` statements from MDX.
+///
+/// <https://spec.commonmark.org/0.31.2/#fenced-code-blocks>
+/// <https://mdxjs.com/docs/what-is-mdx/#esm>
+pub struct MarkdownPartialLoader<'a> {
+    source_text: &'a str,
+    is_mdx: bool,
+}
+
+/// An opening code fence, e.g. ```` ```ts ```` or `~~~js`.
+struct Fence {
+    char: u8,
+    len: usize,
+    /// `None` if the info string is not a JavaScript or TypeScript language
+    source_type: Option<SourceType>,
+    /// Offset of the first line of content
+    start: usize,
+}
+
+impl<'a> MarkdownPartialLoader<'a> {
+    pub fn new(source_text: &'a str, is_mdx: bool) -> Self {
+        Self { source_text, is_mdx }
+    }
+
+    pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
+        let mut results = vec![];
+        let mut fence: Option<Fence> = None;
+        // Start offset of the current MDX ESM block
+        let mut esm_start: Option<usize> = None;
+
+        let mut pointer = 0;
+        for line in self.source_text.split_inclusive('\n') {
+            let line_start = pointer;
+            pointer += line.len();
+
+            if let Some(open) = &fence {
+                if is_closing_fence(line, open) {
+                    if let Some(source_type) = open.source_type {
+                        results.push(self.source(open.start, line_start, source_type));
+                    }
+                    fence = None;
+                }
+                continue;
+            }
+
+            if let Some(start) = esm_start {
+                // ESM blocks end at the first blank line
+                if line.trim().is_empty() {
+                    results.push(self.source(start, line_start, SourceType::jsx()));
+                    esm_start = None;
+                }
+                continue;
+            }
+
+            if let Some((char, len, info)) = parse_opening_fence(line) {
+                fence = Some(Fence { char, len, source_type: language(info), start: pointer });
+            } else if self.is_mdx && is_esm(line) {
+                esm_start = Some(line_start);
+            }
+        }
+
+        // Unclosed code blocks and ESM blocks run to the end of the document
+        if let Some(Fence { source_type: Some(source_type), start, .. }) = fence {
+            results.push(self.source(start, pointer, source_type));
+        }
+        if let Some(start) = esm_start {
+            results.push(self.source(start, pointer, SourceType::jsx()));
+        }
+        results
+    }
+
+    fn source(&self, start: usize, end: usize, source_type: SourceType) -> JavaScriptSource<'a> {
+        // NOTE: loader checked that source_text.len() is less than u32::MAX
+        #[allow(clippy::cast_possible_truncation)]
+        JavaScriptSource::partial(&self.source_text[start..end], source_type, start as u32)
+    }
+}
+
+/// Returns the fence character, the fence length and the info string.
+fn parse_opening_fence(line: &str) -> Option<(u8, usize, &str)> {
+    let trimmed = line.trim_start();
+    let char = *trimmed.as_bytes().first().filter(|c| matches!(c, b'`' | b'~'))?;
+    let len = trimmed.bytes().take_while(|c| *c == char).count();
+    if len < 3 {
+        return None;
+    }
+    let info = trimmed[len..].trim();
+    // The info string of a backtick fence may not contain backticks, otherwise it is inline code
+    if char == b'`' && info.contains('`') {
+        return None;
+    }
+    Some((char, len, info))
+}
+
+fn is_closing_fence(line: &str, fence: &Fence) -> bool {
+    let trimmed = line.trim();
+    trimmed.len() >= fence.len && trimmed.bytes().all(|c| c == fence.char)
+}
+
+/// Maps the language of an info string, e.g. `ts` in ```` ```ts title="foo.ts" ````.
+fn language(info: &str) -> Option<SourceType> {
+    let lang = info.split(|c: char| c.is_whitespace() || c == '{' || c == ',').next()?;
+    match lang.cow_to_ascii_lowercase().as_ref() {
+        "js" | "javascript" | "jsx" | "mjs" => Some(SourceType::jsx()),
+        "cjs" => Some(SourceType::cjs()),
+        "ts" | "typescript" | "mts" | "cts" => Some(SourceType::ts()),
+        "tsx" => Some(SourceType::tsx()),
+        _ => None,
+    }
+}
+
+/// MDX ESM statements start at the beginning of a line.
+fn is_esm(line: &str) -> bool {
+    ["import", "export"].iter().any(|keyword| {
+        line.strip_prefix(keyword)
+            .is_some_and(|rest| rest.starts_with(|c: char| c.is_whitespace() || c == '{'))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{JavaScriptSource, MarkdownPartialLoader};
+
+    fn parse_markdown(source_text: &str) -> Vec<JavaScriptSource<'_>> {
+        MarkdownPartialLoader::new(source_text, false).parse()
+    }
+
+    fn parse_mdx(source_text: &str) -> Vec<JavaScriptSource<'_>> {
+        MarkdownPartialLoader::new(source_text, true).parse()
+    }
+
+    #[test]
+    fn test_parse_markdown() {
+        let source_text = r"# Title
+
+```js
+console.log('Hi');
+```
+
+```ts title=foo.ts
+let x: number = 1;
+```
+";
+        let sources = parse_markdown(source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].source_text, "console.log('Hi');\n");
+        assert_eq!(sources[0].start, 15);
+        assert!(!sources[0].source_type.is_typescript());
+        assert_eq!(sources[1].source_text, "let x: number = 1;\n");
+        assert_eq!(sources[1].start, 58);
+        assert!(sources[1].source_type.is_typescript());
+    }
+
+    #[test]
+    fn test_parse_markdown_languages() {
+        let source_text = r"
+```
+plain
+```
+
+```sh
+npm install
+```
+
+~~~tsx
+<App />
+~~~
+
+```JavaScript {1}
+foo();
+```
+";
+        let sources = parse_markdown(source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].source_text, "<App />\n");
+        assert!(sources[0].source_type.is_typescript());
+        assert!(sources[0].source_type.is_jsx());
+        assert_eq!(sources[1].source_text, "foo();\n");
+    }
+
+    #[test]
+    fn test_parse_markdown_nested_fences() {
+        let source_text = r"
+````md
+```js
+not linted
+```
+````
+
+- list item
+
+  ```js
+  indented();
+  ```
+
+```js
+unclosed();
+";
+        let sources = parse_markdown(source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].source_text, "  indented();\n");
+        assert_eq!(sources[1].source_text, "unclosed();\n");
+    }
+
+    #[test]
+    fn test_parse_mdx() {
+        let source_text = r"import { Chart } from '../components/chart';
+export const meta = {
+  title: 'Hi',
+};
+
+# Hello, {meta.title}
+
+An import statement in prose is not ESM.
+
+```jsx
+<Chart />
+```
+
+export default function Layout() {}";
+        let sources = parse_mdx(source_text);
+        assert_eq!(sources.len(), 3);
+        assert_eq!(
+            sources[0].source_text,
+            "import { Chart } from '../components/chart';\nexport const meta = {\n  title: 'Hi',\n};\n"
+        );
+        assert_eq!(sources[0].start, 0);
+        assert_eq!(sources[1].source_text, "<Chart />\n");
+        assert_eq!(sources[2].source_text, "export default function Layout() {}");
+
+        // ESM is only extracted from MDX
+        assert_eq!(parse_markdown(source_text).len(), 1);
+    }
+}
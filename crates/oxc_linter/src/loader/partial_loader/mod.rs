@@ -1,14 +1,18 @@
 mod astro;
+mod markdown;
 mod svelte;
 mod vue;
 
-pub use self::{astro::AstroPartialLoader, svelte::SveltePartialLoader, vue::VuePartialLoader};
+pub use self::{
+    astro::AstroPartialLoader, markdown::MarkdownPartialLoader, svelte::SveltePartialLoader,
+    vue::VuePartialLoader,
+};
 use crate::loader::JavaScriptSource;
 
 const SCRIPT_START: &str = "<script";
 const SCRIPT_END: &str = "</script>";
 
-pub const LINT_PARTIAL_LOADER_EXT: &[&str] = &["vue", "astro", "svelte"];
+pub const LINT_PARTIAL_LOADER_EXT: &[&str] = &["vue", "astro", "svelte", "md", "mdx"];
 
 pub struct PartialLoader;
 
@@ -20,6 +24,8 @@ impl PartialLoader {
             "vue" => Some(VuePartialLoader::new(source_text).parse()),
             "astro" => Some(AstroPartialLoader::new(source_text).parse()),
             "svelte" => Some(SveltePartialLoader::new(source_text).parse()),
+            "md" => Some(MarkdownPartialLoader::new(source_text, false).parse()),
+            "mdx" => Some(MarkdownPartialLoader::new(source_text, true).parse()),
             _ => None,
         }
     }
@@ -243,6 +243,7 @@ impl GetterReturn {
                                 e.weight(),
                                 EdgeType::Normal
                                     | EdgeType::Jump
+                                    | EdgeType::Join
                                     | EdgeType::Error(ErrorEdgeKind::Explicit)
                             )
                         }) {
@@ -263,6 +264,7 @@ impl GetterReturn {
                                     | InstructionKind::Iteration(_)
                                     | InstructionKind::Unreachable
                                     | InstructionKind::Condition
+                                    | InstructionKind::Suspend(_)
                                     | InstructionKind::Statement => false,
                                 }
                             });
@@ -283,6 +285,7 @@ impl GetterReturn {
                                 e.weight(),
                                 EdgeType::Jump
                                     | EdgeType::Normal
+                                    | EdgeType::Join
                                     | EdgeType::Backedge
                                     | EdgeType::Error(ErrorEdgeKind::Explicit)
                             )
@@ -417,6 +420,9 @@ fn test() {
                 return 0;
             }
         }", None),
+        ("var foo = { get bar() { try { a(); } finally { b(); } return 1; } };", None),
+        ("var foo = { get bar() { try { a(); } finally { return 1; } } };", None),
+        ("var foo = { get bar() { try { a(); } catch { return 1; } finally { b(); } return 2; } };", None),
     ];
 
     let fail = vec![
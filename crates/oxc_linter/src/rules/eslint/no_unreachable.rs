@@ -278,6 +278,10 @@ fn test() {
             b();
         }
         ",
+        "function foo() { try { a(); } catch (e) { return; } finally { b(); } c(); }",
+        "function foo() { try { return; } catch (e) { a(); } finally { b(); } c(); }",
+        "function* foo() { try { yield 1; } finally { a(); } b(); }",
+        "async function foo() { try { await a(); } catch { b(); } c(); }",
     ];
 
     let fail = vec![
@@ -324,6 +328,7 @@ fn test() {
         "function foo() { var x = 1; while (true) { } x = 2; }",
         //[{ messageId: "unreachableCode", type: "ExpressionStatement" }]
         "function foo() { var x = 1; do { } while (true); x = 2; }",
+        "function foo() { try { return 1; } catch (e) { return 2; } finally { a(); } b(); }",
     ];
 
     Tester::new(NoUnreachable::NAME, pass, fail).test_and_snapshot();
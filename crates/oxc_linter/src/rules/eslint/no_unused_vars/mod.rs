@@ -82,7 +82,9 @@ declare_oxc_lint!(
     /// classes, interfaces, and types declared in `.d.ts` files are generally
     /// used by other files, which are not checked by Oxlint. Since Oxlint does
     /// not support parsing Vue templates, this rule cannot tell if a variable
-    /// is used or unused in a Vue file.
+    /// is used or unused in a Vue file. For the same reason, imports in `.mdx`
+    /// files are never reported, as they are usually components used in the
+    /// Markdown body.
     ///
     /// #### Exported
     ///
@@ -209,6 +211,12 @@ impl Rule for NoUnusedVars {
         if Self::should_skip_symbol(&symbol) {
             return;
         }
+        // MDX documents use imported components in their Markdown body, which is not linted
+        if symbol.flags().contains(SymbolFlags::Import)
+            && ctx.file_path().extension().is_some_and(|ext| ext == "mdx")
+        {
+            return;
+        }
 
         self.run_on_symbol_internal(&symbol, ctx);
     }
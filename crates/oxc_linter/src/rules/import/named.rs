@@ -102,26 +102,10 @@ impl Rule for Named {
             if remote_module_record.not_esm {
                 continue;
             }
-            let import_span = import_name.span();
-            let import_name = import_name.name();
-            // Check `import { default as foo } from 'bar'`
-            if import_name.as_str() == "default" && remote_module_record.export_default.is_some() {
-                continue;
-            }
-            // Check remote bindings
-            if remote_module_record.exported_bindings.contains_key(import_name) {
-                continue;
-            }
-            // check re-export
-            if remote_module_record
-                .exported_bindings_from_star_export
-                .iter()
-                .any(|entry| entry.value().contains(import_name))
-            {
-                continue;
+            // Follow re-exports and `export *` to the declaration of the binding
+            if remote_module_record.resolve_export(import_name.name()).is_not_found() {
+                ctx.diagnostic(named_diagnostic(import_name.name(), specifier, import_name.span()));
             }
-
-            ctx.diagnostic(named_diagnostic(import_name, specifier, import_span));
         }
 
         for export_entry in &module_record.indirect_export_entries {
@@ -140,16 +124,10 @@ impl Rule for Named {
             if remote_module_record.not_esm {
                 continue;
             }
-            // Check remote bindings
-            let name = import_name.name();
             // `export { default as foo } from './source'` <> `export default xxx`
-            if *name == "default" && remote_module_record.export_default.is_some() {
-                continue;
-            }
-            if remote_module_record.exported_bindings.contains_key(name) {
-                continue;
+            if remote_module_record.resolve_export(import_name.name()).is_not_found() {
+                ctx.diagnostic(named_diagnostic(import_name.name(), specifier, import_name.span()));
             }
-            ctx.diagnostic(named_diagnostic(name, specifier, import_name.span()));
         }
     }
 }
@@ -170,6 +148,7 @@ fn test() {
         "import { destructingRenamedAssign } from './named-exports'",
         "import { ActionTypes } from './qc'",
         "import {a, b, c, d} from './re-export'",
+        "import { d } from './re-export-default-import'",
         // "import {RuleTester} from './re-export-node_modules'",
         "import { jsxFoo } from './jsx/AnotherComponent'",
         "import {a, b, d} from './common'; // eslint-disable-line named",
@@ -131,6 +131,7 @@ fn contains_return_statement(node: &AstNode, ctx: &LintContext) -> bool {
                     | InstructionKind::Continue(_)
                     | InstructionKind::Iteration(_)
                     | InstructionKind::Condition
+                    | InstructionKind::Suspend(_)
                     | InstructionKind::Statement => {}
                 }
             }
@@ -0,0 +1,325 @@
+use std::{
+    borrow::Cow,
+    fs,
+    hash::{Hash, Hasher},
+    io,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use dashmap::DashMap;
+use oxc_diagnostics::{LabeledSpan, OxcCode, OxcDiagnostic, Severity};
+use oxc_resolver::Resolver;
+use rayon::prelude::*;
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
+use serde::{Deserialize, Serialize};
+
+/// Lint results of previous runs, persisted in a file.
+///
+/// A file's cached results are reused if its content and the linter configuration are unchanged.
+/// With cross-module rules, results are also invalidated when any module the file depends on
+/// (directly or transitively) changed, or when an import which could not be resolved now resolves.
+///
+/// With cross-module rules, files with cached results are still parsed, because the module
+/// records of their dependents are built from them. Only semantic analysis and linting are
+/// skipped.
+///
+/// A single cache can be shared by several [`LintService`](super::LintService)s.
+pub struct LintCache {
+    location: PathBuf,
+    version: String,
+    previous: FxHashMap<PathBuf, CacheEntry>,
+    current: DashMap<PathBuf, CacheEntry>,
+    /// Files in `previous` which depend on a module which changed.
+    invalidated: OnceLock<FxHashSet<PathBuf>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    version: String,
+    entries: FxHashMap<PathBuf, CacheEntry>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub(super) struct CacheEntry {
+    content_hash: u64,
+    config_hash: u64,
+    /// Resolved paths of imported modules, if cross-module rules are enabled.
+    dependencies: Vec<PathBuf>,
+    /// Import specifiers which could not be resolved, if cross-module rules are enabled.
+    #[serde(default)]
+    unresolved: Vec<String>,
+    /// `None` if the file was only loaded as a dependency of another file.
+    diagnostics: Option<Vec<CachedDiagnostic>>,
+}
+
+impl CacheEntry {
+    pub(super) fn new(
+        content_hash: u64,
+        config_hash: u64,
+        dependencies: Vec<PathBuf>,
+        unresolved: Vec<String>,
+        diagnostics: Option<&[OxcDiagnostic]>,
+    ) -> Self {
+        let diagnostics = diagnostics
+            .map(|diagnostics| diagnostics.iter().map(CachedDiagnostic::from).collect::<Vec<_>>());
+        Self { content_hash, config_hash, dependencies, unresolved, diagnostics }
+    }
+}
+
+impl LintCache {
+    /// Load the cache from `location`.
+    ///
+    /// The cache starts empty if the file does not exist, cannot be parsed, or was written by a
+    /// different `version` of the linter.
+    pub fn load(location: PathBuf, version: &str) -> Self {
+        let previous = fs::read(&location)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<CacheFile>(&bytes).ok())
+            .filter(|file| file.version == version)
+            .map(|file| file.entries)
+            .unwrap_or_default();
+        Self {
+            location,
+            version: version.to_string(),
+            previous,
+            current: DashMap::default(),
+            invalidated: OnceLock::new(),
+        }
+    }
+
+    /// Write the cache to its location.
+    ///
+    /// Entries of files which were not linted in this run are kept, unless the files were
+    /// deleted.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self) -> io::Result<()> {
+        let mut entries = self
+            .previous
+            .par_iter()
+            .filter(|(path, _)| !self.current.contains_key(*path) && path.is_file())
+            .map(|(path, entry)| (path.clone(), entry.clone()))
+            .collect::<FxHashMap<_, _>>();
+        entries.extend(self.current.iter().map(|it| (it.key().clone(), it.value().clone())));
+
+        let file = CacheFile { version: self.version.clone(), entries };
+        let json = serde_json::to_vec(&file).map_err(io::Error::other)?;
+        if let Some(dir) = self.location.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.location, json)
+    }
+
+    /// Get cached diagnostics of the file at `path`, if they are still valid.
+    ///
+    /// `resolver` resolves imports of cross-module rules, it is `None` if they are disabled.
+    pub(super) fn get(
+        &self,
+        path: &Path,
+        content_hash: u64,
+        config_hash: u64,
+        resolver: Option<&Resolver>,
+    ) -> Option<Vec<OxcDiagnostic>> {
+        let entry = self.previous.get(path)?;
+        if entry.content_hash != content_hash || entry.config_hash != config_hash {
+            return None;
+        }
+        let diagnostics = entry.diagnostics.as_ref()?;
+        if (!entry.dependencies.is_empty() || !entry.unresolved.is_empty())
+            && resolver.map_or(true, |resolver| self.invalidated(resolver).contains(path))
+        {
+            return None;
+        }
+        self.current.insert(path.to_path_buf(), entry.clone());
+        Some(diagnostics.iter().cloned().map(OxcDiagnostic::from).collect())
+    }
+
+    pub(super) fn insert(&self, path: PathBuf, entry: CacheEntry) {
+        self.current.insert(path, entry);
+    }
+
+    /// Find files which depend on a module which changed since the cache was written, by
+    /// walking the module graph of the previous run from the changed modules to their dependents.
+    ///
+    /// Files with an unresolved import which `resolver` now resolves are treated as changed.
+    fn invalidated(&self, resolver: &Resolver) -> &FxHashSet<PathBuf> {
+        self.invalidated.get_or_init(|| {
+            let mut dependents = FxHashMap::<&Path, Vec<&Path>>::default();
+            for (path, entry) in &self.previous {
+                for dependency in &entry.dependencies {
+                    dependents.entry(dependency).or_default().push(path);
+                }
+            }
+
+            let mut stack = dependents
+                .par_iter()
+                .map(|(dependency, _)| *dependency)
+                .filter(|dependency| {
+                    let content_hash = fs::read(dependency).ok().map(|bytes| hash_content(&bytes));
+                    let entry = self.previous.get(*dependency);
+                    content_hash.is_none() || entry.map(|entry| entry.content_hash) != content_hash
+                })
+                .collect::<Vec<_>>();
+
+            let mut invalidated = FxHashSet::default();
+            let newly_resolved = self
+                .previous
+                .par_iter()
+                .filter(|(path, entry)| {
+                    let dir = path.parent().unwrap_or(Path::new(""));
+                    entry
+                        .unresolved
+                        .iter()
+                        .any(|specifier| resolver.resolve(dir, specifier).is_ok())
+                })
+                .map(|(path, _)| path.as_path())
+                .collect::<Vec<_>>();
+            for path in newly_resolved {
+                if invalidated.insert(path.to_path_buf()) {
+                    stack.push(path);
+                }
+            }
+            while let Some(path) = stack.pop() {
+                for dependent in dependents.get(path).into_iter().flatten() {
+                    if invalidated.insert(dependent.to_path_buf()) {
+                        stack.push(dependent);
+                    }
+                }
+            }
+            invalidated
+        })
+    }
+}
+
+pub(super) fn hash_content(content: &[u8]) -> u64 {
+    let mut hasher = FxHasher::default();
+    hasher.write(content);
+    hasher.finish()
+}
+
+/// Hash the linter configuration along with the inputs of module resolution, which affect the
+/// results of cross-module rules.
+///
+/// Only the content of `tsconfig` itself is hashed, not of files it extends or references.
+pub(super) fn hash_config(linter_config_hash: u64, cross_module: bool, tsconfig: &Path) -> u64 {
+    let mut hasher = FxHasher::default();
+    hasher.write_u64(linter_config_hash);
+    cross_module.hash(&mut hasher);
+    if cross_module {
+        tsconfig.hash(&mut hasher);
+        fs::read(tsconfig).ok().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedDiagnostic {
+    message: String,
+    labels: Option<Vec<CachedLabel>>,
+    help: Option<String>,
+    severity: CachedSeverity,
+    scope: Option<String>,
+    number: Option<String>,
+    url: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedLabel {
+    offset: usize,
+    len: usize,
+    label: Option<String>,
+    primary: bool,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+enum CachedSeverity {
+    Advice,
+    Warning,
+    Error,
+}
+
+impl From<&OxcDiagnostic> for CachedDiagnostic {
+    fn from(diagnostic: &OxcDiagnostic) -> Self {
+        let labels = diagnostic.labels.as_ref().map(|labels| {
+            labels
+                .iter()
+                .map(|label| CachedLabel {
+                    offset: label.offset(),
+                    len: label.len(),
+                    label: label.label().map(ToString::to_string),
+                    primary: label.primary(),
+                })
+                .collect()
+        });
+        let severity = match diagnostic.severity {
+            Severity::Advice => CachedSeverity::Advice,
+            Severity::Warning => CachedSeverity::Warning,
+            Severity::Error => CachedSeverity::Error,
+        };
+        Self {
+            message: diagnostic.message.to_string(),
+            labels,
+            help: diagnostic.help.as_deref().map(ToString::to_string),
+            severity,
+            scope: diagnostic.code.scope.as_deref().map(ToString::to_string),
+            number: diagnostic.code.number.as_deref().map(ToString::to_string),
+            url: diagnostic.url.as_deref().map(ToString::to_string),
+        }
+    }
+}
+
+impl From<CachedDiagnostic> for OxcDiagnostic {
+    fn from(cached: CachedDiagnostic) -> Self {
+        let mut diagnostic = Self::error(cached.message);
+        diagnostic.labels = cached.labels.map(|labels| {
+            labels
+                .into_iter()
+                .map(|label| {
+                    let span = (label.offset, label.len);
+                    if label.primary {
+                        LabeledSpan::new_primary_with_span(label.label, span)
+                    } else {
+                        LabeledSpan::new_with_span(label.label, span)
+                    }
+                })
+                .collect()
+        });
+        diagnostic.help = cached.help.map(Cow::Owned);
+        diagnostic.severity = match cached.severity {
+            CachedSeverity::Advice => Severity::Advice,
+            CachedSeverity::Warning => Severity::Warning,
+            CachedSeverity::Error => Severity::Error,
+        };
+        diagnostic.code =
+            OxcCode { scope: cached.scope.map(Cow::Owned), number: cached.number.map(Cow::Owned) };
+        diagnostic.url = cached.url.map(Cow::Owned);
+        diagnostic
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
+    use oxc_span::Span;
+
+    use super::CachedDiagnostic;
+
+    #[test]
+    fn test_diagnostic_round_trip() {
+        let diagnostic = OxcDiagnostic::warn("message")
+            .with_labels([
+                LabeledSpan::new_primary_with_span(Some("primary".into()), (1, 2)),
+                Span::new(4, 8).into(),
+            ])
+            .with_help("help")
+            .with_error_code("eslint", "no-debugger")
+            .with_url("https://oxc.rs");
+        let cached = CachedDiagnostic::from(&diagnostic);
+        let json = serde_json::to_string(&cached).unwrap();
+        let restored =
+            OxcDiagnostic::from(serde_json::from_str::<CachedDiagnostic>(&json).unwrap());
+        assert_eq!(format!("{restored:?}"), format!("{diagnostic:?}"));
+    }
+}
@@ -1,3 +1,4 @@
+mod cache;
 mod module_cache;
 mod runtime;
 
@@ -13,6 +14,8 @@ use crate::Linter;
 
 use runtime::Runtime;
 
+pub use self::cache::LintCache;
+
 pub struct LintServiceOptions {
     /// Current working directory
     cwd: Box<Path>,
@@ -24,6 +27,9 @@ pub struct LintServiceOptions {
     tsconfig: Option<PathBuf>,
 
     cross_module: bool,
+
+    /// Cache of lint results from previous runs
+    cache: Option<Arc<LintCache>>,
 }
 
 impl LintServiceOptions {
@@ -32,7 +38,7 @@ impl LintServiceOptions {
     where
         T: Into<Box<Path>>,
     {
-        Self { cwd: cwd.into(), paths, tsconfig: None, cross_module: false }
+        Self { cwd: cwd.into(), paths, tsconfig: None, cross_module: false, cache: None }
     }
 
     #[inline]
@@ -57,6 +63,16 @@ impl LintServiceOptions {
         self
     }
 
+    /// Skip linting files whose results are in `cache`, and store new results in it.
+    ///
+    /// With cross-module rules, cached files are still parsed to build their module records.
+    #[inline]
+    #[must_use]
+    pub fn with_cache(mut self, cache: Arc<LintCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
     #[inline]
     pub fn cwd(&self) -> &Path {
         &self.cwd
@@ -90,11 +106,17 @@ impl LintService {
 
     /// # Panics
     pub fn run(&self, tx_error: &DiagnosticSender) {
+        self.run_without_finishing(tx_error);
+        tx_error.send(None).unwrap();
+    }
+
+    /// Like [`LintService::run`], but does not send `None` to `tx_error` when done, so that
+    /// several services can report to the same receiver.
+    pub fn run_without_finishing(&self, tx_error: &DiagnosticSender) {
         self.runtime
             .iter_paths()
             .par_bridge()
             .for_each_with(&self.runtime, |runtime, path| runtime.process_path(path, tx_error));
-        tx_error.send(None).unwrap();
     }
 
     /// For tests
@@ -117,6 +139,7 @@ impl LintService {
                     source_text,
                     source_type,
                     check_syntax_errors,
+                    true,
                     tx_error,
                 )
             })
@@ -8,7 +8,7 @@ use std::{
 };
 
 use oxc_allocator::Allocator;
-use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic};
+use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, LabeledSpan, OxcDiagnostic};
 use oxc_parser::{ParseOptions, Parser};
 use oxc_resolver::Resolver;
 use oxc_semantic::SemanticBuilder;
@@ -23,6 +23,7 @@ use crate::{
 };
 
 use super::{
+    cache::{hash_config, hash_content, CacheEntry, LintCache},
     module_cache::{ModuleCache, ModuleState},
     LintServiceOptions,
 };
@@ -34,19 +35,24 @@ pub struct Runtime {
     pub(super) linter: Linter,
     resolver: Option<Resolver>,
     modules: ModuleCache,
+    /// Cache of lint results, and hash of the linter configuration used as part of its keys
+    cache: Option<(Arc<LintCache>, u64)>,
 }
 
 impl Runtime {
     pub(super) fn new(linter: Linter, options: LintServiceOptions) -> Self {
-        let resolver = options.cross_module.then(|| {
-            Self::get_resolver(options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))))
+        let tsconfig = options.tsconfig.unwrap_or_else(|| options.cwd.join("tsconfig.json"));
+        let cache = options.cache.map(|cache| {
+            (cache, hash_config(linter.config_hash(), options.cross_module, &tsconfig))
         });
+        let resolver = options.cross_module.then(|| Self::get_resolver(Some(tsconfig)));
         Self {
             cwd: options.cwd,
             paths: options.paths.iter().cloned().collect(),
             linter,
             resolver,
             modules: ModuleCache::default(),
+            cache,
         }
     }
 
@@ -72,10 +78,10 @@ impl Runtime {
         path: &Path,
         ext: &str,
     ) -> Option<Result<(SourceType, String), Error>> {
-        let source_type = SourceType::from_path(path);
-        let not_supported_yet =
-            source_type.as_ref().is_err_and(|_| !LINT_PARTIAL_LOADER_EXT.contains(&ext));
-        if not_supported_yet {
+        // JSON files are not linted.
+        let source_type =
+            SourceType::from_path(path).ok().filter(|source_type| !source_type.is_json());
+        if source_type.is_none() && !LINT_PARTIAL_LOADER_EXT.contains(&ext) {
             return None;
         }
         let source_type = source_type.unwrap_or_default();
@@ -117,6 +123,28 @@ impl Runtime {
             }
         };
 
+        let content_hash = self.cache.as_ref().map(|_| hash_content(source_text.as_bytes()));
+        let cached_diagnostics = self.cache.as_ref().zip(content_hash).and_then(
+            |((cache, config_hash), content_hash)| {
+                if !self.paths.contains(path) {
+                    return None;
+                }
+                cache.get(&self.cwd.join(path), content_hash, *config_hash, self.resolver.as_ref())
+            },
+        );
+        if let Some(diagnostics) = &cached_diagnostics {
+            if !diagnostics.is_empty() {
+                let path = path.strip_prefix(&self.cwd).unwrap_or(path);
+                let diagnostics =
+                    DiagnosticService::wrap_diagnostics(path, &source_text, diagnostics.clone());
+                tx_error.send(Some(diagnostics)).unwrap();
+            }
+            // Other modules may still need the module record of this file
+            if self.resolver.is_none() {
+                return;
+            }
+        }
+
         let sources = PartialLoader::parse(ext, &source_text)
             .unwrap_or_else(|| vec![JavaScriptSource::partial(&source_text, source_type, 0)]);
 
@@ -134,6 +162,11 @@ impl Runtime {
         // source code after each fix.
         let mut fix_offset: i32 = 0;
 
+        // Diagnostics and imported modules of all sources, to be cached
+        let mut all_diagnostics = vec![];
+        let mut dependencies = vec![];
+        let mut unresolved = vec![];
+
         let mut allocator = Allocator::default();
         for (i, source) in sources.into_iter().enumerate() {
             if i >= 1 {
@@ -145,8 +178,13 @@ impl Runtime {
                 source.source_text,
                 source.source_type,
                 true,
+                cached_diagnostics.is_none(),
                 tx_error,
             );
+            // Collected before the module record is discarded below
+            if self.cache.is_some() {
+                self.collect_dependencies(path, &mut dependencies, &mut unresolved);
+            }
 
             if self.linter.options().fix.is_some() {
                 let fix_result = Fixer::new(source.source_text, messages).fix();
@@ -165,10 +203,17 @@ impl Runtime {
 
             if !messages.is_empty() {
                 self.ignore_path(path);
-                let errors = messages.into_iter().map(Into::into).collect();
+                let errors = messages
+                    .into_iter()
+                    .map(|message| offset_diagnostic(message.into(), source.start as usize))
+                    .collect::<Vec<_>>();
+                if self.cache.is_some() {
+                    all_diagnostics.extend(errors.iter().cloned());
+                }
                 let path = path.strip_prefix(&self.cwd).unwrap_or(path);
-                let diagnostics =
-                    DiagnosticService::wrap_diagnostics(path, source.source_text, errors);
+                // Report against the whole file so that line and column numbers of partial
+                // sources (e.g. a `<script>` in a `.vue` file) point into the original file.
+                let diagnostics = DiagnosticService::wrap_diagnostics(path, &source_text, errors);
                 tx_error.send(Some(diagnostics)).unwrap();
             }
         }
@@ -177,9 +222,62 @@ impl Runtime {
         // so we write the new source text to the file.
         if let Cow::Owned(new_source_text) = new_source_text {
             fs::write(path, new_source_text).unwrap();
+        } else if let Some(((cache, config_hash), content_hash)) =
+            self.cache.as_ref().zip(content_hash)
+        {
+            if cached_diagnostics.is_none() {
+                dependencies.sort_unstable();
+                dependencies.dedup();
+                unresolved.sort_unstable();
+                unresolved.dedup();
+                let diagnostics = self.paths.contains(path).then_some(all_diagnostics.as_slice());
+                let entry = CacheEntry::new(
+                    content_hash,
+                    *config_hash,
+                    dependencies,
+                    unresolved,
+                    diagnostics,
+                );
+                cache.insert(self.cwd.join(path), entry);
+            }
         }
     }
 
+    /// Collect resolved paths of modules imported by the module at `path`, and import specifiers
+    /// which cannot be resolved.
+    fn collect_dependencies(
+        &self,
+        path: &Path,
+        dependencies: &mut Vec<PathBuf>,
+        unresolved: &mut Vec<String>,
+    ) {
+        let Some(resolver) = self.resolver.as_ref() else {
+            return;
+        };
+        let Some(module) = self.modules.get(path) else {
+            return;
+        };
+        let ModuleState::Resolved(module_record) = module.value() else {
+            return;
+        };
+        dependencies.extend(
+            module_record
+                .loaded_modules
+                .iter()
+                .map(|module| module.value().resolved_absolute_path.clone()),
+        );
+        // Modules which are resolved but not loaded (e.g. `.css` files) are not dependencies
+        let dir = path.parent().unwrap();
+        unresolved.extend(
+            module_record
+                .requested_modules
+                .keys()
+                .filter(|specifier| !module_record.loaded_modules.contains_key(*specifier))
+                .filter(|specifier| resolver.resolve(dir, specifier).is_err())
+                .map(ToString::to_string),
+        );
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub(super) fn process_source<'a>(
         &self,
@@ -188,6 +286,7 @@ impl Runtime {
         source_text: &'a str,
         source_type: SourceType,
         check_syntax_errors: bool,
+        lint: bool,
         tx_error: &DiagnosticSender,
     ) -> Vec<Message<'a>> {
         let ret = Parser::new(allocator, source_text, source_type)
@@ -273,8 +372,8 @@ impl Runtime {
                     .extend(remote_bindings);
             }
 
-            // Stop if the current module is not marked for lint.
-            if !self.paths.contains(path) {
+            // Stop if the current module is not marked for lint, or its results are cached.
+            if !lint || !self.paths.contains(path) {
                 return vec![];
             }
         }
@@ -311,3 +410,58 @@ impl Runtime {
         self.paths.iter()
     }
 }
+
+/// Shift the labels of a diagnostic from a partial source by the source's start offset.
+fn offset_diagnostic(mut diagnostic: OxcDiagnostic, start: usize) -> OxcDiagnostic {
+    if start == 0 {
+        return diagnostic;
+    }
+    if let Some(labels) = &mut diagnostic.labels {
+        for label in labels.iter_mut() {
+            let span = (label.offset() + start, label.len());
+            let text = label.label().map(ToString::to_string);
+            *label = if label.primary() {
+                LabeledSpan::new_primary_with_span(text, span)
+            } else {
+                LabeledSpan::new_with_span(text, span)
+            };
+        }
+    }
+    diagnostic
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, sync::mpsc};
+
+    use oxc_diagnostics::{GraphicalReportHandler, GraphicalTheme};
+
+    use crate::{LintService, LintServiceOptions, Linter};
+
+    /// Diagnostics of partial sources are reported at their position in the whole file.
+    #[test]
+    fn test_partial_loader_diagnostics() {
+        let cwd = env::current_dir().unwrap().join("fixtures/partial_loader");
+        let handler = GraphicalReportHandler::new()
+            .with_links(false)
+            .with_theme(GraphicalTheme::unicode_nocolor());
+        for file in
+            ["debugger.vue", "debugger.svelte", "debugger.astro", "debugger.md", "debugger.mdx"]
+        {
+            let options = LintServiceOptions::new(cwd.clone(), vec![cwd.join(file).into()]);
+            let service = LintService::new(Linter::default(), options);
+            let (tx_error, rx_error) = mpsc::channel();
+            service.run(&tx_error);
+
+            let mut snapshot = String::new();
+            while let Some((_, diagnostics)) = rx_error.recv().unwrap() {
+                for diagnostic in diagnostics {
+                    handler.render_report(&mut snapshot, diagnostic.as_ref()).unwrap();
+                }
+            }
+            insta::with_settings!({ prepend_module_to_snapshot => false }, {
+                insta::assert_snapshot!(file, snapshot);
+            });
+        }
+    }
+}
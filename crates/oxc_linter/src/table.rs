@@ -35,8 +35,9 @@ impl Default for RuleTable {
 impl RuleTable {
     pub fn new() -> Self {
         let default_rules = Linter::default()
-            .rules
-            .into_iter()
+            .config
+            .rules()
+            .iter()
             .map(|rule| rule.name())
             .collect::<FxHashSet<&str>>();
 
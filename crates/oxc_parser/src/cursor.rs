@@ -17,6 +17,7 @@ pub struct ParserCheckpoint<'a> {
     cur_token: Token,
     prev_span_end: u32,
     errors_pos: usize,
+    tokens_pos: usize,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -120,10 +121,22 @@ impl<'a> ParserImpl<'a> {
         }
     }
 
+    /// Record the current token before moving past it, if collecting tokens.
+    /// `kind` is the kind the parser interpreted the token as.
+    #[inline]
+    fn record_token(&mut self, kind: Kind) {
+        if let Some(tokens) = &mut self.tokens {
+            let mut token = self.token;
+            token.kind = kind;
+            tokens.push(token);
+        }
+    }
+
     /// Move to the next token
     /// Checks if the current token is escaped if it is a keyword
     fn advance(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.record_token(kind);
         self.prev_token_end = self.token.end;
         self.token = self.lexer.next_token();
     }
@@ -132,6 +145,7 @@ impl<'a> ParserImpl<'a> {
     /// Checks if the current token is escaped if it is a keyword
     fn advance_for_jsx_child(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.record_token(kind);
         self.prev_token_end = self.token.end;
         self.token = self.lexer.next_jsx_child();
     }
@@ -205,6 +219,24 @@ impl<'a> ParserImpl<'a> {
         Ok(())
     }
 
+    /// Expect the `}` closing a statement list.
+    ///
+    /// In error recovery mode, a `}` missing at the end of file is reported as an error and
+    /// parsing continues, so that a partially written block still produces its statements.
+    /// # Errors
+    pub(crate) fn expect_statement_list_end(&mut self) -> Result<()> {
+        if self.options.recover_from_errors && self.at(Kind::Eof) {
+            let range = self.cur_token().span();
+            self.error(diagnostics::expect_token(
+                Kind::RCurly.to_str(),
+                self.cur_kind().to_str(),
+                range,
+            ));
+            return Ok(());
+        }
+        self.expect(Kind::RCurly)
+    }
+
     /// Expect the next next token to be a `JsxChild`, i.e. `<` or `{` or `JSXText`
     /// # Errors
     pub(crate) fn expect_jsx_child(&mut self, kind: Kind) -> Result<()> {
@@ -279,17 +311,26 @@ impl<'a> ParserImpl<'a> {
             cur_token: self.token,
             prev_span_end: self.prev_token_end,
             errors_pos: self.errors.len(),
+            tokens_pos: self.tokens.as_ref().map_or(0, std::vec::Vec::len),
         }
     }
 
     pub(crate) fn rewind(&mut self, checkpoint: ParserCheckpoint<'a>) {
-        let ParserCheckpoint { lexer, cur_token, prev_span_end, errors_pos: errors_lens } =
-            checkpoint;
+        let ParserCheckpoint {
+            lexer,
+            cur_token,
+            prev_span_end,
+            errors_pos: errors_lens,
+            tokens_pos: tokens_lens,
+        } = checkpoint;
 
         self.lexer.rewind(lexer);
         self.token = cur_token;
         self.prev_token_end = prev_span_end;
         self.errors.truncate(errors_lens);
+        if let Some(tokens) = &mut self.tokens {
+            tokens.truncate(tokens_lens);
+        }
     }
 
     /// # Errors
@@ -329,6 +370,26 @@ impl<'a> ParserImpl<'a> {
         result
     }
 
+    /// Run `cb` one nesting level deeper.
+    /// Returns an error instead if [`ParseOptions::max_nesting_depth`] is exceeded.
+    ///
+    /// [`ParseOptions::max_nesting_depth`]: crate::ParseOptions::max_nesting_depth
+    pub(crate) fn nested<F, T>(&mut self, cb: F) -> Result<T>
+    where
+        F: FnOnce(&mut Self) -> Result<T>,
+    {
+        if self.depth >= self.options.max_nesting_depth {
+            return Err(diagnostics::max_nesting_depth_exceeded(
+                self.options.max_nesting_depth,
+                self.cur_token().span(),
+            ));
+        }
+        self.depth += 1;
+        let result = cb(self);
+        self.depth -= 1;
+        result
+    }
+
     pub(crate) fn consume_decorators(&mut self) -> Vec<'a, Decorator<'a>> {
         let decorators = std::mem::take(&mut self.state.decorators);
         self.ast.vec_from_iter(decorators)
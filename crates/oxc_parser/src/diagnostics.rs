@@ -27,11 +27,49 @@ pub fn overlong_source() -> OxcDiagnostic {
     OxcDiagnostic::error("Source length exceeds 4 GiB limit")
 }
 
+#[cold]
+pub fn max_nesting_depth_exceeded(max_nesting_depth: u32, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Maximum nesting depth of {max_nesting_depth} exceeded"))
+        .with_label(span)
+}
+
 #[cold]
 pub fn flow(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Flow is not supported").with_label(span)
 }
 
+#[cold]
+pub fn typescript_syntax_in_flow(syntax: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("{syntax} is TypeScript syntax, which is not allowed in Flow"))
+        .with_label(span)
+}
+
+#[cold]
+pub fn json_comment(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Comments are not permitted in JSON")
+        .with_help("Use JSONC or JSON5 to allow comments")
+        .with_label(span)
+}
+
+#[cold]
+pub fn json_trailing_comma(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Trailing commas are not permitted in JSON")
+        .with_help("Remove the trailing comma")
+        .with_label(span)
+}
+
+#[cold]
+pub fn json_invalid_number(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Invalid number in JSON").with_label(span)
+}
+
+#[cold]
+pub fn json_invalid_string(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Invalid string in JSON")
+        .with_help("JSON strings must be double quoted and cannot contain control characters or JavaScript-only escapes")
+        .with_label(span)
+}
+
 #[cold]
 pub fn unexpected_token(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Unexpected token").with_label(span)
@@ -463,6 +501,13 @@ pub fn cannot_appear_on_a_parameter(modifier: &Modifier) -> OxcDiagnostic {
         .with_label(modifier.span)
 }
 
+/// TS(1354)
+#[cold]
+pub fn readonly_in_array_or_tuple_type(span: Span) -> OxcDiagnostic {
+    ts_error("1354", "'readonly' type modifier is only permitted on array and tuple literal types.")
+        .with_label(span)
+}
+
 /// TS(18010)
 #[cold]
 pub fn accessibility_modifier_on_private_property(modifier: &Modifier) -> OxcDiagnostic {
@@ -0,0 +1,176 @@
+//! [Flow](https://flow.org) specific syntax.
+//!
+//! Flow is parsed with the TypeScript grammar, and the syntax which TypeScript does not have is
+//! represented by the TypeScript AST nodes with the same meaning.
+//! TypeScript syntax which Flow does not have is reported as an error.
+//! See [`ParseOptions::parse_flow`](crate::ParseOptions::parse_flow).
+
+use oxc_ast::ast::*;
+use oxc_diagnostics::Result;
+use oxc_span::Span;
+
+use crate::{
+    diagnostics,
+    lexer::Kind,
+    modifiers::{Modifier, ModifierKind, Modifiers},
+    ParserImpl,
+};
+
+impl<'a> ParserImpl<'a> {
+    /// Report `syntax` at `span` if parsing Flow.
+    pub(crate) fn check_not_flow(&mut self, syntax: &str, span: Span) {
+        if self.is_flow {
+            self.error(diagnostics::typescript_syntax_in_flow(syntax, span));
+        }
+    }
+
+    /// Accessibility modifiers, `abstract`, `override`, `readonly` and `in` / `out` variance
+    /// annotations are TypeScript only.
+    pub(crate) fn check_flow_modifier(&mut self, modifier: &Modifier) {
+        if !self.is_flow {
+            return;
+        }
+        if matches!(
+            modifier.kind,
+            ModifierKind::Abstract
+                | ModifierKind::Private
+                | ModifierKind::Protected
+                | ModifierKind::Public
+                | ModifierKind::Readonly
+                | ModifierKind::Override
+                | ModifierKind::In
+                | ModifierKind::Out
+        ) {
+            let syntax = format!("`{}` modifier", modifier.kind.as_str());
+            self.error(diagnostics::typescript_syntax_in_flow(&syntax, modifier.span));
+        }
+    }
+
+    /// `(expression: Type)`, represented by a [`TSAsExpression`] marked as a Flow type cast
+    pub(crate) fn parse_assignment_expression_or_flow_type_cast(
+        &mut self,
+    ) -> Result<Expression<'a>> {
+        let span = self.start_span();
+        let expression = self.parse_assignment_expression_or_higher()?;
+        if !(self.is_flow && self.eat(Kind::Colon)) {
+            return Ok(expression);
+        }
+        let type_annotation = self.parse_ts_type()?;
+        Ok(self.ast.expression_ts_as(
+            self.end_span(span),
+            expression,
+            type_annotation,
+            /* flow_type_cast */ true,
+        ))
+    }
+
+    /// Maybe type `?Type`, represented by a prefix [`JSDocNullableType`]
+    pub(crate) fn parse_flow_maybe_type(&mut self) -> Result<TSType<'a>> {
+        let span = self.start_span();
+        self.bump_any(); // bump `?`
+                         // `?() => void` is a maybe function type
+        let type_annotation = if self.is_start_of_function_type_or_constructor_type() {
+            self.parse_function_or_constructor_type()?
+        } else {
+            self.nested(Self::parse_type_operator_or_higher)?
+        };
+        Ok(self.ast.ts_type_js_doc_nullable_type(
+            self.end_span(span),
+            type_annotation,
+            /* postfix */ false,
+        ))
+    }
+
+    /// Object types `{ a: A }`, exact object types `{| a: A |}`
+    /// and object types with spreads `{ ...A, b: B }`.
+    ///
+    /// Spreads are represented by intersecting the spread types with the remaining members.
+    /// Exact object types are represented by a [`TSTypeLiteral`] with `flow_exact` set.
+    pub(crate) fn parse_flow_object_type(&mut self) -> Result<TSType<'a>> {
+        let span = self.start_span();
+        self.expect(Kind::LCurly)?;
+        let mut members = self.ast.vec();
+        let mut spreads = self.ast.vec();
+        // `{||}` is lexed as `{`, `||`, `}`
+        let empty_exact = self.eat(Kind::Pipe2);
+        let exact = empty_exact || self.eat(Kind::Pipe);
+        if !empty_exact {
+            loop {
+                match self.cur_kind() {
+                    Kind::RCurly | Kind::Eof => break,
+                    Kind::Pipe if exact => break,
+                    Kind::Dot3 => {
+                        self.bump_any();
+                        spreads.push(self.parse_ts_type()?);
+                        self.bump(Kind::Comma);
+                        self.bump(Kind::Semicolon);
+                    }
+                    // Variance sigils: `+a: A` is read-only, `-a: A` is write-only
+                    kind @ (Kind::Plus | Kind::Minus) => {
+                        self.bump_any();
+                        let mut member = self.parse_ts_property_or_method_signature_member()?;
+                        if let TSSignature::TSPropertySignature(property) = &mut member {
+                            property.readonly = kind == Kind::Plus;
+                        }
+                        members.push(member);
+                    }
+                    _ => {
+                        let Some(member) = self.parse_ts_type_signature()? else { break };
+                        members.push(member);
+                    }
+                }
+            }
+            if exact {
+                self.expect(Kind::Pipe)?;
+            }
+        }
+        self.expect(Kind::RCurly)?;
+        let span = self.end_span(span);
+        let object_type = self.ast.ts_type_type_literal(span, members, exact);
+        if spreads.is_empty() {
+            return Ok(object_type);
+        }
+        spreads.push(object_type);
+        Ok(self.ast.ts_type_intersection_type(span, spreads))
+    }
+
+    /// `|}` ends an exact object type instead of continuing a union type
+    pub(crate) fn at_flow_exact_object_type_end(&mut self) -> bool {
+        self.is_flow && self.at(Kind::Pipe) && self.peek_at(Kind::RCurly)
+    }
+
+    /// `opaque type`
+    pub(crate) fn at_flow_opaque_type(&mut self) -> bool {
+        self.is_flow
+            && self.at(Kind::Ident)
+            && self.cur_src() == "opaque"
+            && self.peek_at(Kind::Type)
+            && !self.peek_token().is_on_new_line
+    }
+
+    /// `opaque type Name: SuperType = Type`, represented by a type alias of `Type`.
+    /// The optional super type is dropped.
+    ///
+    /// In a declaration (`declare opaque type Name: SuperType;`) the type is optional,
+    /// and the alias is of the super type, or `unknown` without one.
+    pub(crate) fn parse_flow_opaque_type_declaration(
+        &mut self,
+        span: Span,
+        modifiers: &Modifiers<'a>,
+    ) -> Result<Declaration<'a>> {
+        self.bump_any(); // bump `opaque`
+        self.expect(Kind::Type)?;
+        let id = self.parse_binding_identifier()?;
+        let params = self.parse_ts_type_parameters()?;
+        let super_type = if self.eat(Kind::Colon) { Some(self.parse_ts_type()?) } else { None };
+        let declare = modifiers.contains_declare();
+        let annotation = if declare && !self.at(Kind::Eq) {
+            super_type.unwrap_or_else(|| self.ast.ts_type_unknown_keyword(self.end_span(span)))
+        } else {
+            self.expect(Kind::Eq)?;
+            self.parse_ts_type()?
+        };
+        self.asi()?;
+        Ok(self.ast.declaration_ts_type_alias(self.end_span(span), id, params, annotation, declare))
+    }
+}
@@ -0,0 +1,329 @@
+//! Incremental re-parsing of an edited source text.
+
+use oxc_ast::ast::{Comment, Program};
+use oxc_diagnostics::Result;
+use oxc_span::{ForEachSpanMut, GetSpan, Span};
+use oxc_syntax::identifier::is_line_terminator;
+
+use crate::{lexer::Kind, ParserImpl, ParserReturn};
+
+/// A change to the source text, passed to [`Parser::parse_incremental`](crate::Parser::parse_incremental).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextEdit {
+    /// Range of the replaced text, in the previous source text.
+    pub span: Span,
+    /// Length of the replacement text, in UTF-8 bytes.
+    pub new_len: u32,
+}
+
+/// The region of source text covered by all edits.
+#[derive(Debug, Clone, Copy)]
+struct EditedRange {
+    /// Start of the region. Same in the previous and the new source text.
+    start: u32,
+    /// End of the region in the previous source text.
+    old_end: u32,
+    /// Change in length of the source text.
+    delta: i64,
+}
+
+impl EditedRange {
+    fn new(edits: &[TextEdit], old_len: u32, new_len: u32) -> Option<Self> {
+        let start = edits.iter().map(|edit| edit.span.start).min()?;
+        let old_end = edits.iter().map(|edit| edit.span.end).max()?;
+        if old_end > old_len {
+            return None;
+        }
+        let delta = edits
+            .iter()
+            .map(|edit| i64::from(edit.new_len) - i64::from(edit.span.size()))
+            .sum::<i64>();
+        // Edits must be consistent with the new source text.
+        (i64::from(old_len) + delta == i64::from(new_len)).then_some(Self { start, old_end, delta })
+    }
+
+    /// End of the region in the new source text.
+    fn new_end(&self) -> i64 {
+        i64::from(self.old_end) + self.delta
+    }
+
+    /// Convert an offset after the region in the previous source text into the new source text.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn shift(&self, offset: u32) -> u32 {
+        (i64::from(offset) + self.delta) as u32
+    }
+
+    /// Convert an offset after the region in the new source text into the previous source text.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn unshift(&self, offset: u32) -> u32 {
+        (i64::from(offset) - self.delta) as u32
+    }
+
+    fn shift_span(&self, span: &mut Span) {
+        span.start = self.shift(span.start);
+        span.end = self.shift(span.end);
+    }
+
+    fn shift_comment(&self, mut comment: Comment) -> Comment {
+        self.shift_span(&mut comment.span);
+        comment.attached_to = self.shift(comment.attached_to);
+        comment
+    }
+}
+
+impl<'a> ParserImpl<'a> {
+    /// Entry point for incremental parsing
+    ///
+    /// See [`Parser::parse_incremental`](crate::Parser::parse_incremental).
+    pub fn parse_incremental(
+        mut self,
+        previous: ParserReturn<'a>,
+        edits: &[TextEdit],
+    ) -> ParserReturn<'a> {
+        let Some((range, reparse_index)) = self.incremental_range(&previous, edits) else {
+            return self.parse();
+        };
+        let result = self.parse_program_incremental(previous, range, reparse_index);
+        self.into_parser_return(result)
+    }
+
+    /// Find the edited range and the index of the first top-level statement to re-parse.
+    ///
+    /// Returns `None` if the previous result cannot be reused.
+    #[allow(clippy::cast_possible_truncation)]
+    fn incremental_range(
+        &self,
+        previous: &ParserReturn<'a>,
+        edits: &[TextEdit],
+    ) -> Option<(EditedRange, usize)> {
+        let program = &previous.program;
+        if previous.panicked
+            || !previous.errors.is_empty()
+            || self.source_type.is_unambiguous()
+            || self.source_type.is_json()
+            || program.source_type != self.source_type
+            || previous.options != self.options
+        {
+            return None;
+        }
+        let range = EditedRange::new(
+            edits,
+            program.source_text.len() as u32,
+            self.source_text.len() as u32,
+        )?;
+        let first_affected = program
+            .body
+            .iter()
+            .position(|stmt| stmt.span().end >= range.start)
+            .unwrap_or(program.body.len());
+        // The statement before the edit is re-parsed too,
+        // because automatic semicolon insertion depends on the tokens following it.
+        let reparse_index = first_affected.checked_sub(1).filter(|index| *index > 0)?;
+        Some((range, reparse_index))
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn parse_program_incremental(
+        &mut self,
+        previous: ParserReturn<'a>,
+        range: EditedRange,
+        reparse_index: usize,
+    ) -> Result<Program<'a>> {
+        let ParserReturn { program, irregular_whitespaces, .. } = previous;
+        let Program { comments: old_comments, hashbang, directives, body: mut old_body, .. } =
+            program;
+
+        // Continue lexing from the first statement to re-parse.
+        // Comments before it are unchanged, and are reused from the previous result.
+        let prev_token_end = old_body[reparse_index - 1].span().end;
+        let reparse_start = old_body[reparse_index].span().start;
+        let is_on_new_line = self.source_text[prev_token_end as usize..reparse_start as usize]
+            .chars()
+            .any(is_line_terminator);
+        self.lexer.seek(reparse_start, is_on_new_line);
+        self.prev_token_end = prev_token_end;
+        self.bump_any();
+
+        let mut statements = self.ast.vec_with_capacity(old_body.len());
+        statements.extend(old_body.drain(..reparse_index));
+
+        // Parse until the parser reaches the start of a statement after the edited range,
+        // from which point the rest of the previous statements are the same.
+        let new_end = range.new_end();
+        let resync = loop {
+            if self.at(Kind::Eof) {
+                break None;
+            }
+            let start = self.cur_token().start;
+            if i64::from(start) >= new_end {
+                let old_start = range.unshift(start);
+                if let Ok(index) =
+                    old_body.binary_search_by_key(&old_start, |stmt| stmt.span().start)
+                {
+                    break Some((start, old_start, index));
+                }
+            }
+            let stmt = self.parse_statement_list_item_or_recover()?;
+            statements.push(stmt);
+        };
+
+        let trivia = &mut self.lexer.trivia_builder;
+        let (resync_start, old_resync_start) =
+            resync.map_or((u32::MAX, u32::MAX), |(start, old_start, _)| (start, old_start));
+        let new_comments = trivia.comments.iter().filter(|c| c.span.start < resync_start);
+        let comments = old_comments
+            .iter()
+            .filter(|c| c.span.start < reparse_start)
+            .chain(new_comments)
+            .copied()
+            .chain(
+                old_comments
+                    .iter()
+                    .filter(|c| c.span.start >= old_resync_start)
+                    .map(|c| range.shift_comment(*c)),
+            )
+            .collect::<Vec<_>>();
+        trivia.comments = comments;
+        let new_irregular_whitespaces =
+            trivia.irregular_whitespaces.iter().filter(|span| span.start < resync_start);
+        let irregular_whitespaces = irregular_whitespaces
+            .iter()
+            .filter(|span| span.start < reparse_start)
+            .chain(new_irregular_whitespaces)
+            .copied()
+            .chain(irregular_whitespaces.iter().filter(|span| span.start >= old_resync_start).map(
+                |span| {
+                    let mut span = *span;
+                    range.shift_span(&mut span);
+                    span
+                },
+            ))
+            .collect::<Vec<_>>();
+        trivia.irregular_whitespaces = irregular_whitespaces;
+
+        if let Some((_, _, index)) = resync {
+            statements.extend(old_body.drain(index..).map(|mut stmt| {
+                stmt.for_each_span_mut(&mut |span| range.shift_span(span));
+                stmt
+            }));
+        }
+
+        let span = Span::new(0, self.source_text.len() as u32);
+        let comments = self.ast.vec_from_iter(self.lexer.trivia_builder.comments.iter().copied());
+        Ok(self.ast.program(
+            span,
+            self.source_type,
+            self.source_text,
+            comments,
+            hashbang,
+            directives,
+            statements,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Class, Statement};
+    use oxc_span::{GetSpan, SourceType, Span};
+
+    use super::TextEdit;
+    use crate::{ParseOptions, Parser};
+
+    /// Incremental parse of `source` with `old` replaced by `new` must equal a full parse.
+    fn test(source: &str, old: &str, new: &str) {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_typescript(true);
+        let start = source.find(old).unwrap();
+        let edited = format!("{}{new}{}", &source[..start], &source[start + old.len()..]);
+        #[allow(clippy::cast_possible_truncation)]
+        let edit = TextEdit {
+            span: Span::new(start as u32, (start + old.len()) as u32),
+            new_len: new.len() as u32,
+        };
+
+        let previous = Parser::new(&allocator, source, source_type).parse();
+        assert!(previous.errors.is_empty());
+        let incremental =
+            Parser::new(&allocator, &edited, source_type).parse_incremental(previous, &[edit]);
+        let full = Parser::new(&allocator, &edited, source_type).parse();
+        assert_eq!(incremental.errors.len(), full.errors.len(), "{edited}");
+        assert_eq!(format!("{:?}", incremental.program), format!("{:?}", full.program), "{edited}");
+    }
+
+    const SOURCE: &str = "'use strict';
+import a from 'a';
+// leading comment
+const b = a + 1; /* trailing */
+function foo(x) {
+  return x * 2;
+}
+let c = foo(b)
+/** jsdoc */
+class Bar {
+  baz() { return `${c}`; }
+}
+export default Bar;
+";
+
+    #[test]
+    fn reuse_statements() {
+        test(SOURCE, "x * 2", "x * 2 + b * 3");
+        test(SOURCE, "x * 2", "x");
+        test(SOURCE, "return x * 2;", "");
+        test(SOURCE, "function foo(x) {", "async function foo(x, y) {");
+        test(SOURCE, "/** jsdoc */", "// edited comment");
+        test(SOURCE, "export default Bar;", "export default Bar;\nexport { c };");
+        test(SOURCE, "let c = foo(b)\n", "let c = foo(b)\n(0)\n");
+    }
+
+    #[test]
+    fn reused_nodes_are_not_reallocated() {
+        fn class_ptr(stmt: &Statement) -> *const () {
+            match stmt {
+                Statement::ClassDeclaration(class) => std::ptr::from_ref::<Class>(class).cast(),
+                _ => unreachable!(),
+            }
+        }
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "let a = 1;\nlet b = 2;\nlet c = 3;\nclass D {}\n";
+        let edited = "let a = 1;\nlet b = 2;\nlet c = 33;\nclass D {}\n";
+        let edit = TextEdit { span: Span::new(30, 31), new_len: 2 };
+        let previous = Parser::new(&allocator, source, source_type).parse();
+        let ptr = class_ptr(&previous.program.body[3]);
+        let ret = Parser::new(&allocator, edited, source_type).parse_incremental(previous, &[edit]);
+        assert_eq!(class_ptr(&ret.program.body[3]), ptr);
+        assert_eq!(ret.program.body[3].span(), Span::new(34, 44));
+    }
+
+    #[test]
+    fn unterminated_edit() {
+        test(SOURCE, "x * 2", "`x * 2");
+        test(SOURCE, "x * 2", "{ x");
+    }
+
+    #[test]
+    fn full_reparse() {
+        test(SOURCE, "'use strict';", "'use strict'; 'use asm';");
+        test(SOURCE, "import a from 'a';", "import { a } from 'a';");
+    }
+
+    #[test]
+    fn different_options() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "let a = 1;\nlet b = 2;\nlet c = (3);\nlet d = (4);\n";
+        let edited = "let a = 1;\nlet b = 2;\nlet c = (33);\nlet d = (4);\n";
+        let edit = TextEdit { span: Span::new(31, 32), new_len: 2 };
+        let options = ParseOptions { preserve_parens: false, ..ParseOptions::default() };
+        let previous = Parser::new(&allocator, source, source_type).parse();
+        let incremental = Parser::new(&allocator, edited, source_type)
+            .with_options(options)
+            .parse_incremental(previous, &[edit]);
+        let full = Parser::new(&allocator, edited, source_type).with_options(options).parse();
+        assert_eq!(format!("{:?}", incremental.program), format!("{:?}", full.program));
+    }
+}
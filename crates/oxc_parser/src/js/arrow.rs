@@ -131,6 +131,8 @@ impl<'a> ParserImpl<'a> {
                 match third {
                     // If we have something like "(a:", then we must have a
                     // type-annotated parameter in an arrow function expression.
+                    // In Flow, it can also be a type cast "(a: T)".
+                    Kind::Colon if self.is_flow => Tristate::Maybe,
                     Kind::Colon => Tristate::True,
                     // If we have "(a?:" or "(a?," or "(a?=" or "(a?)" then it is definitely a lambda.
                     Kind::Question => {
@@ -29,8 +29,8 @@ impl<'a> ParserImpl<'a> {
 
     pub(crate) fn parse_binding_pattern_kind(&mut self) -> Result<BindingPatternKind<'a>> {
         match self.cur_kind() {
-            Kind::LCurly => self.parse_object_binding_pattern(),
-            Kind::LBrack => self.parse_array_binding_pattern(),
+            Kind::LCurly => self.nested(Self::parse_object_binding_pattern),
+            Kind::LBrack => self.nested(Self::parse_array_binding_pattern),
             _ => self.parse_binding_pattern_identifier(),
         }
     }
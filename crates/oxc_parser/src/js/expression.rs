@@ -206,7 +206,7 @@ impl<'a> ParserImpl<'a> {
                 Kind::RParen,
                 Kind::Comma,
                 /* trailing_separator */ false,
-                Self::parse_assignment_expression_or_higher,
+                Self::parse_assignment_expression_or_flow_type_cast,
             )
         })?;
         self.expect(Kind::RParen)?;
@@ -441,7 +441,7 @@ impl<'a> ParserImpl<'a> {
             Kind::TemplateHead => {
                 quasis.push(self.parse_template_element(tagged));
                 // TemplateHead Expression[+In, ?Yield, ?Await]
-                let expr = self.context(Context::In, Context::empty(), Self::parse_expr)?;
+                let expr = self.parse_template_substitution()?;
                 expressions.push(expr);
                 self.re_lex_template_substitution_tail();
                 loop {
@@ -456,8 +456,7 @@ impl<'a> ParserImpl<'a> {
                         }
                         _ => {
                             // TemplateMiddle Expression[+In, ?Yield, ?Await]
-                            let expr =
-                                self.context(Context::In, Context::empty(), Self::parse_expr)?;
+                            let expr = self.parse_template_substitution()?;
                             expressions.push(expr);
                             self.re_lex_template_substitution_tail();
                         }
@@ -469,6 +468,12 @@ impl<'a> ParserImpl<'a> {
         Ok(self.ast.template_literal(self.end_span(span), quasis, expressions))
     }
 
+    /// A substitution is a nesting level of its own, so that deeply nested template literals,
+    /// which take the most stack space per level, are limited earlier.
+    fn parse_template_substitution(&mut self) -> Result<Expression<'a>> {
+        self.nested(|p| p.context(Context::In, Context::empty(), Self::parse_expr))
+    }
+
     pub(crate) fn parse_template_literal_expression(
         &mut self,
         tagged: bool,
@@ -645,6 +650,7 @@ impl<'a> ParserImpl<'a> {
                     }
                 }
                 Kind::Bang if !self.cur_token().is_on_new_line && self.is_ts => {
+                    self.check_not_flow("Non-null assertion", self.cur_token().span());
                     self.bump_any();
                     self.ast.expression_ts_non_null(self.end_span(lhs_span), lhs)
                 }
@@ -729,7 +735,7 @@ impl<'a> ParserImpl<'a> {
         let rhs_span = self.start_span();
 
         let mut optional = false;
-        let mut callee = self.parse_member_expression_or_higher(&mut optional)?;
+        let mut callee = self.nested(|p| p.parse_member_expression_or_higher(&mut optional))?;
 
         let mut type_parameter = None;
         if let Expression::TSInstantiationExpression(instantiation_expr) = callee {
@@ -919,7 +925,7 @@ impl<'a> ParserImpl<'a> {
         let span = self.start_span();
         let operator = map_unary_operator(self.cur_kind());
         self.bump_any();
-        let argument = self.parse_simple_unary_expression(span)?;
+        let argument = self.nested(|p| p.parse_simple_unary_expression(span))?;
         Ok(self.ast.expression_unary(self.end_span(span), operator, argument))
     }
 
@@ -979,11 +985,14 @@ impl<'a> ParserImpl<'a> {
                 if self.cur_token().is_on_new_line {
                     break;
                 }
+                if kind == Kind::Satisfies {
+                    self.check_not_flow("`satisfies`", self.cur_token().span());
+                }
                 self.bump_any();
                 let type_annotation = self.parse_ts_type()?;
                 let span = self.end_span(lhs_span);
                 lhs = if kind == Kind::As {
-                    self.ast.expression_ts_as(span, lhs, type_annotation)
+                    self.ast.expression_ts_as(span, lhs, type_annotation, false)
                 } else {
                     self.ast.expression_ts_satisfies(span, lhs, type_annotation)
                 };
@@ -1039,6 +1048,10 @@ impl<'a> ParserImpl<'a> {
 
     /// `AssignmentExpression`[In, Yield, Await] :
     pub(crate) fn parse_assignment_expression_or_higher(&mut self) -> Result<Expression<'a>> {
+        self.nested(Self::parse_assignment_expression_or_higher_impl)
+    }
+
+    fn parse_assignment_expression_or_higher_impl(&mut self) -> Result<Expression<'a>> {
         // [+Yield] YieldExpression
         if self.is_yield_expression() {
             return self.parse_yield_expression();
@@ -1110,7 +1123,7 @@ impl<'a> ParserImpl<'a> {
             self.error(diagnostics::await_expression(Span::new(span.start, span.start + 5)));
         }
         let argument = self.context(Context::Await, Context::empty(), |p| {
-            p.parse_simple_unary_expression(lhs_span)
+            p.nested(|p| p.parse_simple_unary_expression(lhs_span))
         })?;
         Ok(self.ast.expression_await(self.end_span(span), argument))
     }
@@ -37,7 +37,7 @@ impl<'a> ParserImpl<'a> {
             p.parse_directives_and_statements(/* is_top_level */ false)
         })?;
 
-        self.expect(Kind::RCurly)?;
+        self.expect_statement_list_end()?;
         Ok(self.ast.alloc_function_body(self.end_span(span), directives, statements))
     }
 
@@ -45,7 +45,13 @@ impl<'a> ParserImpl<'a> {
         }
 
         // `import type ...`
-        let import_kind = self.parse_import_or_export_kind();
+        let import_kind = if self.is_flow && self.at(Kind::Typeof) {
+            // Flow: `import typeof ...`
+            self.bump_any();
+            ImportOrExportKind::Typeof
+        } else {
+            self.parse_import_or_export_kind()
+        };
 
         let specifiers = if self.at(Kind::Str) {
             // import "source"
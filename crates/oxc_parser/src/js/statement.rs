@@ -5,7 +5,10 @@ use oxc_span::{Atom, GetSpan, Span};
 
 use super::{grammar::CoverGrammar, VariableDeclarationContext, VariableDeclarationParent};
 use crate::{
-    diagnostics, lexer::Kind, modifiers::Modifiers, Context, ParserImpl, StatementContext,
+    diagnostics,
+    lexer::{Kind, LexerContext},
+    modifiers::Modifiers,
+    Context, ParserImpl, StatementContext,
 };
 
 impl<'a> ParserImpl<'a> {
@@ -40,7 +43,7 @@ impl<'a> ParserImpl<'a> {
             if !is_top_level && self.at(Kind::RCurly) {
                 break;
             }
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
+            let stmt = self.parse_statement_list_item_or_recover()?;
 
             if is_top_level && stmt.is_module_declaration() {
                 self.set_source_type_to_module_if_unambiguous();
@@ -71,12 +74,80 @@ impl<'a> ParserImpl<'a> {
         Ok((directives, statements))
     }
 
+    /// Parse a `StatementListItem` inside of a `StatementList`.
+    ///
+    /// With [`ParseOptions::recover_from_errors`](crate::ParseOptions::recover_from_errors)
+    /// enabled, a statement which fails to parse is replaced by an [`ErrorStatement`].
+    pub(crate) fn parse_statement_list_item_or_recover(&mut self) -> Result<Statement<'a>> {
+        if !self.options.recover_from_errors {
+            return self.parse_statement_list_item(StatementContext::StatementList);
+        }
+        let checkpoint = self.checkpoint();
+        let ctx = self.ctx;
+        match self.parse_statement_list_item(StatementContext::StatementList) {
+            Ok(stmt) => Ok(stmt),
+            Err(error) => {
+                let error_pos = self.cur_token().start;
+                self.ctx = ctx;
+                self.rewind(checkpoint);
+                self.error(error);
+                Ok(self.skip_error_statement(error_pos))
+            }
+        }
+    }
+
+    /// Skip the tokens of a statement which failed to parse at `error_pos`.
+    ///
+    /// Skipping resumes from the start of the statement so that braces can be balanced,
+    /// and stops after the error position at the first `;`, an unmatched `}`,
+    /// or a token on a new line.
+    fn skip_error_statement(&mut self, error_pos: u32) -> Statement<'a> {
+        let span = self.start_span();
+        self.lexer.set_context(LexerContext::Regular);
+        self.state.decorators.clear();
+        let mut depth = 0u32;
+        // Always consume the first token so that parsing makes progress.
+        loop {
+            match self.cur_kind() {
+                Kind::LCurly => depth += 1,
+                Kind::RCurly => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            self.bump_any();
+            if self.at(Kind::Eof) {
+                break;
+            }
+            if depth > 0 || self.cur_token().start < error_pos {
+                continue;
+            }
+            if self.eat(Kind::Semicolon) {
+                break;
+            }
+            if self.at(Kind::RCurly) || self.cur_token().is_on_new_line {
+                break;
+            }
+        }
+        self.ast.statement_error(self.end_span(span))
+    }
+
     /// `StatementListItem`[Yield, Await, Return] :
     ///     Statement[?Yield, ?Await, ?Return]
     ///     Declaration[?Yield, ?Await]
     pub(crate) fn parse_statement_list_item(
         &mut self,
         stmt_ctx: StatementContext,
+    ) -> Result<Statement<'a>> {
+        // The block body of `if (x) { ... }`, loops, `with` and labels is counted
+        // as the same nesting level as the statement which owns it.
+        if stmt_ctx.is_single_statement() && self.at(Kind::LCurly) {
+            return self.parse_block_statement();
+        }
+        self.nested(|p| p.parse_statement_list_item_impl(stmt_ctx))
+    }
+
+    fn parse_statement_list_item_impl(
+        &mut self,
+        stmt_ctx: StatementContext,
     ) -> Result<Statement<'a>> {
         let start_span = self.start_span();
 
@@ -144,10 +215,10 @@ impl<'a> ParserImpl<'a> {
         self.expect(Kind::LCurly)?;
         let mut body = self.ast.vec();
         while !self.at(Kind::RCurly) && !self.at(Kind::Eof) {
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
+            let stmt = self.parse_statement_list_item_or_recover()?;
             body.push(stmt);
         }
-        self.expect(Kind::RCurly)?;
+        self.expect_statement_list_end()?;
         Ok(self.ast.alloc_block_statement(self.end_span(span), body))
     }
 
@@ -464,7 +535,7 @@ impl<'a> ParserImpl<'a> {
         self.expect(Kind::Colon)?;
         let mut consequent = self.ast.vec();
         while !matches!(self.cur_kind(), Kind::Case | Kind::Default | Kind::RCurly | Kind::Eof) {
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
+            let stmt = self.parse_statement_list_item_or_recover()?;
             consequent.push(stmt);
         }
         Ok(Some(self.ast.switch_case(self.end_span(span), test, consequent)))
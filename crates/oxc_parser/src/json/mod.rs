@@ -0,0 +1,248 @@
+//! [JSON](https://www.json.org), JSONC and [JSON5](https://json5.org) documents.
+//!
+//! A document is represented as a [`Program`] with a single [`ExpressionStatement`] holding the
+//! value, so that tools built for JavaScript can operate on it:
+//!
+//! * objects and arrays are [`ObjectExpression`]s and [`ArrayExpression`]s
+//! * strings, numbers, booleans and `null` are literals
+//! * a signed number `-1` is a [`UnaryExpression`]
+//! * JSON5's `Infinity` and `NaN` are [`IdentifierReference`]s
+//!
+//! Comments are collected into [`Program::comments`] as in JavaScript.
+
+use oxc_ast::ast::*;
+use oxc_diagnostics::Result;
+use oxc_span::Span;
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{diagnostics, lexer::Kind, ParserImpl};
+
+impl<'a> ParserImpl<'a> {
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn parse_json_program(&mut self) -> Result<Program<'a>> {
+        let span = self.start_span();
+        let expression = self.parse_json_value()?;
+        let statement = self.ast.statement_expression(self.end_span(span), expression);
+        self.expect(Kind::Eof)?;
+
+        if !self.source_type.is_jsonc() && !self.source_type.is_json5() {
+            for comment in &self.lexer.trivia_builder.comments {
+                self.errors.push(diagnostics::json_comment(comment.span));
+            }
+        }
+
+        let span = Span::new(0, self.source_text.len() as u32);
+        let comments = self.ast.vec_from_iter(self.lexer.trivia_builder.comments.iter().copied());
+        Ok(self.ast.program(
+            span,
+            self.source_type,
+            self.source_text,
+            comments,
+            None,
+            self.ast.vec(),
+            self.ast.vec1(statement),
+        ))
+    }
+
+    fn parse_json_value(&mut self) -> Result<Expression<'a>> {
+        self.nested(Self::parse_json_value_impl)
+    }
+
+    fn parse_json_value_impl(&mut self) -> Result<Expression<'a>> {
+        match self.cur_kind() {
+            Kind::LCurly => self.parse_json_object(),
+            Kind::LBrack => self.parse_json_array(),
+            Kind::Str => {
+                self.parse_json_string().map(|s| self.ast.expression_from_string_literal(s))
+            }
+            Kind::True | Kind::False | Kind::Null => self.parse_literal_expression(),
+            Kind::Minus | Kind::Plus => {
+                let span = self.start_span();
+                let operator = if self.at(Kind::Minus) {
+                    UnaryOperator::UnaryNegation
+                } else {
+                    if !self.source_type.is_json5() {
+                        return Err(self.unexpected());
+                    }
+                    UnaryOperator::UnaryPlus
+                };
+                self.bump_any();
+                let argument = self.parse_json_number()?;
+                Ok(self.ast.expression_unary(self.end_span(span), operator, argument))
+            }
+            _ => self.parse_json_number(),
+        }
+    }
+
+    /// Number, or JSON5's `Infinity` and `NaN`
+    fn parse_json_number(&mut self) -> Result<Expression<'a>> {
+        let kind = self.cur_kind();
+        if kind.is_number() {
+            if !is_json_number(self.cur_src(), self.source_type.is_json5()) {
+                return Err(diagnostics::json_invalid_number(self.cur_token().span()));
+            }
+            return self
+                .parse_literal_number()
+                .map(|literal| self.ast.expression_from_numeric_literal(literal));
+        }
+        if kind == Kind::Ident
+            && self.source_type.is_json5()
+            && matches!(self.cur_src(), "Infinity" | "NaN")
+        {
+            let span = self.cur_token().span();
+            let name = self.cur_src();
+            self.bump_any();
+            return Ok(self.ast.expression_identifier_reference(span, name));
+        }
+        Err(self.unexpected())
+    }
+
+    fn parse_json_string(&mut self) -> Result<StringLiteral<'a>> {
+        if self.at(Kind::Str) && !self.source_type.is_json5() && !is_json_string(self.cur_src()) {
+            return Err(diagnostics::json_invalid_string(self.cur_token().span()));
+        }
+        self.parse_literal_string()
+    }
+
+    fn parse_json_object(&mut self) -> Result<Expression<'a>> {
+        let span = self.start_span();
+        self.bump_any(); // bump `{`
+        let mut properties = self.ast.vec();
+        let mut trailing_comma = None;
+        while !self.at(Kind::RCurly) {
+            properties.push(self.parse_json_property()?);
+            if !self.at(Kind::Comma) {
+                break;
+            }
+            let comma_span = self.cur_token().span();
+            self.bump_any();
+            if self.at(Kind::RCurly) {
+                self.check_json_trailing_comma(comma_span);
+                trailing_comma = Some(comma_span);
+            }
+        }
+        self.expect(Kind::RCurly)?;
+        Ok(self.ast.expression_object(self.end_span(span), properties, trailing_comma))
+    }
+
+    fn parse_json_property(&mut self) -> Result<ObjectPropertyKind<'a>> {
+        let span = self.start_span();
+        let key = if self.at(Kind::Str) {
+            let literal = self.parse_json_string()?;
+            PropertyKey::StringLiteral(self.ast.alloc(literal))
+        } else if self.source_type.is_json5() && self.cur_kind().is_identifier_name() {
+            let ident = self.parse_identifier_name()?;
+            PropertyKey::StaticIdentifier(self.ast.alloc(ident))
+        } else {
+            return Err(self.unexpected());
+        };
+        self.expect(Kind::Colon)?;
+        let value = self.parse_json_value()?;
+        Ok(self.ast.object_property_kind_object_property(
+            self.end_span(span),
+            PropertyKind::Init,
+            key,
+            value,
+            None,
+            /* method */ false,
+            /* shorthand */ false,
+            /* computed */ false,
+        ))
+    }
+
+    fn parse_json_array(&mut self) -> Result<Expression<'a>> {
+        let span = self.start_span();
+        self.bump_any(); // bump `[`
+        let mut elements = self.ast.vec();
+        let mut trailing_comma = None;
+        while !self.at(Kind::RBrack) {
+            let value = self.parse_json_value()?;
+            elements.push(ArrayExpressionElement::from(value));
+            if !self.at(Kind::Comma) {
+                break;
+            }
+            let comma_span = self.cur_token().span();
+            self.bump_any();
+            if self.at(Kind::RBrack) {
+                self.check_json_trailing_comma(comma_span);
+                trailing_comma = Some(comma_span);
+            }
+        }
+        self.expect(Kind::RBrack)?;
+        Ok(self.ast.expression_array(self.end_span(span), elements, trailing_comma))
+    }
+
+    fn check_json_trailing_comma(&mut self, span: Span) {
+        if !self.source_type.is_jsonc() && !self.source_type.is_json5() {
+            self.error(diagnostics::json_trailing_comma(span));
+        }
+    }
+}
+
+/// JSON strings are double quoted, do not contain control characters,
+/// and only allow the escapes `\" \\ \/ \b \f \n \r \t \uXXXX`.
+fn is_json_string(raw: &str) -> bool {
+    if !raw.starts_with('"') {
+        return false;
+    }
+    let mut bytes = raw.bytes();
+    while let Some(b) = bytes.next() {
+        match b {
+            0..=0x1F => return false,
+            b'\\' => match bytes.next() {
+                Some(b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't') => {}
+                Some(b'u') => {
+                    if !bytes.by_ref().take(4).all(|b| b.is_ascii_hexdigit()) {
+                        return false;
+                    }
+                }
+                _ => return false,
+            },
+            _ => {}
+        }
+    }
+    true
+}
+
+/// JSON numbers are `0` or digits without a leading zero, with an optional fraction and exponent.
+/// JSON5 additionally allows hexadecimal numbers and a leading or trailing decimal point.
+fn is_json_number(raw: &str, json5: bool) -> bool {
+    let bytes = raw.as_bytes();
+    if json5 && bytes.len() > 2 && matches!(&bytes[..2], b"0x" | b"0X") {
+        return bytes[2..].iter().all(u8::is_ascii_hexdigit);
+    }
+    let digits = |i: usize| bytes[i..].iter().take_while(|b| b.is_ascii_digit()).count();
+    let mut i = 0;
+    let int = digits(i);
+    if int > 1 && bytes[0] == b'0' {
+        return false;
+    }
+    i += int;
+    let mut fraction = None;
+    if bytes.get(i) == Some(&b'.') {
+        i += 1;
+        let n = digits(i);
+        fraction = Some(n);
+        i += n;
+    }
+    let valid_mantissa = match fraction {
+        None => int > 0,
+        Some(fraction) if json5 => int > 0 || fraction > 0,
+        Some(fraction) => int > 0 && fraction > 0,
+    };
+    if !valid_mantissa {
+        return false;
+    }
+    if matches!(bytes.get(i), Some(b'e' | b'E')) {
+        i += 1;
+        if matches!(bytes.get(i), Some(b'+' | b'-')) {
+            i += 1;
+        }
+        let n = digits(i);
+        if n == 0 {
+            return false;
+        }
+        i += n;
+    }
+    i == bytes.len()
+}
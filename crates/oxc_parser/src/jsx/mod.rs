@@ -248,11 +248,11 @@ impl<'a> ParserImpl<'a> {
             Kind::LAngle if self.peek_at(Kind::Slash) => Ok(None),
             // <> open fragment
             Kind::LAngle if self.peek_at(Kind::RAngle) => {
-                self.parse_jsx_fragment(true).map(JSXChild::Fragment).map(Some)
+                self.nested(|p| p.parse_jsx_fragment(true)).map(JSXChild::Fragment).map(Some)
             }
             // <ident open element
             Kind::LAngle if self.peek_at(Kind::Ident) || self.peek_kind().is_all_keyword() => {
-                self.parse_jsx_element(true).map(JSXChild::Element).map(Some)
+                self.nested(|p| p.parse_jsx_element(true)).map(JSXChild::Element).map(Some)
             }
             // {...expr}
             Kind::LCurly if self.peek_at(Kind::Dot3) => {
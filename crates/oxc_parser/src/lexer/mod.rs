@@ -165,6 +165,16 @@ impl<'a> Lexer<'a> {
         self.lookahead.clear();
     }
 
+    /// Continue lexing from `offset` in the source text, which must be the start of a token.
+    /// Used for re-parsing a part of a file.
+    pub(crate) fn seek(&mut self, offset: u32, is_on_new_line: bool) {
+        self.source.set_offset(offset);
+        self.token = Token::default();
+        self.token.is_on_new_line = is_on_new_line;
+        self.lookahead.clear();
+        self.context = LexerContext::Regular;
+    }
+
     /// Find the nth lookahead token lazily
     pub fn lookahead(&mut self, n: u8) -> Token {
         let n = n as usize;
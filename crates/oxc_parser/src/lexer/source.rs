@@ -309,6 +309,19 @@ impl<'a> Source<'a> {
         (pos.addr() - self.start as usize) as u32
     }
 
+    /// Move current position to `offset` bytes from the start of source.
+    ///
+    /// # Panic
+    /// Panics if `offset` is past the end of source, or is not on a UTF-8 character boundary.
+    pub(super) fn set_offset(&mut self, offset: u32) {
+        let offset = offset as usize;
+        assert!(self.whole().is_char_boundary(offset), "Offset {offset} is not a valid position");
+
+        // SAFETY: `is_char_boundary` returns `false` for any offset past the end of source,
+        // so the new pointer is in bounds of original `&str`, and on a UTF-8 character boundary.
+        self.ptr = unsafe { self.start.add(offset) };
+    }
+
     /// Move current position back by `n` bytes.
     ///
     /// # Panic
@@ -71,10 +71,16 @@ mod cursor;
 mod modifiers;
 mod state;
 
+mod flow;
 mod js;
+mod json;
 mod jsx;
 mod ts;
 
+mod incremental;
+mod template;
+mod tokenize;
+
 mod diagnostics;
 
 // Expose lexer only in benchmarks
@@ -93,8 +99,19 @@ use oxc_ast::{
 use oxc_diagnostics::{OxcDiagnostic, Result};
 use oxc_span::{ModuleKind, SourceType, Span};
 
+#[doc(hidden)]
+pub use oxc_span::SourceType as __SourceType;
+
+#[doc(hidden)]
+pub use crate::template::{expand as __expand_template, TemplateCache as __TemplateCache};
+pub use crate::{
+    incremental::TextEdit,
+    lexer::Kind,
+    template::{Template, TemplateNode, TemplateValue},
+    tokenize::{tokenize, SyntaxKind, SyntaxToken, TokenizeReturn},
+};
 use crate::{
-    lexer::{Kind, Lexer, Token},
+    lexer::{Lexer, Token},
     state::ParserState,
 };
 
@@ -132,6 +149,10 @@ pub(crate) const MAX_LEN: usize = if std::mem::size_of::<usize>() >= 8 {
 /// When the parser cannot recover, it will abort and terminate parsing early. [`program`] will
 /// be empty and [`panicked`] will be `true`.
 ///
+/// With [`ParseOptions::recover_from_errors`] enabled, statements which fail to parse are
+/// replaced by [`ErrorStatement`](oxc_ast::ast::ErrorStatement)s instead, and [`panicked`]
+/// will be `false`.
+///
 /// [`program`]: ParserReturn::program
 /// [`errors`]: ParserReturn::errors
 /// [`panicked`]: ParserReturn::panicked
@@ -168,12 +189,17 @@ pub struct ParserReturn<'a> {
     /// [`program`]: ParserReturn::program
     /// [`errors`]: ParserReturn::errors
     pub panicked: bool,
+
+    /// Options the source text was parsed with.
+    ///
+    /// [`Parser::parse_incremental`] only reuses nodes parsed with the same options.
+    pub options: ParseOptions,
 }
 
 /// Parse options
 ///
 /// You may provide options to the [`Parser`] using [`Parser::with_options`].
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ParseOptions {
     /// Whether to parse regular expressions or not.
     ///
@@ -198,6 +224,57 @@ pub struct ParseOptions {
     ///
     /// [`ParenthesizedExpression`]: oxc_ast::ast::ParenthesizedExpression
     pub preserve_parens: bool,
+
+    /// Keep parsing after a syntax error in a statement.
+    ///
+    /// If this option is `true`, a statement which fails to parse is reported in
+    /// [`errors`](ParserReturn::errors) and replaced by an [`ErrorStatement`] covering the
+    /// skipped source text. Parsing then continues from the next statement, so that the rest
+    /// of the file still produces a partial AST which can be used for semantic analysis and linting.
+    /// A missing `}` at the end of the file is also reported instead of aborting.
+    ///
+    /// Default: `false`
+    ///
+    /// [`ErrorStatement`]: oxc_ast::ast::ErrorStatement
+    pub recover_from_errors: bool,
+
+    /// Parse [Flow](https://flow.org) type annotations in JavaScript files.
+    ///
+    /// Flow syntax is represented with the TypeScript AST nodes which have the same meaning,
+    /// so that it can be stripped by the TypeScript transform:
+    /// * type annotations, type parameters (`<T: Bound>`) and type casts (`(x: T)`,
+    ///   represented by [`TSAsExpression`] with `flow_type_cast` set)
+    /// * maybe types `?T`, represented by [`TSJSDocNullableType`]
+    /// * object type spreads `{ ...A, b: T }`, represented by [`TSIntersectionType`]
+    /// * exact objects `{| a: T |}`, represented by [`TSTypeLiteral`] with `flow_exact` set
+    /// * `type`, `opaque type` and `declare` declarations, dropping super types of opaque types
+    /// * `import type`, `import typeof` ([`ImportOrExportKind::Typeof`]) and `export type`
+    ///
+    /// TypeScript only syntax (e.g. `enum`, `namespace`, `satisfies`, `x!` and accessibility
+    /// modifiers) is an error.
+    ///
+    /// This option has no effect on TypeScript files.
+    ///
+    /// Default: `false`
+    ///
+    /// [`TSAsExpression`]: oxc_ast::ast::TSAsExpression
+    /// [`TSJSDocNullableType`]: oxc_ast::ast::TSJSDocNullableType
+    /// [`TSTypeLiteral`]: oxc_ast::ast::TSTypeLiteral
+    /// [`TSIntersectionType`]: oxc_ast::ast::TSIntersectionType
+    /// [`ImportOrExportKind::Typeof`]: oxc_ast::ast::ImportOrExportKind::Typeof
+    pub parse_flow: bool,
+
+    /// Maximum nesting depth of statements, expressions, binding patterns, types and JSX elements.
+    ///
+    /// The parser is recursive, so deeply nested source text (e.g. thousands of nested arrays)
+    /// can overflow the stack. Nesting deeper than this limit is reported as an error instead.
+    /// The block body of a statement, e.g. `if (x) { ... }`, is on the same level as the statement.
+    ///
+    /// The default is low enough for a debug build to stay within a 2 MiB stack, which is the
+    /// default for spawned threads and rayon's threads. Raise it only on threads with larger stacks.
+    ///
+    /// Default: `256`
+    pub max_nesting_depth: u32,
 }
 
 impl Default for ParseOptions {
@@ -206,6 +283,9 @@ impl Default for ParseOptions {
             parse_regular_expression: false,
             allow_return_outside_function: false,
             preserve_parens: true,
+            recover_from_errors: false,
+            parse_flow: false,
+            max_nesting_depth: 256,
         }
     }
 }
@@ -293,6 +373,51 @@ mod parser_parse {
             parser.parse()
         }
 
+        /// Re-parse the source text after it has been edited.
+        ///
+        /// `previous` is the result of parsing the source text before `edits` were applied, and
+        /// the source text given to [`Parser::new`] is the source text after the edits.
+        /// Spans of `edits` are offsets into the previous source text.
+        ///
+        /// Only the top-level statements touched by the edits are re-parsed. The statements before
+        /// them are reused as is, and the statements after them are reused with their spans shifted,
+        /// so the cost of re-parsing does not depend on the size of the file.
+        /// The result is the same as calling [`Parser::parse`] on the new source text.
+        ///
+        /// Falls back to a full parse when nothing can be reused safely, e.g. if `previous`
+        /// contains errors, was parsed with different options, or the edits touch the first
+        /// statement.
+        pub fn parse_incremental(
+            self,
+            previous: ParserReturn<'a>,
+            edits: &[TextEdit],
+        ) -> ParserReturn<'a> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            parser.parse_incremental(previous, edits)
+        }
+
+        /// Split the source text into tokens and trivia.
+        ///
+        /// See [`tokenize`].
+        pub fn tokenize(self) -> TokenizeReturn {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            parser.tokenize()
+        }
+
         /// Parse a single [`Expression`].
         ///
         /// # Example
@@ -356,11 +481,20 @@ struct ParserImpl<'a> {
     /// Parsing context
     ctx: Context,
 
+    /// Current nesting depth, see [`ParseOptions::max_nesting_depth`]
+    depth: u32,
+
     /// Ast builder for creating AST nodes
     ast: AstBuilder<'a>,
 
-    /// Precomputed typescript detection
+    /// Precomputed typescript detection, also enabled for Flow
     is_ts: bool,
+
+    /// Parse Flow specific syntax, see [`ParseOptions::parse_flow`]
+    is_flow: bool,
+
+    /// Tokens consumed by the parser, only collected for [`tokenize`]
+    tokens: Option<Vec<Token>>,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -386,8 +520,11 @@ impl<'a> ParserImpl<'a> {
             prev_token_end: 0,
             state: ParserState::default(),
             ctx: Self::default_context(source_type, options),
+            depth: 0,
             ast: AstBuilder::new(allocator),
-            is_ts: source_type.is_typescript(),
+            is_ts: source_type.is_typescript() || options.parse_flow,
+            is_flow: options.parse_flow && !source_type.is_typescript(),
+            tokens: None,
         }
     }
 
@@ -397,7 +534,15 @@ impl<'a> ParserImpl<'a> {
     /// Recoverable errors are stored inside `errors`.
     #[inline]
     pub fn parse(mut self) -> ParserReturn<'a> {
-        let (program, panicked) = match self.parse_program() {
+        let result = self.parse_program();
+        self.into_parser_return(result)
+    }
+
+    /// Collect the parsed `Program` and all errors into a [`ParserReturn`].
+    ///
+    /// Returns an empty `Program` if `result` is an unrecoverable error.
+    fn into_parser_return(mut self, result: Result<Program<'a>>) -> ParserReturn<'a> {
+        let (program, panicked) = match result {
             Ok(program) => (program, false),
             Err(error) => {
                 self.error(self.overlong_error().unwrap_or(error));
@@ -427,7 +572,7 @@ impl<'a> ParserImpl<'a> {
         }
         let irregular_whitespaces =
             self.lexer.trivia_builder.irregular_whitespaces.into_boxed_slice();
-        ParserReturn { program, errors, irregular_whitespaces, panicked }
+        ParserReturn { program, errors, irregular_whitespaces, panicked, options: self.options }
     }
 
     pub fn parse_expression(mut self) -> std::result::Result<Expression<'a>, Vec<OxcDiagnostic>> {
@@ -446,6 +591,10 @@ impl<'a> ParserImpl<'a> {
         // initialize cur_token and prev_token by moving onto the first token
         self.bump_any();
 
+        if self.source_type.is_json() {
+            return self.parse_json_program();
+        }
+
         let hashbang = self.parse_hashbang();
         let (directives, statements) =
             self.parse_directives_and_statements(/* is_top_level */ true)?;
@@ -480,7 +629,7 @@ impl<'a> ParserImpl<'a> {
     /// Check for Flow declaration if the file cannot be parsed.
     /// The declaration must be [on the first line before any code](https://flow.org/en/docs/usage/#toc-prepare-your-code-for-flow)
     fn flow_error(&mut self) -> Option<OxcDiagnostic> {
-        if !self.source_type.is_javascript() {
+        if !self.source_type.is_javascript() || self.is_flow {
             return None;
         };
         let span = self.lexer.trivia_builder.comments.first()?.span;
@@ -541,7 +690,10 @@ impl<'a> ParserImpl<'a> {
 mod test {
     use std::path::Path;
 
-    use oxc_ast::ast::{CommentKind, Expression};
+    use oxc_ast::ast::{
+        CommentKind, Expression, ImportOrExportKind, ObjectPropertyKind, Statement, TSType,
+    };
+    use oxc_span::GetSpan;
 
     use super::*;
 
@@ -657,6 +809,267 @@ mod test {
         }
     }
 
+    #[test]
+    fn parse_flow() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let options = ParseOptions { parse_flow: true, ..ParseOptions::default() };
+        let sources = [
+            "// @flow\nconst x: ?string = null;",
+            "function f<T: Object>(a: T, b?: ?number[]): Array<T> { return [a]; }",
+            "type Props = {| +name: string, -age?: number, onPress: () => void |};",
+            "type Empty = {||};",
+            "type Union = {| kind: 'a' | 'b' |} | {| kind: 'c' |};",
+            "type Spread = { ...Props, ...$Exact<Other>, extra: mixed };",
+            "opaque type ID: string = string;",
+            "declare opaque type Foo;",
+            "declare export opaque type Foo: string;",
+            "export opaque type Token = string;",
+            "declare var __DEV__: boolean;",
+            "declare function foo(x: number): string;",
+            "declare class Bar { baz(): void }",
+            "import type { Node } from 'react';",
+            "import typeof TheModule from './module';",
+            "export type { Props };",
+            "const y = (x: any);",
+            "const z = ((x: any): string).length;",
+            "const arrow = (x: number): number => x;",
+            "class C<+T> { prop: T; method(): void {} }",
+        ];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+            assert!(ret.errors.is_empty(), "{source}: {:?}", ret.errors);
+        }
+
+        let source = "type T = ?{| a: A |}; (x: T); import typeof M from 'm';";
+        let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+        let Statement::TSTypeAliasDeclaration(decl) = &ret.program.body[0] else { unreachable!() };
+        let TSType::JSDocNullableType(maybe) = &decl.type_annotation else { unreachable!() };
+        let TSType::TSTypeLiteral(exact) = &maybe.type_annotation else { unreachable!() };
+        assert!(exact.flow_exact);
+        let Statement::ExpressionStatement(stmt) = &ret.program.body[1] else { unreachable!() };
+        let Expression::ParenthesizedExpression(expr) = &stmt.expression else { unreachable!() };
+        let Expression::TSAsExpression(cast) = &expr.expression else { unreachable!() };
+        assert!(cast.flow_type_cast);
+        let Statement::ImportDeclaration(decl) = &ret.program.body[2] else { unreachable!() };
+        assert_eq!(decl.import_kind, ImportOrExportKind::Typeof);
+
+        // TypeScript only syntax, and Flow syntax which cannot be represented
+        let sources = [
+            ("enum E { A }", "Enum is TypeScript syntax, which is not allowed in Flow"),
+            ("x satisfies T;", "`satisfies` is TypeScript syntax, which is not allowed in Flow"),
+            ("namespace N {}", "Namespace is TypeScript syntax, which is not allowed in Flow"),
+            ("x!.y;", "Non-null assertion is TypeScript syntax, which is not allowed in Flow"),
+            (
+                "class C { private x: T }",
+                "`private` modifier is TypeScript syntax, which is not allowed in Flow",
+            ),
+            (
+                "abstract class C {}",
+                "`abstract` modifier is TypeScript syntax, which is not allowed in Flow",
+            ),
+            // Only declarations may omit the type
+            ("opaque type ID: string;", "Expected `=` but found `;`"),
+        ];
+        for (source, message) in sources {
+            let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+            assert_eq!(ret.errors.len(), 1, "{source}: {:?}", ret.errors);
+            assert_eq!(ret.errors[0].to_string(), message, "{source}");
+        }
+        // Flow libdefs declare modules by name
+        let source = "declare module 'm' { declare var x: number; }";
+        let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+
+        // Flow syntax is an error without the option
+        let ret = Parser::new(&allocator, "// @flow\ntype T = ?string;", source_type).parse();
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.errors.first().unwrap().to_string(), "Flow is not supported");
+    }
+
+    #[test]
+    #[allow(clippy::similar_names)]
+    fn parse_json() {
+        let allocator = Allocator::default();
+        let json = SourceType::json();
+        let jsonc = SourceType::jsonc();
+        let json5 = SourceType::json5();
+        let cases = [
+            ("{}", true, true, true),
+            (
+                r#"{ "a": [1, -2.5e3, true, false, null], "b": { "c": "\u0041\n" } }"#,
+                true,
+                true,
+                true,
+            ),
+            ("  [0.5]\n", true, true, true),
+            ("// comment\n{ /* comment */ }", false, true, true),
+            (r#"{ "a": 1, }"#, false, true, true),
+            ("[1, 2,]", false, true, true),
+            ("{ a: 'b', 0x1F: null }", false, false, false),
+            ("{ a: 'b', c: 0x1F, d: .5, e: 5., f: +Infinity, g: -NaN }", false, false, true),
+            ("01", false, false, false),
+            ("1_000", false, false, false),
+            ("1n", false, false, false),
+            (r#""\x41""#, false, false, true),
+            ("\"\t\"", false, false, true),
+            ("[1,,2]", false, false, false),
+            ("{} {}", false, false, false),
+            ("undefined", false, false, false),
+            ("", false, false, false),
+        ];
+        for (source, is_json, is_jsonc, is_json5) in cases {
+            for (source_type, valid) in [(json, is_json), (jsonc, is_jsonc), (json5, is_json5)] {
+                let ret = Parser::new(&allocator, source, source_type).parse();
+                assert_eq!(
+                    ret.errors.is_empty(),
+                    valid,
+                    "{source} {source_type:?}: {:?}",
+                    ret.errors
+                );
+            }
+        }
+
+        let source = "// deps\n{ \"dependencies\": { \"oxc\": \"1.0.0\" } }";
+        let ret = Parser::new(&allocator, source, jsonc).parse();
+        assert!(ret.program.directives.is_empty());
+        assert_eq!(ret.program.comments.len(), 1);
+        let Statement::ExpressionStatement(stmt) = &ret.program.body[0] else { unreachable!() };
+        let Expression::ObjectExpression(object) = &stmt.expression else { unreachable!() };
+        let ObjectPropertyKind::ObjectProperty(property) = &object.properties[0] else {
+            unreachable!()
+        };
+        assert_eq!(property.key.static_name().unwrap(), "dependencies");
+        assert_eq!(property.value.span().source_text(source), "{ \"oxc\": \"1.0.0\" }");
+
+        let ret = Parser::new(&allocator, "{ \"a\": 1, }", json).parse();
+        assert!(!ret.panicked);
+        assert_eq!(
+            ret.errors.first().unwrap().to_string(),
+            "Trailing commas are not permitted in JSON"
+        );
+    }
+
+    #[test]
+    fn max_nesting_depth() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::tsx();
+        let options = ParseOptions { max_nesting_depth: 50, ..ParseOptions::default() };
+        let nested = |open: &str, inner: &str, close: &str, depth: usize| {
+            format!("{}{inner}{}", open.repeat(depth), close.repeat(depth))
+        };
+        let cases = [
+            ("x = ", "", ";", "[", "]"),
+            ("x = ", "y", ";", "(", ")"),
+            ("x = ", "y", ";", "!", ""),
+            ("x = ", "y", ";", "await ", ""),
+            ("x = ", "y", ";", "y = ", ""),
+            ("x = ", "y", ";", "a ? b : ", ""),
+            ("x = ", "0", ";", "{ a: ", " }"),
+            ("", "", "", "{", "}"),
+            ("", "", "", "if (x) ", ";"),
+            // A block body is on the same level as its statement
+            ("", "", "", "if (x) {", "}"),
+            ("", "", "", "while (x) {", "}"),
+            ("let ", "a", " = x;", "[", "]"),
+            ("type T = ", "A", ";", "Array<", ">"),
+            ("x = ", "", ";", "<a>", "</a>"),
+            ("x = ", "X", ";", "new ", ""),
+            ("x = ", "y", ";", "`${", "}`"),
+            ("type T = ", "A", ";", "keyof ", ""),
+        ];
+        for (prefix, inner, suffix, open, close) in cases {
+            let source = format!("{prefix}{}{suffix}", nested(open, inner, close, 20));
+            let ret = Parser::new(&allocator, &source, source_type).with_options(options).parse();
+            assert!(ret.errors.is_empty(), "{source}: {:?}", ret.errors);
+
+            // Deep enough to overflow the stack without the limit
+            let source = format!("{prefix}{}{suffix}", nested(open, inner, close, 100_000));
+            let ret = Parser::new(&allocator, &source, source_type).with_options(options).parse();
+            assert!(ret.panicked, "{prefix}{open}");
+            assert_eq!(ret.errors.len(), 1);
+            assert_eq!(
+                ret.errors.first().unwrap().to_string(),
+                "Maximum nesting depth of 50 exceeded"
+            );
+        }
+    }
+
+    #[test]
+    fn default_max_nesting_depth() {
+        let nested = |open: &str, inner: &str, close: &str, depth: usize| {
+            format!("{}{inner}{}", open.repeat(depth), close.repeat(depth))
+        };
+        // The cases which take the most stack space per nesting level
+        let cases = [
+            ("x = ", "", ";", "[", "]"),
+            ("x = ", "y", ";", "(", ")"),
+            ("x = ", "0", ";", "{ a: ", " }"),
+            ("x = ", "y", ";", "`${", "}`"),
+            ("x = ", "y", ";", "(class { m() {", "} })"),
+            ("", "", "", "function f() {", "}"),
+            ("let ", "a", " = x;", "[", "]"),
+            ("type T = ", "A", ";", "Array<", ">"),
+            ("type T = ", "A", ";", "[", "]"),
+            ("type T = ", "A", ";", "{ a: ", " }"),
+            ("type T = ", "A", ";", "(A | ", ")"),
+        ];
+        // Run on a thread with the default stack size of spawned threads,
+        // the test passes if the stack does not overflow.
+        std::thread::Builder::new()
+            .stack_size(2 * 1024 * 1024)
+            .spawn(move || {
+                let allocator = Allocator::default();
+                let source_type = SourceType::tsx();
+                for (prefix, inner, suffix, open, close) in cases {
+                    let source = format!("{prefix}{}{suffix}", nested(open, inner, close, 10_000));
+                    let ret = Parser::new(&allocator, &source, source_type).parse();
+                    assert!(ret.panicked, "{prefix}{open}");
+                    assert_eq!(
+                        ret.errors.first().unwrap().to_string(),
+                        "Maximum nesting depth of 256 exceeded"
+                    );
+                }
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn recover_from_errors() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let options = ParseOptions { recover_from_errors: true, ..ParseOptions::default() };
+
+        let source = "let a = 1;\nlet = = 2;\nfunction f() {\n  foo(;\n  bar();\n}\nconst b = 3;";
+        let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+        assert!(!ret.panicked);
+        assert_eq!(ret.errors.len(), 2, "{:?}", ret.errors);
+        let body = &ret.program.body;
+        assert_eq!(body.len(), 4);
+        assert!(matches!(body[0], Statement::VariableDeclaration(_)));
+        let Statement::ErrorStatement(error) = &body[1] else { panic!("{body:?}") };
+        assert_eq!(error.span.source_text(source), "let = = 2;");
+        let Statement::FunctionDeclaration(func) = &body[2] else { panic!("{body:?}") };
+        let statements = &func.body.as_ref().unwrap().statements;
+        assert_eq!(statements.len(), 2);
+        assert!(matches!(statements[0], Statement::ErrorStatement(_)));
+        assert!(matches!(statements[1], Statement::ExpressionStatement(_)));
+        assert!(matches!(body[3], Statement::VariableDeclaration(_)));
+
+        // Missing `}` at end of file
+        let source = "function f() {\n  if (x) {\n    foo();";
+        let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+        assert!(!ret.panicked);
+        assert_eq!(ret.errors.len(), 2);
+        assert!(matches!(ret.program.body[0], Statement::FunctionDeclaration(_)));
+
+        // Errors are fatal without recovery
+        let ret = Parser::new(&allocator, "let = = 2;", source_type).parse();
+        assert!(ret.panicked);
+    }
+
     #[test]
     fn memory_leak() {
         let allocator = Allocator::default();
@@ -302,6 +302,7 @@ impl<'a> ParserImpl<'a> {
             self.bump_any();
             let modifier = self.modifier(kind, self.end_span(span))?;
             self.check_for_duplicate_modifiers(flags, &modifier);
+            self.check_flow_modifier(&modifier);
             flags.set(modifier_flags, true);
             modifiers.push(modifier);
         }
@@ -376,6 +377,7 @@ impl<'a> ParserImpl<'a> {
                 has_seen_static_modifier = true;
             }
             self.check_for_duplicate_modifiers(modifier_flags, &modifier);
+            self.check_flow_modifier(&modifier);
             modifier_flags.set(modifier.kind.into(), true);
             modifiers.push(modifier);
             has_leading_modifier = true;
@@ -397,6 +399,7 @@ impl<'a> ParserImpl<'a> {
                     has_seen_static_modifier = true;
                 }
                 self.check_for_duplicate_modifiers(modifier_flags, &modifier);
+                self.check_flow_modifier(&modifier);
                 modifier_flags.set(modifier.kind.into(), true);
                 modifiers.push(modifier);
             }
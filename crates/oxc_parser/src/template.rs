@@ -0,0 +1,910 @@
+//! Build AST from source text templates containing placeholders.
+//!
+//! A template is parsed once, and can then be instantiated any number of times, substituting
+//! placeholders with AST nodes.
+//!
+//! Placeholders are identifiers starting with `$`, e.g. `$expr`, `$name`, `$body`.
+//! A placeholder can be substituted with:
+//!
+//! * An [`Expression`], where the placeholder is used as an expression.
+//! * An identifier name, where the placeholder is used as an identifier, binding, or property key.
+//! * One or more [`Statement`]s, where the placeholder is used as an expression statement (`$body;`).
+//!
+//! ```ignore
+//! let template = Template::<Statement>::new(&allocator, "if ($test) { $body; }", SourceType::mjs())?;
+//! let stmt = template.instantiate([("test", test.into()), ("body", body.into())])?;
+//!
+//! // Or parse and instantiate in one go
+//! let expr: Expression = js!(&allocator, "$object.hasOwnProperty($key)", object = object, key = key);
+//! ```
+//!
+//! Nodes created from the template have empty spans, and do not have `ScopeId`s, `SymbolId`s
+//! or `ReferenceId`s. Substituted nodes are moved into the result unchanged. If a placeholder
+//! is used more than once, its other uses get clones of the value with `ScopeId`s, `SymbolId`s
+//! and `ReferenceId`s removed, so no IDs are shared between nodes.
+//!
+//! [`js!`](crate::js) and [`ts!`](crate::ts) parse their template once per call site
+//! (and thread), and reuse it afterwards.
+
+use std::cell::{Cell, OnceCell};
+
+use oxc_allocator::{Allocator, CloneIn, Vec};
+use oxc_ast::{ast::*, visit::walk_mut, AstBuilder, Visit, VisitMut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{Atom, ForEachSpanMut, SourceType, SPAN};
+use oxc_syntax::{
+    reference::ReferenceId,
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolId,
+};
+
+use crate::Parser;
+
+/// A value to substitute for a placeholder in a [`Template`].
+#[derive(Debug)]
+pub enum TemplateValue<'a> {
+    Expression(Expression<'a>),
+    Identifier(Atom<'a>),
+    Statement(Statement<'a>),
+    Statements(Vec<'a, Statement<'a>>),
+}
+
+impl<'a> From<Expression<'a>> for TemplateValue<'a> {
+    fn from(expr: Expression<'a>) -> Self {
+        Self::Expression(expr)
+    }
+}
+
+impl<'a> From<Atom<'a>> for TemplateValue<'a> {
+    fn from(name: Atom<'a>) -> Self {
+        Self::Identifier(name)
+    }
+}
+
+impl<'a> From<&'a str> for TemplateValue<'a> {
+    fn from(name: &'a str) -> Self {
+        Self::Identifier(Atom::from(name))
+    }
+}
+
+impl<'a> From<Statement<'a>> for TemplateValue<'a> {
+    fn from(stmt: Statement<'a>) -> Self {
+        Self::Statement(stmt)
+    }
+}
+
+impl<'a> From<Vec<'a, Statement<'a>>> for TemplateValue<'a> {
+    fn from(stmts: Vec<'a, Statement<'a>>) -> Self {
+        Self::Statements(stmts)
+    }
+}
+
+impl<'a> TemplateValue<'a> {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Expression(_) => "an expression",
+            Self::Identifier(_) => "an identifier",
+            Self::Statement(_) | Self::Statements(_) => "statements",
+        }
+    }
+
+    /// Clone value for another use of its placeholder, removing semantic IDs.
+    fn clone_without_ids(&self, allocator: &'a Allocator) -> Self {
+        let mut remover = SemanticIdRemover;
+        match self {
+            Self::Expression(expr) => {
+                let mut expr = expr.clone_in(allocator);
+                remover.visit_expression(&mut expr);
+                Self::Expression(expr)
+            }
+            Self::Identifier(name) => Self::Identifier(name.clone()),
+            Self::Statement(stmt) => {
+                let mut stmt = stmt.clone_in(allocator);
+                remover.visit_statement(&mut stmt);
+                Self::Statement(stmt)
+            }
+            Self::Statements(stmts) => {
+                let mut stmts = stmts.clone_in(allocator);
+                remover.visit_statements(&mut stmts);
+                Self::Statements(stmts)
+            }
+        }
+    }
+}
+
+/// AST node type which a [`Template`] can produce.
+pub trait TemplateNode<'a>: Sized + CloneIn<'a, Cloned = Self> {
+    /// Instantiate a template cached by [`js!`](crate::js) or [`ts!`](crate::ts).
+    #[doc(hidden)]
+    fn expand<'n, I>(
+        cache: &TemplateCache,
+        allocator: &'a Allocator,
+        source_text: &str,
+        source_type: SourceType,
+        values: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = (&'n str, TemplateValue<'a>)>;
+
+    /// Parse template source text.
+    ///
+    /// # Errors
+    /// Returns syntax errors, or an error if source text is not a single node of this type.
+    fn parse(
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        source_type: SourceType,
+    ) -> Result<Self, std::vec::Vec<OxcDiagnostic>>;
+
+    #[doc(hidden)]
+    fn visit<V: Visit<'a>>(&self, visitor: &mut V);
+
+    #[doc(hidden)]
+    fn visit_mut<V: VisitMut<'a>>(&mut self, visitor: &mut V);
+
+    #[doc(hidden)]
+    fn for_each_span_mut<F: FnMut(&mut oxc_span::Span)>(&mut self, f: &mut F);
+}
+
+impl<'a> TemplateNode<'a> for Expression<'a> {
+    fn expand<'n, I>(
+        cache: &TemplateCache,
+        allocator: &'a Allocator,
+        source_text: &str,
+        source_type: SourceType,
+        values: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = (&'n str, TemplateValue<'a>)>,
+    {
+        let clone = |node: &_, allocator| CloneIn::clone_in(node, allocator);
+        expand_cached(
+            &cache.expression,
+            cache.allocator,
+            clone,
+            allocator,
+            source_text,
+            source_type,
+            values,
+        )
+    }
+
+    fn parse(
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        source_type: SourceType,
+    ) -> Result<Self, std::vec::Vec<OxcDiagnostic>> {
+        Parser::new(allocator, source_text, source_type).parse_expression()
+    }
+
+    fn visit<V: Visit<'a>>(&self, visitor: &mut V) {
+        visitor.visit_expression(self);
+    }
+
+    fn visit_mut<V: VisitMut<'a>>(&mut self, visitor: &mut V) {
+        visitor.visit_expression(self);
+    }
+
+    fn for_each_span_mut<F: FnMut(&mut oxc_span::Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(self, f);
+    }
+}
+
+impl<'a> TemplateNode<'a> for Statement<'a> {
+    fn expand<'n, I>(
+        cache: &TemplateCache,
+        allocator: &'a Allocator,
+        source_text: &str,
+        source_type: SourceType,
+        values: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = (&'n str, TemplateValue<'a>)>,
+    {
+        let clone = |node: &_, allocator| CloneIn::clone_in(node, allocator);
+        expand_cached(
+            &cache.statement,
+            cache.allocator,
+            clone,
+            allocator,
+            source_text,
+            source_type,
+            values,
+        )
+    }
+
+    fn parse(
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        source_type: SourceType,
+    ) -> Result<Self, std::vec::Vec<OxcDiagnostic>> {
+        let mut stmts = Vec::parse(allocator, source_text, source_type)?;
+        if stmts.len() != 1 {
+            return Err(vec![OxcDiagnostic::error(format!(
+                "Template must contain a single statement, found {}",
+                stmts.len()
+            ))]);
+        }
+        Ok(stmts.pop().unwrap())
+    }
+
+    fn visit<V: Visit<'a>>(&self, visitor: &mut V) {
+        visitor.visit_statement(self);
+    }
+
+    fn visit_mut<V: VisitMut<'a>>(&mut self, visitor: &mut V) {
+        visitor.visit_statement(self);
+    }
+
+    fn for_each_span_mut<F: FnMut(&mut oxc_span::Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(self, f);
+    }
+}
+
+impl<'a> TemplateNode<'a> for Vec<'a, Statement<'a>> {
+    fn expand<'n, I>(
+        cache: &TemplateCache,
+        allocator: &'a Allocator,
+        source_text: &str,
+        source_type: SourceType,
+        values: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = (&'n str, TemplateValue<'a>)>,
+    {
+        let clone = |node: &_, allocator| CloneIn::clone_in(node, allocator);
+        expand_cached(
+            &cache.statements,
+            cache.allocator,
+            clone,
+            allocator,
+            source_text,
+            source_type,
+            values,
+        )
+    }
+
+    fn parse(
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        source_type: SourceType,
+    ) -> Result<Self, std::vec::Vec<OxcDiagnostic>> {
+        let ret = Parser::new(allocator, source_text, source_type).parse();
+        if !ret.errors.is_empty() {
+            return Err(ret.errors);
+        }
+        let program = ret.program;
+        if program.directives.is_empty() {
+            return Ok(program.body);
+        }
+        // `"foo";` at start of template is parsed as a directive
+        let ast = AstBuilder::new(allocator);
+        let mut stmts =
+            Vec::with_capacity_in(program.directives.len() + program.body.len(), allocator);
+        stmts.extend(program.directives.into_iter().map(|directive| {
+            let expr = Expression::StringLiteral(ast.alloc(directive.expression));
+            ast.statement_expression(directive.span, expr)
+        }));
+        stmts.extend(program.body);
+        Ok(stmts)
+    }
+
+    fn visit<V: Visit<'a>>(&self, visitor: &mut V) {
+        visitor.visit_statements(self);
+    }
+
+    fn visit_mut<V: VisitMut<'a>>(&mut self, visitor: &mut V) {
+        visitor.visit_statements(self);
+    }
+
+    fn for_each_span_mut<F: FnMut(&mut oxc_span::Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(self, f);
+    }
+}
+
+/// A parsed template, which can be instantiated into AST nodes of type `T`.
+///
+/// `T` is [`Expression`], [`Statement`], or `Vec<Statement>`.
+///
+/// See [module docs](self) for the template syntax.
+pub struct Template<'a, T: TemplateNode<'a>> {
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    node: T,
+    placeholders: std::vec::Vec<Atom<'a>>,
+    /// Number of uses of each placeholder in `placeholders`
+    uses: std::vec::Vec<usize>,
+}
+
+impl<'a, T: TemplateNode<'a>> Template<'a, T> {
+    /// Parse a template.
+    ///
+    /// # Errors
+    /// Returns syntax errors, or an error if template is not a single node of type `T`.
+    pub fn new(
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        source_type: SourceType,
+    ) -> Result<Self, std::vec::Vec<OxcDiagnostic>> {
+        let mut node = T::parse(allocator, source_text, source_type)?;
+        node.for_each_span_mut(&mut |span| *span = SPAN);
+
+        let mut collector = PlaceholderCollector::default();
+        node.visit(&mut collector);
+
+        Ok(Self {
+            allocator,
+            source_text,
+            node,
+            placeholders: collector.placeholders,
+            uses: collector.uses,
+        })
+    }
+
+    /// Names of placeholders in the template, without leading `$`, in order of first appearance.
+    pub fn placeholders(&self) -> &[Atom<'a>] {
+        &self.placeholders
+    }
+
+    /// Create AST nodes from the template, substituting placeholders with `values`.
+    ///
+    /// Names of `values` are placeholder names without the leading `$`.
+    ///
+    /// # Errors
+    /// Returns an error if a placeholder has no value, a value does not match a placeholder,
+    /// or a value is not valid in the position of the placeholder.
+    pub fn instantiate<'n, I>(&self, values: I) -> Result<T, OxcDiagnostic>
+    where
+        I: IntoIterator<Item = (&'n str, TemplateValue<'a>)>,
+    {
+        self.substitute(self.node.clone_in(self.allocator), self.allocator, values)
+    }
+
+    /// Substitute placeholders in `node`, a copy of the template in `allocator`.
+    fn substitute<'b, 'n, U, I>(
+        &self,
+        mut node: U,
+        allocator: &'b Allocator,
+        values: I,
+    ) -> Result<U, OxcDiagnostic>
+    where
+        U: TemplateNode<'b>,
+        I: IntoIterator<Item = (&'n str, TemplateValue<'b>)>,
+    {
+        let values = values.into_iter().collect::<std::vec::Vec<_>>();
+        for (name, _) in &values {
+            if !self.placeholders.iter().any(|placeholder| placeholder == name) {
+                return Err(OxcDiagnostic::error(format!("Template has no placeholder `${name}`")));
+            }
+        }
+        for placeholder in &self.placeholders {
+            if !values.iter().any(|(name, _)| placeholder == name) {
+                return Err(OxcDiagnostic::error(format!(
+                    "No value provided for template placeholder `${placeholder}`"
+                )));
+            }
+        }
+
+        let values = values
+            .into_iter()
+            .map(|(name, value)| {
+                let index = self.placeholders.iter().position(|placeholder| placeholder == name);
+                (name, Some(value), index.map_or(0, |index| self.uses[index]))
+            })
+            .collect();
+        let mut substitutor = Substitutor { allocator, values, error: None };
+        node.visit_mut(&mut substitutor);
+        match substitutor.error {
+            Some(error) => Err(error),
+            None => Ok(node),
+        }
+    }
+}
+
+/// Templates parsed by [`js!`](crate::js) and [`ts!`](crate::ts) at one call site.
+///
+/// Templates are not `Sync`, so each thread has its own cache. The allocator holding the
+/// templates lives as long as the program.
+#[doc(hidden)]
+pub struct TemplateCache {
+    allocator: &'static Allocator,
+    expression: OnceCell<Template<'static, Expression<'static>>>,
+    statement: OnceCell<Template<'static, Statement<'static>>>,
+    statements: OnceCell<Template<'static, Vec<'static, Statement<'static>>>>,
+}
+
+impl Default for TemplateCache {
+    fn default() -> Self {
+        Self {
+            allocator: Box::leak(Box::default()),
+            expression: OnceCell::new(),
+            statement: OnceCell::new(),
+            statements: OnceCell::new(),
+        }
+    }
+}
+
+fn parse_template<'a, T: TemplateNode<'a>>(
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    source_type: SourceType,
+) -> Template<'a, T> {
+    Template::<T>::new(allocator, source_text, source_type).unwrap_or_else(|errors| {
+        let messages = errors.iter().map(ToString::to_string).collect::<std::vec::Vec<_>>();
+        panic!("Invalid template `{source_text}`: {}", messages.join(", "))
+    })
+}
+
+/// Instantiate a template, parsing it if it is not in `cache` yet. Panics if the template or
+/// values are invalid.
+fn expand_cached<'a, 'n, S, T, C, I>(
+    cache: &OnceCell<Template<'static, S>>,
+    cache_allocator: &'static Allocator,
+    clone: C,
+    allocator: &'a Allocator,
+    source_text: &str,
+    source_type: SourceType,
+    values: I,
+) -> T
+where
+    S: TemplateNode<'static>,
+    T: TemplateNode<'a>,
+    C: Fn(&S, &'a Allocator) -> T,
+    I: IntoIterator<Item = (&'n str, TemplateValue<'a>)>,
+{
+    let template = cache.get_or_init(|| {
+        parse_template(cache_allocator, cache_allocator.alloc_str(source_text), source_type)
+    });
+    let result = if template.source_text == source_text {
+        template.substitute(clone(&template.node, allocator), allocator, values)
+    } else {
+        // Source text is not a literal, and differs between calls
+        parse_template::<T>(allocator, allocator.alloc_str(source_text), source_type)
+            .instantiate(values)
+    };
+    result.unwrap_or_else(|error| panic!("Invalid template `{source_text}`: {error}"))
+}
+
+/// Used by [`js!`](crate::js) and [`ts!`](crate::ts) macros.
+#[doc(hidden)]
+pub fn expand<'a, 'n, T, I>(
+    cache: &TemplateCache,
+    allocator: &'a Allocator,
+    source_text: &str,
+    source_type: SourceType,
+    values: I,
+) -> T
+where
+    T: TemplateNode<'a>,
+    I: IntoIterator<Item = (&'n str, TemplateValue<'a>)>,
+{
+    T::expand(cache, allocator, source_text, source_type, values)
+}
+
+/// Build AST from a JavaScript template, substituting placeholders.
+///
+/// Produces an [`Expression`], a [`Statement`], or `Vec<Statement>`, depending on the type
+/// expected. Panics if the template is invalid, or values don't match its placeholders.
+///
+/// ```ignore
+/// let expr: Expression = js!(&allocator, "$object[$key]()", object = object, key = key);
+/// let stmt: Statement = js!(&allocator, "let $name = $init;", name = "foo", init = init);
+/// ```
+///
+/// The template is parsed on first use, and reused by later calls from the same place.
+#[macro_export]
+macro_rules! js {
+    ($allocator:expr, $source_text:expr $(, $name:ident = $value:expr)* $(,)?) => {{
+        ::std::thread_local! {
+            static CACHE: $crate::__TemplateCache = $crate::__TemplateCache::default();
+        }
+        CACHE.with(|cache| {
+            $crate::__expand_template(
+                cache,
+                $allocator,
+                $source_text,
+                $crate::__SourceType::mjs(),
+                [$((stringify!($name), $crate::TemplateValue::from($value))),*],
+            )
+        })
+    }};
+}
+
+/// Build AST from a TypeScript template, substituting placeholders.
+///
+/// See [`js!`](crate::js).
+#[macro_export]
+macro_rules! ts {
+    ($allocator:expr, $source_text:expr $(, $name:ident = $value:expr)* $(,)?) => {{
+        ::std::thread_local! {
+            static CACHE: $crate::__TemplateCache = $crate::__TemplateCache::default();
+        }
+        CACHE.with(|cache| {
+            $crate::__expand_template(
+                cache,
+                $allocator,
+                $source_text,
+                $crate::__SourceType::ts(),
+                [$((stringify!($name), $crate::TemplateValue::from($value))),*],
+            )
+        })
+    }};
+}
+
+/// Get placeholder name (without `$`) if `name` is a placeholder.
+fn placeholder_name(name: &str) -> Option<&str> {
+    name.strip_prefix('$').filter(|name| !name.is_empty())
+}
+
+/// Get identifier name if statement is a placeholder (`$name;`).
+fn statement_placeholder<'s>(stmt: &'s Statement) -> Option<&'s str> {
+    match stmt {
+        Statement::ExpressionStatement(stmt) => match &stmt.expression {
+            Expression::Identifier(ident) if placeholder_name(&ident.name).is_some() => {
+                Some(ident.name.as_str())
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+struct PlaceholderCollector<'a> {
+    placeholders: std::vec::Vec<Atom<'a>>,
+    uses: std::vec::Vec<usize>,
+}
+
+impl<'a> PlaceholderCollector<'a> {
+    fn add(&mut self, name: &Atom<'a>) {
+        if let Some(name) = placeholder_name(name.as_str()) {
+            if let Some(index) =
+                self.placeholders.iter().position(|placeholder| placeholder == name)
+            {
+                self.uses[index] += 1;
+            } else {
+                self.placeholders.push(Atom::from(name));
+                self.uses.push(1);
+            }
+        }
+    }
+}
+
+impl<'a> Visit<'a> for PlaceholderCollector<'a> {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        self.add(&it.name);
+    }
+
+    fn visit_identifier_name(&mut self, it: &IdentifierName<'a>) {
+        self.add(&it.name);
+    }
+
+    fn visit_binding_identifier(&mut self, it: &BindingIdentifier<'a>) {
+        self.add(&it.name);
+    }
+
+    fn visit_label_identifier(&mut self, it: &LabelIdentifier<'a>) {
+        self.add(&it.name);
+    }
+
+    fn visit_jsx_identifier(&mut self, it: &JSXIdentifier<'a>) {
+        self.add(&it.name);
+    }
+}
+
+struct Substitutor<'a, 'n> {
+    allocator: &'a Allocator,
+    /// Placeholder names, values, and number of uses of the placeholder not substituted yet.
+    /// The value is moved into the last use, and cloned for the others.
+    values: std::vec::Vec<(&'n str, Option<TemplateValue<'a>>, usize)>,
+    error: Option<OxcDiagnostic>,
+}
+
+impl<'a, 'n> Substitutor<'a, 'n> {
+    fn value(&self, name: &str) -> Option<&TemplateValue<'a>> {
+        let name = placeholder_name(name)?;
+        self.values
+            .iter()
+            .find(|(value_name, _, _)| *value_name == name)
+            .and_then(|(_, value, _)| value.as_ref())
+    }
+
+    /// Get value for a use of placeholder `name`.
+    fn take_value(&mut self, name: &str) -> Option<TemplateValue<'a>> {
+        let name = placeholder_name(name)?;
+        let (_, value, remaining_uses) =
+            self.values.iter_mut().find(|(value_name, _, _)| *value_name == name)?;
+        *remaining_uses = remaining_uses.saturating_sub(1);
+        if *remaining_uses == 0 {
+            value.take()
+        } else {
+            value.as_ref().map(|value| value.clone_without_ids(self.allocator))
+        }
+    }
+
+    fn set_error(&mut self, name: &str, kind: &str, expected: &str) {
+        if self.error.is_none() {
+            self.error = Some(OxcDiagnostic::error(format!(
+                "Template placeholder `{name}` must be {expected}, but {kind} was provided"
+            )));
+        }
+    }
+
+    /// Substitute a placeholder which must be an identifier.
+    fn substitute_name(&mut self, name: &mut Atom<'a>) {
+        match self.value(name) {
+            Some(TemplateValue::Identifier(_)) => {
+                if let Some(TemplateValue::Identifier(value)) = self.take_value(name) {
+                    *name = value;
+                }
+            }
+            Some(value) => {
+                let kind = value.kind();
+                self.set_error(&name.clone(), kind, "an identifier");
+            }
+            None => {}
+        }
+    }
+
+    /// Get substitute statements if statement is a placeholder for statements.
+    fn substitute_statements(&mut self, stmt: &Statement<'a>) -> Option<Vec<'a, Statement<'a>>> {
+        let name = statement_placeholder(stmt)?;
+        match self.value(name)? {
+            TemplateValue::Statement(_) | TemplateValue::Statements(_) => {}
+            _ => return None,
+        }
+        match self.take_value(name)? {
+            TemplateValue::Statement(value) => Some(Vec::from_iter_in([value], self.allocator)),
+            TemplateValue::Statements(values) => Some(values),
+            _ => None,
+        }
+    }
+}
+
+impl<'a, 'n> VisitMut<'a> for Substitutor<'a, 'n> {
+    fn visit_statements(&mut self, it: &mut Vec<'a, Statement<'a>>) {
+        let stmts = std::mem::replace(it, Vec::with_capacity_in(it.len(), self.allocator));
+        for mut stmt in stmts {
+            if let Some(values) = self.substitute_statements(&stmt) {
+                it.extend(values);
+            } else {
+                self.visit_statement(&mut stmt);
+                it.push(stmt);
+            }
+        }
+    }
+
+    fn visit_statement(&mut self, it: &mut Statement<'a>) {
+        if let Some(name) = statement_placeholder(it) {
+            if let Some(TemplateValue::Statements(values)) = self.value(name) {
+                if values.len() != 1 {
+                    let name = name.to_string();
+                    self.set_error(&name, "multiple statements", "a single statement");
+                    return;
+                }
+            }
+        }
+        if let Some(mut values) = self.substitute_statements(it) {
+            *it = values.pop().unwrap();
+            return;
+        }
+        walk_mut::walk_statement(self, it);
+    }
+
+    fn visit_expression(&mut self, it: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = it {
+            match self.value(&ident.name) {
+                Some(TemplateValue::Expression(_)) => {
+                    let name = ident.name.clone();
+                    if let Some(TemplateValue::Expression(value)) = self.take_value(&name) {
+                        *it = value;
+                    }
+                }
+                Some(TemplateValue::Identifier(_)) => {
+                    let name = ident.name.clone();
+                    if let Some(TemplateValue::Identifier(value)) = self.take_value(&name) {
+                        ident.name = value;
+                    }
+                }
+                Some(value) => {
+                    let (name, kind) = (ident.name.clone(), value.kind());
+                    self.set_error(&name, kind, "an expression or identifier");
+                }
+                None => {}
+            }
+            return;
+        }
+        walk_mut::walk_expression(self, it);
+    }
+
+    fn visit_identifier_reference(&mut self, it: &mut IdentifierReference<'a>) {
+        self.substitute_name(&mut it.name);
+    }
+
+    fn visit_identifier_name(&mut self, it: &mut IdentifierName<'a>) {
+        self.substitute_name(&mut it.name);
+    }
+
+    fn visit_binding_identifier(&mut self, it: &mut BindingIdentifier<'a>) {
+        self.substitute_name(&mut it.name);
+    }
+
+    fn visit_label_identifier(&mut self, it: &mut LabelIdentifier<'a>) {
+        self.substitute_name(&mut it.name);
+    }
+
+    fn visit_jsx_identifier(&mut self, it: &mut JSXIdentifier<'a>) {
+        self.substitute_name(&mut it.name);
+    }
+}
+
+/// Removes `ScopeId`s, `SymbolId`s and `ReferenceId`s from cloned nodes.
+struct SemanticIdRemover;
+
+impl<'a> VisitMut<'a> for SemanticIdRemover {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        scope_id.set(None);
+    }
+
+    fn visit_binding_identifier(&mut self, it: &mut BindingIdentifier<'a>) {
+        it.symbol_id.set(None::<SymbolId>);
+    }
+
+    fn visit_identifier_reference(&mut self, it: &mut IdentifierReference<'a>) {
+        it.reference_id.set(None::<ReferenceId>);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::{Allocator, Vec};
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_index::Idx;
+    use oxc_span::{cmp::ContentEq, SourceType};
+    use oxc_syntax::{reference::ReferenceId, scope::ScopeId};
+
+    use super::{Template, TemplateNode, TemplateValue};
+
+    /// Parse source text without placeholders.
+    fn parse<'a, T: TemplateNode<'a>>(allocator: &'a Allocator, source_text: &'a str) -> T {
+        let source_type = SourceType::ts();
+        Template::<T>::new(allocator, source_text, source_type).unwrap().instantiate([]).unwrap()
+    }
+
+    #[test]
+    fn expression() {
+        let allocator = Allocator::default();
+        let object: Expression = parse(&allocator, "foo.bar");
+        let key: Expression = parse(&allocator, "'x'");
+        let expr: Expression =
+            js!(&allocator, "$object.hasOwnProperty($key)", object = object, key = key);
+        let expected: Expression = parse(&allocator, "foo.bar.hasOwnProperty('x')");
+        assert!(expr.content_eq(&expected));
+    }
+
+    #[test]
+    fn identifiers() {
+        let allocator = Allocator::default();
+        let stmt: Statement =
+            js!(&allocator, "let $name = $init.$prop;", name = "x", init = "y", prop = "z");
+        let expected: Statement = parse(&allocator, "let x = y.z;");
+        assert!(stmt.content_eq(&expected));
+
+        let stmt: Statement = ts!(&allocator, "let $name: $T = $name;", name = "x", T = "Foo");
+        let expected: Statement = parse(&allocator, "let x: Foo = x;");
+        assert!(stmt.content_eq(&expected));
+    }
+
+    #[test]
+    fn statements() {
+        let allocator = Allocator::default();
+        let body: Vec<Statement> = parse(&allocator, "a(); b();");
+        let stmt: Statement =
+            js!(&allocator, "function $f() { $body; return 1; }", f = "f", body = body);
+        let expected: Statement = parse(&allocator, "function f() { a(); b(); return 1; }");
+        assert!(stmt.content_eq(&expected));
+
+        // Single statement in statement position
+        let body: Statement = parse(&allocator, "a();");
+        let stmt: Statement = js!(&allocator, "if (x) $body", body = body);
+        let expected: Statement = parse(&allocator, "if (x) a();");
+        assert!(stmt.content_eq(&expected));
+
+        // Directive-like string at start of template
+        let body: Statement = parse(&allocator, "a();");
+        let stmts: Vec<Statement> = js!(&allocator, "'foo'; $body;", body = body);
+        assert_eq!(stmts.len(), 2);
+        assert!(matches!(
+            &stmts[0],
+            Statement::ExpressionStatement(stmt) if matches!(stmt.expression, Expression::StringLiteral(_))
+        ));
+    }
+
+    #[test]
+    fn reuse() {
+        let allocator = Allocator::default();
+        let template =
+            Template::<Expression>::new(&allocator, "$a + $a", SourceType::mjs()).unwrap();
+        assert_eq!(template.placeholders(), ["a"]);
+        for source_text in ["1", "x"] {
+            let value: Expression = parse(&allocator, source_text);
+            let expr = template.instantiate([("a", value.into())]).unwrap();
+            let expected_text = allocator.alloc_str(&format!("{source_text} + {source_text}"));
+            let expected: Expression = parse(&allocator, expected_text);
+            assert!(expr.content_eq(&expected));
+        }
+    }
+
+    #[test]
+    fn values_are_moved() {
+        let allocator = Allocator::default();
+        let value: Expression = parse(&allocator, "x");
+        let Expression::Identifier(ident) = &value else { unreachable!() };
+        ident.reference_id.set(Some(ReferenceId::from_usize(0)));
+        let expr: Expression = js!(&allocator, "$a + $a", a = value);
+        let Expression::BinaryExpression(binary) = &expr else { unreachable!() };
+        let reference_ids = [&binary.left, &binary.right].map(|expr| match expr {
+            Expression::Identifier(ident) => ident.reference_id.get(),
+            _ => unreachable!(),
+        });
+        // Value is moved into its last use, and other uses get clones without IDs
+        assert_eq!(reference_ids, [None, Some(ReferenceId::from_usize(0))]);
+
+        let body: Statement = parse(&allocator, "{ f(); }");
+        let Statement::BlockStatement(block) = &body else { unreachable!() };
+        block.scope_id.set(Some(ScopeId::from_usize(0)));
+        let stmts: Vec<Statement> = js!(&allocator, "$body; $body;", body = body);
+        let scope_ids = stmts
+            .iter()
+            .map(|stmt| match stmt {
+                Statement::BlockStatement(block) => block.scope_id.get(),
+                _ => unreachable!(),
+            })
+            .collect::<std::vec::Vec<_>>();
+        assert_eq!(scope_ids, [None, Some(ScopeId::from_usize(0))]);
+    }
+
+    #[test]
+    fn cached_templates() {
+        let allocator = Allocator::default();
+        // Template at the same call site is parsed once
+        for source_text in ["1", "x"] {
+            let value: Expression = parse(&allocator, source_text);
+            let expr: Expression = js!(&allocator, "$a + 1", a = value);
+            let expected_text = allocator.alloc_str(&format!("{source_text} + 1"));
+            let expected: Expression = parse(&allocator, expected_text);
+            assert!(expr.content_eq(&expected));
+        }
+
+        // Source text which differs between calls
+        for (source_text, expected_text) in [("$a + 1", "x + 1"), ("$a - 1", "x - 1")] {
+            let expr: Expression = js!(&allocator, source_text, a = "x");
+            let expected: Expression = parse(&allocator, expected_text);
+            assert!(expr.content_eq(&expected));
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let allocator = Allocator::default();
+        assert!(Template::<Expression>::new(&allocator, "$a +", SourceType::mjs()).is_err());
+        assert!(Template::<Statement>::new(&allocator, "a; b;", SourceType::mjs()).is_err());
+
+        let template =
+            Template::<Statement>::new(&allocator, "let $a = $b;", SourceType::mjs()).unwrap();
+        // Missing value
+        assert!(template.instantiate([("a", "x".into())]).is_err());
+        // Unknown placeholder
+        assert!(template
+            .instantiate([("a", "x".into()), ("b", "y".into()), ("c", "z".into())])
+            .is_err());
+        // Expression in binding position
+        let value: Expression = parse(&allocator, "1");
+        assert!(template.instantiate([("a", value.into()), ("b", "y".into())]).is_err());
+        // Statements in expression position
+        let value: Statement = parse(&allocator, "foo();");
+        assert!(template
+            .instantiate([("a", "x".into()), ("b", TemplateValue::Statement(value))])
+            .is_err());
+    }
+}
@@ -0,0 +1,241 @@
+//! Public token stream API.
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Comment, CommentKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{SourceType, Span};
+
+use crate::{
+    lexer::{Kind, Token},
+    Parser, ParserImpl,
+};
+
+/// Split source text into tokens, comments and whitespace.
+///
+/// Tokens are produced by running the parser, so context-dependent tokens are reported the way
+/// the parser interpreted them: `/` vs regular expressions, template literal parts, JSX text,
+/// and contextual keywords used as identifiers (reported as [`Kind::Ident`]).
+///
+/// On an unrecoverable syntax error, the rest of the source text is tokenized without parser
+/// context.
+///
+/// # Example
+///
+/// ```rust
+/// use oxc_parser::{tokenize, Kind, SyntaxKind};
+/// use oxc_span::SourceType;
+///
+/// let ret = tokenize("let x = /a/g; // comment", SourceType::default());
+/// let kinds = ret.tokens.iter().map(|token| token.kind).collect::<Vec<_>>();
+/// assert_eq!(kinds[0], SyntaxKind::Token(Kind::Let));
+/// assert_eq!(kinds[6], SyntaxKind::Token(Kind::RegExp));
+/// ```
+pub fn tokenize(source_text: &str, source_type: SourceType) -> TokenizeReturn {
+    let allocator = Allocator::default();
+    Parser::new(&allocator, source_text, source_type).tokenize()
+}
+
+/// Return value of [`tokenize`]
+#[derive(Debug)]
+pub struct TokenizeReturn {
+    /// Tokens and trivia, in source order.
+    /// Together they cover the whole source text without gaps.
+    pub tokens: Vec<SyntaxToken>,
+
+    /// Syntax errors encountered while tokenizing.
+    pub errors: Vec<OxcDiagnostic>,
+}
+
+/// A token or a piece of trivia in the source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyntaxToken {
+    pub kind: SyntaxKind,
+
+    /// Span of the source text, including comment delimiters.
+    pub span: Span,
+
+    /// True if an identifier, string or template token contains escape sequences.
+    pub escaped: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyntaxKind {
+    Token(Kind),
+    Comment(CommentKind),
+    /// Whitespace and line terminators
+    Whitespace,
+}
+
+impl SyntaxKind {
+    pub fn is_trivia(self) -> bool {
+        !matches!(self, Self::Token(_))
+    }
+}
+
+impl<'a> ParserImpl<'a> {
+    /// Entry point for [`tokenize`]
+    pub fn tokenize(mut self) -> TokenizeReturn {
+        self.tokens = Some(vec![]);
+        let result = self.parse_program();
+        let mut tokens = self.tokens.take().unwrap_or_default();
+        // The first recorded token is the empty token before the parser started.
+        if !tokens.is_empty() {
+            tokens.remove(0);
+        }
+
+        if let Err(error) = result {
+            self.error(error);
+            let end = tokens.last().map_or(0, |token| token.end);
+            self.lexer.seek(end, false);
+            loop {
+                let token = self.lexer.next_token();
+                if token.kind == Kind::Eof {
+                    break;
+                }
+                tokens.push(token);
+            }
+        }
+
+        let tokens = with_trivia(self.source_text, &tokens, &self.lexer.trivia_builder.comments);
+        let mut errors = self.lexer.errors;
+        errors.extend(self.errors);
+        TokenizeReturn { tokens, errors }
+    }
+}
+
+/// Merge `tokens` and `comments`, and fill the gaps between them with whitespace.
+#[allow(clippy::cast_possible_truncation)]
+fn with_trivia(source_text: &str, tokens: &[Token], comments: &[Comment]) -> Vec<SyntaxToken> {
+    let mut result = Vec::with_capacity(tokens.len() * 2);
+    let mut pos = 0;
+    let mut push = |result: &mut Vec<SyntaxToken>, kind: SyntaxKind, span: Span, escaped: bool| {
+        if span.start > pos {
+            let whitespace = Span::new(pos, span.start);
+            result.push(SyntaxToken {
+                kind: SyntaxKind::Whitespace,
+                span: whitespace,
+                escaped: false,
+            });
+        }
+        pos = span.end;
+        result.push(SyntaxToken { kind, span, escaped });
+    };
+
+    let mut comments = comments.iter().peekable();
+    for token in tokens {
+        while let Some(comment) = comments.next_if(|comment| comment.span.start < token.start) {
+            push(&mut result, SyntaxKind::Comment(comment.kind), comment_span(comment), false);
+        }
+        push(&mut result, SyntaxKind::Token(token.kind), token.span(), token.escaped());
+    }
+    for comment in comments {
+        push(&mut result, SyntaxKind::Comment(comment.kind), comment_span(comment), false);
+    }
+
+    let end = source_text.len() as u32;
+    if end > pos {
+        let whitespace = Span::new(pos, end);
+        result.push(SyntaxToken { kind: SyntaxKind::Whitespace, span: whitespace, escaped: false });
+    }
+    result
+}
+
+/// Span of a comment including its delimiters.
+fn comment_span(comment: &Comment) -> Span {
+    match comment.kind {
+        CommentKind::Line => Span::new(comment.span.start - 2, comment.span.end),
+        CommentKind::Block => Span::new(comment.span.start - 2, comment.span.end + 2),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_ast::ast::CommentKind;
+    use oxc_span::SourceType;
+
+    use super::{tokenize, SyntaxKind};
+    use crate::Kind;
+
+    fn kinds(source: &str, source_type: SourceType) -> Vec<SyntaxKind> {
+        let ret = tokenize(source, source_type);
+        // Tokens cover the whole source text
+        let mut pos = 0;
+        for token in &ret.tokens {
+            assert_eq!(token.span.start, pos, "{source}");
+            pos = token.span.end;
+        }
+        assert_eq!(pos as usize, source.len(), "{source}");
+        ret.tokens.into_iter().map(|token| token.kind).filter(|kind| !kind.is_trivia()).collect()
+    }
+
+    #[test]
+    fn context_dependent_tokens() {
+        let source_type = SourceType::default();
+        assert_eq!(
+            kinds("a / b; /c/g", source_type),
+            [Kind::Ident, Kind::Slash, Kind::Ident, Kind::Semicolon, Kind::RegExp]
+                .map(SyntaxKind::Token)
+        );
+        assert_eq!(
+            kinds("`a${b}c${d}e`", source_type),
+            [
+                Kind::TemplateHead,
+                Kind::Ident,
+                Kind::TemplateMiddle,
+                Kind::Ident,
+                Kind::TemplateTail
+            ]
+            .map(SyntaxKind::Token)
+        );
+        assert_eq!(
+            kinds("for (let of of x);", source_type)[3..5],
+            [Kind::Ident, Kind::Of].map(SyntaxKind::Token)
+        );
+        assert_eq!(
+            kinds("<div>text</div>", SourceType::jsx()),
+            [
+                Kind::LAngle,
+                Kind::Ident,
+                Kind::RAngle,
+                Kind::JSXText,
+                Kind::LAngle,
+                Kind::Slash,
+                Kind::Ident,
+                Kind::RAngle
+            ]
+            .map(SyntaxKind::Token)
+        );
+    }
+
+    #[test]
+    fn trivia() {
+        let source = "#!/usr/bin/env node\n/* a */ x // b\n";
+        let ret = tokenize(source, SourceType::default());
+        let tokens = ret
+            .tokens
+            .iter()
+            .map(|token| (token.kind, token.span.source_text(source)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            [
+                (SyntaxKind::Token(Kind::HashbangComment), "#!/usr/bin/env node"),
+                (SyntaxKind::Whitespace, "\n"),
+                (SyntaxKind::Comment(CommentKind::Block), "/* a */"),
+                (SyntaxKind::Whitespace, " "),
+                (SyntaxKind::Token(Kind::Ident), "x"),
+                (SyntaxKind::Whitespace, " "),
+                (SyntaxKind::Comment(CommentKind::Line), "// b"),
+                (SyntaxKind::Whitespace, "\n"),
+            ]
+        );
+    }
+
+    #[test]
+    fn syntax_error() {
+        let ret = tokenize("let = 1; foo(bar", SourceType::default());
+        assert!(!ret.errors.is_empty());
+        let kinds = ret.tokens.iter().filter(|token| !token.kind.is_trivia()).count();
+        assert_eq!(kinds, 7);
+    }
+}
@@ -24,6 +24,7 @@ impl<'a> ParserImpl<'a> {
         span: Span,
         modifiers: &Modifiers<'a>,
     ) -> Result<Declaration<'a>> {
+        self.check_not_flow("Enum", self.cur_token().span());
         self.bump_any(); // bump `enum`
         let id = self.parse_binding_identifier()?;
         self.expect(Kind::LCurly)?;
@@ -283,7 +284,7 @@ impl<'a> ParserImpl<'a> {
         self.expect(Kind::LCurly)?;
         let (directives, statements) =
             self.parse_directives_and_statements(/* is_top_level */ false)?;
-        self.expect(Kind::RCurly)?;
+        self.expect_statement_list_end()?;
         Ok(self.ast.alloc_ts_module_block(self.end_span(span), directives, statements))
     }
 
@@ -358,12 +359,17 @@ impl<'a> ParserImpl<'a> {
         match self.cur_kind() {
             Kind::Namespace => {
                 let kind = TSModuleDeclarationKind::Namespace;
+                self.check_not_flow("Namespace", self.cur_token().span());
                 self.bump_any();
                 self.parse_ts_namespace_or_module_declaration_body(start_span, kind, modifiers)
                     .map(Declaration::TSModuleDeclaration)
             }
             Kind::Module => {
                 let kind = TSModuleDeclarationKind::Module;
+                // Flow only has `declare module "name" {}`
+                if !self.peek_at(Kind::Str) {
+                    self.check_not_flow("Namespace", self.cur_token().span());
+                }
                 self.bump_any();
                 self.parse_ts_namespace_or_module_declaration_body(start_span, kind, modifiers)
                     .map(Declaration::TSModuleDeclaration)
@@ -393,6 +399,9 @@ impl<'a> ParserImpl<'a> {
                     modifiers,
                 )
                 .map(Declaration::VariableDeclaration),
+            _ if self.at_flow_opaque_type() => {
+                self.parse_flow_opaque_type_declaration(start_span, modifiers)
+            }
             _ if self.at_function_with_async() => {
                 let declare = modifiers.contains(ModifierKind::Declare);
                 if declare {
@@ -563,6 +572,9 @@ impl<'a> ParserImpl<'a> {
                 Kind::Static => {
                     self.bump_any();
                 }
+                _ if self.at_flow_opaque_type() => {
+                    return true;
+                }
                 _ => {
                     return false;
                 }
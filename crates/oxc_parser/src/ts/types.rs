@@ -13,6 +13,10 @@ use crate::{
 
 impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_ts_type(&mut self) -> Result<TSType<'a>> {
+        self.nested(Self::parse_ts_type_impl)
+    }
+
+    fn parse_ts_type_impl(&mut self) -> Result<TSType<'a>> {
         if self.is_start_of_function_type_or_constructor_type() {
             return self.parse_function_or_constructor_type();
         }
@@ -50,7 +54,7 @@ impl<'a> ParserImpl<'a> {
         Ok(ty)
     }
 
-    fn parse_function_or_constructor_type(&mut self) -> Result<TSType<'a>> {
+    pub(crate) fn parse_function_or_constructor_type(&mut self) -> Result<TSType<'a>> {
         let span = self.start_span();
         let r#abstract = self.eat(Kind::Abstract);
         let is_constructor_type = self.eat(Kind::New);
@@ -84,7 +88,7 @@ impl<'a> ParserImpl<'a> {
         })
     }
 
-    fn is_start_of_function_type_or_constructor_type(&mut self) -> bool {
+    pub(crate) fn is_start_of_function_type_or_constructor_type(&mut self) -> bool {
         if self.at(Kind::LAngle) {
             return true;
         }
@@ -179,6 +183,10 @@ impl<'a> ParserImpl<'a> {
             diagnostics::cannot_appear_on_a_type_parameter,
         );
 
+        // Flow variance: `+T` is covariant like `out T`, `-T` is contravariant like `in T`
+        let covariant = self.is_flow && self.eat(Kind::Plus);
+        let contravariant = self.is_flow && !covariant && self.eat(Kind::Minus);
+
         let name = self.parse_binding_identifier()?;
         let constraint = self.parse_ts_type_constraint()?;
         let default = self.parse_ts_default_type()?;
@@ -188,8 +196,8 @@ impl<'a> ParserImpl<'a> {
             name,
             constraint,
             default,
-            modifiers.contains(ModifierKind::In),
-            modifiers.contains(ModifierKind::Out),
+            modifiers.contains(ModifierKind::In) || contravariant,
+            modifiers.contains(ModifierKind::Out) || covariant,
             modifiers.contains(ModifierKind::Const),
         ))
     }
@@ -218,9 +226,9 @@ impl<'a> ParserImpl<'a> {
         let has_leading_operator = self.eat(kind);
         /* hasLeadingOperator && parseFunctionOrConstructorTypeToError(isUnionType) ||*/
         let mut ty = parse_constituent_type(self)?;
-        if self.at(kind) || has_leading_operator {
+        if (self.at(kind) && !self.at_flow_exact_object_type_end()) || has_leading_operator {
             let mut types = self.ast.vec1(ty);
-            while self.eat(kind) {
+            while !self.at_flow_exact_object_type_end() && self.eat(kind) {
                 types.push(
                     /*parseFunctionOrConstructorTypeToError(isUnionType) || */
                     parse_constituent_type(self)?,
@@ -236,8 +244,9 @@ impl<'a> ParserImpl<'a> {
         Ok(ty)
     }
 
-    fn parse_type_operator_or_higher(&mut self) -> Result<TSType<'a>> {
+    pub(crate) fn parse_type_operator_or_higher(&mut self) -> Result<TSType<'a>> {
         match self.cur_kind() {
+            Kind::Question if self.is_flow => self.parse_flow_maybe_type(),
             Kind::KeyOf => self.parse_type_operator(TSTypeOperatorOperator::Keyof),
             Kind::Unique => self.parse_type_operator(TSTypeOperatorOperator::Unique),
             Kind::Readonly => self.parse_type_operator(TSTypeOperatorOperator::Readonly),
@@ -253,7 +262,15 @@ impl<'a> ParserImpl<'a> {
     fn parse_type_operator(&mut self, operator: TSTypeOperatorOperator) -> Result<TSType<'a>> {
         let span = self.start_span();
         self.bump_any(); // bump operator
-        let type_annotation = self.parse_type_operator_or_higher()?;
+        let operator_span = self.end_span(span);
+        let type_annotation = self.nested(Self::parse_type_operator_or_higher)?;
+
+        if operator == TSTypeOperatorOperator::Readonly
+            && !matches!(type_annotation, TSType::TSArrayType(_) | TSType::TSTupleType(_))
+        {
+            self.error(diagnostics::readonly_in_array_or_tuple_type(operator_span));
+        }
+
         Ok(self.ast.ts_type_type_operator(self.end_span(span), operator, type_annotation))
     }
 
@@ -389,6 +406,7 @@ impl<'a> ParserImpl<'a> {
                     self.parse_type_query()
                 }
             }
+            Kind::LCurly if self.is_flow => self.parse_flow_object_type(),
             Kind::LCurly => {
                 if self.lookahead(Self::is_start_of_mapped_type) {
                     self.parse_mapped_type()
@@ -632,7 +650,7 @@ impl<'a> ParserImpl<'a> {
         let span = self.start_span();
         let member_list =
             self.parse_normal_list(Kind::LCurly, Kind::RCurly, Self::parse_ts_type_signature)?;
-        Ok(self.ast.ts_type_type_literal(self.end_span(span), member_list))
+        Ok(self.ast.ts_type_type_literal(self.end_span(span), member_list, false))
     }
 
     fn parse_type_query(&mut self) -> Result<TSType<'a>> {
@@ -668,7 +686,8 @@ impl<'a> ParserImpl<'a> {
     }
 
     fn parse_ts_type_constraint(&mut self) -> Result<Option<TSType<'a>>> {
-        if !self.at(Kind::Extends) {
+        // Flow: `<T: Bound>`
+        if !(self.at(Kind::Extends) || (self.is_flow && self.at(Kind::Colon))) {
             return Ok(None);
         }
         self.bump_any();
@@ -1330,6 +1349,7 @@ impl<'a> ParserImpl<'a> {
             #[allow(clippy::unnecessary_fallible_conversions)]
             if let Ok(kind) = ModifierKind::try_from(self.cur_kind()) {
                 let modifier = Modifier { kind, span: self.cur_token().span() };
+                self.check_flow_modifier(&modifier);
                 flags.set(kind.into(), true);
                 modifiers.push(modifier);
             } else {
@@ -127,6 +127,7 @@ impl<'a> Format<'a> for Statement<'a> {
             Self::TryStatement(stmt) => stmt.format(p),
             Self::WhileStatement(stmt) => stmt.format(p),
             Self::WithStatement(stmt) => stmt.format(p),
+            Self::ErrorStatement(stmt) => stmt.format(p),
             match_module_declaration!(Self) => self.to_module_declaration().format(p),
             match_declaration!(Self) => self.to_declaration().format(p),
         }
@@ -152,6 +153,12 @@ impl<'a> Format<'a> for EmptyStatement {
     }
 }
 
+impl<'a> Format<'a> for ErrorStatement {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        ss!(self.span.source_text(p.source_text))
+    }
+}
+
 impl<'a> Format<'a> for IfStatement<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         wrap!(p, self, IfStatement, {
@@ -1,9 +1,9 @@
 // Silence erroneous warnings from Rust Analyser for `#[derive(Tsify)]`
 #![allow(non_snake_case)]
 
-use oxc_allocator::{Box, CloneIn, Vec};
+use oxc_allocator::{BinaryCodec, Box, CloneIn, Vec};
 use oxc_ast_macros::ast;
-use oxc_span::{cmp::ContentEq, hash::ContentHash, Atom, GetSpan, Span};
+use oxc_span::{cmp::ContentEq, hash::ContentHash, Atom, ForEachSpanMut, GetSpan, Span};
 #[cfg(feature = "serialize")]
 use serde::Serialize;
 #[cfg(feature = "serialize")]
@@ -12,7 +12,7 @@ use tsify::Tsify;
 /// The root of the `PatternParser` result.
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct Pattern<'a> {
     #[serde(flatten)]
@@ -23,7 +23,7 @@ pub struct Pattern<'a> {
 /// Pile of [`Alternative`]s separated by `|`.
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct Disjunction<'a> {
     #[serde(flatten)]
@@ -34,7 +34,7 @@ pub struct Disjunction<'a> {
 /// Single unit of `|` separated alternatives.
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct Alternative<'a> {
     #[serde(flatten)]
@@ -45,7 +45,7 @@ pub struct Alternative<'a> {
 /// Single unit of [`Alternative`], containing various kinds.
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub enum Term<'a> {
     // Assertion, QuantifiableAssertion
@@ -89,7 +89,7 @@ impl<'a> GetSpan for Term<'a> {
 /// e.g. `^`, `$`, `\b`, `\B`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct BoundaryAssertion {
     pub span: Span,
@@ -98,7 +98,7 @@ pub struct BoundaryAssertion {
 
 #[ast]
 #[derive(Debug, Clone, PartialEq)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub enum BoundaryAssertionKind {
     Start = 0,
@@ -111,7 +111,7 @@ pub enum BoundaryAssertionKind {
 /// e.g. `(?=...)`, `(?!...)`, `(?<=...)`, `(?<!...)`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct LookAroundAssertion<'a> {
     #[serde(flatten)]
@@ -122,7 +122,7 @@ pub struct LookAroundAssertion<'a> {
 
 #[ast]
 #[derive(Debug, Clone, PartialEq)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub enum LookAroundAssertionKind {
     Lookahead = 0,
@@ -135,7 +135,7 @@ pub enum LookAroundAssertionKind {
 /// e.g. `a*`, `b+`, `c?`, `d{3}`, `e{4,}`, `f{5,6}`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct Quantifier<'a> {
     #[serde(flatten)]
@@ -150,7 +150,7 @@ pub struct Quantifier<'a> {
 /// Single character.
 #[ast]
 #[derive(Debug, Clone, Copy)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct Character {
     /// This will be invalid position when `UnicodeMode` is disabled and `value` is a surrogate pair.
@@ -163,7 +163,7 @@ pub struct Character {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub enum CharacterKind {
     ControlLetter = 0,
@@ -183,7 +183,7 @@ pub enum CharacterKind {
 /// e.g. `\d`, `\D`, `\s`, `\S`, `\w`, `\W`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct CharacterClassEscape {
     #[serde(flatten)]
@@ -193,7 +193,7 @@ pub struct CharacterClassEscape {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub enum CharacterClassEscapeKind {
     D = 0,
@@ -208,7 +208,7 @@ pub enum CharacterClassEscapeKind {
 /// e.g. `\p{ASCII}`, `\P{ASCII}`, `\p{sc=Hiragana}`, `\P{sc=Hiragana}`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct UnicodePropertyEscape<'a> {
     #[serde(flatten)]
@@ -223,7 +223,7 @@ pub struct UnicodePropertyEscape<'a> {
 /// The `.`.
 #[ast]
 #[derive(Debug, Clone, Copy)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct Dot {
     #[serde(flatten)]
@@ -234,7 +234,7 @@ pub struct Dot {
 /// e.g. `[a-z]`, `[^A-Z]`, `[abc]`, `[a&&b&&c]`, `[[a-z]--x--y]`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct CharacterClass<'a> {
     #[serde(flatten)]
@@ -250,7 +250,7 @@ pub struct CharacterClass<'a> {
 
 #[ast]
 #[derive(Debug, PartialEq)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub enum CharacterClassContentsKind {
     Union = 0,
@@ -262,7 +262,7 @@ pub enum CharacterClassContentsKind {
 
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub enum CharacterClassContents<'a> {
     CharacterClassRange(Box<'a, CharacterClassRange>) = 0,
@@ -293,7 +293,7 @@ impl<'a> GetSpan for CharacterClassContents<'a> {
 /// e.g. `a-z`, `A-Z`, `0-9`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct CharacterClassRange {
     #[serde(flatten)]
@@ -305,7 +305,7 @@ pub struct CharacterClassRange {
 /// `|` separated string of characters wrapped by `\q{}`.
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct ClassStringDisjunction<'a> {
     #[serde(flatten)]
@@ -318,7 +318,7 @@ pub struct ClassStringDisjunction<'a> {
 /// Single unit of [`ClassStringDisjunction`].
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct ClassString<'a> {
     #[serde(flatten)]
@@ -332,7 +332,7 @@ pub struct ClassString<'a> {
 /// e.g. `(...)`, `(?<name>...)`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct CapturingGroup<'a> {
     #[serde(flatten)]
@@ -346,7 +346,7 @@ pub struct CapturingGroup<'a> {
 /// e.g. `(?:...)`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct IgnoreGroup<'a> {
     #[serde(flatten)]
@@ -359,7 +359,7 @@ pub struct IgnoreGroup<'a> {
 /// e.g. `i` in `(?i:...)`, `-s` in `(?-s:...)`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct Modifiers {
     #[serde(flatten)]
@@ -371,7 +371,7 @@ pub struct Modifiers {
 /// Each part of modifier in [`Modifiers`].
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct Modifier {
     pub ignore_case: bool,
@@ -383,7 +383,7 @@ pub struct Modifier {
 /// e.g. `\1`, `\2`, `\3`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct IndexedReference {
     #[serde(flatten)]
@@ -395,7 +395,7 @@ pub struct IndexedReference {
 /// e.g. `\k<name>`
 #[ast]
 #[derive(Debug)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct NamedReference<'a> {
     #[serde(flatten)]
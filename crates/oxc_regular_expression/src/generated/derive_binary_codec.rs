@@ -0,0 +1,535 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `tasks/ast_tools/src/derives/binary_codec.rs`
+
+#![allow(clippy::match_same_arms, clippy::semicolon_if_nothing_returned)]
+
+use oxc_allocator::{BinaryCodec, DecodeError, Decoder, Encoder};
+
+#[allow(clippy::wildcard_imports)]
+use crate::ast::*;
+
+impl<'a> BinaryCodec<'a> for Pattern<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, body: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Disjunction<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, body: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Alternative<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, body: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Term<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::BoundaryAssertion(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::LookAroundAssertion(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Quantifier(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Character(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Dot(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::CharacterClassEscape(it) => {
+                encoder.write_u32(5u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::UnicodePropertyEscape(it) => {
+                encoder.write_u32(6u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::CharacterClass(it) => {
+                encoder.write_u32(7u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::CapturingGroup(it) => {
+                encoder.write_u32(8u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::IgnoreGroup(it) => {
+                encoder.write_u32(9u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::IndexedReference(it) => {
+                encoder.write_u32(10u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NamedReference(it) => {
+                encoder.write_u32(11u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::BoundaryAssertion(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::LookAroundAssertion(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::Quantifier(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::Character(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::Dot(BinaryCodec::decode(decoder)?)),
+            5u32 => Ok(Self::CharacterClassEscape(BinaryCodec::decode(decoder)?)),
+            6u32 => Ok(Self::UnicodePropertyEscape(BinaryCodec::decode(decoder)?)),
+            7u32 => Ok(Self::CharacterClass(BinaryCodec::decode(decoder)?)),
+            8u32 => Ok(Self::CapturingGroup(BinaryCodec::decode(decoder)?)),
+            9u32 => Ok(Self::IgnoreGroup(BinaryCodec::decode(decoder)?)),
+            10u32 => Ok(Self::IndexedReference(BinaryCodec::decode(decoder)?)),
+            11u32 => Ok(Self::NamedReference(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for BoundaryAssertion {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.kind, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, kind: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for BoundaryAssertionKind {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Start => encoder.write_u32(0u32),
+            Self::End => encoder.write_u32(1u32),
+            Self::Boundary => encoder.write_u32(2u32),
+            Self::NegativeBoundary => encoder.write_u32(3u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Start),
+            1u32 => Ok(Self::End),
+            2u32 => Ok(Self::Boundary),
+            3u32 => Ok(Self::NegativeBoundary),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for LookAroundAssertion<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.kind, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            kind: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for LookAroundAssertionKind {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Lookahead => encoder.write_u32(0u32),
+            Self::NegativeLookahead => encoder.write_u32(1u32),
+            Self::Lookbehind => encoder.write_u32(2u32),
+            Self::NegativeLookbehind => encoder.write_u32(3u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Lookahead),
+            1u32 => Ok(Self::NegativeLookahead),
+            2u32 => Ok(Self::Lookbehind),
+            3u32 => Ok(Self::NegativeLookbehind),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Quantifier<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.min, encoder);
+        BinaryCodec::encode(&self.max, encoder);
+        BinaryCodec::encode(&self.greedy, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            min: BinaryCodec::decode(decoder)?,
+            max: BinaryCodec::decode(decoder)?,
+            greedy: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Character {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.kind, encoder);
+        BinaryCodec::encode(&self.value, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            kind: BinaryCodec::decode(decoder)?,
+            value: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for CharacterKind {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::ControlLetter => encoder.write_u32(0u32),
+            Self::HexadecimalEscape => encoder.write_u32(1u32),
+            Self::Identifier => encoder.write_u32(2u32),
+            Self::Null => encoder.write_u32(3u32),
+            Self::Octal1 => encoder.write_u32(4u32),
+            Self::Octal2 => encoder.write_u32(5u32),
+            Self::Octal3 => encoder.write_u32(6u32),
+            Self::SingleEscape => encoder.write_u32(7u32),
+            Self::Symbol => encoder.write_u32(8u32),
+            Self::UnicodeEscape => encoder.write_u32(9u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::ControlLetter),
+            1u32 => Ok(Self::HexadecimalEscape),
+            2u32 => Ok(Self::Identifier),
+            3u32 => Ok(Self::Null),
+            4u32 => Ok(Self::Octal1),
+            5u32 => Ok(Self::Octal2),
+            6u32 => Ok(Self::Octal3),
+            7u32 => Ok(Self::SingleEscape),
+            8u32 => Ok(Self::Symbol),
+            9u32 => Ok(Self::UnicodeEscape),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for CharacterClassEscape {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.kind, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, kind: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for CharacterClassEscapeKind {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::D => encoder.write_u32(0u32),
+            Self::NegativeD => encoder.write_u32(1u32),
+            Self::S => encoder.write_u32(2u32),
+            Self::NegativeS => encoder.write_u32(3u32),
+            Self::W => encoder.write_u32(4u32),
+            Self::NegativeW => encoder.write_u32(5u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::D),
+            1u32 => Ok(Self::NegativeD),
+            2u32 => Ok(Self::S),
+            3u32 => Ok(Self::NegativeS),
+            4u32 => Ok(Self::W),
+            5u32 => Ok(Self::NegativeW),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for UnicodePropertyEscape<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.negative, encoder);
+        BinaryCodec::encode(&self.strings, encoder);
+        BinaryCodec::encode(&self.name, encoder);
+        BinaryCodec::encode(&self.value, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            negative: BinaryCodec::decode(decoder)?,
+            strings: BinaryCodec::decode(decoder)?,
+            name: BinaryCodec::decode(decoder)?,
+            value: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Dot {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for CharacterClass<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.negative, encoder);
+        BinaryCodec::encode(&self.strings, encoder);
+        BinaryCodec::encode(&self.kind, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            negative: BinaryCodec::decode(decoder)?,
+            strings: BinaryCodec::decode(decoder)?,
+            kind: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for CharacterClassContentsKind {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Union => encoder.write_u32(0u32),
+            Self::Intersection => encoder.write_u32(1u32),
+            Self::Subtraction => encoder.write_u32(2u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Union),
+            1u32 => Ok(Self::Intersection),
+            2u32 => Ok(Self::Subtraction),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for CharacterClassContents<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::CharacterClassRange(it) => {
+                encoder.write_u32(0u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::CharacterClassEscape(it) => {
+                encoder.write_u32(1u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::UnicodePropertyEscape(it) => {
+                encoder.write_u32(2u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::Character(it) => {
+                encoder.write_u32(3u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::NestedCharacterClass(it) => {
+                encoder.write_u32(4u32);
+                BinaryCodec::encode(it, encoder);
+            }
+            Self::ClassStringDisjunction(it) => {
+                encoder.write_u32(5u32);
+                BinaryCodec::encode(it, encoder);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::CharacterClassRange(BinaryCodec::decode(decoder)?)),
+            1u32 => Ok(Self::CharacterClassEscape(BinaryCodec::decode(decoder)?)),
+            2u32 => Ok(Self::UnicodePropertyEscape(BinaryCodec::decode(decoder)?)),
+            3u32 => Ok(Self::Character(BinaryCodec::decode(decoder)?)),
+            4u32 => Ok(Self::NestedCharacterClass(BinaryCodec::decode(decoder)?)),
+            5u32 => Ok(Self::ClassStringDisjunction(BinaryCodec::decode(decoder)?)),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for CharacterClassRange {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.min, encoder);
+        BinaryCodec::encode(&self.max, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            min: BinaryCodec::decode(decoder)?,
+            max: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ClassStringDisjunction<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.strings, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            strings: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for ClassString<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.strings, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            strings: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for CapturingGroup<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.name, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            name: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for IgnoreGroup<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.modifiers, encoder);
+        BinaryCodec::encode(&self.body, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            modifiers: BinaryCodec::decode(decoder)?,
+            body: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Modifiers {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.enabling, encoder);
+        BinaryCodec::encode(&self.disabling, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            span: BinaryCodec::decode(decoder)?,
+            enabling: BinaryCodec::decode(decoder)?,
+            disabling: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for Modifier {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.ignore_case, encoder);
+        BinaryCodec::encode(&self.multiline, encoder);
+        BinaryCodec::encode(&self.sticky, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            ignore_case: BinaryCodec::decode(decoder)?,
+            multiline: BinaryCodec::decode(decoder)?,
+            sticky: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for IndexedReference {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.index, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, index: BinaryCodec::decode(decoder)? })
+    }
+}
+
+impl<'a> BinaryCodec<'a> for NamedReference<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        BinaryCodec::encode(&self.span, encoder);
+        BinaryCodec::encode(&self.name, encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self { span: BinaryCodec::decode(decoder)?, name: BinaryCodec::decode(decoder)? })
+    }
+}
@@ -0,0 +1,218 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `tasks/ast_tools/src/derives/for_each_span_mut.rs`
+
+#![allow(clippy::match_same_arms, clippy::semicolon_if_nothing_returned)]
+
+use oxc_span::{ForEachSpanMut, Span};
+
+#[allow(clippy::wildcard_imports)]
+use crate::ast::*;
+
+impl<'a> ForEachSpanMut for Pattern<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for Disjunction<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for Alternative<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for Term<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::BoundaryAssertion(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::LookAroundAssertion(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Quantifier(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Character(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Dot(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::CharacterClassEscape(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::UnicodePropertyEscape(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::CharacterClass(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::CapturingGroup(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::IgnoreGroup(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::IndexedReference(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NamedReference(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl ForEachSpanMut for BoundaryAssertion {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.kind, f);
+    }
+}
+
+impl ForEachSpanMut for BoundaryAssertionKind {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl<'a> ForEachSpanMut for LookAroundAssertion<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.kind, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl ForEachSpanMut for LookAroundAssertionKind {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl<'a> ForEachSpanMut for Quantifier<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.min, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.max, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.greedy, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl ForEachSpanMut for Character {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.kind, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.value, f);
+    }
+}
+
+impl ForEachSpanMut for CharacterKind {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl ForEachSpanMut for CharacterClassEscape {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.kind, f);
+    }
+}
+
+impl ForEachSpanMut for CharacterClassEscapeKind {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl<'a> ForEachSpanMut for UnicodePropertyEscape<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.negative, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.strings, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.name, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.value, f);
+    }
+}
+
+impl ForEachSpanMut for Dot {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for CharacterClass<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.negative, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.strings, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.kind, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl ForEachSpanMut for CharacterClassContentsKind {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl<'a> ForEachSpanMut for CharacterClassContents<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        match self {
+            Self::CharacterClassRange(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::CharacterClassEscape(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::UnicodePropertyEscape(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::Character(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::NestedCharacterClass(it) => ForEachSpanMut::for_each_span_mut(it, f),
+            Self::ClassStringDisjunction(it) => ForEachSpanMut::for_each_span_mut(it, f),
+        }
+    }
+}
+
+impl ForEachSpanMut for CharacterClassRange {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.min, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.max, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ClassStringDisjunction<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.strings, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for ClassString<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.strings, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for CapturingGroup<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.name, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for IgnoreGroup<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.modifiers, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.body, f);
+    }
+}
+
+impl ForEachSpanMut for Modifiers {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.enabling, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.disabling, f);
+    }
+}
+
+impl ForEachSpanMut for Modifier {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.ignore_case, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.multiline, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.sticky, f);
+    }
+}
+
+impl ForEachSpanMut for IndexedReference {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.index, f);
+    }
+}
+
+impl<'a> ForEachSpanMut for NamedReference<'a> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        ForEachSpanMut::for_each_span_mut(&mut self.span, f);
+        ForEachSpanMut::for_each_span_mut(&mut self.name, f);
+    }
+}
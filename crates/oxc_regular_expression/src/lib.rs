@@ -7,9 +7,12 @@ mod parser;
 mod surrogate_pair;
 
 mod generated {
+    #[cfg(feature = "binary_codec")]
+    mod derive_binary_codec;
     mod derive_clone_in;
     mod derive_content_eq;
     mod derive_content_hash;
+    mod derive_for_each_span_mut;
 }
 
 pub mod ast;
@@ -257,7 +257,9 @@ impl<'a> Binder<'a> for FormalParameter<'a> {
             builder.strict_mode() ||
             // FormalParameters : FormalParameterList
             // * It is a Syntax Error if IsSimpleParameterList of FormalParameterList is false and BoundNames of FormalParameterList contains any duplicate elements.
-            !parameters.is_simple_parameter_list();
+            !parameters.is_simple_parameter_list() ||
+            // MethodDefinition : ClassElementName ( UniqueFormalParameters ) { FunctionBody }
+            is_method_parameters(builder);
 
         let excludes = if is_not_allowed_duplicate_parameters {
             SymbolFlags::FunctionScopedVariable | SymbolFlags::FunctionScopedVariableExcludes
@@ -272,6 +274,19 @@ impl<'a> Binder<'a> for FormalParameter<'a> {
     }
 }
 
+/// Whether the current `FormalParameter` belongs to a class or object method, including
+/// getters and setters, whose parameters are `UniqueFormalParameters`.
+fn is_method_parameters(builder: &SemanticBuilder) -> bool {
+    let nodes = &builder.nodes;
+    let Some(params_id) = nodes.parent_id(builder.current_node_id) else { return false };
+    let Some(function_id) = nodes.parent_id(params_id) else { return false };
+    match nodes.parent_kind(function_id) {
+        Some(AstKind::MethodDefinition(_)) => true,
+        Some(AstKind::ObjectProperty(prop)) => prop.method || prop.kind.is_accessor(),
+        _ => false,
+    }
+}
+
 impl<'a> Binder<'a> for CatchParameter<'a> {
     fn bind(&self, builder: &mut SemanticBuilder) {
         let current_scope_id = builder.current_scope_id;
@@ -9,20 +9,25 @@ use std::{
 use rustc_hash::FxHashMap;
 
 #[allow(clippy::wildcard_imports)]
-use oxc_ast::{ast::*, AstKind, Visit};
+use oxc_ast::{
+    ast::*,
+    visit::walk::{walk_expression, walk_statement},
+    AstKind, Visit,
+};
 use oxc_cfg::{
     ControlFlowGraphBuilder, CtxCursor, CtxFlags, EdgeType, ErrorEdgeKind,
-    IterationInstructionKind, ReturnInstructionKind,
+    IterationInstructionKind, ReturnInstructionKind, SuspendInstructionKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_span::{Atom, CompactStr, SourceType, Span};
+use oxc_span::{Atom, CompactStr, GetSpan, SourceType, Span};
 use oxc_syntax::{module_record::ModuleRecord, operator::AssignmentOperator};
 
 use crate::{
     binder::Binder,
+    call_graph::CallGraph,
     checker,
     class::ClassTableBuilder,
-    diagnostics::redeclaration,
+    diagnostics::{max_nesting_depth_exceeded, redeclaration},
     jsdoc::JSDocBuilder,
     label::UnusedLabels,
     module_record::ModuleRecordBuilder,
@@ -45,6 +50,9 @@ macro_rules! control_flow {
     };
 }
 
+/// Default for [`SemanticBuilder::with_max_nesting_depth`]
+pub(crate) const DEFAULT_MAX_NESTING_DEPTH: u32 = 5000;
+
 /// Semantic Builder
 ///
 /// Traverses a parsed AST and builds a [`Semantic`] representation of the
@@ -106,9 +114,16 @@ pub struct SemanticBuilder<'a> {
 
     pub(crate) cfg: Option<ControlFlowGraphBuilder<'a>>,
 
+    build_call_graph: bool,
+
     pub(crate) class_table_builder: ClassTableBuilder,
 
     ast_node_records: Vec<NodeId>,
+
+    /// Current nesting depth of statements and expressions
+    depth: u32,
+    max_nesting_depth: u32,
+    nesting_depth_exceeded: bool,
 }
 
 /// Data returned by [`SemanticBuilder::build`].
@@ -152,8 +167,12 @@ impl<'a> SemanticBuilder<'a> {
             excess_capacity: 0.0,
             check_syntax_error: false,
             cfg: None,
+            build_call_graph: false,
             class_table_builder: ClassTableBuilder::new(),
             ast_node_records: Vec::new(),
+            depth: 0,
+            max_nesting_depth: DEFAULT_MAX_NESTING_DEPTH,
+            nesting_depth_exceeded: false,
         }
     }
 
@@ -186,12 +205,34 @@ impl<'a> SemanticBuilder<'a> {
         self
     }
 
+    /// Enable or disable building a [`CallGraph`].
+    ///
+    /// [`CallGraph`]: crate::CallGraph
+    #[must_use]
+    pub fn with_call_graph(mut self, yes: bool) -> Self {
+        self.build_call_graph = yes;
+        self
+    }
+
     #[must_use]
     pub fn with_scope_tree_child_ids(mut self, yes: bool) -> Self {
         self.scope.build_child_ids = yes;
         self
     }
 
+    /// Set the maximum nesting depth of statements and expressions.
+    ///
+    /// Semantic analysis visits the AST recursively, so a deeply nested AST can overflow the stack.
+    /// Statements and expressions nested deeper than this limit are skipped, and an error is
+    /// reported instead.
+    ///
+    /// By default, this is `5000`.
+    #[must_use]
+    pub fn with_max_nesting_depth(mut self, max_nesting_depth: u32) -> Self {
+        self.max_nesting_depth = max_nesting_depth;
+        self
+    }
+
     /// Provide statistics about AST to optimize memory usage of semantic analysis.
     ///
     /// Accurate statistics can greatly improve performance, especially for large ASTs.
@@ -270,7 +311,7 @@ impl<'a> SemanticBuilder<'a> {
             let (stats, check_stats) = if let Some(stats) = self.stats {
                 (stats, None)
             } else {
-                let stats = Stats::count(program);
+                let stats = Stats::count_with_max_nesting_depth(program, self.max_nesting_depth);
                 let stats_with_excess = stats.increase_by(self.excess_capacity);
                 (stats_with_excess, Some(stats))
             };
@@ -312,6 +353,9 @@ impl<'a> SemanticBuilder<'a> {
 
         let jsdoc = if self.build_jsdoc { self.jsdoc.build() } else { JSDocFinder::default() };
 
+        let call_graph =
+            self.build_call_graph.then(|| CallGraph::new(&self.nodes, &self.scope, &self.symbols));
+
         let semantic = Semantic {
             source_text: self.source_text,
             source_type: self.source_type,
@@ -325,6 +369,7 @@ impl<'a> SemanticBuilder<'a> {
             jsdoc,
             unused_labels: self.unused_labels.labels,
             cfg: self.cfg.map(ControlFlowGraphBuilder::build),
+            call_graph,
         };
         SemanticBuilderReturn { semantic, errors: self.errors.into_inner() }
     }
@@ -334,6 +379,23 @@ impl<'a> SemanticBuilder<'a> {
         self.errors.borrow_mut().push(error);
     }
 
+    /// Enter a nested statement or expression.
+    ///
+    /// Returns `false` if [`SemanticBuilder::with_max_nesting_depth`] is exceeded,
+    /// in which case the node must be skipped without decrementing the depth.
+    fn enter_nesting(&mut self, span: Span) -> bool {
+        if self.depth >= self.max_nesting_depth {
+            // Only report the first node, its siblings are usually skipped as well.
+            if !self.nesting_depth_exceeded {
+                self.nesting_depth_exceeded = true;
+                self.error(max_nesting_depth_exceeded(self.max_nesting_depth, span));
+            }
+            return false;
+        }
+        self.depth += 1;
+        true
+    }
+
     fn create_ast_node(&mut self, kind: AstKind<'a>) {
         let mut flags = self.current_node_flags;
         if self.build_jsdoc && self.jsdoc.retrieve_attached_jsdoc(&kind) {
@@ -638,6 +700,20 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         self.pop_ast_node();
     }
 
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        if self.enter_nesting(stmt.span()) {
+            walk_statement(self, stmt);
+            self.depth -= 1;
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if self.enter_nesting(expr.span()) {
+            walk_expression(self, expr);
+            self.depth -= 1;
+        }
+    }
+
     fn visit_program(&mut self, program: &Program<'a>) {
         let kind = AstKind::Program(self.alloc(program));
         /* cfg */
@@ -1519,11 +1595,23 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
                 }
             }
             if let Some(finally_block_end_ix) = finally_block_end_ix {
-                if catch_block_end_ix.is_some() {
+                let Some(before_finalizer_graph_ix) = before_finalizer_graph_ix else {
+                    unreachable!("we always create a finalizer when there is a finally block.");
+                };
+                // The finally block also runs after the try and catch blocks complete normally.
+                cfg.add_edge(after_try_block_graph_ix, before_finalizer_graph_ix, EdgeType::Normal);
+                if let Some(catch_block_end_ix) = catch_block_end_ix {
+                    cfg.add_edge(catch_block_end_ix, before_finalizer_graph_ix, EdgeType::Normal);
                     cfg.add_edge(
                         finally_block_end_ix,
                         after_try_statement_block_ix,
-                        EdgeType::Normal,
+                        if cfg.basic_block(after_try_block_graph_ix).is_unreachable()
+                            && cfg.basic_block(catch_block_end_ix).is_unreachable()
+                        {
+                            EdgeType::Unreachable
+                        } else {
+                            EdgeType::Normal
+                        },
                     );
                 } else {
                     cfg.add_edge(
@@ -2021,6 +2109,25 @@ impl<'a> SemanticBuilder<'a> {
                 }
             },
             AstKind::LabeledStatement(_) => self.unused_labels.mark_unused(self.current_node_id),
+            AstKind::CallExpression(_)
+            | AstKind::NewExpression(_)
+            | AstKind::TaggedTemplateExpression(_) => {
+                // A call may throw, so the code after it must not be part of the same block
+                // when the exception can be caught.
+                control_flow!(self, |cfg| {
+                    if cfg.is_in_try() {
+                        cfg.append_throw_point();
+                    }
+                });
+            }
+            AstKind::YieldExpression(_) => {
+                let node_id = self.current_node_id;
+                control_flow!(self, |cfg| cfg.append_suspend(node_id, SuspendInstructionKind::Yield));
+            }
+            AstKind::AwaitExpression(_) => {
+                let node_id = self.current_node_id;
+                control_flow!(self, |cfg| cfg.append_suspend(node_id, SuspendInstructionKind::Await));
+            }
             _ => {}
         }
     }
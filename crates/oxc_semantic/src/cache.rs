@@ -0,0 +1,48 @@
+//! Binary cache format for ASTs together with their [`SymbolTable`] and [`ScopeTree`].
+//!
+//! See [`oxc_ast::cache`] for details of the format.
+//!
+//! Symbol, scope and reference IDs stored in the AST remain valid after decoding.
+//! [`NodeId`]s stored in the symbol table and scope tree refer to [`AstNodes`], which are not
+//! cached. They are only valid for [`AstNodes`] built by running [`SemanticBuilder`] on the
+//! decoded AST.
+//!
+//! [`NodeId`]: crate::NodeId
+//! [`AstNodes`]: crate::AstNodes
+//! [`SemanticBuilder`]: crate::SemanticBuilder
+
+use oxc_allocator::{Allocator, BinaryCodec, DecodeError};
+use oxc_ast::{ast::Program, cache};
+
+use crate::{ScopeTree, SymbolTable};
+
+const EXTENSION: &str = "semantic";
+
+/// Encode `program`, `symbols` and `scopes` in binary cache format.
+pub fn encode_program_with_semantic(
+    program: &Program,
+    symbols: &SymbolTable,
+    scopes: &ScopeTree,
+) -> Vec<u8> {
+    cache::encode_program_with(program, EXTENSION, |encoder| {
+        symbols.encode(encoder);
+        scopes.encode(encoder);
+    })
+}
+
+/// Decode a [`Program`], [`SymbolTable`] and [`ScopeTree`] from `bytes` encoded by
+/// [`encode_program_with_semantic`], allocating the program in `allocator`.
+///
+/// # Errors
+/// Returns [`DecodeError::Incompatible`] if `bytes` were encoded by an incompatible version or
+/// without semantic data, or another [`DecodeError`] if `bytes` are truncated or corrupt.
+pub fn decode_program_with_semantic<'a>(
+    allocator: &'a Allocator,
+    bytes: &[u8],
+) -> Result<(Program<'a>, SymbolTable, ScopeTree), DecodeError> {
+    let (program, (symbols, scopes)) =
+        cache::decode_program_with(allocator, bytes, EXTENSION, |decoder| {
+            Ok((SymbolTable::decode(decoder)?, ScopeTree::decode(decoder)?))
+        })?;
+    Ok((program, symbols, scopes))
+}
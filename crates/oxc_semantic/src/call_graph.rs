@@ -0,0 +1,363 @@
+use oxc_ast::{
+    ast::{ClassElement, Expression, MethodDefinitionKind},
+    AstKind,
+};
+use oxc_cfg::graph::{
+    algo::tarjan_scc,
+    graph::{DiGraph, NodeIndex},
+    visit::EdgeRef,
+    Direction,
+};
+use oxc_syntax::{reference::ReferenceId, scope::ScopeId, symbol::SymbolId};
+use rustc_hash::FxHashMap;
+
+use crate::{AstNodes, NodeId, Reference, ScopeTree, SymbolTable};
+
+/// A call from one function to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Call {
+    /// The `CallExpression`, `NewExpression` or `TaggedTemplateExpression`
+    pub node_id: NodeId,
+    /// The function containing the call, or the `Program` for top-level calls
+    pub caller: NodeId,
+    /// The called function
+    pub callee: NodeId,
+}
+
+/// Intra-file call graph.
+///
+/// Vertices are `Function` and `ArrowFunctionExpression` nodes, and the `Program` node which makes
+/// the top-level calls. Only calls which resolve statically to a function in the same file are
+/// recorded:
+/// * `f()`, where `f` is a function declaration, or a variable initialized with a function and
+///   never reassigned.
+/// * immediately invoked function expressions.
+/// * `this.method()`, `super.method()` and `super()` inside of a class.
+/// * `Class.method()`, `new Class()` and `instance.method()`, where `instance` is a variable
+///   initialized with `new Class()` and never reassigned.
+///
+/// Only present if [`crate::Semantic`] is built with [`crate::SemanticBuilder::with_call_graph`].
+#[derive(Debug)]
+pub struct CallGraph {
+    graph: DiGraph<NodeId, NodeId>,
+    indices: FxHashMap<NodeId, NodeIndex>,
+    /// Strongly connected components, in reverse topological order
+    sccs: Vec<Vec<NodeId>>,
+    scc_ids: FxHashMap<NodeId, usize>,
+}
+
+impl CallGraph {
+    pub(crate) fn new(nodes: &AstNodes, scopes: &ScopeTree, symbols: &SymbolTable) -> Self {
+        let mut graph = DiGraph::default();
+        let mut indices = FxHashMap::default();
+        for node in nodes.iter() {
+            if matches!(
+                node.kind(),
+                AstKind::Program(_) | AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)
+            ) {
+                indices.insert(node.id(), graph.add_node(node.id()));
+            }
+        }
+
+        let resolver = Resolver { nodes, scopes, symbols };
+        for node in nodes.iter() {
+            let callee = match node.kind() {
+                AstKind::CallExpression(call) => {
+                    if matches!(call.callee, Expression::Super(_)) {
+                        resolver
+                            .this_class(node.id())
+                            .and_then(|(class, _)| resolver.super_class(class))
+                            .and_then(|class| resolver.constructor(class))
+                    } else {
+                        resolver.callee(node.id(), &call.callee)
+                    }
+                }
+                AstKind::NewExpression(new) => resolver
+                    .class(&new.callee)
+                    .and_then(|class| resolver.constructor(class))
+                    .or_else(|| resolver.callee(node.id(), &new.callee)),
+                AstKind::TaggedTemplateExpression(expr) => resolver.callee(node.id(), &expr.tag),
+                _ => continue,
+            };
+            let Some(callee) = callee else { continue };
+            let function = nodes
+                .ancestors(node.id())
+                .find(|id| {
+                    matches!(
+                        nodes.kind(*id),
+                        AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)
+                    )
+                })
+                .or_else(|| nodes.root());
+            if let (Some(caller), Some(callee)) =
+                (function.and_then(|id| indices.get(&id)), indices.get(&callee))
+            {
+                graph.add_edge(*caller, *callee, node.id());
+            }
+        }
+
+        let sccs = tarjan_scc(&graph)
+            .into_iter()
+            .map(|scc| scc.into_iter().map(|index| graph[index]).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let scc_ids = sccs
+            .iter()
+            .enumerate()
+            .flat_map(|(scc_id, scc)| scc.iter().map(move |node_id| (*node_id, scc_id)))
+            .collect();
+
+        Self { graph, indices, sccs, scc_ids }
+    }
+
+    /// All functions, and the `Program`.
+    pub fn functions(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.graph.node_weights().copied()
+    }
+
+    /// Calls made by `function`, not including calls made by nested functions.
+    pub fn callees(&self, function: NodeId) -> impl Iterator<Item = Call> + '_ {
+        self.calls(function, Direction::Outgoing)
+    }
+
+    /// Calls to `function`.
+    pub fn callers(&self, function: NodeId) -> impl Iterator<Item = Call> + '_ {
+        self.calls(function, Direction::Incoming)
+    }
+
+    fn calls(&self, function: NodeId, direction: Direction) -> impl Iterator<Item = Call> + '_ {
+        self.indices.get(&function).into_iter().flat_map(move |index| {
+            self.graph.edges_directed(*index, direction).map(|edge| Call {
+                node_id: *edge.weight(),
+                caller: self.graph[edge.source()],
+                callee: self.graph[edge.target()],
+            })
+        })
+    }
+
+    /// Whether `function` calls itself, directly or through other functions.
+    pub fn is_recursive(&self, function: NodeId) -> bool {
+        self.scc(function).len() > 1 || self.callees(function).any(|call| call.callee == function)
+    }
+
+    /// Functions which are mutually recursive with `function`, including itself.
+    pub fn scc(&self, function: NodeId) -> &[NodeId] {
+        self.scc_ids.get(&function).map_or(&[], |scc_id| &self.sccs[*scc_id])
+    }
+
+    /// Strongly connected components of the call graph, in reverse topological order:
+    /// callees come before their callers.
+    pub fn sccs(&self) -> &[Vec<NodeId>] {
+        &self.sccs
+    }
+}
+
+struct Resolver<'s, 'a> {
+    nodes: &'s AstNodes<'a>,
+    scopes: &'s ScopeTree,
+    symbols: &'s SymbolTable,
+}
+
+impl<'s, 'a> Resolver<'s, 'a> {
+    /// The function called by `callee`.
+    fn callee(&self, call: NodeId, callee: &Expression<'a>) -> Option<NodeId> {
+        match callee.get_inner_expression() {
+            Expression::Identifier(ident) => self.function(self.symbol(ident.reference_id())?),
+            Expression::FunctionExpression(func) => self.scope_node(func.scope_id.get()),
+            Expression::ArrowFunctionExpression(func) => self.scope_node(func.scope_id.get()),
+            Expression::PrivateFieldExpression(expr) => {
+                let (class, is_static) = self.this_class_of(call, &expr.object)?;
+                self.method(class, &expr.field.name, is_static, true)
+            }
+            expr => {
+                let member = expr.as_member_expression()?;
+                let name = member.static_property_name()?;
+                let (class, is_static) = self.this_class_of(call, member.object())?;
+                self.method(class, name, is_static, false)
+            }
+        }
+    }
+
+    /// The class and static-ness of the object of a member expression.
+    fn this_class_of(&self, call: NodeId, object: &Expression<'a>) -> Option<(NodeId, bool)> {
+        match object.get_inner_expression() {
+            Expression::ThisExpression(_) => self.this_class(call),
+            Expression::Super(_) => {
+                let (class, is_static) = self.this_class(call)?;
+                Some((self.super_class(class)?, is_static))
+            }
+            Expression::NewExpression(new) => Some((self.class(&new.callee)?, false)),
+            Expression::Identifier(ident) => {
+                let symbol_id = self.symbol(ident.reference_id())?;
+                self.symbol_class(symbol_id)
+                    .map(|class| (class, true))
+                    .or_else(|| self.instance(symbol_id).map(|class| (class, false)))
+            }
+            expr => Some((self.class(expr)?, true)),
+        }
+    }
+
+    /// The class `this` refers to at `node_id`, and whether it is the class itself (in static
+    /// members) or an instance.
+    fn this_class(&self, node_id: NodeId) -> Option<(NodeId, bool)> {
+        for ancestor in self.nodes.ancestors(node_id).skip(1) {
+            let is_static = match self.nodes.kind(ancestor) {
+                AstKind::Function(_) => match self.nodes.parent_kind(ancestor)? {
+                    AstKind::MethodDefinition(method) => method.r#static,
+                    _ => return None,
+                },
+                AstKind::PropertyDefinition(prop) => prop.r#static,
+                AstKind::StaticBlock(_) => true,
+                AstKind::Class(_) => return None,
+                // Arrow functions inherit `this`
+                _ => continue,
+            };
+            let class = self
+                .nodes
+                .ancestors(ancestor)
+                .find(|id| matches!(self.nodes.kind(*id), AstKind::Class(_)))?;
+            return Some((class, is_static));
+        }
+        None
+    }
+
+    fn symbol(&self, reference_id: Option<ReferenceId>) -> Option<SymbolId> {
+        self.symbols.get_reference(reference_id?).symbol_id()
+    }
+
+    fn scope_node(&self, scope_id: Option<ScopeId>) -> Option<NodeId> {
+        Some(self.scopes.get_node_id(scope_id?))
+    }
+
+    /// Symbols which are reassigned can refer to anything.
+    fn is_reassigned(&self, symbol_id: SymbolId) -> bool {
+        self.symbols.get_resolved_references(symbol_id).any(Reference::is_write)
+    }
+
+    /// The function a symbol is bound to.
+    fn function(&self, symbol_id: SymbolId) -> Option<NodeId> {
+        if self.is_reassigned(symbol_id) {
+            return None;
+        }
+        let declaration = self.symbols.get_declaration(symbol_id);
+        match self.nodes.kind(declaration) {
+            AstKind::Function(_) => Some(declaration),
+            AstKind::VariableDeclarator(decl) => match decl.init.as_ref()?.get_inner_expression() {
+                Expression::FunctionExpression(func) => self.scope_node(func.scope_id.get()),
+                Expression::ArrowFunctionExpression(func) => self.scope_node(func.scope_id.get()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// The class a symbol is bound to.
+    fn symbol_class(&self, symbol_id: SymbolId) -> Option<NodeId> {
+        if self.is_reassigned(symbol_id) {
+            return None;
+        }
+        let declaration = self.symbols.get_declaration(symbol_id);
+        match self.nodes.kind(declaration) {
+            AstKind::Class(_) => Some(declaration),
+            AstKind::VariableDeclarator(decl) => self.class(decl.init.as_ref()?),
+            _ => None,
+        }
+    }
+
+    /// The class of the instance a symbol is bound to, e.g. `const a = new A()`.
+    fn instance(&self, symbol_id: SymbolId) -> Option<NodeId> {
+        if self.is_reassigned(symbol_id) {
+            return None;
+        }
+        let declaration = self.symbols.get_declaration(symbol_id);
+        let AstKind::VariableDeclarator(decl) = self.nodes.kind(declaration) else { return None };
+        match decl.init.as_ref()?.get_inner_expression() {
+            Expression::NewExpression(new) => self.class(&new.callee),
+            _ => None,
+        }
+    }
+
+    /// The class an expression evaluates to.
+    fn class(&self, expr: &Expression<'a>) -> Option<NodeId> {
+        match expr.get_inner_expression() {
+            Expression::Identifier(ident) => self.symbol_class(self.symbol(ident.reference_id())?),
+            Expression::ClassExpression(class) => self.scope_node(class.scope_id.get()),
+            _ => None,
+        }
+    }
+
+    fn super_class(&self, class: NodeId) -> Option<NodeId> {
+        let AstKind::Class(class) = self.nodes.kind(class) else { return None };
+        self.class(class.super_class.as_ref()?)
+    }
+
+    /// Find a method by name, including inherited methods.
+    fn method(
+        &self,
+        mut class_id: NodeId,
+        name: &str,
+        is_static: bool,
+        is_private: bool,
+    ) -> Option<NodeId> {
+        // Classes can not extend themselves, but guard against cycles anyway
+        for _ in 0..64 {
+            let AstKind::Class(class) = self.nodes.kind(class_id) else { return None };
+            for element in &class.body.body {
+                let function = match element {
+                    ClassElement::MethodDefinition(method)
+                        if method.kind == MethodDefinitionKind::Method
+                            && method.r#static == is_static
+                            && !method.computed
+                            && method.key.is_private_identifier() == is_private
+                            && method.key.name().is_some_and(|key| key == name) =>
+                    {
+                        self.scope_node(method.value.scope_id.get())
+                    }
+                    ClassElement::PropertyDefinition(prop)
+                        if prop.r#static == is_static
+                            && !prop.computed
+                            && prop.key.is_private_identifier() == is_private
+                            && prop.key.name().is_some_and(|key| key == name) =>
+                    {
+                        match prop.value.as_ref()?.get_inner_expression() {
+                            Expression::FunctionExpression(func) => {
+                                self.scope_node(func.scope_id.get())
+                            }
+                            Expression::ArrowFunctionExpression(func) => {
+                                self.scope_node(func.scope_id.get())
+                            }
+                            _ => None,
+                        }
+                    }
+                    _ => continue,
+                };
+                return function;
+            }
+            // Private methods are not inherited
+            if is_private {
+                return None;
+            }
+            class_id = self.super_class(class_id)?;
+        }
+        None
+    }
+
+    /// The constructor of a class, or the inherited constructor.
+    fn constructor(&self, mut class_id: NodeId) -> Option<NodeId> {
+        for _ in 0..64 {
+            let AstKind::Class(class) = self.nodes.kind(class_id) else { return None };
+            let constructor = class.body.body.iter().find_map(|element| match element {
+                ClassElement::MethodDefinition(method)
+                    if method.kind == MethodDefinitionKind::Constructor =>
+                {
+                    Some(method)
+                }
+                _ => None,
+            });
+            if let Some(constructor) = constructor {
+                return self.scope_node(constructor.value.scope_id.get());
+            }
+            class_id = self.super_class(class_id)?;
+        }
+        None
+    }
+}
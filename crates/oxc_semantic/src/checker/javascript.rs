@@ -503,6 +503,25 @@ pub fn check_function_declaration<'a>(
     };
 }
 
+fn labelled_function_declaration(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Invalid function declaration")
+        .with_help(
+            "Labelled function declarations cannot be the body of an if, with or loop statement",
+        )
+        .with_label(span)
+}
+
+/// It is a Syntax Error if IsLabelledFunction(Statement) is true.
+pub fn check_labelled_function_body<'a>(stmt: &Statement<'a>, ctx: &SemanticBuilder<'a>) {
+    let mut body = stmt;
+    while let Statement::LabeledStatement(labeled) = body {
+        body = &labeled.body;
+    }
+    if let (Statement::LabeledStatement(_), Statement::FunctionDeclaration(decl)) = (stmt, body) {
+        ctx.error(labelled_function_declaration(decl.span));
+    }
+}
+
 fn reg_exp_flag_u_and_v(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error(
         "The 'u' and 'v' regular expression flags cannot be enabled at the same time",
@@ -16,9 +16,13 @@ pub fn check<'a>(node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
     let kind = node.kind();
 
     match kind {
-        AstKind::Program(_) => {
+        AstKind::Program(program) => {
             js::check_duplicate_class_elements(ctx);
+            ts::check_program(program, ctx);
         }
+        AstKind::BlockStatement(block) => ts::check_function_overloads(&block.body, ctx),
+        AstKind::FunctionBody(body) => ts::check_function_overloads(&body.statements, ctx),
+        AstKind::TSModuleBlock(block) => ts::check_ts_module_block(block, ctx),
         AstKind::BindingIdentifier(ident) => {
             js::check_identifier(&ident.name, ident.span, node, ctx);
             js::check_binding_identifier(ident, node, ctx);
@@ -41,6 +45,7 @@ pub fn check<'a>(node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
 
         AstKind::WithStatement(stmt) => {
             js::check_function_declaration(&stmt.body, false, ctx);
+            js::check_labelled_function_body(&stmt.body, ctx);
             js::check_with_statement(stmt, ctx);
         }
         AstKind::SwitchStatement(stmt) => js::check_switch_statement(stmt, ctx),
@@ -52,11 +57,13 @@ pub fn check<'a>(node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
         }
         AstKind::ForInStatement(stmt) => {
             js::check_function_declaration(&stmt.body, false, ctx);
+            js::check_labelled_function_body(&stmt.body, ctx);
             js::check_for_statement_left(&stmt.left, true, node, ctx);
             ts::check_for_statement_left(&stmt.left, true, ctx);
         }
         AstKind::ForOfStatement(stmt) => {
             js::check_function_declaration(&stmt.body, false, ctx);
+            js::check_labelled_function_body(&stmt.body, ctx);
             js::check_for_statement_left(&stmt.left, false, node, ctx);
             ts::check_for_statement_left(&stmt.left, false, ctx);
         }
@@ -64,13 +71,19 @@ pub fn check<'a>(node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
         | AstKind::DoWhileStatement(DoWhileStatement { body, .. })
         | AstKind::ForStatement(ForStatement { body, .. }) => {
             js::check_function_declaration(body, false, ctx);
+            js::check_labelled_function_body(body, ctx);
         }
         AstKind::IfStatement(stmt) => {
             js::check_function_declaration(&stmt.consequent, true, ctx);
+            js::check_labelled_function_body(&stmt.consequent, ctx);
             if let Some(alternate) = &stmt.alternate {
                 js::check_function_declaration(alternate, true, ctx);
+                js::check_labelled_function_body(alternate, ctx);
             }
         }
+        AstKind::Function(func) => ts::check_function(func, ctx),
+        AstKind::ReturnStatement(stmt) => ts::check_return_statement(stmt, ctx),
+        AstKind::CatchParameter(param) => ts::check_catch_parameter(param, ctx),
         AstKind::Class(class) => {
             js::check_class(class, node, ctx);
             ts::check_class(class, ctx);
@@ -111,6 +124,11 @@ pub fn check<'a>(node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
         AstKind::TSTypeParameterDeclaration(declaration) => {
             ts::check_ts_type_parameter_declaration(declaration, ctx);
         }
+        AstKind::TSTypeParameterInstantiation(instantiation) => {
+            ts::check_ts_type_parameter_instantiation(instantiation, ctx);
+        }
+        AstKind::TSTypeAliasDeclaration(decl) => ts::check_ts_type_alias_declaration(decl, ctx),
+        AstKind::TSExportAssignment(assignment) => ts::check_ts_export_assignment(assignment, ctx),
         AstKind::TSModuleDeclaration(decl) => ts::check_ts_module_declaration(decl, ctx),
         AstKind::TSEnumDeclaration(decl) => ts::check_ts_enum_declaration(decl, ctx),
         AstKind::TSImportEqualsDeclaration(decl) => {
@@ -14,8 +14,64 @@ fn ts_error<M: Into<Cow<'static, str>>>(code: &'static str, message: M) -> OxcDi
     OxcDiagnostic::error(message).with_error_code("TS", code)
 }
 
+/// Whether the current node is inside of a `declare namespace`, `declare module`,
+/// `declare global` or any namespace of a `.d.ts` file.
+fn is_in_ambient_namespace(ctx: &SemanticBuilder<'_>) -> bool {
+    ctx.source_type.is_typescript()
+        && ctx.nodes.iter_parents(ctx.current_node_id).skip(1).any(|node| {
+            matches!(node.kind(), AstKind::TSModuleDeclaration(decl)
+                if decl.declare || ctx.source_type.is_typescript_definition())
+        })
+}
+
+/// Whether the current node is in an ambient context, where declarations cannot have
+/// implementations or initializers.
+fn is_in_ambient_context(ctx: &SemanticBuilder<'_>) -> bool {
+    ctx.source_type.is_typescript_definition() || is_in_ambient_namespace(ctx)
+}
+
+/// The nearest namespace or module declaration containing the current node.
+fn enclosing_module_declaration<'a>(
+    ctx: &SemanticBuilder<'a>,
+) -> Option<&'a TSModuleDeclaration<'a>> {
+    ctx.nodes.iter_parents(ctx.current_node_id).skip(1).find_map(|node| match node.kind() {
+        AstKind::TSModuleDeclaration(decl) => Some(decl),
+        _ => None,
+    })
+}
+
+/// Span of the `declare` keyword at the start of `span`, or `span` itself.
+fn declare_keyword_span(span: Span, source_text: &str) -> Span {
+    if span.source_text(source_text).starts_with("declare") {
+        Span::sized(span.start, 7)
+    } else {
+        span
+    }
+}
+
+/// Type parameter list cannot be empty. (1098)
 fn empty_type_parameter_list(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Type parameter list cannot be empty.").with_label(span)
+    ts_error("1098", "Type parameter list cannot be empty.").with_label(span)
+}
+
+/// '{0}' modifier can only appear on a type parameter of a class, interface or type alias. (1274)
+fn variance_modifier_on_type_parameter(modifier: &str, span: Span) -> OxcDiagnostic {
+    ts_error(
+        "1274",
+        format!(
+            "'{modifier}' modifier can only appear on a type parameter of a class, interface or type alias"
+        ),
+    )
+    .with_label(span)
+}
+
+/// 'const' modifier can only appear on a type parameter of a function, method or class. (1277)
+fn const_modifier_on_type_parameter(span: Span) -> OxcDiagnostic {
+    ts_error(
+        "1277",
+        "'const' modifier can only appear on a type parameter of a function, method or class",
+    )
+    .with_label(span)
 }
 
 pub fn check_ts_type_parameter_declaration(
@@ -25,6 +81,40 @@ pub fn check_ts_type_parameter_declaration(
     if declaration.params.is_empty() {
         ctx.error(empty_type_parameter_list(declaration.span));
     }
+
+    let parent_kind = ctx.nodes.parent_kind(ctx.current_node_id);
+    let is_type_declaration = matches!(
+        parent_kind,
+        Some(AstKind::TSInterfaceDeclaration(_) | AstKind::TSTypeAliasDeclaration(_))
+    );
+    let allows_variance = is_type_declaration || matches!(parent_kind, Some(AstKind::Class(_)));
+    for param in &declaration.params {
+        if !allows_variance {
+            if param.r#in {
+                ctx.error(variance_modifier_on_type_parameter("in", param.span));
+            }
+            if param.out {
+                ctx.error(variance_modifier_on_type_parameter("out", param.span));
+            }
+        }
+        if param.r#const && is_type_declaration {
+            ctx.error(const_modifier_on_type_parameter(param.span));
+        }
+    }
+}
+
+/// Type argument list cannot be empty. (1099)
+fn empty_type_argument_list(span: Span) -> OxcDiagnostic {
+    ts_error("1099", "Type argument list cannot be empty.").with_label(span)
+}
+
+pub fn check_ts_type_parameter_instantiation(
+    instantiation: &TSTypeParameterInstantiation<'_>,
+    ctx: &SemanticBuilder<'_>,
+) {
+    if instantiation.params.is_empty() {
+        ctx.error(empty_type_argument_list(instantiation.span));
+    }
 }
 /// '?' at the end of a type is not valid TypeScript syntax. Did you mean to write 'number | null | undefined'?(17019)
 #[allow(clippy::needless_pass_by_value)]
@@ -73,11 +163,85 @@ fn initializer_in_ambient_context(init_span: Span) -> OxcDiagnostic {
     ts_error("1039", "Initializers are not allowed in ambient contexts.").with_label(init_span)
 }
 
+/// A 'const' initializer in an ambient context must be a string or numeric literal or literal enum reference. (1254)
+fn invalid_const_initializer_in_ambient_context(init_span: Span) -> OxcDiagnostic {
+    ts_error(
+        "1254",
+        "A 'const' initializer in an ambient context must be a string or numeric literal or literal enum reference.",
+    )
+    .with_label(init_span)
+}
+
+/// `declare const a = 1;` and `declare class A { readonly a = "a"; }` are allowed.
+fn check_ambient_initializer(
+    init: &Expression<'_>,
+    is_const_like: bool,
+    has_type_annotation: bool,
+    ctx: &SemanticBuilder<'_>,
+) {
+    if !is_const_like || has_type_annotation {
+        ctx.error(initializer_in_ambient_context(init.span()));
+        return;
+    }
+    let is_literal = match init.get_inner_expression() {
+        Expression::StringLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BigIntLiteral(_)
+        | Expression::BooleanLiteral(_) => true,
+        Expression::TemplateLiteral(lit) => lit.expressions.is_empty(),
+        Expression::UnaryExpression(expr) => {
+            expr.operator == UnaryOperator::UnaryNegation
+                && matches!(
+                    expr.argument,
+                    Expression::NumericLiteral(_) | Expression::BigIntLiteral(_)
+                )
+        }
+        // Enum member, e.g. `E.A` or `E["A"]`
+        Expression::StaticMemberExpression(expr) => expr.object.is_identifier_reference(),
+        Expression::ComputedMemberExpression(expr) => {
+            expr.object.is_identifier_reference() && expr.expression.is_string_literal()
+        }
+        _ => false,
+    };
+    if !is_literal {
+        ctx.error(invalid_const_initializer_in_ambient_context(init.span()));
+    }
+}
+
+/// A 'declare' modifier cannot be used in an already ambient context. (1038)
+fn declare_in_ambient_context(span: Span) -> OxcDiagnostic {
+    ts_error("1038", "A 'declare' modifier cannot be used in an already ambient context.")
+        .with_label(span)
+}
+
+pub fn check_declare_modifier(declare: bool, span: Span, ctx: &SemanticBuilder<'_>) {
+    if declare && is_in_ambient_namespace(ctx) {
+        ctx.error(declare_in_ambient_context(declare_keyword_span(span, ctx.source_text)));
+    }
+}
+
+/// A definite assignment assertion '!' is not permitted in this context. (1255)
+fn definite_assignment_not_permitted(span: Span) -> OxcDiagnostic {
+    ts_error("1255", "A definite assignment assertion '!' is not permitted in this context.")
+        .with_label(span)
+}
+
 pub fn check_variable_declaration(decl: &VariableDeclaration, ctx: &SemanticBuilder<'_>) {
-    if decl.declare {
+    check_declare_modifier(decl.declare, decl.span, ctx);
+    if decl.declare || is_in_ambient_context(ctx) {
         for var in &decl.declarations {
             if let Some(init) = &var.init {
-                ctx.error(initializer_in_ambient_context(init.span()));
+                check_ambient_initializer(
+                    init,
+                    decl.kind == VariableDeclarationKind::Const,
+                    var.id.type_annotation.is_some(),
+                    ctx,
+                );
+            }
+            if var.definite {
+                if let Some(span) = find_char(var.span, ctx.source_text, '!') {
+                    ctx.error(definite_assignment_not_permitted(span));
+                }
             }
         }
     }
@@ -141,14 +305,23 @@ pub fn check_variable_declarator(decl: &VariableDeclarator, ctx: &SemanticBuilde
     }
 }
 
+/// A required parameter cannot follow an optional parameter. (1016)
 fn required_parameter_after_optional_parameter(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A required parameter cannot follow an optional parameter.")
-        .with_label(span)
+    ts_error("1016", "A required parameter cannot follow an optional parameter.").with_label(span)
 }
 
-fn parameter_property_outside_constructor(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A parameter property is only allowed in a constructor implementation.")
-        .with_label(span)
+/// Parameter cannot have question mark and initializer. (1015)
+fn optional_parameter_with_initializer(span: Span) -> OxcDiagnostic {
+    ts_error("1015", "Parameter cannot have question mark and initializer.").with_label(span)
+}
+
+/// A parameter initializer is only allowed in a function or constructor implementation. (2371)
+fn parameter_initializer_without_implementation(span: Span) -> OxcDiagnostic {
+    ts_error(
+        "2371",
+        "A parameter initializer is only allowed in a function or constructor implementation.",
+    )
+    .with_label(span)
 }
 
 pub fn check_formal_parameters(params: &FormalParameters, ctx: &SemanticBuilder<'_>) {
@@ -158,9 +331,25 @@ pub fn check_formal_parameters(params: &FormalParameters, ctx: &SemanticBuilder<
 
     let is_inside_constructor =
         !params.kind.is_signature() && ctx.current_scope_flags().is_constructor();
+    let has_implementation = !params.kind.is_signature()
+        && !matches!(
+            ctx.nodes.parent_kind(ctx.current_node_id),
+            Some(AstKind::Function(func)) if func.body.is_none()
+        );
     let mut has_optional = false;
 
     for item in &params.items {
+        if let BindingPatternKind::AssignmentPattern(pattern) = &item.pattern.kind {
+            // function a(optional?: number = 1) { }
+            if pattern.left.optional {
+                ctx.error(optional_parameter_with_initializer(item.span));
+            }
+            // function a(x = 1): void;
+            if !has_implementation {
+                ctx.error(parameter_initializer_without_implementation(pattern.span));
+            }
+        }
+
         // function a(optional?: number, required: number) { }
         if has_optional && !item.pattern.optional && !item.pattern.kind.is_assignment_pattern() {
             ctx.error(required_parameter_after_optional_parameter(item.span));
@@ -171,7 +360,7 @@ pub fn check_formal_parameters(params: &FormalParameters, ctx: &SemanticBuilder<
 
         // function a(public x: number) { }
         if !is_inside_constructor && item.accessibility.is_some() {
-            ctx.error(parameter_property_outside_constructor(item.span));
+            ctx.error(parameter_property_only_in_constructor_impl(item.span));
         }
     }
 }
@@ -185,8 +374,10 @@ fn check_duplicate_bound_names<'a, T: BoundNames<'a>>(bound_names: &T, ctx: &Sem
     });
 }
 
+/// The left-hand side of an assignment expression must be a variable or a property access. (2364)
 fn unexpected_assignment(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
+    ts_error(
+        "2364",
         "The left-hand side of an assignment expression must be a variable or a property access.",
     )
     .with_label(span)
@@ -235,6 +426,7 @@ pub fn check_ts_interface_declaration<'a>(
     decl: &TSInterfaceDeclaration<'a>,
     ctx: &SemanticBuilder<'a>,
 ) {
+    check_declare_modifier(decl.declare, decl.span, ctx);
     if let Some(extends) = &decl.extends {
         for extend in extends {
             if !matches!(
@@ -247,37 +439,131 @@ pub fn check_ts_interface_declaration<'a>(
     }
 }
 
+/// A namespace declaration is only allowed at the top level of a namespace or module. (1235)
 fn not_allowed_namespace_declaration(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
+    ts_error(
+        "1235",
         "A namespace declaration is only allowed at the top level of a namespace or module.",
     )
     .with_label(span)
 }
 
+/// An ambient module declaration is only allowed at the top level in a file. (1234)
+fn ambient_module_not_at_top_level(span: Span) -> OxcDiagnostic {
+    ts_error("1234", "An ambient module declaration is only allowed at the top level in a file.")
+        .with_label(span)
+}
+
+/// Ambient modules cannot be nested in other modules or namespaces. (2435)
+fn nested_ambient_module(span: Span) -> OxcDiagnostic {
+    ts_error("2435", "Ambient modules cannot be nested in other modules or namespaces.")
+        .with_label(span)
+}
+
+/// Ambient module declaration cannot specify relative module name. (2436)
+fn relative_ambient_module_name(span: Span) -> OxcDiagnostic {
+    ts_error("2436", "Ambient module declaration cannot specify relative module name.")
+        .with_label(span)
+}
+
+/// Only ambient modules can use quoted names. (1035)
+fn quoted_name_in_non_ambient_module(span: Span) -> OxcDiagnostic {
+    ts_error("1035", "Only ambient modules can use quoted names.").with_label(span)
+}
+
+/// 'export' modifier cannot be applied to ambient modules and module augmentations since they are always visible. (2668)
+fn export_ambient_module(span: Span) -> OxcDiagnostic {
+    ts_error(
+        "2668",
+        "'export' modifier cannot be applied to ambient modules and module augmentations since they are always visible.",
+    )
+    .with_label(span)
+}
+
 pub fn check_ts_module_declaration<'a>(decl: &TSModuleDeclaration<'a>, ctx: &SemanticBuilder<'a>) {
-    // skip current node
+    check_declare_modifier(decl.declare, decl.span, ctx);
+
+    let TSModuleDeclarationName::StringLiteral(name) = &decl.id else {
+        // skip current node
+        for node in ctx.nodes.iter_parents(ctx.current_node_id).skip(1) {
+            match node.kind() {
+                AstKind::Program(_)
+                | AstKind::TSModuleBlock(_)
+                | AstKind::TSModuleDeclaration(_) => {
+                    break;
+                }
+                AstKind::ExportNamedDeclaration(_) | AstKind::ModuleDeclaration(_) => {
+                    // export namespace N {}
+                    // We need to check the parent of the parent
+                    continue;
+                }
+                _ => {
+                    ctx.error(not_allowed_namespace_declaration(decl.span));
+                    break;
+                }
+            }
+        }
+        return;
+    };
+
+    // declare module "foo" {}
     for node in ctx.nodes.iter_parents(ctx.current_node_id).skip(1) {
         match node.kind() {
-            AstKind::Program(_) | AstKind::TSModuleBlock(_) | AstKind::TSModuleDeclaration(_) => {
-                break;
-            }
-            AstKind::ExportNamedDeclaration(_) | AstKind::ModuleDeclaration(_) => {
-                // export namespace N {}
-                // We need to check the parent of the parent
-                continue;
+            AstKind::Program(_) => break,
+            AstKind::ExportNamedDeclaration(_) => {
+                ctx.error(export_ambient_module(node.kind().span()));
             }
+            AstKind::ModuleDeclaration(_) => {}
             _ => {
-                ctx.error(not_allowed_namespace_declaration(decl.span));
+                if is_in_ambient_namespace(ctx)
+                    || ctx
+                        .nodes
+                        .iter_parents(node.id())
+                        .any(|node| matches!(node.kind(), AstKind::TSModuleBlock(_)))
+                {
+                    ctx.error(nested_ambient_module(name.span));
+                } else {
+                    ctx.error(ambient_module_not_at_top_level(name.span));
+                }
+                break;
             }
         }
     }
+
+    // `declare module "./foo" {}` in an external module is a module augmentation
+    let value = name.value.as_str();
+    if !is_external_module(ctx)
+        && (value.starts_with("./")
+            || value.starts_with("../")
+            || value.starts_with('/')
+            || matches!(value, "." | ".."))
+    {
+        ctx.error(relative_ambient_module_name(name.span));
+    }
+
+    if !decl.declare && !is_in_ambient_context(ctx) {
+        ctx.error(quoted_name_in_non_ambient_module(name.span));
+    }
+}
+
+/// Whether the program has a top level `import` or `export`.
+fn is_external_module(ctx: &SemanticBuilder<'_>) -> bool {
+    ctx.nodes.root_node().is_some_and(|node| match node.kind() {
+        AstKind::Program(program) => program.body.iter().any(|stmt| {
+            matches!(stmt, match_module_declaration!(Statement))
+                || matches!(stmt, Statement::TSImportEqualsDeclaration(_))
+        }),
+        _ => false,
+    })
 }
 
+/// Enum member must have initializer. (1061)
 fn enum_member_must_have_initializer(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Enum member must have initializer.").with_label(span)
+    ts_error("1061", "Enum member must have initializer.").with_label(span)
 }
 
 pub fn check_ts_enum_declaration<'a>(decl: &TSEnumDeclaration<'a>, ctx: &SemanticBuilder<'a>) {
+    check_declare_modifier(decl.declare, decl.span, ctx);
     let mut need_initializer = false;
 
     decl.members.iter().for_each(|member| {
@@ -307,6 +593,12 @@ fn import_alias_cannot_use_import_type(span: Span) -> OxcDiagnostic {
     ts_error("1392", "An import alias cannot use 'import type'").with_label(span)
 }
 
+/// Import declarations in a namespace cannot reference a module. (1147)
+fn import_require_in_namespace(span: Span) -> OxcDiagnostic {
+    ts_error("1147", "Import declarations in a namespace cannot reference a module.")
+        .with_label(span)
+}
+
 pub fn check_ts_import_equals_declaration<'a>(
     decl: &TSImportEqualsDeclaration<'a>,
     ctx: &SemanticBuilder<'a>,
@@ -316,6 +608,30 @@ pub fn check_ts_import_equals_declaration<'a>(
     if decl.import_kind.is_type() && !decl.module_reference.is_external() {
         ctx.error(import_alias_cannot_use_import_type(decl.span));
     }
+
+    // `namespace N { import foo = require('./foo') }` is not allowed
+    if decl.module_reference.is_external()
+        && enclosing_module_declaration(ctx)
+            .is_some_and(|module| matches!(module.id, TSModuleDeclarationName::Identifier(_)))
+    {
+        ctx.error(import_require_in_namespace(decl.module_reference.span()));
+    }
+}
+
+/// An export assignment cannot be used in a namespace. (1063)
+fn export_assignment_in_namespace(span: Span) -> OxcDiagnostic {
+    ts_error("1063", "An export assignment cannot be used in a namespace.").with_label(span)
+}
+
+pub fn check_ts_export_assignment<'a>(
+    assignment: &TSExportAssignment<'a>,
+    ctx: &SemanticBuilder<'a>,
+) {
+    if enclosing_module_declaration(ctx)
+        .is_some_and(|module| matches!(module.id, TSModuleDeclarationName::Identifier(_)))
+    {
+        ctx.error(export_assignment_in_namespace(assignment.span));
+    }
 }
 
 /// - Abstract properties can only appear within an abstract class. (1253)
@@ -327,6 +643,10 @@ fn abstract_elem_in_concrete_class(is_property: bool, span: Span) -> OxcDiagnost
 }
 
 pub fn check_class<'a>(class: &Class<'a>, ctx: &SemanticBuilder<'a>) {
+    check_declare_modifier(class.declare, class.span, ctx);
+    if !class.declare && !is_in_ambient_context(ctx) {
+        check_class_overloads(&class.body.body, ctx);
+    }
     if !class.r#abstract {
         for elem in &class.body.body {
             if elem.is_abstract() {
@@ -400,21 +720,113 @@ fn accessor_without_body(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Getters and setters must have an implementation.").with_label(span)
 }
 
+/// Type parameters cannot appear on a constructor declaration. (1092)
+fn type_parameters_on_constructor(span: Span) -> OxcDiagnostic {
+    ts_error("1092", "Type parameters cannot appear on a constructor declaration.").with_label(span)
+}
+
+/// Type annotation cannot appear on a constructor declaration. (1093)
+fn return_type_on_constructor(span: Span) -> OxcDiagnostic {
+    ts_error("1093", "Type annotation cannot appear on a constructor declaration.").with_label(span)
+}
+
+/// An accessor cannot have type parameters. (1094)
+fn type_parameters_on_accessor(span: Span) -> OxcDiagnostic {
+    ts_error("1094", "An accessor cannot have type parameters.").with_label(span)
+}
+
+/// '{0}' modifier cannot be used with 'abstract' modifier. (1243)
+fn modifier_with_abstract(modifier: &str, span: Span) -> OxcDiagnostic {
+    ts_error("1243", format!("'{modifier}' modifier cannot be used with 'abstract' modifier."))
+        .with_label(span)
+}
+
+/// 'abstract' modifier cannot be used with a private identifier. (18019)
+fn abstract_private_identifier(span: Span) -> OxcDiagnostic {
+    ts_error("18019", "'abstract' modifier cannot be used with a private identifier.")
+        .with_label(span)
+}
+
+/// An implementation cannot be declared in ambient contexts. (1183)
+fn implementation_in_ambient_context(span: Span) -> OxcDiagnostic {
+    ts_error("1183", "An implementation cannot be declared in ambient contexts.").with_label(span)
+}
+
+/// 'async' modifier cannot be used in an ambient context. (1040)
+fn async_in_ambient_context(span: Span) -> OxcDiagnostic {
+    ts_error("1040", "'async' modifier cannot be used in an ambient context.").with_label(span)
+}
+
+/// Whether the class element currently being checked belongs to a `declare class`
+/// or is otherwise in an ambient context.
+fn is_in_declare_class(ctx: &SemanticBuilder<'_>) -> bool {
+    let in_declare_class = ctx.class_table_builder.current_class_id.is_some_and(|id| {
+        let node_id = ctx.class_table_builder.classes.declarations[id];
+        let AstKind::Class(class) = ctx.nodes.get_node(node_id).kind() else {
+            #[cfg(debug_assertions)]
+            panic!("current_class_id is set, but does not point to a Class node.");
+            #[cfg(not(debug_assertions))]
+            return false;
+        };
+        class.declare
+    });
+    in_declare_class || is_in_ambient_context(ctx)
+}
+
+fn check_abstract_modifiers(
+    key: &PropertyKey<'_>,
+    is_static: bool,
+    accessibility: Option<TSAccessibility>,
+    ctx: &SemanticBuilder<'_>,
+) {
+    let key_span = key.span();
+    if is_static {
+        ctx.error(modifier_with_abstract("static", key_span));
+    }
+    if accessibility == Some(TSAccessibility::Private) {
+        ctx.error(modifier_with_abstract("private", key_span));
+    }
+    if key.is_private_identifier() {
+        ctx.error(abstract_private_identifier(key_span));
+    }
+}
+
 pub fn check_method_definition<'a>(method: &MethodDefinition<'a>, ctx: &SemanticBuilder<'a>) {
     let is_abstract = method.r#type.is_abstract();
-    let is_declare = ctx.class_table_builder.current_class_id.map_or(
-        ctx.source_type.is_typescript_definition(),
-        |id| {
-            let node_id = ctx.class_table_builder.classes.declarations[id];
-            let AstKind::Class(class) = ctx.nodes.get_node(node_id).kind() else {
-                #[cfg(debug_assertions)]
-                panic!("current_class_id is set, but does not point to a Class node.");
-                #[cfg(not(debug_assertions))]
-                return ctx.source_type.is_typescript_definition();
-            };
-            class.declare || ctx.source_type.is_typescript_definition()
-        },
-    );
+    let is_declare = is_in_declare_class(ctx);
+    let func = &method.value;
+
+    if method.kind.is_constructor() {
+        // constructor<T>() {}
+        if let Some(type_parameters) = &func.type_parameters {
+            ctx.error(type_parameters_on_constructor(type_parameters.span));
+        }
+        // constructor(): void {}
+        if let Some(return_type) = &func.return_type {
+            ctx.error(return_type_on_constructor(return_type.span));
+        }
+    } else if method.kind.is_accessor() {
+        // get foo<T>() {}
+        if let Some(type_parameters) = &func.type_parameters {
+            ctx.error(type_parameters_on_accessor(type_parameters.span));
+        }
+    }
+
+    if is_abstract {
+        check_abstract_modifiers(&method.key, method.r#static, method.accessibility, ctx);
+        if func.r#async {
+            ctx.error(modifier_with_abstract("async", method.key.span()));
+        }
+    }
+
+    if is_declare {
+        if let Some(body) = &func.body {
+            ctx.error(implementation_in_ambient_context(body.span));
+        }
+        if func.r#async {
+            ctx.error(async_in_ambient_context(method.span));
+        }
+    }
 
     if is_abstract {
         // constructors cannot be abstract, no matter what
@@ -458,12 +870,31 @@ pub fn check_method_definition<'a>(method: &MethodDefinition<'a>, ctx: &Semantic
 }
 
 pub fn check_property_definition<'a>(prop: &PropertyDefinition<'a>, ctx: &SemanticBuilder<'a>) {
-    if prop.r#type.is_abstract() && prop.value.is_some() {
-        let (prop_name, span) = prop.key.prop_name().unwrap_or_else(|| {
-            let key_span = prop.key.span();
-            (&ctx.source_text[key_span], key_span)
-        });
-        ctx.error(abstract_property_cannot_have_initializer(prop_name, span));
+    let is_abstract = prop.r#type.is_abstract();
+    if is_abstract {
+        check_abstract_modifiers(&prop.key, prop.r#static, prop.accessibility, ctx);
+        if prop.value.is_some() {
+            let (prop_name, span) = prop.key.prop_name().unwrap_or_else(|| {
+                let key_span = prop.key.span();
+                (&ctx.source_text[key_span], key_span)
+            });
+            ctx.error(abstract_property_cannot_have_initializer(prop_name, span));
+        }
+    }
+
+    let is_ambient = prop.declare || is_in_declare_class(ctx);
+    if is_ambient && !is_abstract {
+        if let Some(value) = &prop.value {
+            check_ambient_initializer(value, prop.readonly, prop.type_annotation.is_some(), ctx);
+        }
+    }
+
+    // declare class A { foo!: number }
+    if prop.definite && (is_ambient || is_abstract) {
+        let after_key = Span::new(prop.key.span().end, prop.span.end);
+        if let Some(span) = find_char(after_key, ctx.source_text, '!') {
+            ctx.error(definite_assignment_not_permitted(span));
+        }
     }
 }
 
@@ -500,3 +931,263 @@ pub fn check_for_statement_left(left: &ForStatementLeft, is_for_in: bool, ctx: &
         }
     }
 }
+
+pub fn check_function<'a>(func: &Function<'a>, ctx: &SemanticBuilder<'a>) {
+    // Methods are checked in `check_method_definition`
+    if !func.is_declaration() {
+        return;
+    }
+    check_declare_modifier(func.declare, func.span, ctx);
+    if func.declare || is_in_ambient_context(ctx) {
+        // declare function foo() {}
+        if let Some(body) = &func.body {
+            ctx.error(implementation_in_ambient_context(body.span));
+        }
+        // declare async function foo(): Promise<void>;
+        if func.r#async {
+            ctx.error(async_in_ambient_context(func.span));
+        }
+    }
+}
+
+pub fn check_ts_type_alias_declaration<'a>(
+    decl: &TSTypeAliasDeclaration<'a>,
+    ctx: &SemanticBuilder<'a>,
+) {
+    check_declare_modifier(decl.declare, decl.span, ctx);
+}
+
+/// Function implementation is missing or not immediately following the declaration. (2391)
+fn function_implementation_missing(span: Span) -> OxcDiagnostic {
+    ts_error(
+        "2391",
+        "Function implementation is missing or not immediately following the declaration.",
+    )
+    .with_label(span)
+}
+
+/// Constructor implementation is missing. (2390)
+fn constructor_implementation_missing(span: Span) -> OxcDiagnostic {
+    ts_error("2390", "Constructor implementation is missing.").with_label(span)
+}
+
+fn statement_function<'s, 'a>(stmt: &'s Statement<'a>) -> Option<&'s Function<'a>> {
+    match stmt {
+        Statement::FunctionDeclaration(func) => Some(func),
+        Statement::ExportNamedDeclaration(decl) => match &decl.declaration {
+            Some(Declaration::FunctionDeclaration(func)) => Some(func),
+            _ => None,
+        },
+        Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(func) => Some(func),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Checks that every function overload in a statement list is immediately followed by an
+/// implementation with the same name.
+pub fn check_function_overloads<'a>(stmts: &[Statement<'a>], ctx: &SemanticBuilder<'a>) {
+    if !ctx.source_type.is_typescript() || is_in_ambient_context(ctx) {
+        return;
+    }
+    let mut overload: Option<&Function<'a>> = None;
+    for stmt in stmts {
+        let func = statement_function(stmt).filter(|func| !func.declare);
+        if let Some(prev) = overload.take() {
+            let name = |func: &Function<'a>| func.id.as_ref().map(|id| id.name.clone());
+            if !func.is_some_and(|func| name(func) == name(prev)) {
+                ctx.error(function_implementation_missing(
+                    prev.id.as_ref().map_or(prev.span, |id| id.span),
+                ));
+            }
+        }
+        overload = func.filter(|func| func.body.is_none());
+    }
+    if let Some(prev) = overload {
+        ctx.error(function_implementation_missing(
+            prev.id.as_ref().map_or(prev.span, |id| id.span),
+        ));
+    }
+}
+
+fn overload_method<'s, 'a>(elem: &'s ClassElement<'a>) -> Option<&'s MethodDefinition<'a>> {
+    match elem {
+        ClassElement::MethodDefinition(method)
+            if !method.kind.is_accessor() && !method.r#type.is_abstract() && !method.optional =>
+        {
+            Some(method)
+        }
+        _ => None,
+    }
+}
+
+fn check_class_overloads<'a>(elements: &[ClassElement<'a>], ctx: &SemanticBuilder<'a>) {
+    if !ctx.source_type.is_typescript() {
+        return;
+    }
+    let report = |method: &MethodDefinition<'a>| {
+        if method.kind.is_constructor() {
+            ctx.error(constructor_implementation_missing(method.key.span()));
+        } else {
+            ctx.error(function_implementation_missing(method.key.span()));
+        }
+    };
+    let mut overload: Option<&MethodDefinition<'a>> = None;
+    for elem in elements {
+        let method = overload_method(elem);
+        if let Some(prev) = overload.take() {
+            let is_same = method.is_some_and(|method| {
+                method.r#static == prev.r#static
+                    && method.key.static_name().is_some()
+                    && method.key.static_name() == prev.key.static_name()
+            });
+            if !is_same {
+                report(prev);
+            }
+        }
+        overload = method.filter(|method| method.value.body.is_none());
+    }
+    if let Some(prev) = overload {
+        report(prev);
+    }
+}
+
+/// Setters cannot return a value. (2408)
+fn setter_return_value(span: Span) -> OxcDiagnostic {
+    ts_error("2408", "Setters cannot return a value.").with_label(span)
+}
+
+pub fn check_return_statement<'a>(stmt: &ReturnStatement<'a>, ctx: &SemanticBuilder<'a>) {
+    if !ctx.source_type.is_typescript() || stmt.argument.is_none() {
+        return;
+    }
+    let mut ancestors = ctx.nodes.iter_parents(ctx.current_node_id).skip(1);
+    let Some(func) = ancestors.find(|node| {
+        matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
+    }) else {
+        return;
+    };
+    if !matches!(func.kind(), AstKind::Function(_)) {
+        return;
+    }
+    let is_setter = match ctx.nodes.parent_kind(func.id()) {
+        Some(AstKind::MethodDefinition(method)) => method.kind == MethodDefinitionKind::Set,
+        Some(AstKind::ObjectProperty(prop)) => prop.kind == PropertyKind::Set,
+        _ => false,
+    };
+    if is_setter {
+        ctx.error(setter_return_value(stmt.span));
+    }
+}
+
+/// Catch clause variable type annotation must be 'any' or 'unknown' if specified. (1196)
+fn invalid_catch_parameter_type(span: Span) -> OxcDiagnostic {
+    ts_error(
+        "1196",
+        "Catch clause variable type annotation must be 'any' or 'unknown' if specified.",
+    )
+    .with_label(span)
+}
+
+pub fn check_catch_parameter<'a>(param: &CatchParameter<'a>, ctx: &SemanticBuilder<'a>) {
+    if let Some(annotation) = &param.pattern.type_annotation {
+        if !matches!(
+            annotation.type_annotation,
+            TSType::TSAnyKeyword(_) | TSType::TSUnknownKeyword(_)
+        ) {
+            ctx.error(invalid_catch_parameter_type(annotation.type_annotation.span()));
+        }
+    }
+}
+
+/// Statements are not allowed in ambient contexts. (1036)
+fn statement_in_ambient_context(span: Span) -> OxcDiagnostic {
+    ts_error("1036", "Statements are not allowed in ambient contexts.").with_label(span)
+}
+
+/// Top-level declarations in .d.ts files must start with either a 'declare' or 'export' modifier. (1046)
+fn missing_declare_in_definition_file(span: Span) -> OxcDiagnostic {
+    ts_error(
+        "1046",
+        "Top-level declarations in .d.ts files must start with either a 'declare' or 'export' modifier.",
+    )
+    .with_label(span)
+}
+
+/// Only reports the first statement, as TypeScript does.
+fn check_ambient_statements(stmts: &[Statement<'_>], ctx: &SemanticBuilder<'_>) {
+    let statement = stmts.iter().find(|stmt| {
+        !(stmt.is_declaration()
+            || stmt.is_module_declaration()
+            || matches!(stmt, Statement::EmptyStatement(_)))
+    });
+    if let Some(statement) = statement {
+        ctx.error(statement_in_ambient_context(statement.span()));
+    }
+}
+
+pub fn check_ts_module_block<'a>(block: &TSModuleBlock<'a>, ctx: &SemanticBuilder<'a>) {
+    if is_in_ambient_context(ctx) {
+        check_ambient_statements(&block.body, ctx);
+    } else {
+        check_function_overloads(&block.body, ctx);
+    }
+}
+
+/// An export assignment cannot be used in a module with other exported elements. (2309)
+fn export_assignment_with_other_exports(span: Span) -> OxcDiagnostic {
+    ts_error(
+        "2309",
+        "An export assignment cannot be used in a module with other exported elements.",
+    )
+    .with_label(span)
+}
+
+pub fn check_program<'a>(program: &Program<'a>, ctx: &SemanticBuilder<'a>) {
+    if !ctx.source_type.is_typescript() {
+        return;
+    }
+
+    if ctx.source_type.is_typescript_definition() {
+        check_ambient_statements(&program.body, ctx);
+        for stmt in &program.body {
+            let missing_declare = match stmt {
+                Statement::VariableDeclaration(decl) => !decl.declare,
+                Statement::FunctionDeclaration(func) => !func.declare,
+                Statement::ClassDeclaration(class) => !class.declare,
+                Statement::TSEnumDeclaration(decl) => !decl.declare,
+                Statement::TSModuleDeclaration(decl) => !decl.declare && !decl.kind.is_global(),
+                _ => false,
+            };
+            if missing_declare {
+                ctx.error(missing_declare_in_definition_file(stmt.span()));
+            }
+        }
+    } else {
+        check_function_overloads(&program.body, ctx);
+    }
+
+    // export = foo; export const bar = 1;
+    let export_assignment = program.body.iter().find_map(|stmt| match stmt {
+        Statement::TSExportAssignment(assignment) => Some(assignment),
+        _ => None,
+    });
+    if let Some(assignment) = export_assignment {
+        let has_value_exports = program.body.iter().any(|stmt| match stmt {
+            Statement::ExportNamedDeclaration(decl) => {
+                decl.export_kind.is_value()
+                    && decl.declaration.as_ref().map_or_else(
+                        || !decl.specifiers.is_empty(),
+                        |decl| !decl.is_typescript_syntax(),
+                    )
+            }
+            Statement::ExportDefaultDeclaration(_) | Statement::ExportAllDeclaration(_) => true,
+            _ => false,
+        });
+        if has_value_exports {
+            ctx.error(export_assignment_with_other_exports(assignment.span));
+        }
+    }
+}
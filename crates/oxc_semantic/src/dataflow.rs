@@ -0,0 +1,469 @@
+//! Reaching definitions and live variables, built on [`oxc_cfg::dataflow`].
+//!
+//! The effects of a basic block are the definitions and reads of symbols in it, ordered by the
+//! point where they happen. E.g. in `a = a + 1`, `a` is read before it is written.
+//!
+//! Some symbols are not tracked precisely. Every definition of them is assumed to reach every read,
+//! and they are always live:
+//! * symbols referenced from a nested function, where the order of execution is unknown.
+//! * exported and imported symbols, functions (which are hoisted), and redeclared `var`s.
+
+use oxc_ast::AstKind;
+use oxc_cfg::{
+    dataflow::{Analysis, DataflowDirection, DataflowResults},
+    graph::{visit::EdgeRef, Direction},
+    BlockNodeId, ControlFlowGraph, EdgeType,
+};
+use oxc_index::Idx;
+use oxc_span::GetSpan;
+use oxc_syntax::{
+    node::NodeId,
+    reference::ReferenceId,
+    scope::ScopeId,
+    symbol::{SymbolFlags, SymbolId},
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{AstNodes, ScopeTree, Semantic, SymbolTable};
+
+/// A point where a symbol is given a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Definition {
+    /// The declaration of a symbol, e.g. `let a = 1`, `let a;`, `function f(a) {}` or `catch (a) {}`.
+    ///
+    /// `var a;` without an initializer does not define a value.
+    Declaration(SymbolId),
+    /// A write reference, e.g. `a = 1`, `a++`, `a ??= 1` or `for (a of b)`.
+    Write(ReferenceId),
+}
+
+impl Definition {
+    /// # Panics
+    /// if the write reference is unresolved.
+    pub fn symbol_id(self, symbols: &SymbolTable) -> SymbolId {
+        match self {
+            Self::Declaration(symbol_id) => symbol_id,
+            Self::Write(reference_id) => {
+                symbols.get_reference(reference_id).symbol_id().expect("resolved write reference")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Event {
+    /// `kill` is `false` if the definition may not happen, e.g. `a ||= b`
+    Def {
+        definition: Definition,
+        symbol_id: SymbolId,
+        kill: bool,
+    },
+    Use {
+        reference_id: ReferenceId,
+        symbol_id: SymbolId,
+    },
+}
+
+/// Definitions and reads of every basic block.
+struct Events {
+    blocks: FxHashMap<BlockNodeId, Vec<Event>>,
+    definitions: FxHashMap<SymbolId, Vec<Definition>>,
+    untracked: FxHashSet<SymbolId>,
+}
+
+impl Events {
+    fn new(semantic: &Semantic) -> Self {
+        let nodes = semantic.nodes();
+        let scopes = semantic.scopes();
+        let symbols = semantic.symbols();
+
+        let mut blocks = FxHashMap::<BlockNodeId, Vec<(u32, Event)>>::default();
+        let mut definitions = FxHashMap::<SymbolId, Vec<Definition>>::default();
+        let mut untracked = FxHashSet::default();
+
+        for symbol_id in symbols.symbol_ids() {
+            let flags = symbols.get_flags(symbol_id);
+            if !flags.intersects(SymbolFlags::Value | SymbolFlags::Function | SymbolFlags::Import) {
+                continue;
+            }
+            let function_scope_id = function_scope(scopes, symbols.get_scope_id(symbol_id));
+            if flags.intersects(SymbolFlags::Export | SymbolFlags::Function | SymbolFlags::Import)
+                || !symbols.get_redeclarations(symbol_id).is_empty()
+                || symbols.get_resolved_reference_ids(symbol_id).iter().any(|reference_id| {
+                    let node_id = symbols.get_reference(*reference_id).node_id();
+                    function_scope(scopes, nodes.get_node(node_id).scope_id()) != function_scope_id
+                })
+            {
+                untracked.insert(symbol_id);
+            }
+
+            let declaration = symbols.get_declaration(symbol_id);
+            if let AstKind::VariableDeclarator(declarator) = nodes.kind(declaration) {
+                let is_for_in_of = nodes.ancestors(declaration).nth(2).is_some_and(|id| {
+                    matches!(
+                        nodes.kind(id),
+                        AstKind::ForInStatement(_) | AstKind::ForOfStatement(_)
+                    )
+                });
+                if declarator.kind.is_var() && declarator.init.is_none() && !is_for_in_of {
+                    continue;
+                }
+            }
+            let definition = Definition::Declaration(symbol_id);
+            definitions.entry(symbol_id).or_default().push(definition);
+            if !untracked.contains(&symbol_id) {
+                let (block, position) = effect_point(nodes, scopes, declaration);
+                let event = Event::Def { definition, symbol_id, kill: true };
+                blocks.entry(block).or_default().push((position, event));
+            }
+        }
+
+        for (reference_id, reference) in symbols.references.iter_enumerated() {
+            let Some(symbol_id) = reference.symbol_id() else { continue };
+            let node_id = reference.node_id();
+            if reference.is_write() {
+                let definition = Definition::Write(reference_id);
+                definitions.entry(symbol_id).or_default().push(definition);
+                if !untracked.contains(&symbol_id) {
+                    let (block, position, kill) = write_point(nodes, scopes, node_id);
+                    let event = Event::Def { definition, symbol_id, kill };
+                    blocks.entry(block).or_default().push((position, event));
+                }
+            }
+            // `a++` always reads `a`, even if the result is unused
+            if (reference.is_read() || is_update(nodes, node_id)) && !untracked.contains(&symbol_id)
+            {
+                let node = nodes.get_node(node_id);
+                let event = Event::Use { reference_id, symbol_id };
+                blocks.entry(node.cfg_id()).or_default().push((node.kind().span().start, event));
+            }
+        }
+
+        let blocks = blocks
+            .into_iter()
+            .map(|(block, mut events)| {
+                // Reads of `a += 1` happen before its write, which ends at the same position
+                events.sort_by_key(|(position, event)| {
+                    (*position, matches!(event, Event::Def { .. }))
+                });
+                (block, events.into_iter().map(|(_, event)| event).collect())
+            })
+            .collect();
+        Self { blocks, definitions, untracked }
+    }
+
+    fn block(&self, block: BlockNodeId) -> &[Event] {
+        self.blocks.get(&block).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// The scope of the function (or program) containing `scope_id`.
+fn function_scope(scopes: &ScopeTree, scope_id: ScopeId) -> ScopeId {
+    scopes.ancestors(scope_id).find(|id| scopes.get_flags(*id).is_var()).unwrap_or(scope_id)
+}
+
+fn is_update(nodes: &AstNodes, node_id: NodeId) -> bool {
+    nodes
+        .ancestors(node_id)
+        .skip(1)
+        .find(|id| !matches!(nodes.kind(*id), AstKind::SimpleAssignmentTarget(_)))
+        .is_some_and(|id| matches!(nodes.kind(id), AstKind::UpdateExpression(_)))
+}
+
+/// Block and position of the write of a write reference, and whether it always happens.
+fn write_point(nodes: &AstNodes, scopes: &ScopeTree, node_id: NodeId) -> (BlockNodeId, u32, bool) {
+    for ancestor in nodes.ancestors(node_id).skip(1) {
+        match nodes.kind(ancestor) {
+            // `a = b` and `[a] = b` are written after `b` is evaluated
+            AstKind::AssignmentExpression(expr) => {
+                let (block, position) = effect_point(nodes, scopes, ancestor);
+                return (block, position, !expr.operator.is_logical());
+            }
+            AstKind::UpdateExpression(_) => {
+                let (block, position) = effect_point(nodes, scopes, ancestor);
+                return (block, position, true);
+            }
+            AstKind::ForInStatement(_) | AstKind::ForOfStatement(_) => break,
+            _ => {}
+        }
+    }
+    let node = nodes.get_node(node_id);
+    (node.cfg_id(), node.kind().span().start, true)
+}
+
+/// Block and position at the end of evaluating a node.
+fn effect_point(nodes: &AstNodes, scopes: &ScopeTree, node_id: NodeId) -> (BlockNodeId, u32) {
+    let node = nodes.get_node(node_id);
+    let end = node.kind().span().end;
+
+    // Nodes are created in pre-order, so the descendants of a node are the nodes following it
+    let mut next = node_id.index() + 1;
+    while next < nodes.len()
+        && nodes.parent_id(NodeId::from_usize(next)).is_some_and(|parent| parent >= node_id)
+    {
+        next += 1;
+    }
+    let last = nodes.get_node(NodeId::from_usize(next - 1));
+    if last.cfg_id() == node.cfg_id() {
+        return (node.cfg_id(), end);
+    }
+
+    // The node contains control flow, e.g. `a = b ? c : d`. The evaluation continues in the
+    // basic block where the paths join, which is the block of the node following it.
+    if next < nodes.len() {
+        let next = nodes.get_node(NodeId::from_usize(next));
+        if function_scope(scopes, next.scope_id()) == function_scope(scopes, node.scope_id()) {
+            return (next.cfg_id(), end);
+        }
+    }
+    (node.cfg_id(), end)
+}
+
+/// Successors of `block` through [`EdgeType::Error`] edges.
+fn error_successors(
+    cfg: &ControlFlowGraph,
+    block: BlockNodeId,
+) -> impl Iterator<Item = BlockNodeId> + '_ {
+    cfg.graph
+        .edges_directed(block, Direction::Outgoing)
+        .filter(|edge| matches!(edge.weight(), EdgeType::Error(_)))
+        .map(|edge| edge.target())
+}
+
+struct ReachingDefinitionsAnalysis<'e> {
+    events: &'e Events,
+    symbols: &'e SymbolTable,
+}
+
+impl Analysis for ReachingDefinitionsAnalysis<'_> {
+    type Domain = FxHashSet<Definition>;
+
+    const DIRECTION: DataflowDirection = DataflowDirection::Forward;
+
+    fn bottom(&self) -> Self::Domain {
+        FxHashSet::default()
+    }
+
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain) {
+        state.extend(other);
+    }
+
+    fn transfer(&self, block: BlockNodeId, state: &mut Self::Domain) {
+        for event in self.events.block(block) {
+            if let Event::Def { definition, symbol_id, kill } = *event {
+                if kill {
+                    state.retain(|d| d.symbol_id(self.symbols) != symbol_id);
+                }
+                state.insert(definition);
+            }
+        }
+    }
+
+    fn transfer_error(&self, block: BlockNodeId, state: &mut Self::Domain) {
+        for event in self.events.block(block) {
+            if let Event::Def { definition, .. } = *event {
+                state.insert(definition);
+            }
+        }
+    }
+}
+
+/// Reaching definitions and def-use chains.
+///
+/// A definition reaches a read if there is a path in the control flow graph from the
+/// definition to the read, which does not redefine the symbol.
+///
+/// ```ignore
+/// let reaching = ReachingDefinitions::new(&semantic).unwrap();
+/// for definition in reaching.definitions(symbol_id) {
+///     if reaching.uses(*definition).is_empty() {
+///         // The value is never read
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ReachingDefinitions {
+    definitions: FxHashMap<SymbolId, Vec<Definition>>,
+    reaching: FxHashMap<ReferenceId, Vec<Definition>>,
+    uses: FxHashMap<Definition, Vec<ReferenceId>>,
+}
+
+impl ReachingDefinitions {
+    /// Returns `None` if `semantic` was built without a control flow graph.
+    pub fn new(semantic: &Semantic) -> Option<Self> {
+        let cfg = semantic.cfg()?;
+        let symbols = semantic.symbols();
+        let events = Events::new(semantic);
+        let results = cfg.dataflow(&ReachingDefinitionsAnalysis { events: &events, symbols });
+
+        let mut reaching = FxHashMap::<ReferenceId, Vec<Definition>>::default();
+        for (block, block_events) in &events.blocks {
+            let mut state = results.entry(*block).clone();
+            for event in block_events {
+                match *event {
+                    Event::Use { reference_id, symbol_id } => {
+                        let mut definitions = state
+                            .iter()
+                            .filter(|d| d.symbol_id(symbols) == symbol_id)
+                            .copied()
+                            .collect::<Vec<_>>();
+                        definitions.sort_unstable();
+                        reaching.insert(reference_id, definitions);
+                    }
+                    Event::Def { definition, symbol_id, kill } => {
+                        if kill {
+                            state.retain(|d| d.symbol_id(symbols) != symbol_id);
+                        }
+                        state.insert(definition);
+                    }
+                }
+            }
+        }
+        for symbol_id in &events.untracked {
+            let definitions = events.definitions.get(symbol_id).cloned().unwrap_or_default();
+            for reference_id in symbols.get_resolved_reference_ids(*symbol_id) {
+                if symbols.get_reference(*reference_id).is_read() {
+                    reaching.insert(*reference_id, definitions.clone());
+                }
+            }
+        }
+
+        let mut uses = FxHashMap::<Definition, Vec<ReferenceId>>::default();
+        for (reference_id, definitions) in &reaching {
+            for definition in definitions {
+                uses.entry(*definition).or_default().push(*reference_id);
+            }
+        }
+        uses.values_mut().for_each(|references| references.sort_unstable());
+
+        Some(Self { definitions: events.definitions, reaching, uses })
+    }
+
+    /// All definitions of a symbol.
+    pub fn definitions(&self, symbol_id: SymbolId) -> &[Definition] {
+        self.definitions.get(&symbol_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Definitions which may provide the value read by a reference.
+    ///
+    /// Empty if the reference reads the symbol before any definition, e.g. in its TDZ.
+    pub fn reaching(&self, reference_id: ReferenceId) -> &[Definition] {
+        self.reaching.get(&reference_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// References which may read the value of a definition.
+    pub fn uses(&self, definition: Definition) -> &[ReferenceId] {
+        self.uses.get(&definition).map_or(&[], Vec::as_slice)
+    }
+}
+
+struct LiveVariablesAnalysis<'e> {
+    events: &'e Events,
+}
+
+impl Analysis for LiveVariablesAnalysis<'_> {
+    type Domain = FxHashSet<SymbolId>;
+
+    const DIRECTION: DataflowDirection = DataflowDirection::Backward;
+
+    fn bottom(&self) -> Self::Domain {
+        FxHashSet::default()
+    }
+
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain) {
+        state.extend(other);
+    }
+
+    fn transfer(&self, block: BlockNodeId, state: &mut Self::Domain) {
+        for event in self.events.block(block).iter().rev() {
+            match *event {
+                Event::Def { symbol_id, kill: true, .. } => {
+                    state.remove(&symbol_id);
+                }
+                Event::Def { .. } => {}
+                Event::Use { symbol_id, .. } => {
+                    state.insert(symbol_id);
+                }
+            }
+        }
+    }
+
+    fn transfer_error(&self, block: BlockNodeId, state: &mut Self::Domain) {
+        for event in self.events.block(block) {
+            if let Event::Use { symbol_id, .. } = *event {
+                state.insert(symbol_id);
+            }
+        }
+    }
+}
+
+/// Live variables.
+///
+/// A symbol is live at a point if its value at that point may be read later.
+/// Untracked symbols (see the [module documentation](self)) are always live,
+/// but are not included in [`LiveVariables::live_in`] and [`LiveVariables::live_out`].
+#[derive(Debug)]
+pub struct LiveVariables {
+    results: DataflowResults<FxHashSet<SymbolId>>,
+    live_after: FxHashMap<Definition, bool>,
+}
+
+impl LiveVariables {
+    /// Returns `None` if `semantic` was built without a control flow graph.
+    pub fn new(semantic: &Semantic) -> Option<Self> {
+        let cfg = semantic.cfg()?;
+        let events = Events::new(semantic);
+        let results = cfg.dataflow(&LiveVariablesAnalysis { events: &events });
+
+        let mut live_after = FxHashMap::default();
+        for (block, block_events) in &events.blocks {
+            // Symbols read after an exception are live everywhere in the block
+            let mut live_on_error = FxHashSet::<SymbolId>::default();
+            for successor in error_successors(cfg, *block) {
+                live_on_error.extend(results.entry(successor));
+            }
+            let mut state = results.exit(*block).clone();
+            for event in block_events.iter().rev() {
+                match *event {
+                    Event::Def { definition, symbol_id, kill } => {
+                        live_after.insert(
+                            definition,
+                            state.contains(&symbol_id) || live_on_error.contains(&symbol_id),
+                        );
+                        if kill {
+                            state.remove(&symbol_id);
+                        }
+                    }
+                    Event::Use { symbol_id, .. } => {
+                        state.insert(symbol_id);
+                    }
+                }
+            }
+        }
+
+        for symbol_id in &events.untracked {
+            for definition in events.definitions.get(symbol_id).into_iter().flatten() {
+                live_after.insert(*definition, true);
+            }
+        }
+
+        Some(Self { results, live_after })
+    }
+
+    /// Symbols live at the entry of a basic block.
+    pub fn live_in(&self, block: BlockNodeId) -> &FxHashSet<SymbolId> {
+        self.results.entry(block)
+    }
+
+    /// Symbols live at the exit of a basic block.
+    pub fn live_out(&self, block: BlockNodeId) -> &FxHashSet<SymbolId> {
+        self.results.exit(block)
+    }
+
+    /// Whether the value given by a definition may be read later.
+    ///
+    /// `false` means the definition is useless, e.g. `let a = 1; a = 2; use(a);`.
+    pub fn is_live_after(&self, definition: Definition) -> bool {
+        self.live_after.get(&definition).copied().unwrap_or(false)
+    }
+}
@@ -7,3 +7,8 @@ pub fn redeclaration(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
         span2.label("It can not be redeclared here"),
     ])
 }
+
+pub fn max_nesting_depth_exceeded(max_nesting_depth: u32, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Maximum nesting depth of {max_nesting_depth} exceeded"))
+        .with_label(span)
+}
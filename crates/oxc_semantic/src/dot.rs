@@ -9,7 +9,7 @@ use oxc_cfg::{
         visit::EdgeRef,
     },
     BasicBlock, ControlFlowGraph, EdgeType, ErrorEdgeKind, Instruction, InstructionKind,
-    IterationInstructionKind, LabeledInstruction, ReturnInstructionKind,
+    IterationInstructionKind, LabeledInstruction, ReturnInstructionKind, SuspendInstructionKind,
 };
 use oxc_syntax::node::NodeId;
 
@@ -176,6 +176,12 @@ impl DebugDot for Instruction {
             InstructionKind::Return(ReturnInstructionKind::NotImplicitUndefined) => {
                 "return <value>".to_string()
             }
+            InstructionKind::Suspend(SuspendInstructionKind::Yield) => {
+                "suspend <yield>".to_string()
+            }
+            InstructionKind::Suspend(SuspendInstructionKind::Await) => {
+                "suspend <await>".to_string()
+            }
         }
     }
 }
@@ -0,0 +1,339 @@
+//! [eslint-scope](https://github.com/eslint/js/tree/main/packages/eslint-scope) compatible scope analysis.
+//!
+//! Converts [`ScopeTree`], [`SymbolTable`] and references of a [`Semantic`] into the data model of
+//! eslint-scope's `ScopeManager`, which is used by `context.getScope()` in ESLint rules.
+//! For TypeScript files, scope and definition types follow
+//! [@typescript-eslint/scope-manager](https://typescript-eslint.io/packages/scope-manager).
+//!
+//! Scopes, variables and references refer to each other by their index in [`EslintScopeManager`].
+//!
+//! [`ScopeTree`]: crate::ScopeTree
+//! [`SymbolTable`]: crate::SymbolTable
+
+// Silence erroneous warnings from Rust Analyser for `#[derive(Tsify)]`
+#![allow(non_snake_case)]
+
+use oxc_ast::AstKind;
+use oxc_index::Idx;
+use oxc_span::{CompactStr, GetSpan, Span};
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+#[cfg(feature = "serialize")]
+use tsify::Tsify;
+
+use crate::{NodeId, ScopeFlags, ScopeId, Semantic, SymbolId};
+
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Tsify), serde(rename_all = "camelCase"))]
+pub struct EslintScopeManager {
+    /// All scopes in the program. The first scope is the global scope.
+    pub scopes: Vec<EslintScope>,
+    pub variables: Vec<EslintVariable>,
+    pub references: Vec<EslintReference>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Tsify), serde(rename_all = "camelCase"))]
+pub struct EslintScope {
+    /// e.g. `"global"`, `"module"`, `"function"`, `"block"`
+    pub r#type: &'static str,
+    pub is_strict: bool,
+    /// Index of the parent scope
+    pub upper: Option<usize>,
+    pub child_scopes: Vec<usize>,
+    /// Index of the nearest function, module or global scope, where `var` declarations are hoisted to
+    pub variable_scope: usize,
+    /// The AST node which creates this scope
+    pub block: EslintNode,
+    /// Indexes of variables declared in this scope
+    pub variables: Vec<usize>,
+    /// Indexes of references made in this scope
+    pub references: Vec<usize>,
+    /// Indexes of references made in this scope or its child scopes
+    /// which could not be resolved in this scope
+    pub through: Vec<usize>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Tsify), serde(rename_all = "camelCase"))]
+pub struct EslintVariable {
+    pub name: CompactStr,
+    /// Index of the scope which declares this variable
+    pub scope: usize,
+    /// Declared identifiers, including redeclarations
+    pub identifiers: Vec<EslintNode>,
+    /// Indexes of references resolved to this variable
+    pub references: Vec<usize>,
+    pub defs: Vec<EslintDefinition>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Tsify), serde(rename_all = "camelCase"))]
+pub struct EslintDefinition {
+    /// e.g. `"Variable"`, `"FunctionName"`, `"Parameter"`, `"ImportBinding"`
+    pub r#type: &'static str,
+    pub name: EslintNode,
+    pub node: EslintNode,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Tsify), serde(rename_all = "camelCase"))]
+pub struct EslintReference {
+    pub identifier: EslintNode,
+    /// Index of the scope where this reference occurs
+    pub from: usize,
+    /// Index of the variable this reference resolves to, `None` for globals
+    pub resolved: Option<usize>,
+    pub is_read: bool,
+    pub is_write: bool,
+    pub is_type_reference: bool,
+    pub is_value_reference: bool,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Tsify), serde(rename_all = "camelCase"))]
+pub struct EslintNode {
+    pub r#type: &'static str,
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Option::is_none"))]
+    pub name: Option<CompactStr>,
+    pub start: u32,
+    pub end: u32,
+}
+
+impl EslintNode {
+    fn identifier(name: &str, span: Span) -> Self {
+        Self { r#type: "Identifier", name: Some(name.into()), start: span.start, end: span.end }
+    }
+
+    fn from_kind(kind: AstKind) -> Self {
+        let span = kind.span();
+        Self { r#type: node_type(kind), name: None, start: span.start, end: span.end }
+    }
+}
+
+impl EslintScopeManager {
+    pub fn new(semantic: &Semantic) -> Self {
+        let scopes = semantic.scopes();
+        let symbols = semantic.symbols();
+        let nodes = semantic.nodes();
+        let is_module = semantic.source_type().is_module();
+
+        // eslint-scope puts the module scope inside of an empty global scope
+        let offset = usize::from(is_module);
+        let scope_index = |scope_id: ScopeId| scope_id.index() + offset;
+
+        let mut manager = Self::default();
+        if is_module {
+            let program = nodes.kind(scopes.get_node_id(scopes.root_scope_id()));
+            manager.scopes.push(EslintScope {
+                r#type: "global",
+                is_strict: false,
+                upper: None,
+                child_scopes: vec![1],
+                variable_scope: 0,
+                block: EslintNode::from_kind(program),
+                variables: vec![],
+                references: vec![],
+                through: vec![],
+            });
+        }
+
+        for scope_id in scopes.descendants_from_root() {
+            let flags = scopes.get_flags(scope_id);
+            let kind = nodes.kind(scopes.get_node_id(scope_id));
+            let upper = scopes.get_parent_id(scope_id).map(scope_index).or(is_module.then_some(0));
+            let variable_scope = scopes
+                .ancestors(scope_id)
+                .find(|scope_id| scopes.get_flags(*scope_id).is_var())
+                .map_or(0, scope_index);
+            manager.scopes.push(EslintScope {
+                r#type: scope_type(kind, flags, is_module),
+                is_strict: flags.is_strict_mode(),
+                upper,
+                child_scopes: vec![],
+                variable_scope,
+                block: EslintNode::from_kind(kind),
+                variables: vec![],
+                references: vec![],
+                through: vec![],
+            });
+        }
+        for index in 1..manager.scopes.len() {
+            if let Some(upper) = manager.scopes[index].upper {
+                if !(is_module && upper == 0) {
+                    manager.scopes[upper].child_scopes.push(index);
+                }
+            }
+        }
+
+        for symbol_id in symbols.symbol_ids() {
+            let name = symbols.get_name(symbol_id);
+            let (def_type, def_node) = definition(semantic, symbols.get_declaration(symbol_id));
+            let mut scope_id = symbols.get_scope_id(symbol_id);
+            // Catch parameters are bound in the scope of the catch body,
+            // but eslint-scope declares them in the `catch` scope
+            if def_type == "CatchClause" {
+                scope_id = scopes.get_parent_id(scope_id).unwrap_or(scope_id);
+            }
+            let scope = scope_index(scope_id);
+            let identifiers = std::iter::once(symbols.get_span(symbol_id))
+                .chain(symbols.get_redeclarations(symbol_id).iter().copied())
+                .map(|span| EslintNode::identifier(name, span))
+                .collect::<Vec<_>>();
+            let defs = identifiers
+                .iter()
+                .map(|identifier| EslintDefinition {
+                    r#type: def_type,
+                    name: identifier.clone(),
+                    node: def_node.clone(),
+                })
+                .collect();
+            let references =
+                symbols.get_resolved_reference_ids(symbol_id).iter().map(|id| id.index()).collect();
+            manager.scopes[scope].variables.push(manager.variables.len());
+            manager.variables.push(EslintVariable {
+                name: name.into(),
+                scope,
+                identifiers,
+                references,
+                defs,
+            });
+        }
+
+        for (index, reference) in symbols.references.iter().enumerate() {
+            let node = nodes.get_node(reference.node_id());
+            let identifier = match node.kind() {
+                AstKind::IdentifierReference(ident) => {
+                    EslintNode::identifier(&ident.name, ident.span)
+                }
+                kind => EslintNode::from_kind(kind),
+            };
+            let from = scope_index(node.scope_id());
+            let flags = reference.flags();
+            manager.scopes[from].references.push(index);
+
+            // Add to `through` of every scope between the reference and its declaration
+            let declaring_scope =
+                reference.symbol_id().map(|symbol_id| manager.variables[symbol_id.index()].scope);
+            for scope_id in scopes.ancestors(node.scope_id()) {
+                if Some(scope_index(scope_id)) == declaring_scope {
+                    break;
+                }
+                manager.scopes[scope_index(scope_id)].through.push(index);
+            }
+            if is_module && declaring_scope.is_none() {
+                manager.scopes[0].through.push(index);
+            }
+
+            manager.references.push(EslintReference {
+                identifier,
+                from,
+                resolved: reference.symbol_id().map(SymbolId::index),
+                is_read: flags.is_read(),
+                is_write: flags.is_write(),
+                is_type_reference: flags.is_type(),
+                is_value_reference: flags.is_value(),
+            });
+        }
+
+        manager
+    }
+}
+
+fn scope_type(kind: AstKind, flags: ScopeFlags, is_module: bool) -> &'static str {
+    match kind {
+        AstKind::Program(_) if is_module => "module",
+        AstKind::Program(_) => "global",
+        AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => "function",
+        AstKind::Class(_) => "class",
+        AstKind::StaticBlock(_) => "class-static-block",
+        AstKind::CatchClause(_) => "catch",
+        AstKind::ForStatement(_) | AstKind::ForInStatement(_) | AstKind::ForOfStatement(_) => "for",
+        AstKind::SwitchStatement(_) => "switch",
+        AstKind::WithStatement(_) => "with",
+        AstKind::TSModuleDeclaration(_) => "tsModule",
+        AstKind::TSEnumDeclaration(_) => "tsEnum",
+        AstKind::TSTypeAliasDeclaration(_) | AstKind::TSInterfaceDeclaration(_) => "type",
+        AstKind::TSConditionalType(_) => "conditionalType",
+        AstKind::TSMappedType(_) => "mappedType",
+        _ if flags.is_function() => "functionType",
+        _ => "block",
+    }
+}
+
+/// Definition type and node of a symbol declared by the AST node `node_id`.
+fn definition(semantic: &Semantic, node_id: NodeId) -> (&'static str, EslintNode) {
+    let nodes = semantic.nodes();
+    let kind = nodes.kind(node_id);
+    let r#type = match kind {
+        AstKind::Function(_) => "FunctionName",
+        AstKind::Class(_) => "ClassName",
+        AstKind::CatchParameter(_) => "CatchClause",
+        AstKind::ImportSpecifier(_)
+        | AstKind::ImportDefaultSpecifier(_)
+        | AstKind::ImportNamespaceSpecifier(_)
+        | AstKind::TSImportEqualsDeclaration(_) => "ImportBinding",
+        AstKind::TSTypeAliasDeclaration(_)
+        | AstKind::TSInterfaceDeclaration(_)
+        | AstKind::TSTypeParameter(_) => "Type",
+        AstKind::TSEnumDeclaration(_) => "TSEnumName",
+        AstKind::TSEnumMember(_) => "TSEnumMember",
+        AstKind::TSModuleDeclaration(_) => "TSModuleName",
+        AstKind::FormalParameter(_) => "Parameter",
+        AstKind::BindingRestElement(_)
+            if matches!(nodes.parent_kind(node_id), Some(AstKind::FormalParameters(_))) =>
+        {
+            "Parameter"
+        }
+        _ => "Variable",
+    };
+    let node = match r#type {
+        // The node of a parameter is the function
+        "Parameter" => nodes
+            .ancestors(node_id)
+            .map(|node_id| nodes.kind(node_id))
+            .find(|kind| matches!(kind, AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)))
+            .unwrap_or(kind),
+        "CatchClause" => nodes.parent_kind(node_id).unwrap_or(kind),
+        _ => kind,
+    };
+    (r#type, EslintNode::from_kind(node))
+}
+
+/// ESTree node type
+fn node_type(kind: AstKind) -> &'static str {
+    match kind {
+        AstKind::Program(_) => "Program",
+        AstKind::Function(func) if func.is_expression() => "FunctionExpression",
+        AstKind::Function(_) => "FunctionDeclaration",
+        AstKind::ArrowFunctionExpression(_) => "ArrowFunctionExpression",
+        AstKind::Class(class) if class.is_expression() => "ClassExpression",
+        AstKind::Class(_) => "ClassDeclaration",
+        AstKind::StaticBlock(_) => "StaticBlock",
+        AstKind::CatchClause(_) => "CatchClause",
+        AstKind::BlockStatement(_) => "BlockStatement",
+        AstKind::ForStatement(_) => "ForStatement",
+        AstKind::ForInStatement(_) => "ForInStatement",
+        AstKind::ForOfStatement(_) => "ForOfStatement",
+        AstKind::SwitchStatement(_) => "SwitchStatement",
+        AstKind::WithStatement(_) => "WithStatement",
+        AstKind::VariableDeclarator(_) => "VariableDeclarator",
+        AstKind::ImportSpecifier(_) => "ImportSpecifier",
+        AstKind::ImportDefaultSpecifier(_) => "ImportDefaultSpecifier",
+        AstKind::ImportNamespaceSpecifier(_) => "ImportNamespaceSpecifier",
+        AstKind::IdentifierReference(_) | AstKind::BindingIdentifier(_) => "Identifier",
+        AstKind::JSXIdentifier(_) => "JSXIdentifier",
+        AstKind::TSImportEqualsDeclaration(_) => "TSImportEqualsDeclaration",
+        AstKind::TSTypeAliasDeclaration(_) => "TSTypeAliasDeclaration",
+        AstKind::TSInterfaceDeclaration(_) => "TSInterfaceDeclaration",
+        AstKind::TSTypeParameter(_) => "TSTypeParameter",
+        AstKind::TSEnumDeclaration(_) => "TSEnumDeclaration",
+        AstKind::TSEnumMember(_) => "TSEnumMember",
+        AstKind::TSModuleDeclaration(_) => "TSModuleDeclaration",
+        AstKind::TSConditionalType(_) => "TSConditionalType",
+        AstKind::TSMappedType(_) => "TSMappedType",
+        AstKind::TSMethodSignature(_) => "TSMethodSignature",
+        AstKind::TSConstructSignatureDeclaration(_) => "TSConstructSignatureDeclaration",
+        _ => "Unknown",
+    }
+}
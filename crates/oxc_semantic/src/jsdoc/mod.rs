@@ -4,4 +4,7 @@ mod parser;
 
 pub use builder::JSDocBuilder;
 pub use finder::JSDocFinder;
-pub use parser::{JSDoc, JSDocTag};
+pub use parser::{
+    JSDoc, JSDocFunctionParam, JSDocFunctionType, JSDocObjectField, JSDocTag, JSDocType,
+    JSDocTypeKind,
+};
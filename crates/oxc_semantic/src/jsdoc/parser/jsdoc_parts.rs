@@ -1,5 +1,8 @@
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
 
+use super::jsdoc_type::{parse_jsdoc_type, JSDocType};
+
 /// Used for `JSDoc.comment` and `JSDocTag.comment`
 #[derive(Debug, Clone, Copy)]
 pub struct JSDocCommentPart<'a> {
@@ -125,6 +128,11 @@ impl<'a> JSDocTagTypePart<'a> {
         // +1 for `{`, -1 for `}`
         self.raw[1..self.raw.len() - 1].trim()
     }
+
+    /// Parses the type content, e.g. `Array<string>` or `function(number): void`.
+    pub fn parsed_type(&self) -> Result<JSDocType<'a>, OxcDiagnostic> {
+        parse_jsdoc_type(&self.raw[1..self.raw.len() - 1], self.span.start + 1)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -161,6 +169,7 @@ mod test {
     use oxc_span::{Span, SPAN};
 
     use super::{JSDocCommentPart, JSDocTagKindPart, JSDocTagTypeNamePart, JSDocTagTypePart};
+    use crate::jsdoc::parser::JSDocTypeKind;
 
     #[test]
     fn comment_part_parsed() {
@@ -291,6 +300,18 @@ mod test {
         }
     }
 
+    #[test]
+    fn type_part_parsed_type() {
+        let source = "{ Array<string> }";
+        let type_part = JSDocTagTypePart::new(source, Span::new(0, 17));
+        let ty = type_part.parsed_type().unwrap();
+        assert_eq!(ty.span.source_text(source), "Array<string>");
+        assert!(matches!(ty.kind, JSDocTypeKind::Generic { .. }));
+
+        let type_part = JSDocTagTypePart::new("{Array<}", SPAN);
+        assert!(type_part.parsed_type().is_err());
+    }
+
     #[test]
     fn type_name_part_parsed() {
         for (actual, expect) in [
@@ -0,0 +1,1185 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+
+// The type grammar accepted here is the union of what JSDoc, Closure Compiler and TypeScript
+// (in JSDoc) accept, since all of them are found in the wild, often mixed in the same file.
+// e.g. `{Array.<string>}`, `{?function(this:Foo, ...number): void}`, `{(a: string) => void}`
+//
+// Syntax which is specific to TypeScript type declarations, like mapped types `{[K in T]: U}`
+// or `infer U`, is not supported.
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JSDocType<'a> {
+    pub span: Span,
+    pub kind: JSDocTypeKind<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JSDocTypeKind<'a> {
+    /// `*`
+    Any,
+    /// `?`
+    Unknown,
+    /// `string`, `foo.Bar`, `Foo#bar`, `module:foo/bar~Baz`
+    Name(&'a str),
+    /// `"foo"`, `'foo'`, without quotes
+    StringLiteral(&'a str),
+    /// `1`, `-1.5`
+    NumberLiteral(&'a str),
+    /// `?T`, `T?`
+    Nullable(Box<JSDocType<'a>>),
+    /// `!T`, `T!`
+    NonNullable(Box<JSDocType<'a>>),
+    /// `T=`
+    Optional(Box<JSDocType<'a>>),
+    /// `...T`, or `...` alone
+    Variadic(Option<Box<JSDocType<'a>>>),
+    /// `(T)`
+    Parenthesized(Box<JSDocType<'a>>),
+    /// `A | B`
+    Union(Vec<JSDocType<'a>>),
+    /// `A & B`
+    Intersection(Vec<JSDocType<'a>>),
+    /// `Array<T>`, `Array.<T>`
+    Generic { base: Box<JSDocType<'a>>, arguments: Vec<JSDocType<'a>> },
+    /// `T[]`
+    Array(Box<JSDocType<'a>>),
+    /// `T[K]`
+    IndexedAccess { object: Box<JSDocType<'a>>, index: Box<JSDocType<'a>> },
+    /// `[A, B]`
+    Tuple(Vec<JSDocType<'a>>),
+    /// `{a: A, b?: B}`, `{a, b}`
+    Object(Vec<JSDocObjectField<'a>>),
+    /// `function(string): void`, `(a: string) => void`
+    Function(Box<JSDocFunctionType<'a>>),
+    /// `typeof foo`
+    TypeOf(Box<JSDocType<'a>>),
+    /// `keyof T`
+    KeyOf(Box<JSDocType<'a>>),
+    /// `import("./foo")`, the module specifier without quotes
+    Import(&'a str),
+    /// `import("./foo").Bar`
+    Member { object: Box<JSDocType<'a>>, name: &'a str },
+    /// `T extends U ? X : Y`
+    Conditional {
+        check: Box<JSDocType<'a>>,
+        extends: Box<JSDocType<'a>>,
+        true_type: Box<JSDocType<'a>>,
+        false_type: Box<JSDocType<'a>>,
+    },
+    /// `x is T`, `asserts x is T`, `asserts x`
+    Predicate { asserts: bool, parameter: &'a str, r#type: Option<Box<JSDocType<'a>>> },
+    /// `` `foo-${T}` ``, the quasis are raw text without the backticks
+    TemplateLiteral { quasis: Vec<&'a str>, types: Vec<JSDocType<'a>> },
+    /// `unique symbol`
+    UniqueSymbol,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JSDocObjectField<'a> {
+    pub span: Span,
+    /// Without quotes if it is a string literal.
+    pub key: &'a str,
+    /// `{a?: T}`
+    pub optional: bool,
+    /// `None` for `{a}`
+    pub value: Option<JSDocType<'a>>,
+    /// `Some(string)` for the index signature `{[key: string]: T}`, whose `key` is `key`
+    pub index: Option<JSDocType<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JSDocFunctionType<'a> {
+    /// `(a: string) => void`, rather than `function(string): void`
+    pub arrow: bool,
+    /// `new () => T`
+    pub constructor: bool,
+    /// `function(this: T)`
+    pub this_type: Option<JSDocType<'a>>,
+    /// `function(new: T)`
+    pub new_type: Option<JSDocType<'a>>,
+    pub params: Vec<JSDocFunctionParam<'a>>,
+    pub return_type: Option<JSDocType<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JSDocFunctionParam<'a> {
+    pub span: Span,
+    /// Only arrow functions have named parameters.
+    pub name: Option<&'a str>,
+    /// `(a?: T) => void`
+    pub optional: bool,
+    /// `None` for `(a) => void`
+    pub r#type: Option<JSDocType<'a>>,
+}
+
+/// source_text: Inside of `{}`, may span multiple lines with leading `*`
+/// span_start: Global positioned `Span` start for `source_text`
+pub fn parse_jsdoc_type(
+    source_text: &str,
+    span_start: u32,
+) -> Result<JSDocType<'_>, OxcDiagnostic> {
+    let mut parser = TypeParser { source: source_text, pos: 0, span_start, depth: 0 };
+    parser.skip_whitespace();
+    let ty = parser.parse_type()?;
+    parser.skip_whitespace();
+    match parser.peek() {
+        None => Ok(ty),
+        Some(ch) => Err(parser.unexpected(ch)),
+    }
+}
+
+/// Types nested deeper than this are rejected instead of overflowing the stack.
+const MAX_NESTING_DEPTH: u32 = 128;
+
+struct TypeParser<'a> {
+    source: &'a str,
+    /// Parser local offset, not for global span
+    pos: usize,
+    span_start: u32,
+    /// Current nesting depth, see [`MAX_NESTING_DEPTH`]
+    depth: u32,
+}
+
+impl<'a> TypeParser<'a> {
+    #[allow(clippy::cast_possible_truncation)]
+    fn span(&self, start: usize, end: usize) -> Span {
+        Span::new(self.span_start + start as u32, self.span_start + end as u32)
+    }
+
+    fn ty(&self, start: usize, kind: JSDocTypeKind<'a>) -> JSDocType<'a> {
+        JSDocType { span: self.span(start, self.pos), kind }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.rest().chars().nth(n)
+    }
+
+    fn bump(&mut self) {
+        if let Some(ch) = self.peek() {
+            self.pos += ch.len_utf8();
+        }
+    }
+
+    fn eat(&mut self, ch: char) -> bool {
+        if self.peek() == Some(ch) {
+            self.bump();
+            return true;
+        }
+        false
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        if self.rest().starts_with(s) {
+            self.pos += s.len();
+            return true;
+        }
+        false
+    }
+
+    /// Also skips the leading `*` of each line in a multiline type.
+    fn skip_whitespace(&mut self) {
+        let mut line_start = false;
+        while let Some(ch) = self.peek() {
+            if ch.is_whitespace() {
+                line_start |= ch == '\n';
+            } else if ch == '*' && line_start {
+                line_start = false;
+            } else {
+                break;
+            }
+            self.bump();
+        }
+    }
+
+    fn expect(&mut self, ch: char) -> Result<(), OxcDiagnostic> {
+        self.skip_whitespace();
+        if self.eat(ch) {
+            return Ok(());
+        }
+        let span = self.span(self.pos, self.pos + self.peek().map_or(0, char::len_utf8));
+        Err(OxcDiagnostic::error(format!("Expected `{ch}` in JSDoc type")).with_label(span))
+    }
+
+    fn unexpected(&self, ch: char) -> OxcDiagnostic {
+        OxcDiagnostic::error(format!("Unexpected character `{ch}` in JSDoc type"))
+            .with_label(self.span(self.pos, self.pos + ch.len_utf8()))
+    }
+
+    /// Run `parse` one nesting level deeper, or fail if that exceeds [`MAX_NESTING_DEPTH`].
+    fn nested<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<T, OxcDiagnostic>,
+    ) -> Result<T, OxcDiagnostic> {
+        if self.depth >= MAX_NESTING_DEPTH {
+            return Err(OxcDiagnostic::error(format!(
+                "Maximum nesting depth of {MAX_NESTING_DEPTH} exceeded in JSDoc type"
+            ))
+            .with_label(self.span(self.pos, self.pos)));
+        }
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    /// Whether the next character ends the current type.
+    fn at_type_end(&mut self) -> bool {
+        let pos = self.pos;
+        self.skip_whitespace();
+        let end = matches!(
+            self.peek(),
+            None | Some(',' | ')' | ']' | '>' | '}' | '|' | '&' | '=' | ':' | ';')
+        );
+        self.pos = pos;
+        end
+    }
+
+    /// A union type, a conditional type or a type predicate.
+    fn parse_type(&mut self) -> Result<JSDocType<'a>, OxcDiagnostic> {
+        self.nested(Self::parse_conditional_type)
+    }
+
+    fn parse_conditional_type(&mut self) -> Result<JSDocType<'a>, OxcDiagnostic> {
+        let start = self.pos;
+        if let Some(predicate) = self.try_parse_type_predicate(start)? {
+            return Ok(predicate);
+        }
+        let check = self.parse_union()?;
+        let pos = self.pos;
+        self.skip_whitespace();
+        if !self.at_keyword("extends") {
+            self.pos = pos;
+            return Ok(check);
+        }
+        self.pos += "extends".len();
+        self.skip_whitespace();
+        let extends = self.parse_union()?;
+        self.expect('?')?;
+        self.skip_whitespace();
+        let true_type = self.parse_type()?;
+        self.expect(':')?;
+        self.skip_whitespace();
+        let false_type = self.parse_type()?;
+        Ok(self.ty(
+            start,
+            JSDocTypeKind::Conditional {
+                check: Box::new(check),
+                extends: Box::new(extends),
+                true_type: Box::new(true_type),
+                false_type: Box::new(false_type),
+            },
+        ))
+    }
+
+    /// `x is T`, `this is T`, `asserts x is T`, `asserts x`
+    ///
+    /// Returns `None` without consuming anything if this is not a type predicate.
+    fn try_parse_type_predicate(
+        &mut self,
+        start: usize,
+    ) -> Result<Option<JSDocType<'a>>, OxcDiagnostic> {
+        let asserts = self.at_keyword("asserts");
+        if asserts {
+            self.pos += "asserts".len();
+            self.skip_whitespace();
+        }
+        if !self.peek().is_some_and(is_identifier_start) {
+            self.pos = start;
+            return Ok(None);
+        }
+        let parameter = self.parse_identifier();
+        let parameter_end = self.pos;
+        self.skip_whitespace();
+        if self.at_keyword("is") {
+            self.pos += "is".len();
+            self.skip_whitespace();
+            let ty = Box::new(self.parse_type()?);
+            let kind = JSDocTypeKind::Predicate { asserts, parameter, r#type: Some(ty) };
+            return Ok(Some(self.ty(start, kind)));
+        }
+        if asserts {
+            self.pos = parameter_end;
+            let kind = JSDocTypeKind::Predicate { asserts, parameter, r#type: None };
+            return Ok(Some(self.ty(start, kind)));
+        }
+        self.pos = start;
+        Ok(None)
+    }
+
+    fn parse_union(&mut self) -> Result<JSDocType<'a>, OxcDiagnostic> {
+        let start = self.pos;
+        // Leading `|` is allowed, e.g. multiline unions
+        if self.eat('|') {
+            self.skip_whitespace();
+        }
+        let first = self.parse_intersection()?;
+        let mut types = vec![first];
+        loop {
+            let pos = self.pos;
+            self.skip_whitespace();
+            if !self.eat('|') {
+                self.pos = pos;
+                break;
+            }
+            self.skip_whitespace();
+            types.push(self.parse_intersection()?);
+        }
+        if types.len() == 1 {
+            return Ok(types.pop().unwrap());
+        }
+        Ok(self.ty(start, JSDocTypeKind::Union(types)))
+    }
+
+    fn parse_intersection(&mut self) -> Result<JSDocType<'a>, OxcDiagnostic> {
+        let start = self.pos;
+        let first = self.parse_prefix()?;
+        let mut types = vec![first];
+        loop {
+            let pos = self.pos;
+            self.skip_whitespace();
+            if !self.eat('&') {
+                self.pos = pos;
+                break;
+            }
+            self.skip_whitespace();
+            types.push(self.parse_prefix()?);
+        }
+        if types.len() == 1 {
+            return Ok(types.pop().unwrap());
+        }
+        Ok(self.ty(start, JSDocTypeKind::Intersection(types)))
+    }
+
+    fn parse_prefix(&mut self) -> Result<JSDocType<'a>, OxcDiagnostic> {
+        let start = self.pos;
+        if self.eat_str("...") {
+            if self.at_type_end() {
+                return Ok(self.ty(start, JSDocTypeKind::Variadic(None)));
+            }
+            self.skip_whitespace();
+            let ty = self.nested(Self::parse_prefix)?;
+            return Ok(self.ty(start, JSDocTypeKind::Variadic(Some(Box::new(ty)))));
+        }
+        if self.eat('?') {
+            if self.at_type_end() {
+                return Ok(self.ty(start, JSDocTypeKind::Unknown));
+            }
+            self.skip_whitespace();
+            let ty = self.nested(Self::parse_prefix)?;
+            return Ok(self.ty(start, JSDocTypeKind::Nullable(Box::new(ty))));
+        }
+        if self.eat('!') {
+            self.skip_whitespace();
+            let ty = self.nested(Self::parse_prefix)?;
+            return Ok(self.ty(start, JSDocTypeKind::NonNullable(Box::new(ty))));
+        }
+        if self.at_keyword("unique") {
+            let pos = self.pos;
+            self.pos += "unique".len();
+            self.skip_whitespace();
+            if self.at_word("symbol") {
+                self.pos += "symbol".len();
+                return Ok(self.ty(start, JSDocTypeKind::UniqueSymbol));
+            }
+            self.pos = pos;
+        }
+        for keyword in ["typeof", "keyof"] {
+            if self.at_keyword(keyword) {
+                self.pos += keyword.len();
+                self.skip_whitespace();
+                let ty = Box::new(self.nested(Self::parse_prefix)?);
+                let kind = if keyword == "typeof" {
+                    JSDocTypeKind::TypeOf(ty)
+                } else {
+                    JSDocTypeKind::KeyOf(ty)
+                };
+                return Ok(self.ty(start, kind));
+            }
+        }
+        self.parse_postfix()
+    }
+
+    /// `keyword` followed by whitespace and something which is not the end of the type.
+    fn at_keyword(&mut self, keyword: &str) -> bool {
+        let rest = self.rest();
+        if !rest.starts_with(keyword)
+            || !rest[keyword.len()..].starts_with(|ch: char| ch.is_whitespace())
+        {
+            return false;
+        }
+        let pos = self.pos;
+        self.pos += keyword.len();
+        let at_end = self.at_type_end();
+        self.pos = pos;
+        !at_end
+    }
+
+    /// `word`, not followed by an identifier character.
+    fn at_word(&self, word: &str) -> bool {
+        self.rest().strip_prefix(word).is_some_and(|rest| !rest.starts_with(is_identifier_part))
+    }
+
+    fn parse_postfix(&mut self) -> Result<JSDocType<'a>, OxcDiagnostic> {
+        let start = self.pos;
+        let mut ty = self.parse_primary()?;
+        loop {
+            match self.peek() {
+                Some('[') => {
+                    self.bump();
+                    self.skip_whitespace();
+                    if self.eat(']') {
+                        ty = self.ty(start, JSDocTypeKind::Array(Box::new(ty)));
+                    } else {
+                        let index = self.parse_type()?;
+                        self.expect(']')?;
+                        ty = self.ty(
+                            start,
+                            JSDocTypeKind::IndexedAccess {
+                                object: Box::new(ty),
+                                index: Box::new(index),
+                            },
+                        );
+                    }
+                }
+                Some('<') => {
+                    self.bump();
+                    ty = self.parse_type_arguments(start, ty)?;
+                }
+                Some('.') if self.peek_nth(1) == Some('<') => {
+                    self.pos += 2;
+                    ty = self.parse_type_arguments(start, ty)?;
+                }
+                Some('.') if self.peek_nth(1).is_some_and(is_identifier_start) => {
+                    self.bump();
+                    let name = self.parse_identifier();
+                    ty = self.ty(start, JSDocTypeKind::Member { object: Box::new(ty), name });
+                }
+                Some('?') => {
+                    let pos = self.pos;
+                    self.bump();
+                    // `T extends U ? X : Y`
+                    if !self.at_type_end() {
+                        self.pos = pos;
+                        break;
+                    }
+                    ty = self.ty(start, JSDocTypeKind::Nullable(Box::new(ty)));
+                }
+                Some('!') => {
+                    self.bump();
+                    ty = self.ty(start, JSDocTypeKind::NonNullable(Box::new(ty)));
+                }
+                Some('=') if self.peek_nth(1) != Some('>') => {
+                    self.bump();
+                    ty = self.ty(start, JSDocTypeKind::Optional(Box::new(ty)));
+                }
+                _ => break,
+            }
+        }
+        Ok(ty)
+    }
+
+    /// After `<`
+    fn parse_type_arguments(
+        &mut self,
+        start: usize,
+        base: JSDocType<'a>,
+    ) -> Result<JSDocType<'a>, OxcDiagnostic> {
+        let mut arguments = vec![];
+        loop {
+            self.skip_whitespace();
+            arguments.push(self.parse_type()?);
+            self.skip_whitespace();
+            if !self.eat(',') {
+                break;
+            }
+        }
+        self.expect('>')?;
+        Ok(self.ty(start, JSDocTypeKind::Generic { base: Box::new(base), arguments }))
+    }
+
+    fn parse_primary(&mut self) -> Result<JSDocType<'a>, OxcDiagnostic> {
+        let start = self.pos;
+        let Some(ch) = self.peek() else {
+            return Err(OxcDiagnostic::error("Expected a JSDoc type")
+                .with_label(self.span(self.pos, self.pos)));
+        };
+        match ch {
+            '*' => {
+                self.bump();
+                Ok(self.ty(start, JSDocTypeKind::Any))
+            }
+            '(' => {
+                if let Some(function) = self.try_parse_arrow_function(start, false) {
+                    return Ok(function);
+                }
+                self.bump();
+                self.skip_whitespace();
+                let ty = self.parse_type()?;
+                self.expect(')')?;
+                Ok(self.ty(start, JSDocTypeKind::Parenthesized(Box::new(ty))))
+            }
+            '{' => self.parse_object(),
+            '[' => {
+                self.bump();
+                let types = self.parse_list(']')?;
+                Ok(self.ty(start, JSDocTypeKind::Tuple(types)))
+            }
+            '"' | '\'' => {
+                let value = self.parse_string()?;
+                Ok(self.ty(start, JSDocTypeKind::StringLiteral(value)))
+            }
+            '`' => self.parse_template_literal(),
+            '-' | '0'..='9' => {
+                let value = self.parse_number();
+                if value.is_empty() || value == "-" {
+                    return Err(self.unexpected(ch));
+                }
+                Ok(self.ty(start, JSDocTypeKind::NumberLiteral(value)))
+            }
+            ch if is_identifier_start(ch) => {
+                let rest = self.rest();
+                if rest.starts_with("function") && self.peek_after("function") == Some('(') {
+                    return self.parse_closure_function();
+                }
+                if rest.starts_with("new") && self.peek_after("new") == Some('(') {
+                    let pos = self.pos;
+                    self.pos += "new".len();
+                    self.skip_whitespace();
+                    if let Some(function) = self.try_parse_arrow_function(start, true) {
+                        return Ok(function);
+                    }
+                    self.pos = pos;
+                }
+                if rest.starts_with("import") && self.peek_after("import") == Some('(') {
+                    self.pos += "import".len();
+                    self.expect('(')?;
+                    self.skip_whitespace();
+                    let specifier = self.parse_string()?;
+                    self.expect(')')?;
+                    return Ok(self.ty(start, JSDocTypeKind::Import(specifier)));
+                }
+                let name = self.parse_name_path()?;
+                Ok(self.ty(start, JSDocTypeKind::Name(name)))
+            }
+            ch => Err(self.unexpected(ch)),
+        }
+    }
+
+    /// `` `foo-${T}-bar` ``
+    fn parse_template_literal(&mut self) -> Result<JSDocType<'a>, OxcDiagnostic> {
+        let start = self.pos;
+        self.bump();
+        let mut quasis = vec![];
+        let mut types = vec![];
+        let mut quasi_start = self.pos;
+        loop {
+            match self.peek() {
+                None => {
+                    return Err(OxcDiagnostic::error(
+                        "Unterminated template literal in JSDoc type",
+                    )
+                    .with_label(self.span(start, self.pos)));
+                }
+                Some('\\') => {
+                    self.bump();
+                    self.bump();
+                }
+                Some('`') => {
+                    quasis.push(&self.source[quasi_start..self.pos]);
+                    self.bump();
+                    break;
+                }
+                Some('$') if self.peek_nth(1) == Some('{') => {
+                    quasis.push(&self.source[quasi_start..self.pos]);
+                    self.pos += 2;
+                    self.skip_whitespace();
+                    types.push(self.parse_type()?);
+                    self.expect('}')?;
+                    quasi_start = self.pos;
+                }
+                Some(_) => self.bump(),
+            }
+        }
+        Ok(self.ty(start, JSDocTypeKind::TemplateLiteral { quasis, types }))
+    }
+
+    /// The next non-whitespace character after `keyword`, if `keyword` is a whole word.
+    fn peek_after(&mut self, keyword: &str) -> Option<char> {
+        if self.rest()[keyword.len()..].starts_with(is_identifier_part) {
+            return None;
+        }
+        let pos = self.pos;
+        self.pos += keyword.len();
+        self.skip_whitespace();
+        let ch = self.peek();
+        self.pos = pos;
+        ch
+    }
+
+    /// Comma separated types until `end`, after the opening bracket.
+    fn parse_list(&mut self, end: char) -> Result<Vec<JSDocType<'a>>, OxcDiagnostic> {
+        let mut types = vec![];
+        loop {
+            self.skip_whitespace();
+            if self.eat(end) {
+                return Ok(types);
+            }
+            types.push(self.parse_type()?);
+            self.skip_whitespace();
+            if !self.eat(',') {
+                self.expect(end)?;
+                return Ok(types);
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<JSDocType<'a>, OxcDiagnostic> {
+        let start = self.pos;
+        self.bump();
+        let mut fields = vec![];
+        loop {
+            self.skip_whitespace();
+            if self.eat('}') {
+                break;
+            }
+            let field_start = self.pos;
+            let mut index = None;
+            let key = match self.peek() {
+                // `[key: string]: T`
+                Some('[') => {
+                    self.bump();
+                    self.skip_whitespace();
+                    match self.peek() {
+                        Some(ch) if is_identifier_start(ch) => {}
+                        Some(ch) => return Err(self.unexpected(ch)),
+                        None => {
+                            self.expect(']')?;
+                        }
+                    }
+                    let key = self.parse_identifier();
+                    self.expect(':')?;
+                    self.skip_whitespace();
+                    index = Some(self.parse_type()?);
+                    self.expect(']')?;
+                    key
+                }
+                Some('"' | '\'') => self.parse_string()?,
+                Some('0'..='9') => self.parse_number(),
+                Some(ch) if is_identifier_start(ch) => self.parse_identifier(),
+                Some(ch) => return Err(self.unexpected(ch)),
+                None => {
+                    self.expect('}')?;
+                    break;
+                }
+            };
+            self.skip_whitespace();
+            let optional = self.eat('?');
+            self.skip_whitespace();
+            // The value of an index signature is required
+            let value = if index.is_some() {
+                self.expect(':')?;
+                self.skip_whitespace();
+                Some(self.parse_type()?)
+            } else if self.eat(':') {
+                self.skip_whitespace();
+                Some(self.parse_type()?)
+            } else {
+                None
+            };
+            fields.push(JSDocObjectField {
+                span: self.span(field_start, self.pos),
+                key,
+                optional,
+                value,
+                index,
+            });
+            self.skip_whitespace();
+            if !self.eat(',') && !self.eat(';') {
+                self.expect('}')?;
+                break;
+            }
+        }
+        Ok(self.ty(start, JSDocTypeKind::Object(fields)))
+    }
+
+    /// `function(this: T, new: T, string, number=, ...boolean): void`
+    fn parse_closure_function(&mut self) -> Result<JSDocType<'a>, OxcDiagnostic> {
+        let start = self.pos;
+        self.pos += "function".len();
+        self.expect('(')?;
+        let mut function = JSDocFunctionType {
+            arrow: false,
+            constructor: false,
+            this_type: None,
+            new_type: None,
+            params: vec![],
+            return_type: None,
+        };
+        loop {
+            self.skip_whitespace();
+            if self.eat(')') {
+                break;
+            }
+            let param_start = self.pos;
+            let mut context = None;
+            for keyword in ["this", "new"] {
+                if self.rest().starts_with(keyword) && self.peek_after(keyword) == Some(':') {
+                    self.pos += keyword.len();
+                    self.expect(':')?;
+                    self.skip_whitespace();
+                    context = Some(keyword);
+                }
+            }
+            let ty = self.parse_type()?;
+            match context {
+                Some("this") => function.this_type = Some(ty),
+                Some(_) => function.new_type = Some(ty),
+                None => function.params.push(JSDocFunctionParam {
+                    span: self.span(param_start, self.pos),
+                    name: None,
+                    optional: false,
+                    r#type: Some(ty),
+                }),
+            }
+            self.skip_whitespace();
+            if !self.eat(',') {
+                self.expect(')')?;
+                break;
+            }
+        }
+        let pos = self.pos;
+        self.skip_whitespace();
+        if self.eat(':') {
+            self.skip_whitespace();
+            function.return_type = Some(self.nested(Self::parse_prefix)?);
+        } else {
+            self.pos = pos;
+        }
+        Ok(self.ty(start, JSDocTypeKind::Function(Box::new(function))))
+    }
+
+    /// `(a: string, b?: number, ...c: boolean[]) => void`, at `(`.
+    ///
+    /// Returns `None` without consuming anything if this is not an arrow function,
+    /// e.g. a parenthesized type.
+    fn try_parse_arrow_function(
+        &mut self,
+        start: usize,
+        constructor: bool,
+    ) -> Option<JSDocType<'a>> {
+        let pos = self.pos;
+        let function = self.parse_arrow_function(start, constructor);
+        if function.is_none() {
+            self.pos = pos;
+        }
+        function
+    }
+
+    fn parse_arrow_function(&mut self, start: usize, constructor: bool) -> Option<JSDocType<'a>> {
+        if !self.eat('(') {
+            return None;
+        }
+        let mut params = vec![];
+        loop {
+            self.skip_whitespace();
+            if self.eat(')') {
+                break;
+            }
+            let param_start = self.pos;
+            let rest = self.eat_str("...");
+            if !self.peek().is_some_and(is_identifier_start) {
+                return None;
+            }
+            let name = self.parse_identifier();
+            self.skip_whitespace();
+            let optional = self.eat('?');
+            self.skip_whitespace();
+            let mut ty = if self.eat(':') {
+                self.skip_whitespace();
+                Some(self.parse_type().ok()?)
+            } else {
+                None
+            };
+            if rest {
+                ty = Some(self.ty(param_start, JSDocTypeKind::Variadic(ty.map(Box::new))));
+            }
+            params.push(JSDocFunctionParam {
+                span: self.span(param_start, self.pos),
+                name: Some(name),
+                optional,
+                r#type: ty,
+            });
+            self.skip_whitespace();
+            if !self.eat(',') {
+                if !self.eat(')') {
+                    return None;
+                }
+                break;
+            }
+        }
+        self.skip_whitespace();
+        if !self.eat_str("=>") {
+            return None;
+        }
+        self.skip_whitespace();
+        let return_type = self.parse_type().ok()?;
+        let function = JSDocFunctionType {
+            arrow: true,
+            constructor,
+            this_type: None,
+            new_type: None,
+            params,
+            return_type: Some(return_type),
+        };
+        Some(self.ty(start, JSDocTypeKind::Function(Box::new(function))))
+    }
+
+    fn parse_identifier(&mut self) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some_and(is_identifier_part) {
+            self.bump();
+        }
+        &self.source[start..self.pos]
+    }
+
+    /// `foo.bar`, `Foo#bar`, `Foo~bar`, `module:foo/bar.Baz`, `external:"foo"`
+    fn parse_name_path(&mut self) -> Result<&'a str, OxcDiagnostic> {
+        let start = self.pos;
+        let first = self.parse_identifier();
+        if matches!(first, "module" | "external" | "event") && self.peek() == Some(':') {
+            self.bump();
+            if matches!(self.peek(), Some('"' | '\'')) {
+                self.parse_string()?;
+            } else {
+                while self
+                    .peek()
+                    .is_some_and(|ch| is_identifier_part(ch) || matches!(ch, '/' | '-' | '@'))
+                    || (self.peek() == Some('.')
+                        && self.peek_nth(1).is_some_and(is_identifier_part))
+                {
+                    self.bump();
+                }
+            }
+        }
+        while matches!(self.peek(), Some('.' | '#' | '~'))
+            && self.peek_nth(1).is_some_and(is_identifier_start)
+        {
+            self.bump();
+            self.parse_identifier();
+        }
+        Ok(&self.source[start..self.pos])
+    }
+
+    /// Returns the content without quotes.
+    fn parse_string(&mut self) -> Result<&'a str, OxcDiagnostic> {
+        let start = self.pos;
+        let Some(quote @ ('"' | '\'')) = self.peek() else {
+            return Err(OxcDiagnostic::error("Expected a string in JSDoc type")
+                .with_label(self.span(self.pos, self.pos)));
+        };
+        self.bump();
+        loop {
+            match self.peek() {
+                None => {
+                    return Err(OxcDiagnostic::error("Unterminated string in JSDoc type")
+                        .with_label(self.span(start, self.pos)));
+                }
+                Some('\\') => {
+                    self.bump();
+                    self.bump();
+                }
+                Some(ch) => {
+                    self.bump();
+                    if ch == quote {
+                        return Ok(&self.source[start + 1..self.pos - 1]);
+                    }
+                }
+            }
+        }
+    }
+
+    fn parse_number(&mut self) -> &'a str {
+        let start = self.pos;
+        self.eat('-');
+        while self.peek().is_some_and(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '.' | '_')) {
+            if self.peek() == Some('.') && !self.peek_nth(1).is_some_and(|ch| ch.is_ascii_digit()) {
+                break;
+            }
+            self.bump();
+        }
+        &self.source[start..self.pos]
+    }
+}
+
+fn is_identifier_start(ch: char) -> bool {
+    ch.is_alphabetic() || matches!(ch, '_' | '$')
+}
+
+fn is_identifier_part(ch: char) -> bool {
+    ch.is_alphanumeric() || matches!(ch, '_' | '$')
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::Span;
+
+    use super::{parse_jsdoc_type, JSDocType, JSDocTypeKind, MAX_NESTING_DEPTH};
+
+    /// Prints the type back with explicit grouping, to compare the structure.
+    fn print(ty: &JSDocType) -> String {
+        let list =
+            |types: &[JSDocType], sep: &str| types.iter().map(print).collect::<Vec<_>>().join(sep);
+        match &ty.kind {
+            JSDocTypeKind::Any => "*".to_string(),
+            JSDocTypeKind::Unknown => "?".to_string(),
+            JSDocTypeKind::Name(name) | JSDocTypeKind::NumberLiteral(name) => (*name).to_string(),
+            JSDocTypeKind::StringLiteral(value) => format!("'{value}'"),
+            JSDocTypeKind::Nullable(ty) => format!("?{}", print(ty)),
+            JSDocTypeKind::NonNullable(ty) => format!("!{}", print(ty)),
+            JSDocTypeKind::Optional(ty) => format!("{}=", print(ty)),
+            JSDocTypeKind::Variadic(ty) => {
+                format!("...{}", ty.as_ref().map_or(String::new(), |ty| print(ty)))
+            }
+            JSDocTypeKind::Parenthesized(ty) => format!("({})", print(ty)),
+            JSDocTypeKind::Union(types) => format!("U[{}]", list(types, ", ")),
+            JSDocTypeKind::Intersection(types) => format!("I[{}]", list(types, ", ")),
+            JSDocTypeKind::Generic { base, arguments } => {
+                format!("{}<{}>", print(base), list(arguments, ", "))
+            }
+            JSDocTypeKind::Array(ty) => format!("{}[]", print(ty)),
+            JSDocTypeKind::IndexedAccess { object, index } => {
+                format!("{}[{}]", print(object), print(index))
+            }
+            JSDocTypeKind::Tuple(types) => format!("[{}]", list(types, ", ")),
+            JSDocTypeKind::Object(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|field| {
+                        let key = field.index.as_ref().map_or_else(
+                            || field.key.to_string(),
+                            |index| format!("[{}: {}]", field.key, print(index)),
+                        );
+                        format!(
+                            "{}{}{}",
+                            key,
+                            if field.optional { "?" } else { "" },
+                            field
+                                .value
+                                .as_ref()
+                                .map_or(String::new(), |ty| format!(": {}", print(ty)))
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                format!("{{{}}}", fields.join(", "))
+            }
+            JSDocTypeKind::Function(function) => {
+                let mut params = vec![];
+                if let Some(ty) = &function.this_type {
+                    params.push(format!("this: {}", print(ty)));
+                }
+                if let Some(ty) = &function.new_type {
+                    params.push(format!("new: {}", print(ty)));
+                }
+                for param in &function.params {
+                    params.push(format!(
+                        "{}{}{}",
+                        param.name.unwrap_or_default(),
+                        if param.optional { "?" } else { "" },
+                        param.r#type.as_ref().map_or(String::new(), |ty| if param.name.is_some() {
+                            format!(": {}", print(ty))
+                        } else {
+                            print(ty)
+                        })
+                    ));
+                }
+                format!(
+                    "{}fn({}){}",
+                    if function.constructor { "new " } else { "" },
+                    params.join(", "),
+                    function
+                        .return_type
+                        .as_ref()
+                        .map_or(String::new(), |ty| format!(" -> {}", print(ty)))
+                )
+            }
+            JSDocTypeKind::TypeOf(ty) => format!("typeof {}", print(ty)),
+            JSDocTypeKind::KeyOf(ty) => format!("keyof {}", print(ty)),
+            JSDocTypeKind::Import(specifier) => format!("import('{specifier}')"),
+            JSDocTypeKind::Member { object, name } => format!("{}.{name}", print(object)),
+            JSDocTypeKind::Conditional { check, extends, true_type, false_type } => format!(
+                "C[{}, {}, {}, {}]",
+                print(check),
+                print(extends),
+                print(true_type),
+                print(false_type)
+            ),
+            JSDocTypeKind::Predicate { asserts, parameter, r#type } => format!(
+                "{}{parameter}{}",
+                if *asserts { "asserts " } else { "" },
+                r#type.as_ref().map_or(String::new(), |ty| format!(" is {}", print(ty)))
+            ),
+            JSDocTypeKind::TemplateLiteral { quasis, types } => {
+                let mut text = format!("`{}", quasis[0]);
+                for (ty, quasi) in types.iter().zip(&quasis[1..]) {
+                    text.push_str(&format!("${{{}}}{quasi}", print(ty)));
+                }
+                text.push('`');
+                text
+            }
+            JSDocTypeKind::UniqueSymbol => "unique symbol".to_string(),
+        }
+    }
+
+    #[test]
+    fn parse() {
+        for (source, expected) in [
+            ("string", "string"),
+            ("*", "*"),
+            ("?", "?"),
+            ("foo.Bar#baz~qux", "foo.Bar#baz~qux"),
+            ("module:foo/bar-baz.Qux", "module:foo/bar-baz.Qux"),
+            ("'foo' | \"bar\" | -1.5", "U['foo', 'bar', -1.5]"),
+            ("Array<string|number>", "Array<U[string, number]>"),
+            ("Array.<string>", "Array<string>"),
+            ("Object<string, Array<number>>", "Object<string, Array<number>>"),
+            ("?string", "?string"),
+            ("string?", "?string"),
+            ("!Object", "!Object"),
+            ("number=", "number="),
+            ("...number", "...number"),
+            ("(string|number)[]", "(U[string, number])[]"),
+            ("string[][]", "string[][]"),
+            ("T['key']", "T['key']"),
+            ("A & B | C", "U[I[A, B], C]"),
+            ("| A | B", "U[A, B]"),
+            ("[string, number=]", "[string, number=]"),
+            ("{a: string, 'b'?: number; c}", "{a: string, b?: number, c}"),
+            ("{}", "{}"),
+            ("function", "function"),
+            ("function(string, number=): void", "fn(string, number=) -> void"),
+            ("function(this:Foo, new:Bar, ...*)", "fn(this: Foo, new: Bar, ...*)"),
+            ("?function(): (string|undefined)", "?fn() -> (U[string, undefined])"),
+            (
+                "(a: string, b?: number, ...c: boolean[]) => void",
+                "fn(a: string, b?: number, c: ...boolean[]) -> void",
+            ),
+            ("() => () => void", "fn() -> fn() -> void"),
+            ("new (a) => Foo", "new fn(a) -> Foo"),
+            ("(() => void) | null", "U[(fn() -> void), null]"),
+            ("typeof foo", "typeof foo"),
+            ("keyof typeof foo", "keyof typeof foo"),
+            ("typeof", "typeof"),
+            ("import('./foo').Bar<T>", "import('./foo').Bar<T>"),
+            ("{\n *   a: string,\n *   b: number\n * }", "{a: string, b: number}"),
+            ("{[key: string]: number}", "{[key: string]: number}"),
+            ("{[ key : string|number ]?: T, a: A}", "{[key: U[string, number]]?: T, a: A}"),
+            ("x is string", "x is string"),
+            ("this is Array<T>", "this is Array<T>"),
+            ("asserts x is string[]", "asserts x is string[]"),
+            ("asserts value", "asserts value"),
+            ("(x: unknown) => x is Foo", "fn(x: unknown) -> x is Foo"),
+            ("T extends string ? 'a' : 'b'", "C[T, string, 'a', 'b']"),
+            ("T extends U? X : Y extends Z ? 1 : 2", "C[T, U, X, C[Y, Z, 1, 2]]"),
+            ("Array<T extends (infer) ? A : B>", "Array<C[T, (infer), A, B]>"),
+            ("`prefix-${string}`", "`prefix-${string}`"),
+            ("`${A | B}.${number}` | ''", "U[`${U[A, B]}.${number}`, '']"),
+            ("`\\`${`nested`}`", "`\\`${`nested`}`"),
+            ("unique symbol", "unique symbol"),
+            ("{a: unique symbol}", "{a: unique symbol}"),
+            ("unique", "unique"),
+            ("is", "is"),
+            ("asserts", "asserts"),
+        ] {
+            let ty = parse_jsdoc_type(source, 0).unwrap_or_else(|err| panic!("{source}: {err}"));
+            assert_eq!(print(&ty), expected, "{source}");
+            assert_eq!(ty.span, Span::new(0, u32::try_from(source.len()).unwrap()), "{source}");
+        }
+    }
+
+    #[test]
+    fn parse_error() {
+        for source in [
+            "",
+            "Array<string",
+            "string number",
+            "{a: }",
+            "function(",
+            "'foo",
+            "(a: string) =>",
+            "#",
+            "{[key]: string}",
+            "{[key: string]}",
+            "x is",
+            "T extends U",
+            "T extends U ? X",
+            "`${T`",
+            "`${}`",
+            "unique string",
+            // Mapped types are not supported
+            "{[K in keyof T]: T[K]}",
+            "{readonly [K in T]?: X}",
+        ] {
+            assert!(parse_jsdoc_type(source, 0).is_err(), "{source}");
+        }
+    }
+
+    #[test]
+    fn span() {
+        let ty = parse_jsdoc_type("Array<foo | bar>", 10).unwrap();
+        let JSDocTypeKind::Generic { arguments, .. } = ty.kind else { unreachable!() };
+        let JSDocTypeKind::Union(types) = &arguments[0].kind else { unreachable!() };
+        assert_eq!(arguments[0].span, Span::new(16, 25));
+        assert_eq!(types[1].span, Span::new(22, 25));
+    }
+
+    #[test]
+    fn max_nesting_depth() {
+        let nest = |depth: usize, (open, close): (&str, &str)| {
+            open.repeat(depth) + "T" + &close.repeat(depth)
+        };
+        let cases = [
+            ("(", ")"),
+            ("Array<", ">"),
+            ("{a: ", "}"),
+            ("[", "]"),
+            ("?", ""),
+            ("keyof ", ""),
+            ("function(): ", ""),
+            ("T extends U ? ", " : X"),
+        ];
+        // A JSDoc comment is parsed on a linter thread, with a 2 MiB stack.
+        std::thread::Builder::new()
+            .stack_size(2 * 1024 * 1024)
+            .spawn(move || {
+                for case in cases {
+                    let source = nest(100, case);
+                    assert!(parse_jsdoc_type(&source, 0).is_ok(), "{source}");
+                    let source = nest(100_000, case);
+                    let error = parse_jsdoc_type(&source, 0).unwrap_err();
+                    assert_eq!(
+                        error.message,
+                        format!(
+                            "Maximum nesting depth of {MAX_NESTING_DEPTH} exceeded in JSDoc type"
+                        ),
+                        "{}",
+                        case.0
+                    );
+                }
+                // Falls back to a parenthesized type, which then fails on `:`.
+                let source = nest(100_000, ("(a: ", ") => void"));
+                assert!(parse_jsdoc_type(&source, 0).is_err());
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+}
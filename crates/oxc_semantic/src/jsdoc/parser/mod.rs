@@ -1,8 +1,12 @@
 mod jsdoc;
 mod jsdoc_parts;
 mod jsdoc_tag;
+mod jsdoc_type;
 mod parse;
 mod utils;
 
 pub use jsdoc::JSDoc;
 pub use jsdoc_tag::JSDocTag;
+pub use jsdoc_type::{
+    JSDocFunctionParam, JSDocFunctionType, JSDocObjectField, JSDocType, JSDocTypeKind,
+};
@@ -19,15 +19,21 @@ pub use oxc_syntax::{
     symbol::{SymbolFlags, SymbolId},
 };
 
+#[cfg(feature = "binary_codec")]
+pub mod cache;
 pub mod dot;
 
 mod binder;
 mod builder;
+mod call_graph;
 mod checker;
 mod class;
+mod dataflow;
 mod diagnostics;
+mod eslint_scope;
 mod jsdoc;
 mod label;
+mod module_graph;
 mod module_record;
 mod node;
 mod reference;
@@ -38,7 +44,17 @@ mod unresolved_stack;
 
 pub use crate::{
     builder::{SemanticBuilder, SemanticBuilderReturn},
-    jsdoc::{JSDoc, JSDocFinder, JSDocTag},
+    call_graph::{Call, CallGraph},
+    dataflow::{Definition, LiveVariables, ReachingDefinitions},
+    eslint_scope::{
+        EslintDefinition, EslintNode, EslintReference, EslintScope, EslintScopeManager,
+        EslintVariable,
+    },
+    jsdoc::{
+        JSDoc, JSDocFinder, JSDocFunctionParam, JSDocFunctionType, JSDocObjectField, JSDocTag,
+        JSDocType, JSDocTypeKind,
+    },
+    module_graph::ModuleGraph,
     node::{AstNode, AstNodes, NodeId},
     reference::{Reference, ReferenceFlags, ReferenceId},
     scope::ScopeTree,
@@ -92,6 +108,10 @@ pub struct Semantic<'a> {
     /// Control flow graph. Only present if [`Semantic`] is built with cfg
     /// creation enabled using [`SemanticBuilder::with_cfg`].
     cfg: Option<ControlFlowGraph>,
+
+    /// Call graph. Only present if [`Semantic`] is built with call graph creation enabled using
+    /// [`SemanticBuilder::with_call_graph`].
+    call_graph: Option<CallGraph>,
 }
 
 impl<'a> Semantic<'a> {
@@ -185,6 +205,14 @@ impl<'a> Semantic<'a> {
         self.cfg.as_ref()
     }
 
+    /// Call graph.
+    ///
+    /// Only present if [`Semantic`] is built with call graph creation enabled using
+    /// [`SemanticBuilder::with_call_graph`].
+    pub fn call_graph(&self) -> Option<&CallGraph> {
+        self.call_graph.as_ref()
+    }
+
     /// Get statistics about data held in `Semantic`.
     pub fn stats(&self) -> Stats {
         #[allow(clippy::cast_possible_truncation)]
@@ -472,4 +500,21 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_max_nesting_depth() {
+        let allocator = Allocator::default();
+        // Member expressions are parsed in a loop, but visited recursively
+        let source = format!("x = a{};", ".b".repeat(100));
+        let parse = oxc_parser::Parser::new(&allocator, &source, SourceType::default()).parse();
+        let program = allocator.alloc(parse.program);
+
+        let ret = SemanticBuilder::new().build(program);
+        assert!(ret.errors.is_empty());
+
+        // Stats are checked against the skipped nodes in debug builds
+        let ret = SemanticBuilder::new().with_max_nesting_depth(50).build(program);
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.errors[0].to_string(), "Maximum nesting depth of 50 exceeded");
+    }
 }
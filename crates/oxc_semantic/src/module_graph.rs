@@ -0,0 +1,125 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use oxc_span::CompactStr;
+use oxc_syntax::{
+    module_record::{BindingName, ModuleRecord, ResolvedBinding, ResolvedExport},
+    symbol::SymbolId,
+};
+use rustc_hash::FxHashMap;
+
+use crate::Semantic;
+
+/// Project-level symbol graph.
+///
+/// Links the [`ModuleRecord`]s of multiple modules together, and resolves imported bindings
+/// (through re-exports and `export *`) to the [`SymbolId`] of their declaration in another module.
+///
+/// ```ignore
+/// let mut graph = ModuleGraph::default();
+/// graph.add_module(&semantic_a);
+/// graph.add_module(&semantic_b);
+/// graph.link(|importer, specifier| resolver.resolve(importer, specifier).ok());
+///
+/// if let ResolvedExport::Found(binding) = graph.resolve_import(path_a, "foo") {
+///     let symbol_id = graph.symbol_id(&binding);
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct ModuleGraph {
+    modules: FxHashMap<PathBuf, ModuleGraphNode>,
+}
+
+#[derive(Debug)]
+struct ModuleGraphNode {
+    module_record: Arc<ModuleRecord>,
+    /// Bindings declared in the module scope
+    bindings: FxHashMap<CompactStr, SymbolId>,
+}
+
+impl ModuleGraph {
+    /// Add a module, keyed by [`ModuleRecord::resolved_absolute_path`].
+    ///
+    /// The [`Semantic`] must be built with [`crate::SemanticBuilder::build_module_record`].
+    pub fn add_module(&mut self, semantic: &Semantic) {
+        let scopes = semantic.scopes();
+        let bindings = scopes
+            .get_bindings(scopes.root_scope_id())
+            .iter()
+            .map(|(name, symbol_id)| (name.clone(), *symbol_id))
+            .collect();
+        let module_record = Arc::clone(&semantic.module_record);
+        self.modules.insert(
+            module_record.resolved_absolute_path.clone(),
+            ModuleGraphNode { module_record, bindings },
+        );
+    }
+
+    /// Fill in [`ModuleRecord::loaded_modules`] of all modules in the graph.
+    ///
+    /// `resolve` is called with the path of the importing module and a module specifier,
+    /// and returns the resolved absolute path. Specifiers resolving to modules which are not
+    /// in the graph are left unlinked.
+    pub fn link<F: Fn(&Path, &str) -> Option<PathBuf>>(&self, resolve: F) {
+        for (path, node) in &self.modules {
+            for specifier in node.module_record.requested_modules.keys() {
+                let Some(resolved) = resolve(path, specifier) else {
+                    continue;
+                };
+                if let Some(target) = self.modules.get(&resolved) {
+                    node.module_record
+                        .loaded_modules
+                        .insert(specifier.clone(), Arc::clone(&target.module_record));
+                }
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.modules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+
+    pub fn module_record(&self, path: &Path) -> Option<&Arc<ModuleRecord>> {
+        self.modules.get(path).map(|node| &node.module_record)
+    }
+
+    /// Resolve `export_name` exported by the module at `path`.
+    ///
+    /// See [`ModuleRecord::resolve_export`].
+    pub fn resolve_export(&self, path: &Path, export_name: &str) -> ResolvedExport {
+        self.modules
+            .get(path)
+            .map_or(ResolvedExport::Unlinked, |node| node.module_record.resolve_export(export_name))
+    }
+
+    /// Resolve the binding imported as `local_name` in the module at `path`.
+    ///
+    /// Returns [`ResolvedExport::NotFound`] if `local_name` is not an imported binding.
+    pub fn resolve_import(&self, path: &Path, local_name: &str) -> ResolvedExport {
+        let Some(node) = self.modules.get(path) else {
+            return ResolvedExport::Unlinked;
+        };
+        node.module_record
+            .import_entries
+            .iter()
+            .find(|entry| entry.local_name.name() == local_name)
+            .map_or(ResolvedExport::NotFound, |entry| node.module_record.resolve_import(entry))
+    }
+
+    /// Get the [`SymbolId`] declaring a resolved binding.
+    ///
+    /// Returns `None` for namespace objects, anonymous default exports,
+    /// and modules which are not in the graph.
+    pub fn symbol_id(&self, binding: &ResolvedBinding) -> Option<SymbolId> {
+        let BindingName::Name(name) = &binding.binding_name else {
+            return None;
+        };
+        self.modules.get(&binding.module)?.bindings.get(name).copied()
+    }
+}
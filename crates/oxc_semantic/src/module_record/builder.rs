@@ -123,10 +123,10 @@ impl ModuleRecordBuilder {
                                         }
                                         // `import d from "mod"`
                                         // `export { d }`
-                                        //           ^ this is local_name of ie
-                                        ImportImportName::Default(_) => {
-                                            ExportImportName::Name(ie.local_name.clone())
-                                        }
+                                        // re-exports the `default` export of "mod"
+                                        ImportImportName::Default(span) => ExportImportName::Name(
+                                            NameSpan::new("default".into(), *span),
+                                        ),
                                         ImportImportName::NamespaceObject => unreachable!(),
                                     },
                                     export_name: ee.export_name.clone(),
@@ -6,6 +6,8 @@ use serde::Serialize;
 #[cfg(feature = "serialize")]
 use tsify::Tsify;
 
+#[cfg(feature = "binary_codec")]
+use oxc_allocator::{BinaryCodec, DecodeError, Decoder, Encoder};
 pub use oxc_syntax::reference::{ReferenceFlags, ReferenceId};
 
 use crate::{symbol::SymbolId, NodeId};
@@ -122,3 +124,20 @@ impl Reference {
         self.flags.is_type()
     }
 }
+
+#[cfg(feature = "binary_codec")]
+impl<'a> BinaryCodec<'a> for Reference {
+    fn encode(&self, encoder: &mut Encoder) {
+        self.node_id.encode(encoder);
+        self.symbol_id.encode(encoder);
+        self.flags.encode(encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            node_id: BinaryCodec::decode(decoder)?,
+            symbol_id: BinaryCodec::decode(decoder)?,
+            flags: BinaryCodec::decode(decoder)?,
+        })
+    }
+}
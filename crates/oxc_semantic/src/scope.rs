@@ -3,6 +3,8 @@ use std::hash::BuildHasherDefault;
 use indexmap::IndexMap;
 use rustc_hash::{FxHashMap, FxHasher};
 
+#[cfg(feature = "binary_codec")]
+use oxc_allocator::{BinaryCodec, DecodeError, Decoder, Encoder};
 use oxc_index::IndexVec;
 use oxc_span::CompactStr;
 use oxc_syntax::reference::ReferenceId;
@@ -311,3 +313,77 @@ impl ScopeTree {
         }
     }
 }
+
+#[cfg(feature = "binary_codec")]
+impl<'a> BinaryCodec<'a> for ScopeTree {
+    fn encode(&self, encoder: &mut Encoder) {
+        self.parent_ids.raw.encode(encoder);
+        self.child_ids.raw.encode(encoder);
+        self.build_child_ids.encode(encoder);
+        self.node_ids.raw.encode(encoder);
+        self.flags.raw.encode(encoder);
+
+        encoder.write_len(self.bindings.len());
+        for bindings in &self.bindings {
+            encoder.write_len(bindings.len());
+            for (name, symbol_id) in bindings {
+                name.encode(encoder);
+                symbol_id.encode(encoder);
+            }
+        }
+
+        encoder.write_len(self.root_unresolved_references.len());
+        for (name, reference_ids) in &self.root_unresolved_references {
+            name.encode(encoder);
+            reference_ids.encode(encoder);
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        let parent_ids = IndexVec::from_vec(BinaryCodec::decode(decoder)?);
+        let child_ids = IndexVec::from_vec(BinaryCodec::decode(decoder)?);
+        let build_child_ids = BinaryCodec::decode(decoder)?;
+        let node_ids = IndexVec::from_vec(BinaryCodec::decode(decoder)?);
+        let flags = IndexVec::from_vec(BinaryCodec::decode(decoder)?);
+
+        let bindings_len = decoder.read_len()?;
+        let mut bindings = IndexVec::with_capacity(bindings_len);
+        for _ in 0..bindings_len {
+            let len = decoder.read_len()?;
+            let mut scope_bindings =
+                Bindings::with_capacity_and_hasher(len, BuildHasherDefault::default());
+            for _ in 0..len {
+                scope_bindings.insert(BinaryCodec::decode(decoder)?, BinaryCodec::decode(decoder)?);
+            }
+            bindings.push(scope_bindings);
+        }
+
+        let len = decoder.read_len()?;
+        let mut root_unresolved_references =
+            UnresolvedReferences::with_capacity_and_hasher(len, rustc_hash::FxBuildHasher);
+        for _ in 0..len {
+            root_unresolved_references
+                .insert(BinaryCodec::decode(decoder)?, BinaryCodec::decode(decoder)?);
+        }
+
+        let scopes = Self {
+            parent_ids,
+            child_ids,
+            build_child_ids,
+            node_ids,
+            flags,
+            bindings,
+            root_unresolved_references,
+        };
+        // All per-scope columns must have same length
+        let len = scopes.len();
+        if scopes.node_ids.len() != len
+            || scopes.flags.len() != len
+            || scopes.bindings.len() != len
+            || (scopes.build_child_ids && scopes.child_ids.len() != len)
+        {
+            return Err(DecodeError::InvalidValue);
+        }
+        Ok(scopes)
+    }
+}
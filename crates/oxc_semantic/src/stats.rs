@@ -2,13 +2,18 @@ use std::cell::Cell;
 
 use oxc_ast::{
     ast::{
-        BindingIdentifier, IdentifierReference, Program, TSEnumMemberName, TSModuleDeclarationName,
+        BindingIdentifier, Expression, IdentifierReference, Program, Statement, TSEnumMemberName,
+        TSModuleDeclarationName,
+    },
+    visit::walk::{
+        walk_expression, walk_statement, walk_ts_enum_member_name, walk_ts_module_declaration_name,
     },
-    visit::walk::{walk_ts_enum_member_name, walk_ts_module_declaration_name},
     AstKind, Visit,
 };
 use oxc_syntax::scope::{ScopeFlags, ScopeId};
 
+use crate::builder::DEFAULT_MAX_NESTING_DEPTH;
+
 /// Macro to assert that `left >= right`
 macro_rules! assert_ge {
     ($left:expr, $right:expr, $($msg_args:tt)+) => {
@@ -91,7 +96,15 @@ impl Stats {
     ///
     /// [`Semantic::stats`]: super::Semantic::stats
     pub fn count(program: &Program) -> Self {
-        let mut counter = Counter::default();
+        Self::count_with_max_nesting_depth(program, DEFAULT_MAX_NESTING_DEPTH)
+    }
+
+    /// Gather [`Stats`], skipping statements and expressions nested deeper than
+    /// `max_nesting_depth` the same way as [`SemanticBuilder`] does.
+    ///
+    /// [`SemanticBuilder`]: crate::SemanticBuilder
+    pub(crate) fn count_with_max_nesting_depth(program: &Program, max_nesting_depth: u32) -> Self {
+        let mut counter = Counter { stats: Stats::default(), depth: 0, max_nesting_depth };
         counter.visit_program(program);
         counter.stats
     }
@@ -131,9 +144,10 @@ impl Stats {
     }
 }
 
-#[derive(Default)]
 struct Counter {
     stats: Stats,
+    depth: u32,
+    max_nesting_depth: u32,
 }
 
 /// Visitor to count nodes, scopes, symbols and references in AST
@@ -148,6 +162,24 @@ impl<'a> Visit<'a> for Counter {
         self.stats.scopes += 1;
     }
 
+    #[inline]
+    fn visit_statement(&mut self, it: &Statement<'a>) {
+        if self.depth < self.max_nesting_depth {
+            self.depth += 1;
+            walk_statement(self, it);
+            self.depth -= 1;
+        }
+    }
+
+    #[inline]
+    fn visit_expression(&mut self, it: &Expression<'a>) {
+        if self.depth < self.max_nesting_depth {
+            self.depth += 1;
+            walk_expression(self, it);
+            self.depth -= 1;
+        }
+    }
+
     #[inline]
     fn visit_binding_identifier(&mut self, _: &BindingIdentifier<'a>) {
         self.stats.nodes += 1;
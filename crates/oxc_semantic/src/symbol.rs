@@ -5,6 +5,8 @@ use serde::Serialize;
 #[cfg(feature = "serialize")]
 use tsify::Tsify;
 
+#[cfg(feature = "binary_codec")]
+use oxc_allocator::{BinaryCodec, DecodeError, Decoder, Encoder};
 use oxc_ast::ast::{Expression, IdentifierReference};
 use oxc_index::IndexVec;
 use oxc_span::{CompactStr, Span};
@@ -264,6 +266,51 @@ impl SymbolTable {
     }
 }
 
+#[cfg(feature = "binary_codec")]
+impl<'a> BinaryCodec<'a> for SymbolTable {
+    fn encode(&self, encoder: &mut Encoder) {
+        self.spans.raw.encode(encoder);
+        self.names.raw.encode(encoder);
+        self.flags.raw.encode(encoder);
+        self.scope_ids.raw.encode(encoder);
+        self.declarations.raw.encode(encoder);
+        self.resolved_references.raw.encode(encoder);
+        self.redeclarations.raw.encode(encoder);
+        self.redeclaration_spans.raw.encode(encoder);
+        self.references.raw.encode(encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        let symbols = Self {
+            spans: IndexVec::from_vec(BinaryCodec::decode(decoder)?),
+            names: IndexVec::from_vec(BinaryCodec::decode(decoder)?),
+            flags: IndexVec::from_vec(BinaryCodec::decode(decoder)?),
+            scope_ids: IndexVec::from_vec(BinaryCodec::decode(decoder)?),
+            declarations: IndexVec::from_vec(BinaryCodec::decode(decoder)?),
+            resolved_references: IndexVec::from_vec(BinaryCodec::decode(decoder)?),
+            redeclarations: IndexVec::from_vec(BinaryCodec::decode(decoder)?),
+            redeclaration_spans: IndexVec::from_vec(BinaryCodec::decode(decoder)?),
+            references: IndexVec::from_vec(BinaryCodec::decode(decoder)?),
+        };
+        // All per-symbol columns must have same length
+        let len = symbols.len();
+        if [
+            symbols.names.len(),
+            symbols.flags.len(),
+            symbols.scope_ids.len(),
+            symbols.declarations.len(),
+            symbols.resolved_references.len(),
+            symbols.redeclarations.len(),
+        ]
+        .iter()
+        .any(|&column_len| column_len != len)
+        {
+            return Err(DecodeError::InvalidValue);
+        }
+        Ok(symbols)
+    }
+}
+
 /// Checks whether the a identifier reference is a global value or not.
 pub trait IsGlobalReference {
     fn is_global_reference(&self, _symbols: &SymbolTable) -> bool;
@@ -0,0 +1,51 @@
+use oxc_allocator::{Allocator, DecodeError};
+use oxc_ast::cache::{decode_program, encode_program};
+use oxc_parser::Parser;
+use oxc_semantic::{
+    cache::{decode_program_with_semantic, encode_program_with_semantic},
+    SemanticBuilder,
+};
+use oxc_span::SourceType;
+
+#[test]
+fn round_trip() {
+    let source_text = "
+        let x = 1;
+        function f(a) { var x = a; return () => x + y; }
+        class C { m() { return f(x); } }
+        y = 2;
+    ";
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let (symbols, scopes) = SemanticBuilder::new()
+        .with_scope_tree_child_ids(true)
+        .build(&program)
+        .semantic
+        .into_symbol_table_and_scope_tree();
+
+    let bytes = encode_program_with_semantic(&program, &symbols, &scopes);
+    let decode_allocator = Allocator::default();
+    let (decoded, decoded_symbols, decoded_scopes) =
+        decode_program_with_semantic(&decode_allocator, &bytes).unwrap();
+
+    assert_eq!(format!("{program:?}"), format!("{decoded:?}"));
+    assert_eq!(format!("{symbols:?}"), format!("{decoded_symbols:?}"));
+
+    assert_eq!(decoded_scopes.len(), scopes.len());
+    for scope_id in scopes.descendants_from_root() {
+        assert_eq!(decoded_scopes.get_parent_id(scope_id), scopes.get_parent_id(scope_id));
+        assert_eq!(decoded_scopes.get_child_ids(scope_id), scopes.get_child_ids(scope_id));
+        assert_eq!(decoded_scopes.get_flags(scope_id), scopes.get_flags(scope_id));
+        assert_eq!(decoded_scopes.get_node_id(scope_id), scopes.get_node_id(scope_id));
+        assert_eq!(decoded_scopes.get_bindings(scope_id), scopes.get_bindings(scope_id));
+    }
+    assert_eq!(decoded_scopes.root_unresolved_references(), scopes.root_unresolved_references());
+
+    // Data with and without semantic can't be mixed up
+    assert_eq!(decode_program(&decode_allocator, &bytes).unwrap_err(), DecodeError::Incompatible);
+    let bytes = encode_program(&program);
+    assert_eq!(
+        decode_program_with_semantic(&decode_allocator, &bytes).unwrap_err(),
+        DecodeError::Incompatible
+    );
+}
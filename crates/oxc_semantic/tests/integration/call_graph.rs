@@ -0,0 +1,132 @@
+use oxc_ast::AstKind;
+use oxc_semantic::{NodeId, Semantic};
+
+use crate::util::SemanticTester;
+
+/// Name of a function node, or `<program>`.
+fn name(semantic: &Semantic, node_id: NodeId) -> String {
+    let nodes = semantic.nodes();
+    match nodes.kind(node_id) {
+        AstKind::Program(_) => "<program>".to_string(),
+        AstKind::Function(func) if func.id.is_some() => func.id.as_ref().unwrap().name.to_string(),
+        _ => match nodes.parent_kind(node_id) {
+            Some(AstKind::VariableDeclarator(decl)) => {
+                decl.id.get_identifier().unwrap().to_string()
+            }
+            Some(AstKind::MethodDefinition(method)) => method.key.name().unwrap().to_string(),
+            Some(AstKind::PropertyDefinition(prop)) => prop.key.name().unwrap().to_string(),
+            _ => "<anonymous>".to_string(),
+        },
+    }
+}
+
+/// `caller -> callee` edges, sorted.
+fn edges(semantic: &Semantic) -> Vec<String> {
+    let call_graph = semantic.call_graph().unwrap();
+    let mut edges = call_graph
+        .functions()
+        .flat_map(|function| call_graph.callees(function))
+        .map(|call| format!("{} -> {}", name(semantic, call.caller), name(semantic, call.callee)))
+        .collect::<Vec<_>>();
+    edges.sort();
+    edges
+}
+
+fn function(semantic: &Semantic, function_name: &str) -> NodeId {
+    semantic
+        .call_graph()
+        .unwrap()
+        .functions()
+        .find(|id| name(semantic, *id) == function_name)
+        .unwrap()
+}
+
+#[test]
+fn test_functions() {
+    let tester = SemanticTester::js(
+        "
+        function a() { b(); c(); }
+        const b = () => a();
+        let c = function () { c(); };
+        let d = () => {};
+        d = () => {};
+        a();
+        d();
+        (() => {})();
+        unknown();
+        ",
+    )
+    .with_call_graph(true);
+    let semantic = tester.build();
+    assert_eq!(
+        edges(&semantic),
+        ["<program> -> <anonymous>", "<program> -> a", "a -> b", "a -> c", "b -> a", "c -> c"]
+    );
+
+    let call_graph = semantic.call_graph().unwrap();
+    let (a, b, c, d) = (
+        function(&semantic, "a"),
+        function(&semantic, "b"),
+        function(&semantic, "c"),
+        function(&semantic, "d"),
+    );
+    assert!(call_graph.is_recursive(a));
+    assert!(call_graph.is_recursive(c));
+    assert!(!call_graph.is_recursive(d));
+    let mut scc = call_graph.scc(a).to_vec();
+    scc.sort();
+    assert_eq!(scc, [a, b]);
+    assert_eq!(call_graph.scc(c), [c]);
+    assert_eq!(call_graph.callers(c).map(|call| call.caller).collect::<Vec<_>>(), [c, a]);
+
+    // Callees come before their callers
+    let sccs = call_graph.sccs();
+    let position = |id| sccs.iter().position(|scc| scc.contains(&id)).unwrap();
+    assert!(position(c) < position(a));
+}
+
+#[test]
+fn test_classes() {
+    let tester = SemanticTester::js(
+        "
+        class A {
+            constructor() { this.#init(); }
+            #init() {}
+            foo() { this.bar(); }
+            bar = () => { this.foo(); };
+            static create() { return new this(); }
+        }
+        class B extends A {
+            constructor() { super(); }
+            baz() { super.foo(); this.bar(); B.qux(); }
+            static qux() {}
+        }
+        const b = new B();
+        b.baz();
+        A.create();
+        ",
+    )
+    .with_call_graph(true);
+    let semantic = tester.build();
+    assert_eq!(
+        edges(&semantic),
+        [
+            "<program> -> baz",
+            "<program> -> constructor",
+            "<program> -> create",
+            "bar -> foo",
+            "baz -> bar",
+            "baz -> foo",
+            "baz -> qux",
+            "constructor -> constructor",
+            "constructor -> init",
+            "foo -> bar",
+        ]
+    );
+}
+
+#[test]
+fn test_without_call_graph() {
+    let tester = SemanticTester::js("function a() { a(); }");
+    assert!(tester.build().call_graph().is_none());
+}
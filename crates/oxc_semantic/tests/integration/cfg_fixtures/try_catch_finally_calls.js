@@ -0,0 +1,11 @@
+async function foo() {
+  try {
+    a();
+    await b();
+  } catch (e) {
+    return c();
+  } finally {
+    d();
+  }
+  return e();
+}
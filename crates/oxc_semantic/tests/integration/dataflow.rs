@@ -0,0 +1,174 @@
+use oxc_ast::AstKind;
+use oxc_semantic::{
+    Definition, LiveVariables, ReachingDefinitions, ReferenceId, Semantic, SymbolId,
+};
+use oxc_span::GetSpan;
+
+use crate::util::SemanticTester;
+
+fn symbol(semantic: &Semantic, name: &str) -> SymbolId {
+    let symbols = semantic.symbols();
+    symbols.symbol_ids().find(|id| symbols.get_name(*id) == name).unwrap()
+}
+
+/// References to `name`, in source order.
+fn references(semantic: &Semantic, name: &str) -> Vec<ReferenceId> {
+    let symbol_id = symbol(semantic, name);
+    let mut references = semantic.symbols().get_resolved_reference_ids(symbol_id).clone();
+    references.sort_by_key(|id| {
+        let node_id = semantic.symbols().get_reference(*id).node_id();
+        semantic.nodes().kind(node_id).span().start
+    });
+    references
+}
+
+fn is_identifier_reference(semantic: &Semantic, reference_id: ReferenceId) -> bool {
+    let node_id = semantic.symbols().get_reference(reference_id).node_id();
+    matches!(semantic.nodes().kind(node_id), AstKind::IdentifierReference(_))
+}
+
+#[test]
+fn test_straight_line() {
+    let tester = SemanticTester::js("let a = 1; a = 2; use(a);").with_cfg(true);
+    let semantic = tester.build();
+    let reaching = ReachingDefinitions::new(&semantic).unwrap();
+    let live = LiveVariables::new(&semantic).unwrap();
+
+    let a = symbol(&semantic, "a");
+    let refs = references(&semantic, "a");
+    assert!(refs.iter().all(|id| is_identifier_reference(&semantic, *id)));
+    let (declaration, write) = (Definition::Declaration(a), Definition::Write(refs[0]));
+
+    assert_eq!(reaching.definitions(a), [declaration, write]);
+    assert_eq!(reaching.reaching(refs[1]), [write]);
+    assert_eq!(reaching.uses(write), [refs[1]]);
+    assert!(reaching.uses(declaration).is_empty());
+
+    assert!(!live.is_live_after(declaration));
+    assert!(live.is_live_after(write));
+}
+
+#[test]
+fn test_branches() {
+    let tester =
+        SemanticTester::js("let a = 1; if (c) { a = 2; } else { use(a); } use(a);").with_cfg(true);
+    let semantic = tester.build();
+    let reaching = ReachingDefinitions::new(&semantic).unwrap();
+    let live = LiveVariables::new(&semantic).unwrap();
+
+    let a = symbol(&semantic, "a");
+    let refs = references(&semantic, "a");
+    let (declaration, write) = (Definition::Declaration(a), Definition::Write(refs[0]));
+
+    assert_eq!(reaching.reaching(refs[1]), [declaration]);
+    assert_eq!(reaching.reaching(refs[2]), [declaration, write]);
+    assert_eq!(reaching.uses(declaration), [refs[1], refs[2]]);
+    assert!(live.is_live_after(declaration));
+    assert!(live.is_live_after(write));
+}
+
+#[test]
+fn test_loop() {
+    let tester =
+        SemanticTester::js("let i = 0; while (i < 10) { i++; } let j = 0; for (;;) { j = 1; }")
+            .with_cfg(true);
+    let semantic = tester.build();
+    let reaching = ReachingDefinitions::new(&semantic).unwrap();
+    let live = LiveVariables::new(&semantic).unwrap();
+
+    let i = symbol(&semantic, "i");
+    let refs = references(&semantic, "i");
+    let expected = [Definition::Declaration(i), Definition::Write(refs[1])];
+    // `i < 10`
+    assert_eq!(reaching.reaching(refs[0]), expected);
+    // `i++` reads `i` before writing it
+    assert_eq!(reaching.reaching(refs[1]), expected);
+    assert!(live.is_live_after(Definition::Write(refs[1])));
+
+    let j = symbol(&semantic, "j");
+    let refs = references(&semantic, "j");
+    assert!(!live.is_live_after(Definition::Declaration(j)));
+    assert!(!live.is_live_after(Definition::Write(refs[0])));
+}
+
+#[test]
+fn test_read_before_write() {
+    let tester =
+        SemanticTester::js("let a = 1; a = a + 1; a = c ? a : 0; a ||= 2; use(a);").with_cfg(true);
+    let semantic = tester.build();
+    let reaching = ReachingDefinitions::new(&semantic).unwrap();
+
+    let a = symbol(&semantic, "a");
+    let refs = references(&semantic, "a");
+    // `a = a + 1`
+    assert_eq!(reaching.reaching(refs[1]), [Definition::Declaration(a)]);
+    // `a = c ? a : 0`, the write happens after the conditional expression
+    assert_eq!(reaching.reaching(refs[3]), [Definition::Write(refs[0])]);
+    // `a ||= 2` may not write
+    assert_eq!(
+        reaching.reaching(refs[5]),
+        [Definition::Write(refs[2]), Definition::Write(refs[4])]
+    );
+}
+
+#[test]
+fn test_try_catch() {
+    let tester = SemanticTester::js(
+        "let a = 1; try { a = 2; foo(); a = 3; } catch { use(a); } let b = 1; b = 2; use(b);",
+    )
+    .with_cfg(true);
+    let semantic = tester.build();
+    let reaching = ReachingDefinitions::new(&semantic).unwrap();
+    let live = LiveVariables::new(&semantic).unwrap();
+
+    let a = symbol(&semantic, "a");
+    let refs = references(&semantic, "a");
+    // `foo()` may throw after any of the assignments
+    assert_eq!(
+        reaching.reaching(refs[2]),
+        [Definition::Declaration(a), Definition::Write(refs[0]), Definition::Write(refs[1])]
+    );
+    assert!(live.is_live_after(Definition::Write(refs[0])));
+
+    let b = symbol(&semantic, "b");
+    assert!(!live.is_live_after(Definition::Declaration(b)));
+}
+
+#[test]
+fn test_untracked() {
+    let tester = SemanticTester::js(
+        "let a = 1; const f = () => a; a = 2; f(); export let b = 1; b = 2; var c; c = 1;",
+    )
+    .with_cfg(true);
+    let semantic = tester.build();
+    let reaching = ReachingDefinitions::new(&semantic).unwrap();
+    let live = LiveVariables::new(&semantic).unwrap();
+
+    // `a` is read in a closure
+    let a = symbol(&semantic, "a");
+    let refs = references(&semantic, "a");
+    assert_eq!(
+        reaching.reaching(refs[0]),
+        [Definition::Declaration(a), Definition::Write(refs[1])]
+    );
+    assert!(live.is_live_after(Definition::Declaration(a)));
+    assert!(live.is_live_after(Definition::Write(refs[1])));
+
+    // `b` is exported
+    let b = symbol(&semantic, "b");
+    assert!(live.is_live_after(Definition::Declaration(b)));
+
+    // `var c;` does not define a value
+    let c = symbol(&semantic, "c");
+    let refs = references(&semantic, "c");
+    assert_eq!(reaching.definitions(c), [Definition::Write(refs[0])]);
+    assert!(!live.is_live_after(Definition::Write(refs[0])));
+}
+
+#[test]
+fn test_without_cfg() {
+    let tester = SemanticTester::js("let a = 1;");
+    let semantic = tester.build();
+    assert!(ReachingDefinitions::new(&semantic).is_none());
+    assert!(LiveVariables::new(&semantic).is_none());
+}
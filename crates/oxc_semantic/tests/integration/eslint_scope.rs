@@ -0,0 +1,136 @@
+use oxc_semantic::EslintScopeManager;
+
+use crate::util::SemanticTester;
+
+#[test]
+fn test_script() {
+    let tester = SemanticTester::js(
+        "
+        var a = 1;
+        function foo(b) {
+            let c = a + b;
+            { const d = c; }
+            return undeclared;
+        }
+        try {} catch (e) { e; }
+        ",
+    )
+    .with_module(false);
+    let manager = EslintScopeManager::new(&tester.build());
+
+    let types = manager.scopes.iter().map(|scope| scope.r#type).collect::<Vec<_>>();
+    assert_eq!(types, ["global", "function", "block", "block", "catch", "block"]);
+
+    let global = &manager.scopes[0];
+    assert_eq!(global.upper, None);
+    assert_eq!(global.child_scopes, [1, 3, 4]);
+    let names = global
+        .variables
+        .iter()
+        .map(|index| manager.variables[*index].name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["a", "foo"]);
+    // `undeclared` is not resolved
+    assert_eq!(global.through.len(), 1);
+    assert_eq!(
+        manager.references[global.through[0]].identifier.name.as_deref(),
+        Some("undeclared")
+    );
+
+    let function = &manager.scopes[1];
+    assert_eq!(function.upper, Some(0));
+    assert_eq!(function.block.r#type, "FunctionDeclaration");
+    assert_eq!(function.variable_scope, 1);
+    // `a` and `undeclared` are resolved outside of the function
+    assert_eq!(function.through.len(), 2);
+
+    let block = &manager.scopes[2];
+    assert_eq!(block.upper, Some(1));
+    assert_eq!(block.through.len(), 1);
+    assert_eq!(block.variable_scope, 1);
+    let d = &manager.variables[block.variables[0]];
+    assert_eq!(d.name, "d");
+    assert_eq!(d.defs[0].r#type, "Variable");
+    assert_eq!(d.defs[0].node.r#type, "VariableDeclarator");
+
+    let b = manager.variables.iter().find(|variable| variable.name == "b").unwrap();
+    assert_eq!(b.defs[0].r#type, "Parameter");
+    assert_eq!(b.defs[0].node.r#type, "FunctionDeclaration");
+    assert_eq!(b.references.len(), 1);
+    let reference = &manager.references[b.references[0]];
+    assert!(reference.is_read);
+    assert!(!reference.is_write);
+    assert_eq!(reference.from, 1);
+    assert_eq!(
+        reference.resolved,
+        Some(manager.variables.iter().position(|v| v.name == "b").unwrap())
+    );
+
+    let e = manager.variables.iter().find(|variable| variable.name == "e").unwrap();
+    assert_eq!(e.defs[0].r#type, "CatchClause");
+    assert_eq!(e.scope, 4);
+    // `e` is resolved in the catch scope, through the block of the catch body
+    assert_eq!(manager.scopes[5].through.len(), 1);
+    assert!(manager.scopes[4].through.is_empty());
+}
+
+#[test]
+fn test_module() {
+    let tester = SemanticTester::js(
+        "
+        import { a } from 'a';
+        export const b = a;
+        b = window;
+        ",
+    );
+    let manager = EslintScopeManager::new(&tester.build());
+
+    let types = manager.scopes.iter().map(|scope| scope.r#type).collect::<Vec<_>>();
+    assert_eq!(types, ["global", "module"]);
+    assert!(manager.scopes[1].is_strict);
+    assert_eq!(manager.scopes[0].child_scopes, [1]);
+    assert_eq!(manager.scopes[1].upper, Some(0));
+    assert!(manager.scopes[0].variables.is_empty());
+    assert_eq!(manager.scopes[1].variables.len(), 2);
+    assert_eq!(manager.variables[0].defs[0].r#type, "ImportBinding");
+
+    // only `window` is unresolved
+    assert_eq!(manager.scopes[0].through.len(), 1);
+    assert_eq!(manager.scopes[1].through, manager.scopes[0].through);
+
+    let b = &manager.variables[1];
+    let write = &manager.references[b.references[0]];
+    assert!(write.is_write);
+    assert!(!write.is_read);
+}
+
+#[test]
+fn test_typescript() {
+    let tester = SemanticTester::ts(
+        "
+        type T<U> = U;
+        interface I {}
+        enum E { A }
+        namespace N {}
+        let x: T<I> = E.A;
+        ",
+    );
+    let manager = EslintScopeManager::new(&tester.build());
+
+    let types = manager.scopes.iter().map(|scope| scope.r#type).collect::<Vec<_>>();
+    assert_eq!(types, ["global", "module", "type", "type", "tsEnum", "tsModule"]);
+
+    let def_type = |name: &str| {
+        manager.variables.iter().find(|variable| variable.name == name).unwrap().defs[0].r#type
+    };
+    assert_eq!(def_type("T"), "Type");
+    assert_eq!(def_type("U"), "Type");
+    assert_eq!(def_type("I"), "Type");
+    assert_eq!(def_type("E"), "TSEnumName");
+    assert_eq!(def_type("A"), "TSEnumMember");
+    assert_eq!(def_type("N"), "TSModuleName");
+
+    let type_references =
+        manager.references.iter().filter(|reference| reference.is_type_reference).count();
+    assert_eq!(type_references, 3);
+}
@@ -1,7 +1,12 @@
 #![allow(clippy::missing_panics_doc)]
 
+#[cfg(feature = "binary_codec")]
+pub mod cache;
+pub mod call_graph;
 pub mod cfg;
 pub mod classes;
+pub mod dataflow;
+pub mod eslint_scope;
 pub mod modules;
 pub mod scopes;
 pub mod symbols;
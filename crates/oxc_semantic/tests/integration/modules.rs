@@ -1,4 +1,10 @@
-use oxc_semantic::{SemanticBuilderReturn, SymbolFlags};
+use std::path::{Path, PathBuf};
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::{ModuleGraph, SemanticBuilder, SemanticBuilderReturn, SymbolFlags};
+use oxc_span::SourceType;
+use oxc_syntax::module_record::{BindingName, ResolvedBinding, ResolvedExport};
 
 use crate::util::SemanticTester;
 
@@ -258,3 +264,103 @@ fn test_import_type() {
         .contains_flags(SymbolFlags::TypeImport)
         .test();
 }
+
+#[test]
+fn test_module_graph() {
+    let modules = [
+        (
+            "/a.js",
+            "import { foo, bar as b, baz, ns } from './b';
+            import Bar, * as c from './c';
+            import { missing } from './b';
+            import { external } from 'external';
+            const local = 1;",
+        ),
+        (
+            "/b.js",
+            "export { foo } from './c';
+            export * as ns from './c';
+            export * from './d';
+            export * from './e';",
+        ),
+        ("/c.js", "export function foo() {} export default class Bar {}"),
+        ("/d.js", "export const bar = 1; export const baz = 1;"),
+        ("/e.js", "export const baz = 2;"),
+    ];
+
+    let mut graph = ModuleGraph::default();
+    for (path, source_text) in modules {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+        let semantic = SemanticBuilder::new()
+            .build_module_record(Path::new(path), &program)
+            .build(&program)
+            .semantic;
+        graph.add_module(&semantic);
+    }
+    graph.link(|_, specifier| {
+        specifier.strip_prefix("./").map(|name| PathBuf::from(format!("/{name}.js")))
+    });
+    assert_eq!(graph.len(), 5);
+
+    let a = Path::new("/a.js");
+    let resolve = |local_name: &str| match graph.resolve_import(a, local_name) {
+        ResolvedExport::Found(binding) => {
+            Some((binding.module.to_string_lossy().to_string(), graph.symbol_id(&binding)))
+        }
+        _ => None,
+    };
+
+    // `foo` is re-exported from `./c`
+    let (module, symbol_id) = resolve("foo").unwrap();
+    assert_eq!(module, "/c.js");
+    assert!(symbol_id.is_some());
+    // `bar` is re-exported from `./d` by `export *`
+    assert_eq!(resolve("b").unwrap().0, "/d.js");
+    // `Bar` is the default export of `./c`
+    assert_eq!(resolve("Bar").unwrap().0, "/c.js");
+    assert!(resolve("Bar").unwrap().1.is_some());
+
+    // namespace objects
+    let namespace =
+        ResolvedBinding { module: PathBuf::from("/c.js"), binding_name: BindingName::Namespace };
+    assert_eq!(graph.resolve_import(a, "ns"), ResolvedExport::Found(namespace.clone()));
+    assert_eq!(graph.resolve_import(a, "c"), ResolvedExport::Found(namespace.clone()));
+    assert_eq!(graph.symbol_id(&namespace), None);
+
+    // `baz` is exported by both `./d` and `./e`
+    assert_eq!(graph.resolve_import(a, "baz"), ResolvedExport::Ambiguous);
+    assert_eq!(graph.resolve_import(a, "missing"), ResolvedExport::NotFound);
+    assert_eq!(graph.resolve_import(a, "external"), ResolvedExport::Unlinked);
+    // not an import
+    assert_eq!(graph.resolve_import(a, "local"), ResolvedExport::NotFound);
+
+    assert_eq!(graph.resolve_export(Path::new("/b.js"), "default"), ResolvedExport::NotFound);
+}
+
+#[test]
+fn test_module_graph_circular() {
+    let modules = [
+        ("/a.js", "export * from './b'; export { x } from './b'; export const a = 1;"),
+        ("/b.js", "export * from './a'; export { x } from './a';"),
+    ];
+    let mut graph = ModuleGraph::default();
+    for (path, source_text) in modules {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+        let semantic = SemanticBuilder::new()
+            .build_module_record(Path::new(path), &program)
+            .build(&program)
+            .semantic;
+        graph.add_module(&semantic);
+    }
+    graph.link(|_, specifier| {
+        specifier.strip_prefix("./").map(|name| PathBuf::from(format!("/{name}.js")))
+    });
+
+    let b = Path::new("/b.js");
+    assert_eq!(graph.resolve_export(b, "x"), ResolvedExport::NotFound);
+    let ResolvedExport::Found(binding) = graph.resolve_export(b, "a") else { unreachable!() };
+    assert_eq!(binding.module, Path::new("/a.js"));
+    assert!(graph.symbol_id(&binding).is_some());
+}
@@ -462,7 +462,7 @@ fn test_module_like_declarations() {
         .contains_flags(SymbolFlags::NameSpaceModule)
         .test();
 
-    SemanticTester::ts(r#"module "A" { export const x = 1; }"#)
+    SemanticTester::ts(r#"declare module "A" { export const x = 1; }"#)
         .has_root_symbol("A")
         .contains_flags(SymbolFlags::NameSpaceModule)
         .test();
@@ -25,6 +25,10 @@ pub struct SemanticTester<'a> {
     ///
     /// [`ControlFlowGraph`]: oxc_cfg::ControlFlowGraph
     cfg: bool,
+    /// Build a [`CallGraph`]?
+    ///
+    /// [`CallGraph`]: oxc_semantic::CallGraph
+    call_graph: bool,
     /// Build a child ids for scope tree?
     scope_tree_child_ids: bool,
     /// Expect semantic analysis to produce errors.
@@ -65,6 +69,7 @@ impl<'a> SemanticTester<'a> {
             source_type,
             source_text,
             cfg: false,
+            call_graph: false,
             scope_tree_child_ids: false,
             expect_errors: false,
         }
@@ -98,6 +103,14 @@ impl<'a> SemanticTester<'a> {
         self
     }
 
+    /// Enable or disable building a [`CallGraph`].
+    ///
+    /// [`CallGraph`]: oxc_semantic::CallGraph
+    pub fn with_call_graph(mut self, yes: bool) -> Self {
+        self.call_graph = yes;
+        self
+    }
+
     pub fn with_scope_tree_child_ids(mut self, yes: bool) -> Self {
         self.scope_tree_child_ids = yes;
         self
@@ -170,6 +183,7 @@ impl<'a> SemanticTester<'a> {
         SemanticBuilder::new()
             .with_check_syntax_error(true)
             .with_cfg(self.cfg)
+            .with_call_graph(self.call_graph)
             .with_scope_tree_child_ids(self.scope_tree_child_ids)
             .build(program)
     }
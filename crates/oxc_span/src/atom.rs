@@ -5,6 +5,8 @@ use std::{
 };
 
 use oxc_allocator::{Allocator, CloneIn, FromIn};
+#[cfg(feature = "binary_codec")]
+use oxc_allocator::{BinaryCodec, DecodeError, Decoder, Encoder};
 #[cfg(feature = "serialize")]
 use serde::Serialize;
 
@@ -62,6 +64,17 @@ impl<'old_alloc, 'new_alloc> CloneIn<'new_alloc> for Atom<'old_alloc> {
     }
 }
 
+#[cfg(feature = "binary_codec")]
+impl<'a> BinaryCodec<'a> for Atom<'a> {
+    fn encode(&self, encoder: &mut Encoder) {
+        self.0.encode(encoder);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        <&'a str>::decode(decoder).map(Self)
+    }
+}
+
 impl<'alloc> FromIn<'alloc, &Atom<'alloc>> for Atom<'alloc> {
     fn from_in(s: &Atom<'alloc>, _: &'alloc Allocator) -> Self {
         Self::from(s.0)
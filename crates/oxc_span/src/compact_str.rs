@@ -5,6 +5,8 @@ use std::{
 };
 
 use compact_str::CompactString;
+#[cfg(feature = "binary_codec")]
+use oxc_allocator::{BinaryCodec, DecodeError, Decoder, Encoder};
 #[cfg(feature = "serialize")]
 use serde::{Serialize, Serializer};
 
@@ -152,6 +154,17 @@ impl From<Cow<'_, str>> for CompactStr {
     }
 }
 
+#[cfg(feature = "binary_codec")]
+impl<'a> BinaryCodec<'a> for CompactStr {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_bytes(self.as_bytes());
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        decoder.read_str().map(Self::from)
+    }
+}
+
 impl Deref for CompactStr {
     type Target = str;
 
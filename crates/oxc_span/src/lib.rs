@@ -16,7 +16,7 @@ pub use crate::{
     source_type::{
         Language, LanguageVariant, ModuleKind, SourceType, UnknownExtension, VALID_EXTENSIONS,
     },
-    span::{GetSpan, GetSpanMut, Span, SPAN},
+    span::{ForEachSpanMut, GetSpan, GetSpanMut, Span, SPAN},
 };
 
 #[doc(hidden)]
@@ -6,6 +6,8 @@ mod error;
 use std::{hash::Hash, path::Path};
 
 use oxc_allocator::{Allocator, CloneIn};
+#[cfg(feature = "binary_codec")]
+use oxc_allocator::{BinaryCodec, DecodeError, Decoder, Encoder};
 use oxc_ast_macros::ast;
 #[cfg(feature = "serialize")]
 use {serde::Serialize, tsify::Tsify};
@@ -13,13 +15,13 @@ use {serde::Serialize, tsify::Tsify};
 use crate::{cmp::ContentEq, hash::ContentHash};
 pub use error::UnknownExtension;
 
-/// Source Type for JavaScript vs TypeScript / Script vs Module / JSX
+/// Source Type for JavaScript vs TypeScript vs JSON / Script vs Module / JSX
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct SourceType {
-    /// JavaScript or TypeScript, default JavaScript
+    /// JavaScript, TypeScript or JSON, default JavaScript
     pub(super) language: Language,
 
     /// Script or Module, default Module
@@ -29,7 +31,7 @@ pub struct SourceType {
     pub(super) variant: LanguageVariant,
 }
 
-/// JavaScript or TypeScript
+/// JavaScript, TypeScript or JSON
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
@@ -39,6 +41,12 @@ pub enum Language {
     TypeScript = 1,
     #[serde(rename = "typescriptDefinition")]
     TypeScriptDefinition = 2,
+    /// Strict [JSON](https://www.json.org)
+    Json = 3,
+    /// JSON with JavaScript comments and trailing commas, e.g. `tsconfig.json`
+    Jsonc = 4,
+    /// [JSON5](https://json5.org)
+    Json5 = 5,
 }
 
 /// Script or Module
@@ -88,6 +96,39 @@ impl<'a> CloneIn<'a> for SourceType {
     }
 }
 
+#[cfg(feature = "binary_codec")]
+impl<'a> BinaryCodec<'a> for SourceType {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_u8(self.language as u8);
+        encoder.write_u8(self.module_kind as u8);
+        encoder.write_u8(self.variant as u8);
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        let language = match decoder.read_u8()? {
+            0 => Language::JavaScript,
+            1 => Language::TypeScript,
+            2 => Language::TypeScriptDefinition,
+            3 => Language::Json,
+            4 => Language::Jsonc,
+            5 => Language::Json5,
+            _ => return Err(DecodeError::InvalidValue),
+        };
+        let module_kind = match decoder.read_u8()? {
+            0 => ModuleKind::Script,
+            1 => ModuleKind::Module,
+            2 => ModuleKind::Unambiguous,
+            _ => return Err(DecodeError::InvalidValue),
+        };
+        let variant = match decoder.read_u8()? {
+            0 => LanguageVariant::Standard,
+            1 => LanguageVariant::Jsx,
+            _ => return Err(DecodeError::InvalidValue),
+        };
+        Ok(Self { language, module_kind, variant })
+    }
+}
+
 impl ContentEq for SourceType {
     #[inline]
     fn content_eq(&self, other: &Self) -> bool {
@@ -230,6 +271,44 @@ impl SourceType {
         }
     }
 
+    /// Creates a [`SourceType`] representing a [`JSON`] file.
+    ///
+    /// JSON files are parsed as a single value, see [`SourceType::is_json`].
+    ///
+    /// ## Example
+    /// ```
+    /// # use oxc_span::SourceType;
+    ///
+    /// let json = SourceType::json();
+    /// assert!(json.is_json());
+    /// assert!(!json.is_javascript());
+    /// assert!(!json.is_typescript());
+    /// ```
+    ///
+    /// [`JSON`]: Language::Json
+    pub const fn json() -> Self {
+        Self {
+            language: Language::Json,
+            module_kind: ModuleKind::Script,
+            variant: LanguageVariant::Standard,
+        }
+    }
+
+    /// Creates a [`SourceType`] representing a [`JSONC`] file, which allows comments and
+    /// trailing commas.
+    ///
+    /// [`JSONC`]: Language::Jsonc
+    pub const fn jsonc() -> Self {
+        Self { language: Language::Jsonc, ..Self::json() }
+    }
+
+    /// Creates a [`SourceType`] representing a [`JSON5`] file.
+    ///
+    /// [`JSON5`]: Language::Json5
+    pub const fn json5() -> Self {
+        Self { language: Language::Json5, ..Self::json() }
+    }
+
     pub fn is_script(self) -> bool {
         self.module_kind == ModuleKind::Script
     }
@@ -261,6 +340,21 @@ impl SourceType {
         self.language == Language::TypeScriptDefinition
     }
 
+    /// Returns `true` if this is a JSON, JSONC or JSON5 file.
+    ///
+    /// I.e., `true` for `.json`, `.jsonc` and `.json5` files.
+    pub fn is_json(self) -> bool {
+        matches!(self.language, Language::Json | Language::Jsonc | Language::Json5)
+    }
+
+    pub fn is_jsonc(self) -> bool {
+        self.language == Language::Jsonc
+    }
+
+    pub fn is_json5(self) -> bool {
+        self.language == Language::Json5
+    }
+
     pub fn is_jsx(self) -> bool {
         self.variant == LanguageVariant::Jsx
     }
@@ -367,16 +461,24 @@ impl SourceType {
     /// strategy. Any file with a `.c[tj]s` extension is treated as a [`script`].
     /// All other files are treated as [`modules`].
     ///
+    /// ### JSON
+    /// `.json`, `.jsonc` and `.json5` files are [`JSON`], [`JSONC`] and [`JSON5`]
+    /// respectively. `tsconfig.json` and `jsconfig.json` (and variants such as
+    /// `tsconfig.base.json`) are [`JSONC`], since TypeScript allows comments in them.
+    ///
     /// # Errors
     /// Returns [`UnknownExtension`] if:
     ///   * there is no file name
     ///   * the file extension is not one of "js", "mjs", "cjs", "jsx", "ts",
-    ///     "mts", "cts", "tsx". See [`VALID_EXTENSIONS`] for the list of valid
-    ///     extensions.
+    ///     "mts", "cts", "tsx", "json", "jsonc", "json5". See [`VALID_EXTENSIONS`]
+    ///     for the list of valid JavaScript and TypeScript extensions.
     ///
     /// [`script`]: ModuleKind::Script
     /// [`scripts`]: ModuleKind::Script
     /// [`modules`]: ModuleKind::Module
+    /// [`JSON`]: Language::Json
+    /// [`JSONC`]: Language::Jsonc
+    /// [`JSON5`]: Language::Json5
     pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, UnknownExtension> {
         let file_name = path
             .as_ref()
@@ -384,10 +486,22 @@ impl SourceType {
             .and_then(std::ffi::OsStr::to_str)
             .ok_or_else(|| UnknownExtension::new("Please provide a valid file name."))?;
 
-        let extension = path
-            .as_ref()
-            .extension()
-            .and_then(std::ffi::OsStr::to_str)
+        let extension = path.as_ref().extension().and_then(std::ffi::OsStr::to_str);
+
+        match extension {
+            // TypeScript reads its config files as JSONC, e.g. `tsconfig.json`, `tsconfig.base.json`.
+            Some("json")
+                if file_name.starts_with("tsconfig.") || file_name.starts_with("jsconfig.") =>
+            {
+                return Ok(Self::jsonc());
+            }
+            Some("json") => return Ok(Self::json()),
+            Some("jsonc") => return Ok(Self::jsonc()),
+            Some("json5") => return Ok(Self::json5()),
+            _ => {}
+        }
+
+        let extension = extension
             .filter(|s| VALID_EXTENSIONS.contains(s))
             .ok_or_else(|| {
                 let path = path.as_ref().to_string_lossy();
@@ -542,4 +656,35 @@ mod tests {
         assert!(cjs.is_jsx());
         assert!(jsx.is_jsx());
     }
+
+    #[test]
+    #[allow(clippy::similar_names)]
+    fn test_json_from_path() {
+        let json = SourceType::from_path("package.json")
+            .expect("package.json should be a valid JSON file path.");
+        let jsonc = SourceType::from_path(".vscode/settings.jsonc")
+            .expect("settings.jsonc should be a valid JSONC file path.");
+        let json5 = SourceType::from_path("config.json5")
+            .expect("config.json5 should be a valid JSON5 file path.");
+        let tsconfig = SourceType::from_path("packages/a/tsconfig.json")
+            .expect("tsconfig.json should be a valid JSONC file path.");
+        let tsconfig_base = SourceType::from_path("tsconfig.base.json")
+            .expect("tsconfig.base.json should be a valid JSONC file path.");
+        let jsconfig = SourceType::from_path("jsconfig.json")
+            .expect("jsconfig.json should be a valid JSONC file path.");
+
+        for ty in &[json, jsonc, json5, tsconfig, tsconfig_base, jsconfig] {
+            assert!(ty.is_json(), "{ty:?}");
+            assert!(!ty.is_javascript(), "{ty:?}");
+            assert!(!ty.is_typescript(), "{ty:?}");
+            assert!(!ty.is_jsx(), "{ty:?}");
+        }
+
+        assert_eq!(SourceType::json(), json);
+        assert_eq!(SourceType::jsonc(), jsonc);
+        assert_eq!(SourceType::json5(), json5);
+        assert_eq!(SourceType::jsonc(), tsconfig);
+        assert_eq!(SourceType::jsonc(), tsconfig_base);
+        assert_eq!(SourceType::jsonc(), jsconfig);
+    }
 }
@@ -4,6 +4,8 @@ use miette::{LabeledSpan, SourceOffset, SourceSpan};
 
 mod types;
 use oxc_allocator::{Allocator, CloneIn};
+#[cfg(feature = "binary_codec")]
+use oxc_allocator::{BinaryCodec, DecodeError, Decoder, Encoder};
 pub use types::Span;
 
 /// An Empty span useful for creating AST nodes.
@@ -386,6 +388,67 @@ impl GetSpanMut for Span {
     }
 }
 
+/// Mutably visit every [`Span`] of an AST node, including the spans of all of its descendants.
+///
+/// Used to relocate a subtree, e.g. when reusing nodes after the source text has been edited.
+pub trait ForEachSpanMut {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F);
+}
+
+impl ForEachSpanMut for Span {
+    #[inline]
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        f(self);
+    }
+}
+
+impl<T: ForEachSpanMut> ForEachSpanMut for Option<T> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        if let Some(it) = self {
+            it.for_each_span_mut(f);
+        }
+    }
+}
+
+impl<'a, T: ForEachSpanMut> ForEachSpanMut for oxc_allocator::Box<'a, T> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        self.as_mut().for_each_span_mut(f);
+    }
+}
+
+impl<'a, T: ForEachSpanMut> ForEachSpanMut for oxc_allocator::Vec<'a, T> {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, f: &mut F) {
+        for it in self.iter_mut() {
+            it.for_each_span_mut(f);
+        }
+    }
+}
+
+mod auto_impl_for_each_span_mut {
+    use super::{ForEachSpanMut, Span};
+    use crate::{Atom, SourceType};
+
+    macro_rules! impl_for_each_span_mut {
+        ($($t:ty)*) => {
+            $(
+                impl ForEachSpanMut for $t {
+                    #[inline]
+                    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+                }
+            )*
+        };
+    }
+
+    impl_for_each_span_mut! {
+        char &str
+        bool isize usize
+        u8 u16 u32 u64 u128
+        i8 i16 i32 i64 i128
+        f32 f64
+        Atom<'_> SourceType
+    }
+}
+
 impl<'a> CloneIn<'a> for Span {
     type Cloned = Self;
 
@@ -395,6 +458,22 @@ impl<'a> CloneIn<'a> for Span {
     }
 }
 
+#[cfg(feature = "binary_codec")]
+impl<'a> BinaryCodec<'a> for Span {
+    /// Encoded as start, relative to the previous position, and size, as both are usually small.
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_position(self.start);
+        encoder.write_u32(self.size());
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        let start = decoder.read_position()?;
+        let size = decoder.read_u32()?;
+        let end = start.checked_add(size).ok_or(DecodeError::InvalidValue)?;
+        Ok(Self::new(start, end))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::Span;
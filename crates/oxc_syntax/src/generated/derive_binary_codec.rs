@@ -0,0 +1,221 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `tasks/ast_tools/src/derives/binary_codec.rs`
+
+#![allow(clippy::match_same_arms, clippy::semicolon_if_nothing_returned)]
+
+use oxc_allocator::{BinaryCodec, DecodeError, Decoder, Encoder};
+
+#[allow(clippy::wildcard_imports)]
+use crate::number::*;
+
+#[allow(clippy::wildcard_imports)]
+use crate::operator::*;
+
+impl<'a> BinaryCodec<'a> for NumberBase {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Float => encoder.write_u32(0u32),
+            Self::Decimal => encoder.write_u32(1u32),
+            Self::Binary => encoder.write_u32(2u32),
+            Self::Octal => encoder.write_u32(3u32),
+            Self::Hex => encoder.write_u32(4u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Float),
+            1u32 => Ok(Self::Decimal),
+            2u32 => Ok(Self::Binary),
+            3u32 => Ok(Self::Octal),
+            4u32 => Ok(Self::Hex),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for BigintBase {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Decimal => encoder.write_u32(0u32),
+            Self::Binary => encoder.write_u32(1u32),
+            Self::Octal => encoder.write_u32(2u32),
+            Self::Hex => encoder.write_u32(3u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Decimal),
+            1u32 => Ok(Self::Binary),
+            2u32 => Ok(Self::Octal),
+            3u32 => Ok(Self::Hex),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for AssignmentOperator {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Assign => encoder.write_u32(0u32),
+            Self::Addition => encoder.write_u32(1u32),
+            Self::Subtraction => encoder.write_u32(2u32),
+            Self::Multiplication => encoder.write_u32(3u32),
+            Self::Division => encoder.write_u32(4u32),
+            Self::Remainder => encoder.write_u32(5u32),
+            Self::ShiftLeft => encoder.write_u32(6u32),
+            Self::ShiftRight => encoder.write_u32(7u32),
+            Self::ShiftRightZeroFill => encoder.write_u32(8u32),
+            Self::BitwiseOR => encoder.write_u32(9u32),
+            Self::BitwiseXOR => encoder.write_u32(10u32),
+            Self::BitwiseAnd => encoder.write_u32(11u32),
+            Self::LogicalAnd => encoder.write_u32(12u32),
+            Self::LogicalOr => encoder.write_u32(13u32),
+            Self::LogicalNullish => encoder.write_u32(14u32),
+            Self::Exponential => encoder.write_u32(15u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Assign),
+            1u32 => Ok(Self::Addition),
+            2u32 => Ok(Self::Subtraction),
+            3u32 => Ok(Self::Multiplication),
+            4u32 => Ok(Self::Division),
+            5u32 => Ok(Self::Remainder),
+            6u32 => Ok(Self::ShiftLeft),
+            7u32 => Ok(Self::ShiftRight),
+            8u32 => Ok(Self::ShiftRightZeroFill),
+            9u32 => Ok(Self::BitwiseOR),
+            10u32 => Ok(Self::BitwiseXOR),
+            11u32 => Ok(Self::BitwiseAnd),
+            12u32 => Ok(Self::LogicalAnd),
+            13u32 => Ok(Self::LogicalOr),
+            14u32 => Ok(Self::LogicalNullish),
+            15u32 => Ok(Self::Exponential),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for BinaryOperator {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Equality => encoder.write_u32(0u32),
+            Self::Inequality => encoder.write_u32(1u32),
+            Self::StrictEquality => encoder.write_u32(2u32),
+            Self::StrictInequality => encoder.write_u32(3u32),
+            Self::LessThan => encoder.write_u32(4u32),
+            Self::LessEqualThan => encoder.write_u32(5u32),
+            Self::GreaterThan => encoder.write_u32(6u32),
+            Self::GreaterEqualThan => encoder.write_u32(7u32),
+            Self::ShiftLeft => encoder.write_u32(8u32),
+            Self::ShiftRight => encoder.write_u32(9u32),
+            Self::ShiftRightZeroFill => encoder.write_u32(10u32),
+            Self::Addition => encoder.write_u32(11u32),
+            Self::Subtraction => encoder.write_u32(12u32),
+            Self::Multiplication => encoder.write_u32(13u32),
+            Self::Division => encoder.write_u32(14u32),
+            Self::Remainder => encoder.write_u32(15u32),
+            Self::BitwiseOR => encoder.write_u32(16u32),
+            Self::BitwiseXOR => encoder.write_u32(17u32),
+            Self::BitwiseAnd => encoder.write_u32(18u32),
+            Self::In => encoder.write_u32(19u32),
+            Self::Instanceof => encoder.write_u32(20u32),
+            Self::Exponential => encoder.write_u32(21u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Equality),
+            1u32 => Ok(Self::Inequality),
+            2u32 => Ok(Self::StrictEquality),
+            3u32 => Ok(Self::StrictInequality),
+            4u32 => Ok(Self::LessThan),
+            5u32 => Ok(Self::LessEqualThan),
+            6u32 => Ok(Self::GreaterThan),
+            7u32 => Ok(Self::GreaterEqualThan),
+            8u32 => Ok(Self::ShiftLeft),
+            9u32 => Ok(Self::ShiftRight),
+            10u32 => Ok(Self::ShiftRightZeroFill),
+            11u32 => Ok(Self::Addition),
+            12u32 => Ok(Self::Subtraction),
+            13u32 => Ok(Self::Multiplication),
+            14u32 => Ok(Self::Division),
+            15u32 => Ok(Self::Remainder),
+            16u32 => Ok(Self::BitwiseOR),
+            17u32 => Ok(Self::BitwiseXOR),
+            18u32 => Ok(Self::BitwiseAnd),
+            19u32 => Ok(Self::In),
+            20u32 => Ok(Self::Instanceof),
+            21u32 => Ok(Self::Exponential),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for LogicalOperator {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Or => encoder.write_u32(0u32),
+            Self::And => encoder.write_u32(1u32),
+            Self::Coalesce => encoder.write_u32(2u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Or),
+            1u32 => Ok(Self::And),
+            2u32 => Ok(Self::Coalesce),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for UnaryOperator {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::UnaryNegation => encoder.write_u32(0u32),
+            Self::UnaryPlus => encoder.write_u32(1u32),
+            Self::LogicalNot => encoder.write_u32(2u32),
+            Self::BitwiseNot => encoder.write_u32(3u32),
+            Self::Typeof => encoder.write_u32(4u32),
+            Self::Void => encoder.write_u32(5u32),
+            Self::Delete => encoder.write_u32(6u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::UnaryNegation),
+            1u32 => Ok(Self::UnaryPlus),
+            2u32 => Ok(Self::LogicalNot),
+            3u32 => Ok(Self::BitwiseNot),
+            4u32 => Ok(Self::Typeof),
+            5u32 => Ok(Self::Void),
+            6u32 => Ok(Self::Delete),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<'a> BinaryCodec<'a> for UpdateOperator {
+    fn encode(&self, encoder: &mut Encoder) {
+        match self {
+            Self::Increment => encoder.write_u32(0u32),
+            Self::Decrement => encoder.write_u32(1u32),
+        }
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        match decoder.read_u32()? {
+            0u32 => Ok(Self::Increment),
+            1u32 => Ok(Self::Decrement),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
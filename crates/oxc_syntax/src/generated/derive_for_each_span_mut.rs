@@ -0,0 +1,40 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `tasks/ast_tools/src/derives/for_each_span_mut.rs`
+
+#![allow(clippy::match_same_arms, clippy::semicolon_if_nothing_returned)]
+
+use oxc_span::{ForEachSpanMut, Span};
+
+#[allow(clippy::wildcard_imports)]
+use crate::number::*;
+
+#[allow(clippy::wildcard_imports)]
+use crate::operator::*;
+
+impl ForEachSpanMut for NumberBase {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl ForEachSpanMut for BigintBase {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl ForEachSpanMut for AssignmentOperator {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl ForEachSpanMut for BinaryOperator {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl ForEachSpanMut for LogicalOperator {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl ForEachSpanMut for UnaryOperator {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
+
+impl ForEachSpanMut for UpdateOperator {
+    fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, _: &mut F) {}
+}
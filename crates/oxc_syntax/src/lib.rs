@@ -14,7 +14,10 @@ pub mod scope;
 pub mod symbol;
 pub mod xml_entities;
 mod generated {
+    #[cfg(feature = "binary_codec")]
+    mod derive_binary_codec;
     mod derive_clone_in;
     mod derive_content_eq;
     mod derive_content_hash;
+    mod derive_for_each_span_mut;
 }
@@ -89,6 +89,191 @@ impl ModuleRecord {
     pub fn new(resolved_absolute_path: PathBuf) -> Self {
         Self { resolved_absolute_path, ..Self::default() }
     }
+
+    /// [ResolveExport](https://tc39.es/ecma262/#sec-resolveexport)
+    ///
+    /// Find the module and binding which declare `export_name`, following re-exports and
+    /// `export *` declarations through [`ModuleRecord::loaded_modules`].
+    pub fn resolve_export(&self, export_name: &str) -> ResolvedExport {
+        self.resolve_export_impl(export_name, &mut vec![])
+    }
+
+    /// Find the module and binding which declare the binding imported by `import_entry`.
+    pub fn resolve_import(&self, import_entry: &ImportEntry) -> ResolvedExport {
+        let Some(imported_module) = self.imported_module(&import_entry.module_request) else {
+            return ResolvedExport::Unlinked;
+        };
+        match &import_entry.import_name {
+            ImportImportName::Name(name) => imported_module.resolve_export(name.name()),
+            ImportImportName::Default(_) => imported_module.resolve_export("default"),
+            ImportImportName::NamespaceObject => ResolvedExport::Found(ResolvedBinding {
+                module: imported_module.resolved_absolute_path.clone(),
+                binding_name: BindingName::Namespace,
+            }),
+        }
+    }
+
+    fn resolve_export_impl(
+        &self,
+        export_name: &str,
+        resolve_set: &mut Vec<(*const Self, CompactStr)>,
+    ) -> ResolvedExport {
+        // Exports of CommonJS modules can not be resolved statically
+        if self.not_esm {
+            return ResolvedExport::Unlinked;
+        }
+        // 2. For each Record { [[Module]], [[ExportName]] } r of resolveSet, do
+        //   a. If module and r.[[Module]] are the same Module Record and exportName is r.[[ExportName]], then
+        if resolve_set
+            .iter()
+            .any(|(module, name)| std::ptr::eq(*module, self) && name.as_str() == export_name)
+        {
+            //  i. Assert: This is a circular import request.
+            //  ii. Return null.
+            return ResolvedExport::NotFound;
+        }
+        // 3. Append the Record { [[Module]]: module, [[ExportName]]: exportName } to resolveSet.
+        resolve_set.push((self, export_name.into()));
+
+        // 4. For each ExportEntry Record e of module.[[LocalExportEntries]], do
+        for e in &self.local_export_entries {
+            // a. If exportName is e.[[ExportName]], then
+            if e.export_name.name() == Some(export_name) {
+                // i. Return ResolvedBinding Record { [[Module]]: module, [[BindingName]]: e.[[LocalName]] }.
+                let binding_name = e
+                    .local_name
+                    .name()
+                    .map_or(BindingName::AnonymousDefault, |name| BindingName::Name(name.clone()));
+                return ResolvedExport::Found(ResolvedBinding {
+                    module: self.resolved_absolute_path.clone(),
+                    binding_name,
+                });
+            }
+        }
+
+        // 5. For each ExportEntry Record e of module.[[IndirectExportEntries]], do
+        for e in &self.indirect_export_entries {
+            // a. If exportName is e.[[ExportName]], then
+            if e.export_name.name() != Some(export_name) {
+                continue;
+            }
+            // i. Let importedModule be GetImportedModule(module, e.[[ModuleRequest]]).
+            let Some(imported_module) =
+                e.module_request.as_ref().and_then(|request| self.imported_module(request))
+            else {
+                return ResolvedExport::Unlinked;
+            };
+            return match &e.import_name {
+                // ii. If e.[[ImportName]] is all, then
+                ExportImportName::All | ExportImportName::AllButDefault => {
+                    // 2. Return ResolvedBinding Record { [[Module]]: importedModule, [[BindingName]]: namespace }.
+                    ResolvedExport::Found(ResolvedBinding {
+                        module: imported_module.resolved_absolute_path.clone(),
+                        binding_name: BindingName::Namespace,
+                    })
+                }
+                // iii. Else,
+                // 2. Return importedModule.ResolveExport(e.[[ImportName]], resolveSet).
+                ExportImportName::Name(name) => {
+                    imported_module.resolve_export_impl(name.name(), resolve_set)
+                }
+                ExportImportName::Null => ResolvedExport::NotFound,
+            };
+        }
+
+        // 6. If exportName is "default", then
+        if export_name == "default" {
+            // a. NOTE: A default export cannot be provided by an export * from "mod" declaration.
+            // c. Return null.
+            return ResolvedExport::NotFound;
+        }
+
+        // 7. Let starResolution be null.
+        let mut star_resolution: Option<ResolvedBinding> = None;
+        let mut unlinked = false;
+        // 8. For each ExportEntry Record e of module.[[StarExportEntries]], do
+        for e in &self.star_export_entries {
+            // a. Let importedModule be GetImportedModule(module, e.[[ModuleRequest]]).
+            let Some(imported_module) =
+                e.module_request.as_ref().and_then(|request| self.imported_module(request))
+            else {
+                unlinked = true;
+                continue;
+            };
+            // b. Let resolution be importedModule.ResolveExport(exportName, resolveSet).
+            match imported_module.resolve_export_impl(export_name, resolve_set) {
+                // c. If resolution is ambiguous, return ambiguous.
+                ResolvedExport::Ambiguous => return ResolvedExport::Ambiguous,
+                // d. If resolution is not null, then
+                ResolvedExport::Found(resolution) => match &star_resolution {
+                    // ii. If starResolution is null, set starResolution to resolution.
+                    None => star_resolution = Some(resolution),
+                    // iii. Else,
+                    // 1. If resolution.[[Module]] and starResolution.[[Module]] are not the same Module Record, return ambiguous.
+                    // 2. If resolution.[[BindingName]] is not starResolution.[[BindingName]], return ambiguous.
+                    Some(star_resolution) => {
+                        if *star_resolution != resolution {
+                            return ResolvedExport::Ambiguous;
+                        }
+                    }
+                },
+                ResolvedExport::Unlinked => unlinked = true,
+                ResolvedExport::NotFound => {}
+            }
+        }
+
+        // 9. Return starResolution.
+        match star_resolution {
+            Some(resolution) => ResolvedExport::Found(resolution),
+            // The name may be exported by a module which is not loaded
+            None if unlinked => ResolvedExport::Unlinked,
+            None => ResolvedExport::NotFound,
+        }
+    }
+
+    /// [GetImportedModule](https://tc39.es/ecma262/#sec-GetImportedModule)
+    fn imported_module(&self, module_request: &NameSpan) -> Option<Arc<Self>> {
+        self.loaded_modules.get(module_request.name()).map(|module| Arc::clone(module.value()))
+    }
+}
+
+/// Result of [`ModuleRecord::resolve_export`] and [`ModuleRecord::resolve_import`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolvedExport {
+    /// The export resolves to a single binding.
+    Found(ResolvedBinding),
+    /// The name is not exported, or the export is circular.
+    NotFound,
+    /// The name is exported by multiple `export *` declarations with different bindings.
+    Ambiguous,
+    /// The export can not be resolved because a requested module is missing from
+    /// [`ModuleRecord::loaded_modules`], or is not an ES module.
+    Unlinked,
+}
+
+impl ResolvedExport {
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, Self::NotFound)
+    }
+}
+
+/// [ResolvedBinding Record](https://tc39.es/ecma262/#resolvedbinding-record)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedBinding {
+    /// Resolved absolute path of the module which declares the binding.
+    pub module: PathBuf,
+
+    pub binding_name: BindingName,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BindingName {
+    /// A binding in the module scope.
+    Name(CompactStr),
+    /// The namespace object of the module, e.g. `export * as ns from "mod"`.
+    Namespace,
+    /// An anonymous `export default` expression, function or class.
+    AnonymousDefault,
 }
 
 impl fmt::Debug for ModuleRecord {
@@ -302,6 +487,15 @@ pub enum ExportExportName {
 }
 
 impl ExportExportName {
+    /// The exported name, `"default"` for default exports.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Name(name) => Some(name.name()),
+            Self::Default(_) => Some("default"),
+            Self::Null => None,
+        }
+    }
+
     pub fn is_default(&self) -> bool {
         matches!(self, Self::Default(_))
     }
@@ -1,5 +1,7 @@
 use bitflags::bitflags;
 use nonmax::NonMaxU32;
+#[cfg(feature = "binary_codec")]
+use oxc_allocator::{BinaryCodec, DecodeError, Decoder, Encoder};
 use oxc_index::Idx;
 #[cfg(feature = "serialize")]
 use serde::{Serialize, Serializer};
@@ -46,6 +48,17 @@ impl Idx for NodeId {
     }
 }
 
+#[cfg(feature = "binary_codec")]
+impl<'a> BinaryCodec<'a> for NodeId {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_u32(self.0.get());
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        NonMaxU32::new(decoder.read_u32()?).map(Self).ok_or(DecodeError::InvalidValue)
+    }
+}
+
 #[cfg(feature = "serialize")]
 impl Serialize for NodeId {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
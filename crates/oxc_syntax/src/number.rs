@@ -1,10 +1,10 @@
-use oxc_allocator::CloneIn;
+use oxc_allocator::{BinaryCodec, CloneIn};
 use oxc_ast_macros::ast;
-use oxc_span::{cmp::ContentEq, hash::ContentHash};
+use oxc_span::{cmp::ContentEq, hash::ContentHash, ForEachSpanMut};
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 pub enum NumberBase {
     Float = 0,
     Decimal = 1,
@@ -21,7 +21,7 @@ impl NumberBase {
 
 #[ast]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[generate_derive(CloneIn, ContentEq, ContentHash)]
+#[generate_derive(CloneIn, ContentEq, ContentHash, ForEachSpanMut, BinaryCodec)]
 pub enum BigintBase {
     Decimal = 0,
     Binary = 1,
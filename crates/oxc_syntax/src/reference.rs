@@ -1,6 +1,8 @@
 use bitflags::bitflags;
 use nonmax::NonMaxU32;
 use oxc_allocator::CloneIn;
+#[cfg(feature = "binary_codec")]
+use oxc_allocator::{BinaryCodec, DecodeError, Decoder, Encoder};
 use oxc_index::Idx;
 #[cfg(feature = "serialize")]
 use serde::{Serialize, Serializer};
@@ -21,6 +23,17 @@ impl Idx for ReferenceId {
     }
 }
 
+#[cfg(feature = "binary_codec")]
+impl<'a> BinaryCodec<'a> for ReferenceId {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_u32(self.0.get());
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        NonMaxU32::new(decoder.read_u32()?).map(Self).ok_or(DecodeError::InvalidValue)
+    }
+}
+
 #[cfg(feature = "serialize")]
 impl Serialize for ReferenceId {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -93,6 +106,17 @@ bitflags! {
     }
 }
 
+#[cfg(feature = "binary_codec")]
+impl<'a> BinaryCodec<'a> for ReferenceFlags {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_u8(self.bits());
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Self::from_bits(decoder.read_u8()?).ok_or(DecodeError::InvalidValue)
+    }
+}
+
 impl ReferenceFlags {
     #[inline]
     pub const fn read() -> Self {
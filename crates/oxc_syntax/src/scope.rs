@@ -1,5 +1,7 @@
 use bitflags::bitflags;
 use nonmax::NonMaxU32;
+#[cfg(feature = "binary_codec")]
+use oxc_allocator::{BinaryCodec, DecodeError, Decoder, Encoder};
 use oxc_index::Idx;
 #[cfg(feature = "serialize")]
 use serde::{Serialize, Serializer};
@@ -43,6 +45,17 @@ impl Idx for ScopeId {
     }
 }
 
+#[cfg(feature = "binary_codec")]
+impl<'a> BinaryCodec<'a> for ScopeId {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_u32(self.0.get());
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        NonMaxU32::new(decoder.read_u32()?).map(Self).ok_or(DecodeError::InvalidValue)
+    }
+}
+
 #[cfg(feature = "serialize")]
 impl Serialize for ScopeId {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -77,6 +90,18 @@ bitflags! {
     }
 }
 
+#[cfg(feature = "binary_codec")]
+impl<'a> BinaryCodec<'a> for ScopeFlags {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_u32(u32::from(self.bits()));
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        let bits = u16::try_from(decoder.read_u32()?).map_err(|_| DecodeError::InvalidValue)?;
+        Self::from_bits(bits).ok_or(DecodeError::InvalidValue)
+    }
+}
+
 impl ScopeFlags {
     #[must_use]
     #[inline]
@@ -1,5 +1,7 @@
 use bitflags::bitflags;
 use nonmax::NonMaxU32;
+#[cfg(feature = "binary_codec")]
+use oxc_allocator::{BinaryCodec, DecodeError, Decoder, Encoder};
 use oxc_index::Idx;
 #[cfg(feature = "serialize")]
 use serde::{Serialize, Serializer};
@@ -20,6 +22,17 @@ impl Idx for SymbolId {
     }
 }
 
+#[cfg(feature = "binary_codec")]
+impl<'a> BinaryCodec<'a> for SymbolId {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_u32(self.0.get());
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        NonMaxU32::new(decoder.read_u32()?).map(Self).ok_or(DecodeError::InvalidValue)
+    }
+}
+
 #[cfg(feature = "serialize")]
 impl Serialize for SymbolId {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -46,6 +59,17 @@ impl Idx for RedeclarationId {
     }
 }
 
+#[cfg(feature = "binary_codec")]
+impl<'a> BinaryCodec<'a> for RedeclarationId {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_u32(self.0.get());
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        NonMaxU32::new(decoder.read_u32()?).map(Self).ok_or(DecodeError::InvalidValue)
+    }
+}
+
 #[cfg(feature = "serialize")]
 impl Serialize for RedeclarationId {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -129,6 +153,17 @@ bitflags! {
     }
 }
 
+#[cfg(feature = "binary_codec")]
+impl<'a> BinaryCodec<'a> for SymbolFlags {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.write_u32(self.bits());
+    }
+
+    fn decode(decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+        Self::from_bits(decoder.read_u32()?).ok_or(DecodeError::InvalidValue)
+    }
+}
+
 impl SymbolFlags {
     #[inline]
     pub fn is_variable(&self) -> bool {
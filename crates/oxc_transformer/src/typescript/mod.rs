@@ -34,6 +34,11 @@ use crate::TransformCtx;
 ///
 /// This plugin is included in `preset-typescript`.
 ///
+/// Flow type annotations parsed with [`ParseOptions::parse_flow`] are represented by the same
+/// TypeScript AST nodes, so they are stripped by this preset as well.
+///
+/// [`ParseOptions::parse_flow`]: https://docs.rs/oxc_parser/latest/oxc_parser/struct.ParseOptions.html#structfield.parse_flow
+///
 /// ## Example
 ///
 /// In:  `const x: number = 0;`
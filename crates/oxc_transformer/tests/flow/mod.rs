@@ -0,0 +1,57 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{TransformOptions, Transformer};
+
+use crate::plugins::run;
+
+fn test(source_text: &str, expected: &str) {
+    let source_type = SourceType::jsx();
+    let allocator = Allocator::default();
+    let options = ParseOptions { parse_flow: true, ..ParseOptions::default() };
+    let ret = Parser::new(&allocator, source_text, source_type).with_options(options).parse();
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    let program = allocator.alloc(ret.program);
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(program).semantic.into_symbol_table_and_scope_tree();
+    let ret = Transformer::new(&allocator, Path::new("test.js"), TransformOptions::default())
+        .build_with_symbols_and_scopes(symbols, scopes, program);
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    let result = CodeGenerator::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(program)
+        .code;
+    let expected = run(expected, source_type);
+    assert_eq!(result, expected, "for source {source_text}");
+}
+
+#[test]
+fn strip_annotations() {
+    test(
+        "
+        // @flow
+        import type { Node } from 'react';
+        import typeof TheModule from './module';
+        import { type Props as P, View } from 'react-native';
+        export type Props = {| +name: string, ...$Exact<P>, onPress?: ?() => void |};
+        opaque type ID = string;
+        declare var __DEV__: boolean;
+        export function render<T: Object>(props: Props, items: Array<?T>): Node {
+          const id = (props.name: any);
+          return <View>{((id: any): ID)}</View>;
+        }
+        ",
+        "
+        import { View } from 'react-native';
+        import { jsx as _jsx } from 'react/jsx-runtime';
+        export function render(props, items) {
+          const id = props.name;
+          return _jsx(View, { children: id });
+        }
+        ",
+    );
+}
@@ -1 +1,2 @@
+mod flow;
 mod plugins;
@@ -18,9 +18,12 @@ use crate::{
 
 mod ancestry;
 mod bound_identifier;
+mod rename;
+mod subtree_scoping;
 use ancestry::PopToken;
 pub use ancestry::TraverseAncestry;
 pub use bound_identifier::BoundIdentifier;
+pub use rename::RenameConflict;
 mod scoping;
 pub use scoping::TraverseScoping;
 
@@ -503,6 +506,110 @@ impl<'a> TraverseCtx<'a> {
         self.scoping.clone_identifier_reference(ident, flags)
     }
 
+    /// Rename a symbol, and all its references.
+    ///
+    /// See [`TraverseScoping::rename_symbol`] for details, including when the AST is updated.
+    ///
+    /// This is a shortcut for `ctx.scoping.rename_symbol`.
+    ///
+    /// # Errors
+    /// Returns a [`RenameConflict`] if renaming would change the meaning of the program.
+    #[inline]
+    pub fn rename_symbol(
+        &mut self,
+        symbol_id: SymbolId,
+        new_name: CompactStr,
+    ) -> Result<(), RenameConflict> {
+        self.scoping.rename_symbol(symbol_id, new_name)
+    }
+
+    /// Rename a symbol, and all its references, adding a numerical postfix to `name` if required
+    /// to avoid conflicts.
+    ///
+    /// This is a shortcut for `ctx.scoping.rename_symbol_avoiding_conflicts`.
+    #[inline]
+    pub fn rename_symbol_avoiding_conflicts(
+        &mut self,
+        symbol_id: SymbolId,
+        name: &str,
+    ) -> CompactStr {
+        self.scoping.rename_symbol_avoiding_conflicts(symbol_id, name)
+    }
+
+    /// Unregister the references, bindings and scopes of a statement which is being removed from AST.
+    ///
+    /// This is a shortcut for `ctx.scoping.delete_statement_scoping`.
+    #[inline]
+    pub fn delete_statement_scoping(&mut self, stmt: &Statement) {
+        self.scoping.delete_statement_scoping(stmt);
+    }
+
+    /// Unregister the references, bindings and scopes of an expression which is being removed from AST.
+    ///
+    /// This is a shortcut for `ctx.scoping.delete_expression_scoping`.
+    #[inline]
+    pub fn delete_expression_scoping(&mut self, expr: &Expression) {
+        self.scoping.delete_expression_scoping(expr);
+    }
+
+    /// Register the references, bindings and scopes of a statement which is being inserted into AST,
+    /// in current scope.
+    ///
+    /// This is a shortcut for `ctx.scoping.add_statement_scoping`.
+    #[inline]
+    pub fn add_statement_scoping(&mut self, stmt: &Statement) {
+        self.scoping.add_statement_scoping(stmt);
+    }
+
+    /// Register the references, bindings and scopes of an expression which is being inserted into AST,
+    /// in current scope.
+    ///
+    /// This is a shortcut for `ctx.scoping.add_expression_scoping`.
+    #[inline]
+    pub fn add_expression_scoping(&mut self, expr: &Expression) {
+        self.scoping.add_expression_scoping(expr);
+    }
+
+    /// Remove a statement, replacing it with an empty statement, and unregister its scoping.
+    ///
+    /// See [`TraverseScoping::delete_statement_scoping`].
+    pub fn remove_statement(&mut self, stmt: &mut Statement<'a>) {
+        self.scoping.delete_statement_scoping(stmt);
+        *stmt = self.ast.statement_empty(SPAN);
+    }
+
+    /// Replace a statement with `new_stmt`, unregistering the scoping of the old statement
+    /// and registering the scoping of the new one in current scope.
+    ///
+    /// Returns the old statement.
+    ///
+    /// See [`TraverseScoping::delete_statement_scoping`] and [`TraverseScoping::add_statement_scoping`].
+    pub fn replace_statement(
+        &mut self,
+        stmt: &mut Statement<'a>,
+        new_stmt: Statement<'a>,
+    ) -> Statement<'a> {
+        self.scoping.delete_statement_scoping(stmt);
+        self.scoping.add_statement_scoping(&new_stmt);
+        std::mem::replace(stmt, new_stmt)
+    }
+
+    /// Replace an expression with `new_expr`, unregistering the scoping of the old expression
+    /// and registering the scoping of the new one in current scope.
+    ///
+    /// Returns the old expression.
+    ///
+    /// See [`TraverseScoping::delete_expression_scoping`] and [`TraverseScoping::add_expression_scoping`].
+    pub fn replace_expression(
+        &mut self,
+        expr: &mut Expression<'a>,
+        new_expr: Expression<'a>,
+    ) -> Expression<'a> {
+        self.scoping.delete_expression_scoping(expr);
+        self.scoping.add_expression_scoping(&new_expr);
+        std::mem::replace(expr, new_expr)
+    }
+
     /// Determine whether evaluating the specific input `node` is a consequenceless reference.
     ///
     /// I.E evaluating it won't result in potentially arbitrary code from being ran. The following are
@@ -0,0 +1,520 @@
+use rustc_hash::FxHashMap;
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{
+    ast::*,
+    visit::{walk_mut, VisitMut},
+    AstBuilder, NONE,
+};
+use oxc_semantic::{NodeId, Reference, ScopeTree, SymbolTable};
+use oxc_span::{Atom, CompactStr, SPAN};
+use oxc_syntax::{
+    identifier::is_identifier_name, keyword::is_reserved_keyword, reference::ReferenceFlags,
+    scope::ScopeId, symbol::SymbolId,
+};
+use oxc_syntax_operations::BoundNames;
+
+/// Reason why a symbol cannot be renamed to a given name without changing the meaning of the program.
+///
+/// Returned by [`TraverseScoping::rename_symbol`].
+///
+/// [`TraverseScoping::rename_symbol`]: crate::TraverseScoping::rename_symbol
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameConflict {
+    /// Another symbol with the new name is declared in the same scope.
+    ///
+    /// ```js
+    /// let foo, bar; // renaming `foo` to `bar`
+    /// ```
+    Redeclaration(SymbolId),
+    /// A symbol with the new name is declared in a nested scope, and would shadow references
+    /// to the renamed symbol.
+    ///
+    /// ```js
+    /// let foo; // renaming `foo` to `bar`
+    /// function f() { let bar; foo; }
+    /// ```
+    Shadowed(SymbolId),
+    /// A symbol with the new name is declared in an outer scope and has references,
+    /// which could be captured by the renamed symbol.
+    ///
+    /// ```js
+    /// let bar;
+    /// function f() { let foo; bar; } // renaming `foo` to `bar`
+    /// ```
+    CapturesOuter(SymbolId),
+    /// There are references to a global with the new name, which could be captured by the
+    /// renamed symbol.
+    ///
+    /// ```js
+    /// let foo; // renaming `foo` to `console`
+    /// console.log();
+    /// ```
+    CapturesGlobal,
+    /// The new name is not a valid identifier, or is a reserved word.
+    ///
+    /// ```js
+    /// let foo; // renaming `foo` to `class`, `arguments` or `1a`
+    /// ```
+    InvalidName,
+}
+
+/// Find a reason why renaming `symbol_id` to `new_name` would change the meaning of the program.
+///
+/// References only record the symbol they resolve to, not the scope they are in, so checks for
+/// shadowing and capture are conservative: a binding anywhere in a nested scope, or references
+/// anywhere in the program, are treated as a conflict.
+pub(super) fn find_rename_conflict(
+    symbols: &SymbolTable,
+    scopes: &ScopeTree,
+    symbol_id: SymbolId,
+    new_name: &str,
+) -> Option<RenameConflict> {
+    if !is_valid_binding_name(new_name) {
+        return Some(RenameConflict::InvalidName);
+    }
+
+    let scope_id = symbols.get_scope_id(symbol_id);
+
+    if let Some(other) = scopes.get_binding(scope_id, new_name) {
+        if other != symbol_id {
+            return Some(RenameConflict::Redeclaration(other));
+        }
+    }
+
+    if !symbols.get_resolved_reference_ids(symbol_id).is_empty() {
+        let shadowing = scopes.iter_bindings().find(|&(binding_scope_id, other, name)| {
+            other != symbol_id
+                && name == new_name
+                && is_descendant_scope(scopes, binding_scope_id, scope_id)
+        });
+        if let Some((_, other, _)) = shadowing {
+            return Some(RenameConflict::Shadowed(other));
+        }
+    }
+
+    for ancestor_id in scopes.ancestors(scope_id).skip(1) {
+        if let Some(other) = scopes.get_binding(ancestor_id, new_name) {
+            // Any references to globals with `new_name` must be outside of this ancestor scope
+            return (!symbols.get_resolved_reference_ids(other).is_empty())
+                .then_some(RenameConflict::CapturesOuter(other));
+        }
+    }
+
+    scopes
+        .root_unresolved_references()
+        .get(new_name)
+        .is_some_and(|reference_ids| !reference_ids.is_empty())
+        .then_some(RenameConflict::CapturesGlobal)
+}
+
+/// `arguments` and `eval` cannot be bound in strict mode code.
+fn is_valid_binding_name(name: &str) -> bool {
+    is_identifier_name(name) && !is_reserved_keyword(name) && !matches!(name, "arguments" | "eval")
+}
+
+fn is_descendant_scope(scopes: &ScopeTree, scope_id: ScopeId, ancestor_id: ScopeId) -> bool {
+    scope_id != ancestor_id && scopes.ancestors(scope_id).any(|id| id == ancestor_id)
+}
+
+/// Updates the names of `BindingIdentifier`s and `IdentifierReference`s of renamed symbols.
+///
+/// Shorthand properties (`{ foo }`, `const { foo } = obj`, `({ foo } = obj)`) are expanded,
+/// so that the property key keeps its original name.
+///
+/// Exported declarations are split into the declaration and an `export { bar as foo }`,
+/// so that the exported name does not change.
+pub(super) struct SymbolRenamer<'a, 'r> {
+    ast: AstBuilder<'a>,
+    symbols: &'r mut SymbolTable,
+    renamed_symbols: &'r FxHashMap<SymbolId, CompactStr>,
+}
+
+impl<'a, 'r> SymbolRenamer<'a, 'r> {
+    pub(super) fn new(
+        ast: AstBuilder<'a>,
+        symbols: &'r mut SymbolTable,
+        renamed_symbols: &'r FxHashMap<SymbolId, CompactStr>,
+    ) -> Self {
+        Self { ast, symbols, renamed_symbols }
+    }
+
+    fn new_binding_name(&self, ident: &BindingIdentifier<'a>) -> Option<Atom<'a>> {
+        let symbol_id = ident.symbol_id.get()?;
+        self.renamed_symbols.get(&symbol_id).map(|name| self.ast.atom(name))
+    }
+
+    fn new_reference_name(&self, ident: &IdentifierReference<'a>) -> Option<Atom<'a>> {
+        let reference_id = ident.reference_id.get()?;
+        let symbol_id = self.symbols.get_reference(reference_id).symbol_id()?;
+        self.renamed_symbols.get(&symbol_id).map(|name| self.ast.atom(name))
+    }
+
+    fn is_renamed_binding_pattern(&self, pattern: &BindingPattern<'a>) -> bool {
+        match &pattern.kind {
+            BindingPatternKind::BindingIdentifier(ident) => self.new_binding_name(ident).is_some(),
+            BindingPatternKind::AssignmentPattern(pattern) => {
+                self.is_renamed_binding_pattern(&pattern.left)
+            }
+            _ => false,
+        }
+    }
+
+    fn is_renamed_export(&self, stmt: &Statement<'a>) -> bool {
+        match stmt {
+            Statement::ExportNamedDeclaration(decl) => {
+                decl.declaration.as_ref().is_some_and(|decl| {
+                    let mut renamed = false;
+                    declaration_bindings(decl, &mut |ident| {
+                        renamed |= self.new_binding_name(ident).is_some();
+                    });
+                    renamed
+                })
+            }
+            Statement::ExportDefaultDeclaration(decl) => default_export_binding(&decl.declaration)
+                .is_some_and(|ident| self.new_binding_name(ident).is_some()),
+            _ => false,
+        }
+    }
+
+    /// `export const foo = 1` -> `const bar = 1; export { bar as foo }`
+    /// `export default function foo() {}` -> `function bar() {} export { bar as default }`
+    fn split_renamed_exports(&mut self, program: &mut Program<'a>) {
+        if !program.body.iter().any(|stmt| self.is_renamed_export(stmt)) {
+            return;
+        }
+
+        let body = std::mem::replace(&mut program.body, self.ast.vec());
+        for stmt in body {
+            if !self.is_renamed_export(&stmt) {
+                program.body.push(stmt);
+                continue;
+            }
+            let (declaration, export_kind, is_default) = match stmt {
+                Statement::ExportNamedDeclaration(decl) => {
+                    let decl = decl.unbox();
+                    (decl.declaration.unwrap(), decl.export_kind, false)
+                }
+                Statement::ExportDefaultDeclaration(decl) => match decl.unbox().declaration {
+                    ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                        (Declaration::FunctionDeclaration(func), ImportOrExportKind::Value, true)
+                    }
+                    ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                        (Declaration::ClassDeclaration(class), ImportOrExportKind::Value, true)
+                    }
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            };
+
+            let mut bindings = vec![];
+            declaration_bindings(&declaration, &mut |ident| {
+                if let Some(symbol_id) = ident.symbol_id.get() {
+                    let local = self.new_binding_name(ident).unwrap_or_else(|| ident.name.clone());
+                    bindings.push((ident.span, symbol_id, local, ident.name.clone()));
+                }
+            });
+            let mut specifiers = self.ast.vec_with_capacity(bindings.len());
+            for (span, symbol_id, local, name) in bindings {
+                let reference =
+                    Reference::new_with_symbol_id(NodeId::DUMMY, symbol_id, ReferenceFlags::Read);
+                let reference_id = self.symbols.create_reference(reference);
+                self.symbols.resolved_references[symbol_id].push(reference_id);
+                let local =
+                    IdentifierReference::new_with_reference_id(span, local, Some(reference_id));
+                let exported = if is_default { Atom::from("default") } else { name };
+                specifiers.push(self.ast.export_specifier(
+                    span,
+                    ModuleExportName::IdentifierReference(local),
+                    self.ast.module_export_name_identifier_name(span, exported),
+                    export_kind,
+                ));
+            }
+
+            program.body.push(Statement::from(declaration));
+            program.body.push(Statement::from(
+                self.ast.module_declaration_export_named_declaration(
+                    SPAN,
+                    None,
+                    specifiers,
+                    None,
+                    export_kind,
+                    NONE,
+                ),
+            ));
+        }
+    }
+}
+
+/// Calls `f` with the identifiers declared by `decl`, including TypeScript declarations.
+fn declaration_bindings<'a, F: FnMut(&BindingIdentifier<'a>)>(decl: &Declaration<'a>, f: &mut F) {
+    match decl {
+        Declaration::TSTypeAliasDeclaration(decl) => f(&decl.id),
+        Declaration::TSInterfaceDeclaration(decl) => f(&decl.id),
+        Declaration::TSEnumDeclaration(decl) => f(&decl.id),
+        Declaration::TSModuleDeclaration(decl) => {
+            if let TSModuleDeclarationName::Identifier(ident) = &decl.id {
+                f(ident);
+            }
+        }
+        Declaration::TSImportEqualsDeclaration(decl) => f(&decl.id),
+        _ => decl.bound_names(f),
+    }
+}
+
+fn default_export_binding<'b, 'a>(
+    decl: &'b ExportDefaultDeclarationKind<'a>,
+) -> Option<&'b BindingIdentifier<'a>> {
+    match decl {
+        ExportDefaultDeclarationKind::FunctionDeclaration(func) => func.id.as_ref(),
+        ExportDefaultDeclarationKind::ClassDeclaration(class) => class.id.as_ref(),
+        _ => None,
+    }
+}
+
+impl<'a, 'r> VisitMut<'a> for SymbolRenamer<'a, 'r> {
+    fn visit_program(&mut self, program: &mut Program<'a>) {
+        self.split_renamed_exports(program);
+        walk_mut::walk_program(self, program);
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(name) = self.new_binding_name(ident) {
+            ident.name = name;
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if let Some(name) = self.new_reference_name(ident) {
+            ident.name = name;
+        }
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        if prop.shorthand {
+            if let Expression::Identifier(ident) = &prop.value {
+                if self.new_reference_name(ident).is_some() {
+                    prop.shorthand = false;
+                }
+            }
+        }
+        walk_mut::walk_object_property(self, prop);
+    }
+
+    fn visit_binding_property(&mut self, prop: &mut BindingProperty<'a>) {
+        if prop.shorthand && self.is_renamed_binding_pattern(&prop.value) {
+            prop.shorthand = false;
+        }
+        walk_mut::walk_binding_property(self, prop);
+    }
+
+    fn visit_assignment_target_property(&mut self, prop: &mut AssignmentTargetProperty<'a>) {
+        // `({ foo } = obj)` -> `({ foo: bar } = obj)`
+        // `({ foo = 1 } = obj)` -> `({ foo: bar = 1 } = obj)`
+        if let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident_prop) = prop {
+            if let Some(name) = self.new_reference_name(&ident_prop.binding) {
+                let key = self.ast.property_key_identifier_name(
+                    ident_prop.binding.span,
+                    ident_prop.binding.name.clone(),
+                );
+                let binding = IdentifierReference::new_with_reference_id(
+                    ident_prop.binding.span,
+                    name,
+                    ident_prop.binding.reference_id.get(),
+                );
+                let target = AssignmentTarget::AssignmentTargetIdentifier(self.ast.alloc(binding));
+                let target = match ident_prop.init.take() {
+                    Some(init) => {
+                        self.ast.assignment_target_maybe_default_assignment_target_with_default(
+                            ident_prop.span,
+                            target,
+                            init,
+                        )
+                    }
+                    None => self.ast.assignment_target_maybe_default_assignment_target(target),
+                };
+                *prop = AssignmentTargetProperty::AssignmentTargetPropertyProperty(
+                    self.ast.alloc_assignment_target_property_property(
+                        ident_prop.span,
+                        key,
+                        target,
+                    ),
+                );
+            }
+        }
+        walk_mut::walk_assignment_target_property(self, prop);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::*;
+    use oxc_codegen::{CodeGenerator, CodegenOptions};
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{CompactStr, SourceType};
+
+    use super::RenameConflict;
+    use crate::{traverse_mut, Traverse, TraverseCtx};
+
+    /// Renames the binding named `from` declared by the first `VariableDeclarator` or function
+    /// with that name.
+    struct Rename {
+        from: &'static str,
+        to: &'static str,
+        avoid_conflicts: bool,
+        result: Option<Result<CompactStr, RenameConflict>>,
+    }
+
+    impl Rename {
+        fn rename(&mut self, ident: &BindingIdentifier<'_>, ctx: &mut TraverseCtx<'_>) {
+            if self.result.is_some() || ident.name != self.from {
+                return;
+            }
+            let symbol_id = ident.symbol_id.get().unwrap();
+            self.result = Some(if self.avoid_conflicts {
+                Ok(ctx.rename_symbol_avoiding_conflicts(symbol_id, self.to))
+            } else {
+                ctx.rename_symbol(symbol_id, self.to.into()).map(|()| self.to.into())
+            });
+        }
+    }
+
+    impl<'a> Traverse<'a> for Rename {
+        fn enter_binding_identifier(
+            &mut self,
+            ident: &mut BindingIdentifier<'a>,
+            ctx: &mut TraverseCtx<'a>,
+        ) {
+            self.rename(ident, ctx);
+        }
+    }
+
+    fn run(
+        source_text: &str,
+        from: &'static str,
+        to: &'static str,
+        avoid_conflicts: bool,
+    ) -> (Result<CompactStr, RenameConflict>, String) {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        assert!(ret.errors.is_empty());
+        let mut program = ret.program;
+        let (symbols, scopes) =
+            SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+        let mut rename = Rename { from, to, avoid_conflicts, result: None };
+        let (symbols, _) = traverse_mut(&mut rename, &allocator, &mut program, symbols, scopes);
+
+        if let Some(Ok(name)) = &rename.result {
+            assert!(symbols.names.iter().any(|symbol_name| symbol_name == name));
+        }
+        let code = CodeGenerator::new()
+            .with_options(CodegenOptions { minify: true, ..CodegenOptions::default() })
+            .build(&program)
+            .code;
+        (rename.result.expect("binding not found"), code)
+    }
+
+    fn pass(source_text: &str, from: &'static str, to: &'static str, expected: &str) {
+        let (result, code) = run(source_text, from, to, false);
+        assert_eq!(result, Ok(CompactStr::from(to)), "{source_text}");
+        assert_eq!(code, expected, "{source_text}");
+    }
+
+    fn fail(source_text: &str, from: &'static str, to: &'static str) -> RenameConflict {
+        let (result, code) = run(source_text, from, to, false);
+        let conflict = result.expect_err(source_text);
+        let (_, unchanged) = run(source_text, from, from, false);
+        assert_eq!(code, unchanged, "{source_text}");
+        conflict
+    }
+
+    #[test]
+    fn rename() {
+        pass("let foo = 1; foo++; f(foo);", "foo", "bar", "let bar=1;bar++;f(bar);");
+        pass(
+            "function foo() { return foo; } foo();",
+            "foo",
+            "bar",
+            "function bar(){return bar}bar();",
+        );
+        pass(
+            "let foo; function f() { let baz; return () => foo; }",
+            "foo",
+            "bar",
+            "let bar;function f(){let baz;return ()=>bar}",
+        );
+        // Unrelated bindings with the same name are not renamed
+        pass(
+            "let foo; function f(foo) { foo; } foo;",
+            "foo",
+            "bar",
+            "let bar;function f(foo){foo}bar;",
+        );
+        // A nested binding with the new name is fine if the symbol has no references
+        pass("let foo; function f() { let bar; }", "foo", "bar", "let bar;function f(){let bar}");
+    }
+
+    #[test]
+    fn rename_shorthand() {
+        pass("let foo; x = { foo };", "foo", "bar", "let bar;x={foo:bar};");
+        pass("let { foo } = x; foo;", "foo", "bar", "let {foo:bar}=x;bar;");
+        pass("let { foo = 1 } = x;", "foo", "bar", "let {foo:bar=1}=x;");
+        pass("let foo; ({ foo } = x);", "foo", "bar", "let bar;({foo:bar}=x);");
+        pass("let foo; ({ foo = 1 } = x);", "foo", "bar", "let bar;({foo:bar=1}=x);");
+        pass("let foo; export { foo };", "foo", "bar", "let bar;export {bar as foo};");
+        pass("import { foo } from 'x'; foo;", "foo", "bar", "import {foo as bar} from \"x\";bar;");
+    }
+
+    #[test]
+    fn rename_exports() {
+        pass("export const foo = 1; foo;", "foo", "bar", "const bar=1;export {bar as foo};bar;");
+        pass("export let foo, baz;", "foo", "bar", "let bar,baz;export {bar as foo,baz};");
+        pass(
+            "export const { foo, baz = foo } = x;",
+            "foo",
+            "bar",
+            "const {foo:bar,baz=bar}=x;export {bar as foo,baz};",
+        );
+        pass("export function foo() {}", "foo", "bar", "function bar(){}export {bar as foo};");
+        pass("export class foo {}", "foo", "bar", "class bar{}export {bar as foo};");
+        pass(
+            "export default function foo() { foo; }",
+            "foo",
+            "bar",
+            "function bar(){bar}export {bar as default};",
+        );
+        pass("export default class foo {}", "foo", "bar", "class bar{}export {bar as default};");
+        // Exports of other bindings are unchanged
+        pass("export const baz = 1; let foo;", "foo", "bar", "export const baz=1;let bar;");
+    }
+
+    #[test]
+    fn rename_conflicts() {
+        assert!(matches!(fail("let foo, bar;", "foo", "bar"), RenameConflict::Redeclaration(_)));
+        assert!(matches!(
+            fail("let foo; function f() { let bar; foo; }", "foo", "bar"),
+            RenameConflict::Shadowed(_)
+        ));
+        assert!(matches!(
+            fail("let bar; function f() { let foo; bar; }", "foo", "bar"),
+            RenameConflict::CapturesOuter(_)
+        ));
+        assert_eq!(fail("let foo; bar;", "foo", "bar"), RenameConflict::CapturesGlobal);
+        for name in ["class", "let", "yield", "arguments", "eval", "1a", "a-b", ""] {
+            assert_eq!(fail("let foo; foo;", "foo", name), RenameConflict::InvalidName, "{name}");
+        }
+    }
+
+    #[test]
+    fn rename_avoiding_conflicts() {
+        let (result, code) = run("let foo, bar, bar2; function f() { bar3; }", "foo", "bar", true);
+        assert_eq!(result, Ok(CompactStr::from("bar4")));
+        assert_eq!(code, "let bar4,bar,bar2;function f(){bar3}");
+
+        let (result, code) = run("let foo; foo;", "foo", "class", true);
+        assert_eq!(result, Ok(CompactStr::from("class2")));
+        assert_eq!(code, "let class2;class2;");
+    }
+}
@@ -2,18 +2,23 @@ use std::str;
 
 use compact_str::CompactString;
 use itoa::Buffer as ItoaBuffer;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 #[allow(clippy::wildcard_imports)]
-use oxc_ast::{ast::*, visit::Visit};
+use oxc_ast::{ast::*, visit::Visit, AstBuilder, VisitMut};
 use oxc_semantic::{NodeId, Reference, ScopeTree, SymbolTable};
 use oxc_span::{Atom, CompactStr, Span};
 use oxc_syntax::{
+    identifier::is_identifier_name,
     reference::{ReferenceFlags, ReferenceId},
     scope::{ScopeFlags, ScopeId},
     symbol::SymbolId,
 };
 
+use super::{
+    rename::{find_rename_conflict, RenameConflict, SymbolRenamer},
+    subtree_scoping::{SubtreeNode, SubtreeScopingCollector, SubtreeScopingRegistrar},
+};
 use crate::scopes_collector::ChildScopeCollector;
 
 /// Traverse scope context.
@@ -26,6 +31,9 @@ pub struct TraverseScoping {
     scopes: ScopeTree,
     symbols: SymbolTable,
     uid_names: Option<FxHashSet<CompactStr>>,
+    /// Symbols renamed with [`TraverseScoping::rename_symbol`], whose identifiers in the AST
+    /// have not been updated yet
+    renamed_symbols: FxHashMap<SymbolId, CompactStr>,
     current_scope_id: ScopeId,
 }
 
@@ -357,6 +365,128 @@ impl TraverseScoping {
         self.create_reference_id(ident.span, ident.name.clone(), symbol_id, flags)
     }
 
+    /// Unregister the references, bindings and scopes of a statement which is being removed from AST.
+    ///
+    /// * References in the statement are deleted.
+    /// * Symbols declared in the statement are removed from their scopes' bindings.
+    ///   Any references to them outside the statement are re-bound to the binding with the same name
+    ///   in an outer scope, or become unresolved references to a global.
+    ///   A `var` which is also declared outside of the statement is not removed.
+    /// * Scopes in the statement are detached from their parent scopes.
+    ///
+    /// `SymbolId`s, `ReferenceId`s and `ScopeId`s are not reused, and remain set on the AST nodes,
+    /// so the statement can be re-inserted elsewhere with [`TraverseScoping::add_statement_scoping`].
+    pub fn delete_statement_scoping(&mut self, stmt: &Statement) {
+        self.delete_subtree_scoping(stmt);
+    }
+
+    /// Unregister the references, bindings and scopes of an expression which is being removed from AST.
+    ///
+    /// See [`TraverseScoping::delete_statement_scoping`].
+    pub fn delete_expression_scoping(&mut self, expr: &Expression) {
+        self.delete_subtree_scoping(expr);
+    }
+
+    /// Register the references, bindings and scopes of a statement which is being inserted into AST,
+    /// in current scope.
+    ///
+    /// This is the inverse of [`TraverseScoping::delete_statement_scoping`], and is also suitable for
+    /// a statement moved from elsewhere in the AST.
+    ///
+    /// * Top-level scopes of the statement become children of current scope.
+    /// * Symbols declared in the statement are added to their scopes' bindings.
+    /// * Unresolved references, or references to a symbol which is no longer bound, are resolved
+    ///   again from the scope they are in.
+    ///
+    /// Existing references outside the statement are not updated. If the statement declares a binding
+    /// which they should now resolve to, that must be handled by the caller.
+    ///
+    /// Nodes must already have `SymbolId`s, `ReferenceId`s and `ScopeId`s. Create new bindings
+    /// and references with e.g. [`TraverseCtx::generate_uid`] and [`TraverseCtx::create_reference_id`].
+    ///
+    /// [`TraverseCtx::generate_uid`]: crate::TraverseCtx::generate_uid
+    /// [`TraverseCtx::create_reference_id`]: crate::TraverseCtx::create_reference_id
+    pub fn add_statement_scoping(&mut self, stmt: &Statement) {
+        self.add_subtree_scoping(stmt);
+    }
+
+    /// Register the references, bindings and scopes of an expression which is being inserted into AST,
+    /// in current scope.
+    ///
+    /// See [`TraverseScoping::add_statement_scoping`].
+    pub fn add_expression_scoping(&mut self, expr: &Expression) {
+        self.add_subtree_scoping(expr);
+    }
+
+    /// Find a reason why renaming `symbol_id` to `new_name` would change the meaning of the program.
+    ///
+    /// Returns `None` if the symbol can be renamed safely.
+    ///
+    /// References do not record which scope they are in, so shadowing and capture checks are
+    /// conservative. e.g. renaming `foo` to `bar` is reported as a conflict if any scope nested
+    /// inside the scope `foo` is declared in contains a binding `bar`, even if `foo` is not
+    /// referenced within that scope.
+    pub fn find_rename_conflict(
+        &self,
+        symbol_id: SymbolId,
+        new_name: &str,
+    ) -> Option<RenameConflict> {
+        find_rename_conflict(&self.symbols, &self.scopes, symbol_id, new_name)
+    }
+
+    /// Rename a symbol.
+    ///
+    /// Updates the symbol's name in `SymbolTable` and its binding in `ScopeTree` immediately.
+    /// `BindingIdentifier`s and `IdentifierReference`s for the symbol in the AST are updated
+    /// once traversal of the whole program finishes. Until then, they keep the old name.
+    ///
+    /// Shorthand properties which refer to the symbol (`{ foo }`) are expanded to keep their key
+    /// (`{ foo: bar }`). So are exports: `export const foo = 1` becomes
+    /// `const bar = 1; export { bar as foo }`.
+    ///
+    /// # Errors
+    /// Returns a [`RenameConflict`] without renaming anything if the rename would change which
+    /// binding an identifier refers to, or `new_name` is not a valid identifier or is a reserved
+    /// word. See [`TraverseScoping::find_rename_conflict`].
+    pub fn rename_symbol(
+        &mut self,
+        symbol_id: SymbolId,
+        new_name: CompactStr,
+    ) -> Result<(), RenameConflict> {
+        if let Some(conflict) = self.find_rename_conflict(symbol_id, &new_name) {
+            return Err(conflict);
+        }
+        self.rename_symbol_unchecked(symbol_id, new_name);
+        Ok(())
+    }
+
+    /// Rename a symbol to `name`, or if that would cause a conflict, to `name` with the lowest
+    /// numerical postfix which does not (`name2`, `name3`, ...).
+    ///
+    /// Returns the new name. See [`TraverseScoping::rename_symbol`] for details of what is renamed.
+    ///
+    /// # Panics
+    /// Panics if `name` is not a valid identifier, since no postfix makes it one.
+    pub fn rename_symbol_avoiding_conflicts(
+        &mut self,
+        symbol_id: SymbolId,
+        name: &str,
+    ) -> CompactStr {
+        assert!(is_identifier_name(name), "`{name}` is not a valid identifier");
+        let mut new_name = CompactStr::from(name);
+        let mut postfix = 2u32;
+        while self.find_rename_conflict(symbol_id, &new_name).is_some() {
+            let mut buffer = ItoaBuffer::new();
+            let mut postfixed = CompactString::with_capacity(name.len() + 2);
+            postfixed.push_str(name);
+            postfixed.push_str(buffer.format(postfix));
+            new_name = CompactStr::from(postfixed);
+            postfix += 1;
+        }
+        self.rename_symbol_unchecked(symbol_id, new_name.clone());
+        new_name
+    }
+
     /// Determine whether evaluating the specific input `node` is a consequenceless reference.
     ///
     /// I.E evaluating it won't result in potentially arbitrary code from being ran. The following are
@@ -395,6 +525,7 @@ impl TraverseScoping {
             scopes,
             symbols,
             uid_names: None,
+            renamed_symbols: FxHashMap::default(),
             // Dummy value. Immediately overwritten in `walk_program`.
             current_scope_id: ScopeId::new(0),
         }
@@ -406,6 +537,177 @@ impl TraverseScoping {
         self.current_scope_id = scope_id;
     }
 
+    /// Update identifiers in the AST for symbols renamed with [`TraverseScoping::rename_symbol`].
+    pub(crate) fn apply_symbol_renames<'a>(
+        &mut self,
+        program: &mut Program<'a>,
+        ast: AstBuilder<'a>,
+    ) {
+        if self.renamed_symbols.is_empty() {
+            return;
+        }
+        let renamed_symbols = std::mem::take(&mut self.renamed_symbols);
+        SymbolRenamer::new(ast, &mut self.symbols, &renamed_symbols).visit_program(program);
+    }
+
+    fn delete_subtree_scoping<'a, N: SubtreeNode<'a>>(&mut self, node: &N) {
+        let mut collector = SubtreeScopingCollector::default();
+        node.accept(&mut collector);
+
+        // Detach scopes from parents outside the subtree
+        if self.scopes.has_child_ids() {
+            for &scope_id in &collector.scope_ids {
+                let Some(parent_id) = self.scopes.get_parent_id(scope_id) else { continue };
+                if !collector.scope_ids.contains(&parent_id) {
+                    self.scopes.get_child_ids_mut(parent_id).retain(|&id| id != scope_id);
+                }
+            }
+        }
+
+        for (reference_id, name) in &collector.references {
+            self.delete_reference(*reference_id, name);
+        }
+
+        // `var x` may be declared more than once. The symbol is only deleted if all of its
+        // declarations are in the subtree.
+        let mut declaration_counts = FxHashMap::<SymbolId, usize>::default();
+        for (symbol_id, _) in &collector.bindings {
+            *declaration_counts.entry(*symbol_id).or_default() += 1;
+        }
+        for (symbol_id, name) in collector.bindings {
+            let Some(count) = declaration_counts.remove(&symbol_id) else { continue };
+            if count <= self.symbols.get_redeclarations(symbol_id).len() {
+                continue;
+            }
+            self.delete_symbol(symbol_id, &name);
+        }
+    }
+
+    /// Remove a symbol's binding, and re-bind any remaining references to it.
+    fn delete_symbol(&mut self, symbol_id: SymbolId, name: &str) {
+        let scope_id = self.symbols.get_scope_id(symbol_id);
+        if self.scopes.get_binding(scope_id, name) == Some(symbol_id) {
+            self.scopes.remove_binding(scope_id, &CompactStr::from(name));
+        }
+
+        let reference_ids = std::mem::take(&mut self.symbols.resolved_references[symbol_id]);
+        if reference_ids.is_empty() {
+            return;
+        }
+        let outer_symbol_id = self
+            .scopes
+            .get_parent_id(scope_id)
+            .and_then(|parent_id| self.scopes.find_binding(parent_id, name));
+        for reference_id in reference_ids {
+            self.rebind_reference(reference_id, name, outer_symbol_id);
+        }
+    }
+
+    /// Set the symbol a reference resolves to, and add it to the symbol's resolved references,
+    /// or to root unresolved references if `symbol_id` is `None`.
+    fn rebind_reference(
+        &mut self,
+        reference_id: ReferenceId,
+        name: &str,
+        symbol_id: Option<SymbolId>,
+    ) {
+        let reference = self.symbols.get_reference(reference_id);
+        let (node_id, flags) = (reference.node_id(), reference.flags());
+        if let Some(symbol_id) = symbol_id {
+            self.symbols.references[reference_id] =
+                Reference::new_with_symbol_id(node_id, symbol_id, flags);
+            self.symbols.resolved_references[symbol_id].push(reference_id);
+        } else {
+            self.symbols.references[reference_id] = Reference::new(node_id, flags);
+            self.scopes.add_root_unresolved_reference(CompactStr::from(name), reference_id);
+        }
+    }
+
+    fn add_subtree_scoping<'a, N: SubtreeNode<'a>>(&mut self, node: &N) {
+        let mut registrar = SubtreeScopingRegistrar::new(self.current_scope_id);
+        node.accept(&mut registrar);
+
+        for scope_id in registrar.child_scope_ids {
+            // Scope may have been detached from current scope when it was deleted
+            if self.scopes.get_parent_id(scope_id) == Some(self.current_scope_id)
+                && (!self.scopes.has_child_ids()
+                    || self.scopes.get_child_ids(self.current_scope_id).contains(&scope_id))
+            {
+                continue;
+            }
+            if self.scopes.has_child_ids() {
+                if let Some(parent_id) = self.scopes.get_parent_id(scope_id) {
+                    self.scopes.get_child_ids_mut(parent_id).retain(|&id| id != scope_id);
+                }
+            }
+            // Also adds scope to current scope's child IDs
+            self.scopes.set_parent_id(scope_id, Some(self.current_scope_id));
+        }
+
+        for (symbol_id, name) in registrar.bindings {
+            let mut scope_id = self.symbols.get_scope_id(symbol_id);
+            if !registrar.scope_ids.contains(&scope_id) {
+                // Symbol is declared in the scope the subtree is inserted into.
+                // It may have moved from another scope.
+                if self.scopes.get_binding(scope_id, &name) == Some(symbol_id) {
+                    self.scopes.remove_binding(scope_id, &name.to_compact_str());
+                }
+                scope_id = self.current_scope_id;
+                if self.symbols.get_flags(symbol_id).is_function_scoped_declaration() {
+                    while !self.scopes.get_flags(scope_id).is_var() {
+                        let Some(parent_id) = self.scopes.get_parent_id(scope_id) else { break };
+                        scope_id = parent_id;
+                    }
+                }
+                self.symbols.set_scope_id(symbol_id, scope_id);
+            }
+            if self.scopes.get_binding(scope_id, &name).is_none() {
+                self.scopes.add_binding(scope_id, name.to_compact_str(), symbol_id);
+            }
+        }
+
+        for (reference_id, name, scope_id) in registrar.references {
+            // Reference may have moved, so resolve it again from the scope it's now in.
+            // Fall back to the symbol it was bound to if that symbol is still bound under its name.
+            let bound_symbol_id = self.symbols.get_reference(reference_id).symbol_id();
+            let symbol_id = self.scopes.find_binding(scope_id, &name).or_else(|| {
+                bound_symbol_id.filter(|&symbol_id| {
+                    let symbol_scope_id = self.symbols.get_scope_id(symbol_id);
+                    self.scopes.get_binding(symbol_scope_id, self.symbols.get_name(symbol_id))
+                        == Some(symbol_id)
+                })
+            });
+
+            // Remove from lists it may still be in, if it was not unregistered before
+            if let Some(bound_symbol_id) = bound_symbol_id {
+                self.symbols.resolved_references[bound_symbol_id].retain(|&id| id != reference_id);
+            } else if self
+                .scopes
+                .root_unresolved_references()
+                .get(name.as_str())
+                .is_some_and(|reference_ids| reference_ids.contains(&reference_id))
+            {
+                self.scopes.delete_root_unresolved_reference(&name, reference_id);
+            }
+
+            self.rebind_reference(reference_id, &name, symbol_id);
+        }
+    }
+
+    fn rename_symbol_unchecked(&mut self, symbol_id: SymbolId, new_name: CompactStr) {
+        let scope_id = self.symbols.get_scope_id(symbol_id);
+        let old_name = self.symbols.get_name(symbol_id).into();
+        self.scopes.remove_binding(scope_id, &old_name);
+        self.scopes.add_binding(scope_id, new_name.clone(), symbol_id);
+        self.symbols.set_name(symbol_id, new_name.clone());
+        if let Some(uid_names) = &mut self.uid_names {
+            if new_name.as_bytes().first() == Some(&b'_') {
+                uid_names.insert(new_name.clone());
+            }
+        }
+        self.renamed_symbols.insert(symbol_id, new_name);
+    }
+
     /// Initialize `uid_names`.
     ///
     /// Iterate through all symbols and unresolved references in AST and identify any var names
@@ -0,0 +1,324 @@
+use std::cell::Cell;
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, visit::Visit};
+use oxc_span::Atom;
+use oxc_syntax::{
+    reference::ReferenceId,
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolId,
+};
+
+/// Visitor which collects all references, bindings and scopes in a subtree.
+#[derive(Default)]
+pub(super) struct SubtreeScopingCollector<'a> {
+    pub(super) references: Vec<(ReferenceId, Atom<'a>)>,
+    pub(super) bindings: Vec<(SymbolId, Atom<'a>)>,
+    pub(super) scope_ids: Vec<ScopeId>,
+}
+
+impl<'a> Visit<'a> for SubtreeScopingCollector<'a> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if let Some(scope_id) = scope_id.get() {
+            self.scope_ids.push(scope_id);
+        }
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        if let Some(symbol_id) = ident.symbol_id.get() {
+            self.bindings.push((symbol_id, ident.name.clone()));
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if let Some(reference_id) = ident.reference_id.get() {
+            self.references.push((reference_id, ident.name.clone()));
+        }
+    }
+}
+
+/// Visitor which collects references and bindings in a subtree, along with the scope each
+/// reference is in.
+///
+/// Scopes are tracked relative to `root_scope_id`, which is the scope the subtree is inserted into.
+pub(super) struct SubtreeScopingRegistrar<'a> {
+    scope_stack: Vec<ScopeId>,
+    /// Scopes which are direct children of `root_scope_id`
+    pub(super) child_scope_ids: Vec<ScopeId>,
+    /// All scopes in the subtree
+    pub(super) scope_ids: Vec<ScopeId>,
+    pub(super) references: Vec<(ReferenceId, Atom<'a>, ScopeId)>,
+    pub(super) bindings: Vec<(SymbolId, Atom<'a>)>,
+}
+
+impl<'a> SubtreeScopingRegistrar<'a> {
+    pub(super) fn new(root_scope_id: ScopeId) -> Self {
+        Self {
+            scope_stack: vec![root_scope_id],
+            child_scope_ids: vec![],
+            scope_ids: vec![],
+            references: vec![],
+            bindings: vec![],
+        }
+    }
+
+    fn current_scope_id(&self) -> ScopeId {
+        *self.scope_stack.last().unwrap()
+    }
+}
+
+impl<'a> Visit<'a> for SubtreeScopingRegistrar<'a> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        let scope_id = scope_id.get().unwrap_or_else(|| self.current_scope_id());
+        if self.scope_stack.len() == 1 {
+            self.child_scope_ids.push(scope_id);
+        }
+        self.scope_ids.push(scope_id);
+        self.scope_stack.push(scope_id);
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_stack.pop();
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        if let Some(symbol_id) = ident.symbol_id.get() {
+            self.bindings.push((symbol_id, ident.name.clone()));
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if let Some(reference_id) = ident.reference_id.get() {
+            self.references.push((reference_id, ident.name.clone(), self.current_scope_id()));
+        }
+    }
+}
+
+/// A node whose scoping can be registered or unregistered.
+pub(super) trait SubtreeNode<'a> {
+    fn accept<V: Visit<'a>>(&self, visitor: &mut V);
+}
+
+impl<'a> SubtreeNode<'a> for Statement<'a> {
+    fn accept<V: Visit<'a>>(&self, visitor: &mut V) {
+        visitor.visit_statement(self);
+    }
+}
+
+impl<'a> SubtreeNode<'a> for Expression<'a> {
+    fn accept<V: Visit<'a>>(&self, visitor: &mut V) {
+        visitor.visit_expression(self);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::*;
+    use oxc_parser::Parser;
+    use oxc_semantic::{ScopeTree, SemanticBuilder, SymbolTable};
+    use oxc_span::{SourceType, SPAN};
+    use oxc_syntax::{
+        number::NumberBase,
+        symbol::{SymbolFlags, SymbolId},
+    };
+
+    use crate::{traverse_mut, Traverse, TraverseCtx};
+
+    /// Removes statements labelled `remove`, moves statements labelled `move` to the end of
+    /// the program, and replaces arrow functions with `0`.
+    ///
+    /// Blocks labelled `uid` get a `var` declaration of a generated UID, and are then removed.
+    #[derive(Default)]
+    struct Transform<'a> {
+        moved: Vec<Statement<'a>>,
+    }
+
+    impl<'a> Traverse<'a> for Transform<'a> {
+        fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+            let Statement::LabeledStatement(labeled) = stmt else { return };
+            match labeled.label.name.as_str() {
+                "remove" => ctx.remove_statement(stmt),
+                "move" => {
+                    let empty = ctx.ast.statement_empty(SPAN);
+                    let Statement::LabeledStatement(labeled) = ctx.replace_statement(stmt, empty)
+                    else {
+                        unreachable!()
+                    };
+                    self.moved.push(labeled.unbox().body);
+                }
+                "uid" => {
+                    let Statement::BlockStatement(block) = &mut labeled.body else {
+                        unreachable!()
+                    };
+                    let scope_id = ctx.current_scope_id();
+                    let binding =
+                        ctx.generate_uid("x", scope_id, SymbolFlags::FunctionScopedVariable);
+                    let declarator = ctx.ast.variable_declarator(
+                        SPAN,
+                        VariableDeclarationKind::Var,
+                        binding.create_binding_pattern(ctx),
+                        None,
+                        false,
+                    );
+                    let declaration = ctx.ast.declaration_variable(
+                        SPAN,
+                        VariableDeclarationKind::Var,
+                        ctx.ast.vec1(declarator),
+                        false,
+                    );
+                    block.body.push(Statement::from(declaration));
+                    ctx.remove_statement(stmt);
+                }
+                _ => {}
+            }
+        }
+
+        fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+            if matches!(expr, Expression::ArrowFunctionExpression(_)) {
+                let zero = ctx.ast.expression_numeric_literal(SPAN, 0.0, "0", NumberBase::Decimal);
+                ctx.replace_expression(expr, zero);
+            }
+        }
+
+        fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+            for stmt in self.moved.drain(..) {
+                ctx.add_statement_scoping(&stmt);
+                program.body.push(stmt);
+            }
+        }
+    }
+
+    fn run(source_text: &str) -> (SymbolTable, ScopeTree) {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        assert!(ret.errors.is_empty());
+        let mut program = ret.program;
+        let (symbols, scopes) = SemanticBuilder::new()
+            .with_scope_tree_child_ids(true)
+            .build(&program)
+            .semantic
+            .into_symbol_table_and_scope_tree();
+        let (symbols, scopes) =
+            traverse_mut(&mut Transform::default(), &allocator, &mut program, symbols, scopes);
+
+        // Check resolved and unresolved references are consistent
+        for symbol_id in symbols.symbol_ids() {
+            for &reference_id in symbols.get_resolved_reference_ids(symbol_id) {
+                assert_eq!(symbols.get_reference(reference_id).symbol_id(), Some(symbol_id));
+            }
+        }
+        for reference_id in scopes.root_unresolved_references_ids().flatten() {
+            assert_eq!(symbols.get_reference(reference_id).symbol_id(), None);
+        }
+
+        (symbols, scopes)
+    }
+
+    /// Get IDs of symbols with `name`, in order of declaration.
+    fn symbols_named(symbols: &SymbolTable, name: &str) -> Vec<SymbolId> {
+        symbols.symbol_ids().filter(|&symbol_id| symbols.get_name(symbol_id) == name).collect()
+    }
+
+    fn is_bound(symbols: &SymbolTable, scopes: &ScopeTree, symbol_id: SymbolId) -> bool {
+        let scope_id = symbols.get_scope_id(symbol_id);
+        scopes.get_binding(scope_id, symbols.get_name(symbol_id)) == Some(symbol_id)
+    }
+
+    fn unresolved_count(scopes: &ScopeTree, name: &str) -> usize {
+        scopes.root_unresolved_references().get(name).map_or(0, Vec::len)
+    }
+
+    #[test]
+    fn remove() {
+        let (symbols, scopes) = run("
+            let x = 1;
+            remove: { let y = x; f(y); function g() { x } }
+            x;
+        ");
+        let x = symbols_named(&symbols, "x")[0];
+        assert_eq!(symbols.get_resolved_reference_ids(x).len(), 1);
+        for name in ["y", "g"] {
+            let symbol_id = symbols_named(&symbols, name)[0];
+            assert!(!is_bound(&symbols, &scopes, symbol_id));
+            assert!(symbols.get_resolved_reference_ids(symbol_id).is_empty());
+        }
+        assert_eq!(unresolved_count(&scopes, "f"), 0);
+        assert!(scopes.get_child_ids(scopes.root_scope_id()).is_empty());
+    }
+
+    #[test]
+    fn remove_rebinds_outer_references() {
+        // Reference to removed `var` falls back to binding in outer scope
+        let (symbols, scopes) = run("
+            let x = 1;
+            function f() { x; remove: var x = 2; }
+        ");
+        let [outer_x, inner_x] = symbols_named(&symbols, "x")[..] else { panic!() };
+        assert!(!is_bound(&symbols, &scopes, inner_x));
+        assert!(symbols.get_resolved_reference_ids(inner_x).is_empty());
+        assert_eq!(symbols.get_resolved_reference_ids(outer_x).len(), 1);
+
+        // Or becomes a reference to a global
+        let (symbols, scopes) = run("function f() { x; remove: var x = 2; }");
+        let x = symbols_named(&symbols, "x")[0];
+        assert!(!is_bound(&symbols, &scopes, x));
+        assert_eq!(unresolved_count(&scopes, "x"), 1);
+
+        // `var` which is also declared outside removed statement is not removed
+        let (symbols, scopes) = run("var x = 1; remove: var x = 2; x;");
+        let x = symbols_named(&symbols, "x")[0];
+        assert!(is_bound(&symbols, &scopes, x));
+        assert_eq!(symbols.get_resolved_reference_ids(x).len(), 1);
+    }
+
+    #[test]
+    fn remove_generated_binding() {
+        // The generated binding is declared inside the removed statement
+        let (symbols, scopes) = run("let a; uid: {}");
+        let x = symbols_named(&symbols, "_x")[0];
+        assert!(!is_bound(&symbols, &scopes, x));
+    }
+
+    #[test]
+    fn replace_expression() {
+        let (symbols, scopes) = run("let x = 1; g(() => x);");
+        let x = symbols_named(&symbols, "x")[0];
+        assert!(symbols.get_resolved_reference_ids(x).is_empty());
+        assert_eq!(unresolved_count(&scopes, "g"), 1);
+        assert!(scopes.get_child_ids(scopes.root_scope_id()).is_empty());
+    }
+
+    #[test]
+    fn move_statement() {
+        let (symbols, scopes) = run("
+            let x = 1;
+            { let x = 2; move: { let y = x; f(y); } }
+        ");
+        let [outer_x, inner_x] = symbols_named(&symbols, "x")[..] else { panic!() };
+        assert_eq!(symbols.get_resolved_reference_ids(outer_x).len(), 1);
+        assert!(symbols.get_resolved_reference_ids(inner_x).is_empty());
+        assert_eq!(unresolved_count(&scopes, "f"), 1);
+
+        let y = symbols_named(&symbols, "y")[0];
+        assert!(is_bound(&symbols, &scopes, y));
+        assert_eq!(symbols.get_resolved_reference_ids(y).len(), 1);
+
+        // Moved block is now a child of root scope
+        let root_scope_id = scopes.root_scope_id();
+        let block_scope_id = symbols.get_scope_id(y);
+        assert_eq!(scopes.get_parent_id(block_scope_id), Some(root_scope_id));
+        assert_eq!(scopes.get_child_ids(root_scope_id).len(), 2);
+    }
+
+    #[test]
+    fn move_var() {
+        let (symbols, scopes) = run("function f() { if (x) { move: { var y = 1; y; } } }");
+        let y = symbols_named(&symbols, "y")[0];
+        assert!(is_bound(&symbols, &scopes, y));
+        assert_eq!(symbols.get_scope_id(y), scopes.root_scope_id());
+        assert_eq!(symbols.get_resolved_reference_ids(y).len(), 1);
+        let f_scope_id = scopes.get_child_ids(scopes.root_scope_id())[0];
+        assert!(scopes.get_binding(f_scope_id, "y").is_none());
+    }
+}
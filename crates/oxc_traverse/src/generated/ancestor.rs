@@ -11829,6 +11829,7 @@ impl<'a, 't> TSModuleBlockWithoutBody<'a, 't> {
 
 pub(crate) const OFFSET_TS_TYPE_LITERAL_SPAN: usize = offset_of!(TSTypeLiteral, span);
 pub(crate) const OFFSET_TS_TYPE_LITERAL_MEMBERS: usize = offset_of!(TSTypeLiteral, members);
+pub(crate) const OFFSET_TS_TYPE_LITERAL_FLOW_EXACT: usize = offset_of!(TSTypeLiteral, flow_exact);
 
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug)]
@@ -11842,6 +11843,11 @@ impl<'a, 't> TSTypeLiteralWithoutMembers<'a, 't> {
     pub fn span(self) -> &'t Span {
         unsafe { &*((self.0 as *const u8).add(OFFSET_TS_TYPE_LITERAL_SPAN) as *const Span) }
     }
+
+    #[inline]
+    pub fn flow_exact(self) -> &'t bool {
+        unsafe { &*((self.0 as *const u8).add(OFFSET_TS_TYPE_LITERAL_FLOW_EXACT) as *const bool) }
+    }
 }
 
 pub(crate) const OFFSET_TS_INFER_TYPE_SPAN: usize = offset_of!(TSInferType, span);
@@ -12690,6 +12696,8 @@ pub(crate) const OFFSET_TS_AS_EXPRESSION_SPAN: usize = offset_of!(TSAsExpression
 pub(crate) const OFFSET_TS_AS_EXPRESSION_EXPRESSION: usize = offset_of!(TSAsExpression, expression);
 pub(crate) const OFFSET_TS_AS_EXPRESSION_TYPE_ANNOTATION: usize =
     offset_of!(TSAsExpression, type_annotation);
+pub(crate) const OFFSET_TS_AS_EXPRESSION_FLOW_TYPE_CAST: usize =
+    offset_of!(TSAsExpression, flow_type_cast);
 
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug)]
@@ -12711,6 +12719,13 @@ impl<'a, 't> TSAsExpressionWithoutExpression<'a, 't> {
                 as *const TSType<'a>)
         }
     }
+
+    #[inline]
+    pub fn flow_type_cast(self) -> &'t bool {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_TS_AS_EXPRESSION_FLOW_TYPE_CAST) as *const bool)
+        }
+    }
 }
 
 #[repr(transparent)]
@@ -12733,6 +12748,13 @@ impl<'a, 't> TSAsExpressionWithoutTypeAnnotation<'a, 't> {
                 as *const Expression<'a>)
         }
     }
+
+    #[inline]
+    pub fn flow_type_cast(self) -> &'t bool {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_TS_AS_EXPRESSION_FLOW_TYPE_CAST) as *const bool)
+        }
+    }
 }
 
 pub(crate) const OFFSET_TS_SATISFIES_EXPRESSION_SPAN: usize =
@@ -837,6 +837,11 @@ pub trait Traverse<'a> {
     fn exit_debugger_statement(&mut self, node: &mut DebuggerStatement, ctx: &mut TraverseCtx<'a>) {
     }
 
+    #[inline]
+    fn enter_error_statement(&mut self, node: &mut ErrorStatement, ctx: &mut TraverseCtx<'a>) {}
+    #[inline]
+    fn exit_error_statement(&mut self, node: &mut ErrorStatement, ctx: &mut TraverseCtx<'a>) {}
+
     #[inline]
     fn enter_binding_pattern(&mut self, node: &mut BindingPattern<'a>, ctx: &mut TraverseCtx<'a>) {}
     #[inline]
@@ -1365,6 +1365,9 @@ pub(crate) unsafe fn walk_statement<'a, Tr: Traverse<'a>>(
         Statement::WithStatement(node) => {
             walk_with_statement(traverser, (&mut **node) as *mut _, ctx)
         }
+        Statement::ErrorStatement(node) => {
+            walk_error_statement(traverser, (&mut **node) as *mut _, ctx)
+        }
         Statement::VariableDeclaration(_)
         | Statement::FunctionDeclaration(_)
         | Statement::ClassDeclaration(_)
@@ -2094,6 +2097,15 @@ pub(crate) unsafe fn walk_debugger_statement<'a, Tr: Traverse<'a>>(
     traverser.exit_debugger_statement(&mut *node, ctx);
 }
 
+pub(crate) unsafe fn walk_error_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: *mut ErrorStatement,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_error_statement(&mut *node, ctx);
+    traverser.exit_error_statement(&mut *node, ctx);
+}
+
 pub(crate) unsafe fn walk_binding_pattern<'a, Tr: Traverse<'a>>(
     traverser: &mut Tr,
     node: *mut BindingPattern<'a>,
@@ -66,7 +66,9 @@ use oxc_semantic::{ScopeTree, SymbolTable};
 
 pub mod ast_operations;
 mod context;
-pub use context::{BoundIdentifier, TraverseAncestry, TraverseCtx, TraverseScoping};
+pub use context::{
+    BoundIdentifier, RenameConflict, TraverseAncestry, TraverseCtx, TraverseScoping,
+};
 
 mod generated {
     pub mod ancestor;
@@ -163,4 +165,5 @@ pub fn walk_program<'a, Tr: Traverse<'a>>(
 ) {
     // SAFETY: Walk functions are constructed to avoid unsoundness
     unsafe { walk::walk_program(traverser, std::ptr::from_mut(program), ctx) };
+    ctx.scoping.apply_symbol_renames(program, ctx.ast);
 }
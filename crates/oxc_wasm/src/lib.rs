@@ -187,6 +187,13 @@ impl Oxc {
             preserve_parens: parser_options
                 .preserve_parens
                 .unwrap_or(default_parser_options.preserve_parens),
+            recover_from_errors: parser_options
+                .recover_from_errors
+                .unwrap_or(default_parser_options.recover_from_errors),
+            parse_flow: parser_options.parse_flow.unwrap_or(default_parser_options.parse_flow),
+            max_nesting_depth: parser_options
+                .max_nesting_depth
+                .unwrap_or(default_parser_options.max_nesting_depth),
         };
         let ParserReturn { mut program, errors, .. } =
             Parser::new(&allocator, source_text, source_type)
@@ -54,6 +54,12 @@ pub struct OxcParserOptions {
     pub allow_return_outside_function: Option<bool>,
     #[tsify(optional)]
     pub preserve_parens: Option<bool>,
+    #[tsify(optional)]
+    pub recover_from_errors: Option<bool>,
+    #[tsify(optional)]
+    pub parse_flow: Option<bool>,
+    #[tsify(optional)]
+    pub max_nesting_depth: Option<u32>,
     #[tsify(optional, type = "\"script\" | \"module\"")]
     pub source_type: Option<String>,
     #[tsify(optional)]
@@ -93,6 +93,8 @@ export declare function parseAsync(sourceText: string, options?: ParserOptions |
 export interface ParseResult {
   program: string
   comments: Array<Comment>
+  /** JSON of the scope analysis, if `scopeManager` is enabled */
+  scopeManager?: string
   errors: Array<string>
 }
 
@@ -114,6 +116,28 @@ export interface ParserOptions {
    * Default: true
    */
   preserveParens?: boolean
+  /**
+   * Emit an [ESTree](https://github.com/estree/estree) conformant AST,
+   * or a [TS-ESTree](https://typescript-eslint.io/packages/typescript-estree/ast-spec)
+   * conformant AST for TypeScript files.
+   *
+   * Parenthesized expressions are never preserved in this AST,
+   * and `start` / `end` are UTF-16 offsets.
+   *
+   * Default: false
+   */
+  estree?: boolean
+  /**
+   * Return scope analysis in the data model of
+   * [eslint-scope](https://github.com/eslint/js/tree/main/packages/eslint-scope)'s `ScopeManager`
+   * as `ParseResult.scopeManager`.
+   *
+   * Scopes, variables and references refer to each other by their index,
+   * and `start` / `end` are the same offsets as in `program`.
+   *
+   * Default: false
+   */
+  scopeManager?: boolean
 }
 
 /**
@@ -7,10 +7,11 @@ use napi_derive::napi;
 
 use oxc::{
     allocator::Allocator,
-    ast::CommentKind,
+    ast::{CommentKind, EstreeFlavor},
     diagnostics::{Error, NamedSource},
     napi::parse::{Comment, ParseResult, ParserOptions},
     parser::{ParseOptions, Parser, ParserReturn},
+    semantic::{EslintNode, EslintScopeManager, SemanticBuilder},
     span::SourceType,
 };
 
@@ -58,7 +59,25 @@ pub fn parse_without_return(source_text: String, options: Option<ParserOptions>)
 fn parse_with_return<'a>(source_text: &'a str, options: &ParserOptions) -> ParseResult {
     let allocator = Allocator::default();
     let ret = parse(&allocator, source_text, options);
-    let program = serde_json::to_string(&ret.program).unwrap();
+    let program = if options.estree == Some(true) {
+        let flavor = if ret.program.source_type.is_typescript() {
+            EstreeFlavor::TypeScript
+        } else {
+            EstreeFlavor::JavaScript
+        };
+        ret.program.to_estree_json(flavor)
+    } else {
+        serde_json::to_string(&ret.program).unwrap()
+    };
+
+    let scope_manager = (options.scope_manager == Some(true)).then(|| {
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        let mut scope_manager = EslintScopeManager::new(&semantic);
+        if options.estree == Some(true) {
+            to_utf16_offsets(&mut scope_manager, source_text);
+        }
+        serde_json::to_string(&scope_manager).unwrap()
+    });
 
     let errors = if ret.errors.is_empty() {
         vec![]
@@ -87,7 +106,38 @@ fn parse_with_return<'a>(source_text: &'a str, options: &ParserOptions) -> Parse
         })
         .collect::<Vec<Comment>>();
 
-    ParseResult { program, comments, errors }
+    ParseResult { program, comments, scope_manager, errors }
+}
+
+/// Convert UTF-8 byte offsets to UTF-16 offsets, to match the ESTree AST.
+#[allow(clippy::cast_possible_truncation)]
+fn to_utf16_offsets(scope_manager: &mut EslintScopeManager, source_text: &str) {
+    // UTF-16 offset of every char boundary
+    let mut offsets = vec![0; source_text.len() + 1];
+    let mut utf16_offset = 0;
+    for (offset, c) in source_text.char_indices() {
+        offsets[offset] = utf16_offset;
+        utf16_offset += c.len_utf16() as u32;
+    }
+    offsets[source_text.len()] = utf16_offset;
+
+    let convert = |node: &mut EslintNode| {
+        node.start = offsets[node.start as usize];
+        node.end = offsets[node.end as usize];
+    };
+    for scope in &mut scope_manager.scopes {
+        convert(&mut scope.block);
+    }
+    for variable in &mut scope_manager.variables {
+        variable.identifiers.iter_mut().for_each(convert);
+        for def in &mut variable.defs {
+            convert(&mut def.name);
+            convert(&mut def.node);
+        }
+    }
+    for reference in &mut scope_manager.references {
+        convert(&mut reference.identifier);
+    }
 }
 
 /// # Panics
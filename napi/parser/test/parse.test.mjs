@@ -18,4 +18,30 @@ describe('parse', () => {
     assert(ret.errors.length == 0);
     assert(ret.comments.length == 1);
   });
+
+  it('estree', () => {
+    const ret = oxc.parseSync('a.b = (1);', { estree: true });
+    const expr = JSON.parse(ret.program).body[0].expression;
+    assert(expr.left.type == 'MemberExpression');
+    assert(expr.right.type == 'Literal');
+    assert(expr.right.raw == '1');
+  });
+
+  it('ts-estree', () => {
+    const ret = oxc.parseSync('let x: string;', { sourceFilename: 'test.ts', estree: true });
+    const id = JSON.parse(ret.program).body[0].declarations[0].id;
+    assert(id.typeAnnotation.type == 'TSTypeAnnotation');
+    assert(id.range[0] == 4);
+  });
+
+  it('scope manager', () => {
+    const ret = oxc.parseSync('let a = 1; function f(b) { return a + b; }', { scopeManager: true });
+    const { scopes, variables, references } = JSON.parse(ret.scopeManager);
+    assert(scopes[0].type == 'global');
+    assert(scopes[1].type == 'module');
+    assert(scopes[2].type == 'function');
+    assert(scopes[2].through.length == 1);
+    assert(variables[scopes[2].variables[0]].name == 'b');
+    assert(references[scopes[2].through[0]].identifier.name == 'a');
+  });
 });
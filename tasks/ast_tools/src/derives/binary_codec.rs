@@ -0,0 +1,131 @@
+use itertools::Itertools;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+use super::{define_derive, Derive, DeriveOutput};
+use crate::{
+    codegen::LateCtx,
+    schema::{EnumDef, GetIdent, StructDef, TypeDef},
+};
+
+define_derive! {
+    pub struct DeriveBinaryCodec;
+}
+
+impl Derive for DeriveBinaryCodec {
+    fn trait_name() -> &'static str {
+        "BinaryCodec"
+    }
+
+    fn derive(&mut self, def: &TypeDef, _: &LateCtx) -> TokenStream {
+        match &def {
+            TypeDef::Enum(it) => derive_enum(it),
+            TypeDef::Struct(it) => derive_struct(it),
+        }
+    }
+
+    fn prelude() -> TokenStream {
+        quote! {
+            #![allow(clippy::match_same_arms, clippy::semicolon_if_nothing_returned)]
+
+            ///@@line_break
+            use oxc_allocator::{BinaryCodec, DecodeError, Decoder, Encoder};
+        }
+    }
+}
+
+fn derive_enum(def: &EnumDef) -> TokenStream {
+    let ty_ident = def.ident();
+
+    let (encode_matches, decode_matches): (Vec<_>, Vec<_>) = def
+        .all_variants()
+        .enumerate()
+        .map(|(index, var)| {
+            let ident = var.ident();
+            #[expect(clippy::cast_possible_truncation)]
+            let index = index as u32;
+            if var.is_unit() {
+                (
+                    quote!(Self :: #ident => encoder.write_u32(#index)),
+                    quote!(#index => Ok(Self :: #ident)),
+                )
+            } else {
+                (
+                    quote!(Self :: #ident(it) => {
+                        encoder.write_u32(#index);
+                        BinaryCodec::encode(it, encoder);
+                    }),
+                    quote!(#index => Ok(Self :: #ident(BinaryCodec::decode(decoder)?))),
+                )
+            }
+        })
+        .unzip();
+
+    let encode_body = quote! {
+        match self {
+            #(#encode_matches),*
+        }
+    };
+    let decode_body = quote! {
+        match decoder.read_u32()? {
+            #(#decode_matches),*,
+            _ => Err(DecodeError::InvalidValue),
+        }
+    };
+
+    impl_binary_codec(&ty_ident, def.has_lifetime, true, &encode_body, &decode_body)
+}
+
+fn derive_struct(def: &StructDef) -> TokenStream {
+    let ty_ident = def.ident();
+
+    let encode_fields = def
+        .fields
+        .iter()
+        .map(|field| {
+            let ident = field.ident();
+            quote!(BinaryCodec::encode(&self.#ident, encoder);)
+        })
+        .collect_vec();
+    let decode_fields = def.fields.iter().map(|field| {
+        let ident = field.ident();
+        quote!(#ident: BinaryCodec::decode(decoder)?)
+    });
+
+    let encode_body = quote!(#(#encode_fields)*);
+    let decode_body = quote!(Ok(Self { #(#decode_fields),* }));
+
+    impl_binary_codec(
+        &ty_ident,
+        def.has_lifetime,
+        !def.fields.is_empty(),
+        &encode_body,
+        &decode_body,
+    )
+}
+
+fn impl_binary_codec(
+    ty_ident: &Ident,
+    has_lifetime: bool,
+    uses_params: bool,
+    encode_body: &TokenStream,
+    decode_body: &TokenStream,
+) -> TokenStream {
+    let (encoder, decoder) =
+        if uses_params { (quote!(encoder), quote!(decoder)) } else { (quote!(_), quote!(_)) };
+    let ty = if has_lifetime { quote!(#ty_ident<'a>) } else { quote!(#ty_ident) };
+
+    quote! {
+        impl <'a> BinaryCodec<'a> for #ty {
+            fn encode(&self, #encoder: &mut Encoder) {
+                #encode_body
+            }
+
+            ///@@line_break
+            fn decode(#decoder: &mut Decoder<'a, '_>) -> Result<Self, DecodeError> {
+                #decode_body
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use itertools::Itertools;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::{define_derive, Derive, DeriveOutput};
+use crate::{
+    codegen::LateCtx,
+    schema::{EnumDef, GetGenerics, StructDef, ToType, TypeDef},
+    util::ToIdent,
+};
+
+define_derive! {
+    pub struct DeriveForEachSpanMut;
+}
+
+const IGNORE_FIELD_TYPES: [/* type name */ &str; 3] = [
+    "ScopeId",
+    "SymbolId",
+    "ReferenceId",
+];
+
+impl Derive for DeriveForEachSpanMut {
+    fn trait_name() -> &'static str {
+        "ForEachSpanMut"
+    }
+
+    fn derive(&mut self, def: &TypeDef, _: &LateCtx) -> TokenStream {
+        let (f, body) = match &def {
+            TypeDef::Enum(it) => derive_enum(it),
+            TypeDef::Struct(it) => derive_struct(it),
+        };
+
+        impl_for_each_span_mut(def, f, &body)
+    }
+
+    fn prelude() -> TokenStream {
+        quote! {
+            #![allow(clippy::match_same_arms, clippy::semicolon_if_nothing_returned)]
+
+            ///@@line_break
+            use oxc_span::{ForEachSpanMut, Span};
+        }
+    }
+}
+
+fn derive_enum(def: &EnumDef) -> (&str, TokenStream) {
+    if def.is_unit() {
+        return ("_", TokenStream::default());
+    }
+
+    let mut non_exhaustive = false;
+    let matches = def
+        .all_variants()
+        .filter_map(|var| {
+            let ident = var.ident();
+            if var.is_unit() {
+                non_exhaustive = true;
+                None
+            } else {
+                Some(quote!(Self :: #ident(it) => ForEachSpanMut::for_each_span_mut(it, f)))
+            }
+        })
+        .collect_vec();
+    let exhaust = non_exhaustive.then(|| quote!(_ => {}));
+    let body = quote! {
+        match self {
+            #(#matches),*
+            #exhaust
+        }
+    };
+
+    ("f", body)
+}
+
+fn derive_struct(def: &StructDef) -> (&str, TokenStream) {
+    let fields = def
+        .fields
+        .iter()
+        .filter(|field| !IGNORE_FIELD_TYPES.iter().any(|it| field.typ.name().inner_name() == *it))
+        .map(|field| {
+            let ident = field.ident();
+            quote!(ForEachSpanMut::for_each_span_mut(&mut self.#ident, f);)
+        })
+        .collect_vec();
+    if fields.is_empty() {
+        ("_", TokenStream::default())
+    } else {
+        ("f", quote!(#(#fields)*))
+    }
+}
+
+fn impl_for_each_span_mut(def: &TypeDef, f_name: &str, body: &TokenStream) -> TokenStream {
+    let ty = def.to_type();
+    let generics = def.generics();
+    let f = f_name.to_ident();
+
+    quote! {
+        impl #generics ForEachSpanMut for #ty {
+            fn for_each_span_mut<F: FnMut(&mut Span)>(&mut self, #f: &mut F) {
+                #body
+            }
+        }
+    }
+}
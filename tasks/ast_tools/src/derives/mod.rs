@@ -4,14 +4,18 @@ use proc_macro2::TokenStream;
 
 use crate::{codegen::LateCtx, schema::TypeDef};
 
+mod binary_codec;
 mod clone_in;
 mod content_eq;
 mod content_hash;
+mod for_each_span_mut;
 mod get_span;
 
+pub use binary_codec::DeriveBinaryCodec;
 pub use clone_in::DeriveCloneIn;
 pub use content_eq::DeriveContentEq;
 pub use content_hash::DeriveContentHash;
+pub use for_each_span_mut::DeriveForEachSpanMut;
 pub use get_span::{DeriveGetSpan, DeriveGetSpanMut};
 
 pub trait Derive {
@@ -154,6 +154,11 @@ impl Generator for AstKindGenerator {
             .map(|(ident, _)| parse_quote!(Self :: #ident(it) => it.span()))
             .collect_vec();
 
+        let content_eq_matches: Vec<Arm> = have_kinds
+            .iter()
+            .map(|(ident, _)| parse_quote!((Self :: #ident(a), Self :: #ident(b)) => a.content_eq(b)))
+            .collect_vec();
+
         let as_ast_kind_impls: Vec<ImplItemFn> = have_kinds
             .iter()
             .map(|(ident, typ)| {
@@ -180,7 +185,7 @@ impl Generator for AstKindGenerator {
             quote! {
                 #header
 
-                use oxc_span::{GetSpan, Span};
+                use oxc_span::{cmp::ContentEq, GetSpan, Span};
 
                 ///@@line_break
                 #[allow(clippy::wildcard_imports)]
@@ -209,6 +214,17 @@ impl Generator for AstKindGenerator {
                     }
                 }
 
+                ///@@line_break
+                impl<'a> ContentEq for AstKind<'a> {
+                    #[allow(clippy::match_same_arms)]
+                    fn content_eq(&self, other: &Self) -> bool {
+                        match (self, other) {
+                            #(#content_eq_matches),*,
+                            _ => false,
+                        }
+                    }
+                }
+
                 ///@@line_break
                 impl<'a> AstKind<'a> {
                     #(#as_ast_kind_impls)*
@@ -0,0 +1,109 @@
+use std::hash::{Hash, Hasher};
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use rustc_hash::FxHasher;
+use syn::Item;
+
+use super::define_generator;
+use crate::{
+    codegen::{generated_header, LateCtx},
+    output, Generator, GeneratorOutput,
+};
+
+/// Files containing hand-written `BinaryCodec` implementations for data which is cached
+/// alongside the AST, e.g. `SymbolTable` and `ScopeTree`.
+pub const HAND_WRITTEN_CODEC_PATHS: &[&str] = &[
+    "crates/oxc_syntax/src/node.rs",
+    "crates/oxc_syntax/src/reference.rs",
+    "crates/oxc_syntax/src/scope.rs",
+    "crates/oxc_syntax/src/symbol.rs",
+    "crates/oxc_semantic/src/reference.rs",
+    "crates/oxc_semantic/src/scope.rs",
+    "crates/oxc_semantic/src/symbol.rs",
+];
+
+define_generator! {
+    pub struct BinaryCodecSchemaGenerator;
+}
+
+impl Generator for BinaryCodecSchemaGenerator {
+    fn generate(&mut self, ctx: &LateCtx) -> GeneratorOutput {
+        // Hash definitions of all types which are encoded by `BinaryCodec`,
+        // so binary data encoded by a different version of the AST is rejected
+        let mut hasher = FxHasher::default();
+        for def in ctx.schema().into_iter().filter(|def| def.generates_derive("BinaryCodec")) {
+            serde_json::to_string(def).unwrap().hash(&mut hasher);
+        }
+        for path in HAND_WRITTEN_CODEC_PATHS {
+            hash_hand_written_codecs(path, &mut hasher);
+        }
+        let hash = hasher.finish();
+        let hash = format!("{hash:016x}");
+        let hash: TokenStream =
+            format!("0x{}_{}_{}_{}", &hash[0..4], &hash[4..8], &hash[8..12], &hash[12..16])
+                .parse()
+                .unwrap();
+
+        let header = generated_header!();
+
+        GeneratorOutput(
+            output(crate::AST_CRATE, "binary_codec_schema.rs"),
+            quote! {
+                #header
+
+                /// Hash of definitions of all AST types which implement `BinaryCodec`,
+                /// and of hand-written `BinaryCodec` implementations for semantic data
+                /// (`SymbolTable`, `ScopeTree`, and the types they contain).
+                ///
+                /// Changes whenever the binary format of the AST changes.
+                pub const AST_SCHEMA_HASH: u64 = #hash;
+            },
+        )
+    }
+}
+
+/// Hash `BinaryCodec` implementations in file at `path`, and definitions of the types they are
+/// implemented for.
+fn hash_hand_written_codecs(path: &str, hasher: &mut FxHasher) {
+    let source_text = std::fs::read_to_string(path).unwrap();
+    let file = syn::parse_file(&source_text).unwrap();
+
+    let is_codec_impl = |item: &Item| match item {
+        Item::Impl(item) => item
+            .trait_
+            .as_ref()
+            .and_then(|(_, path, _)| path.segments.last())
+            .is_some_and(|segment| segment.ident == "BinaryCodec"),
+        _ => false,
+    };
+    let codec_types = file
+        .items
+        .iter()
+        .filter(|item| is_codec_impl(item))
+        .filter_map(|item| match item {
+            Item::Impl(item) => Some(item.self_ty.to_token_stream().to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let is_codec_type = |name: String| codec_types.contains(&name);
+
+    for item in &file.items {
+        let is_hashed = match item {
+            Item::Impl(_) => is_codec_impl(item),
+            Item::Struct(item) => is_codec_type(item.ident.to_string()),
+            Item::Enum(item) => is_codec_type(item.ident.to_string()),
+            // `bitflags! { pub struct SymbolFlags: u32 { ... } }`
+            Item::Macro(item) => {
+                item.mac.path.is_ident("bitflags")
+                    && item.mac.tokens.clone().into_iter().any(|token| {
+                        matches!(token, proc_macro2::TokenTree::Ident(ident) if is_codec_type(ident.to_string()))
+                    })
+            }
+            _ => false,
+        };
+        if is_hashed {
+            item.to_token_stream().to_string().hash(hasher);
+        }
+    }
+}
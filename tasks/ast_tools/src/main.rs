@@ -17,11 +17,14 @@ mod rust_ast;
 mod schema;
 mod util;
 
-use derives::{DeriveCloneIn, DeriveContentEq, DeriveContentHash, DeriveGetSpan, DeriveGetSpanMut};
+use derives::{
+    DeriveBinaryCodec, DeriveCloneIn, DeriveContentEq, DeriveContentHash, DeriveForEachSpanMut,
+    DeriveGetSpan, DeriveGetSpanMut,
+};
 use fmt::cargo_fmt;
 use generators::{
-    AssertLayouts, AstBuilderGenerator, AstKindGenerator, Generator, GeneratorOutput,
-    VisitGenerator, VisitMutGenerator,
+    AssertLayouts, AstBuilderGenerator, AstKindGenerator, BinaryCodecSchemaGenerator, Generator,
+    GeneratorOutput, VisitGenerator, VisitMutGenerator, HAND_WRITTEN_CODEC_PATHS,
 };
 use passes::{CalcLayout, Linker};
 use util::{write_all_to, NormalizeError};
@@ -75,8 +78,11 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         .derive(DeriveGetSpanMut)
         .derive(DeriveContentEq)
         .derive(DeriveContentHash)
+        .derive(DeriveForEachSpanMut)
+        .derive(DeriveBinaryCodec)
         .generate(AssertLayouts)
         .generate(AstKindGenerator)
+        .generate(BinaryCodecSchemaGenerator)
         .generate(AstBuilderGenerator)
         .generate(VisitGenerator)
         .generate(VisitMutGenerator)
@@ -93,7 +99,8 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
                 path
             })
             .collect();
-        write_ci_filter(SOURCE_PATHS, side_effects, ".github/.generated_ast_watch_list.yml")?;
+        let inputs = [SOURCE_PATHS, HAND_WRITTEN_CODEC_PATHS].concat();
+        write_ci_filter(&inputs, side_effects, ".github/.generated_ast_watch_list.yml")?;
     }
 
     if !cli_options.no_fmt {
@@ -0,0 +1,43 @@
+use oxc_allocator::Allocator;
+use oxc_ast::cache::{decode_program, encode_program};
+use oxc_benchmark::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_span::SourceType;
+use oxc_tasks_common::TestFiles;
+
+/// Compare decoding a cached AST with parsing the source text it was encoded from.
+fn bench_cache(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("cache");
+    for file in TestFiles::complicated().files() {
+        let source_type = SourceType::from_path(&file.file_name).unwrap();
+        let options = ParseOptions { parse_regular_expression: true, ..ParseOptions::default() };
+
+        group.bench_with_input(
+            BenchmarkId::new("parse", &file.file_name),
+            &file.source_text,
+            |b, source_text| {
+                let mut allocator = Allocator::default();
+                b.iter(|| {
+                    Parser::new(&allocator, source_text, source_type).with_options(options).parse();
+                    allocator.reset();
+                });
+            },
+        );
+
+        let allocator = Allocator::default();
+        let ret =
+            Parser::new(&allocator, &file.source_text, source_type).with_options(options).parse();
+        let bytes = encode_program(&ret.program);
+        group.bench_with_input(BenchmarkId::new("decode", &file.file_name), &bytes, |b, bytes| {
+            let mut allocator = Allocator::default();
+            b.iter(|| {
+                decode_program(&allocator, bytes).unwrap();
+                allocator.reset();
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(cache, bench_cache);
+criterion_main!(cache);
@@ -0,0 +1,16 @@
+declare namespace N1 {
+  declare const x: number;
+  function g() {}
+  async function h(): Promise<void>;
+  const y = foo;
+  let w = 1;
+  f();
+}
+declare class D1 { m() {} q = 1; r!: number }
+namespace N2 { import x = require("x"); export = x; }
+declare module "./rel" {}
+module "quoted" {}
+export declare module "exported" {}
+function outer() { declare module "inner" {} }
+export = N1;
+export const other = 1;
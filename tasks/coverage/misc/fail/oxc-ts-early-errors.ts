@@ -0,0 +1,23 @@
+function f1(a?: number = 1) {}
+function f2(x = 1): void;
+function f2(x) {}
+type T1 = Array<>;
+type T2 = readonly number;
+function f3<in T>() {}
+interface I1<const T> {}
+class C1 {
+  constructor<T>(): void {}
+  get a<T>() { return 1 }
+  set b(v) { return 1; }
+  foo(): void;
+  bar() {}
+}
+abstract class C2 {
+  abstract static m(): void;
+  private abstract p: number;
+  abstract async q(): Promise<void>;
+  abstract #r: number;
+}
+try {} catch (e: Error) {}
+function over(): void;
+let between = 1;
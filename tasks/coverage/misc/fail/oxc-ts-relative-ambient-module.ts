@@ -0,0 +1,3 @@
+declare module "./observable" {
+  interface Observable<T> {}
+}
@@ -0,0 +1,9 @@
+import { Observable } from "./observable";
+
+declare module "./observable" {
+  interface Observable<T> {
+    map<U>(f: (x: T) => U): Observable<U>;
+  }
+}
+
+Observable.prototype.map = function () {};
@@ -19,6 +19,10 @@ use oxc_tasks_transform_checker::{check_semantic_after_transform, check_semantic
 
 use crate::suite::TestResult;
 
+/// Coverage is built with optimizations, so the `huge_*` misc cases can nest deeper than
+/// [`ParseOptions::max_nesting_depth`] allows by default.
+pub const MAX_NESTING_DEPTH: u32 = 2000;
+
 #[expect(clippy::struct_excessive_bools)]
 #[derive(Default)]
 pub struct Driver {
@@ -41,6 +45,7 @@ impl CompilerInterface for Driver {
         ParseOptions {
             parse_regular_expression: true,
             allow_return_outside_function: self.allow_return_outside_function,
+            max_nesting_depth: MAX_NESTING_DEPTH,
             ..ParseOptions::default()
         }
     }
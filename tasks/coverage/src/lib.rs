@@ -25,6 +25,7 @@ use crate::{
     test262::{Test262Case, Test262Suite},
     tools::{
         codegen::{CodegenBabelCase, CodegenMiscCase, CodegenTest262Case, CodegenTypeScriptCase},
+        estree::{EstreeMiscCase, EstreeTest262Case, EstreeTypeScriptCase, TypeScriptEslintSuite},
         minifier::{MinifierBabelCase, MinifierTest262Case},
         prettier::{
             PrettierBabelCase, PrettierMiscCase, PrettierTest262Case, PrettierTypeScriptCase,
@@ -66,6 +67,7 @@ impl AppArgs {
         self.run_parser();
         self.run_semantic();
         self.run_codegen();
+        self.run_estree();
         // self.run_prettier();
         self.run_transformer();
         self.run_transpiler();
@@ -94,6 +96,14 @@ impl AppArgs {
         MiscSuite::<CodegenMiscCase>::new().run("codegen_misc", self);
     }
 
+    /// Requires [acorn-test262](https://github.com/oxc-project/acorn-test262) and
+    /// [typescript-eslint](https://github.com/typescript-eslint/typescript-eslint) to be cloned.
+    pub fn run_estree(&self) {
+        Test262Suite::<EstreeTest262Case>::new().run("estree_test262", self);
+        TypeScriptEslintSuite::<EstreeTypeScriptCase>::new().run("estree_typescript", self);
+        MiscSuite::<EstreeMiscCase>::new().run("estree_misc", self);
+    }
+
     pub fn run_prettier(&self) {
         Test262Suite::<PrettierTest262Case>::new().run("prettier_test262", self);
         BabelSuite::<PrettierBabelCase>::new().run("prettier_babel", self);
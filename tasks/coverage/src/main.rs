@@ -22,6 +22,7 @@ fn main() {
         "parser" => args.run_parser(),
         "semantic" => args.run_semantic(),
         "codegen" => args.run_codegen(),
+        "estree" => args.run_estree(),
         "codegen-runtime" => args.run_codegen_runtime(),
         // "prettier" => args.run_prettier(),
         "transformer" => args.run_transformer(),
@@ -0,0 +1,395 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    thread,
+};
+
+use oxc::{
+    allocator::Allocator,
+    ast::EstreeFlavor,
+    parser::{ParseOptions, Parser},
+    span::SourceType,
+};
+use serde_json::Value;
+
+use crate::{
+    driver::MAX_NESTING_DEPTH,
+    misc::MiscCase,
+    suite::{Case, Suite, TestResult},
+    test262::{Test262Case, TestFlag},
+    workspace_root,
+};
+
+/// [acorn-test262](https://github.com/oxc-project/acorn-test262): test262 files parsed by acorn
+const ACORN_FIXTURES_PATH: &str = "acorn-test262/tests";
+
+/// typescript-eslint AST spec fixtures, with TS-ESTree snapshots
+const TYPESCRIPT_ESLINT_FIXTURES_PATH: &str = "typescript-eslint/packages/ast-spec/src";
+
+fn parse_to_estree(
+    source_text: &str,
+    source_type: SourceType,
+    flavor: EstreeFlavor,
+) -> Result<Value, TestResult> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type)
+        .with_options(ParseOptions {
+            preserve_parens: false,
+            max_nesting_depth: MAX_NESTING_DEPTH,
+            ..ParseOptions::default()
+        })
+        .parse();
+    if ret.panicked || !ret.errors.is_empty() {
+        let error = ret.errors.first().map(ToString::to_string).unwrap_or_default();
+        return Err(TestResult::ParseError(error, ret.panicked));
+    }
+    Ok(ret.program.to_estree(flavor))
+}
+
+fn compare(mut actual: Value, mut expected: Value) -> TestResult {
+    normalize(&mut actual);
+    normalize(&mut expected);
+    if actual == expected {
+        TestResult::Passed
+    } else {
+        let actual = serde_json::to_string_pretty(&actual).unwrap();
+        let expected = serde_json::to_string_pretty(&expected).unwrap();
+        TestResult::Mismatch("Mismatch", actual, expected)
+    }
+}
+
+/// Compare all numbers as floats, and ignore values which can not be represented in JSON.
+fn normalize(value: &mut Value) {
+    match value {
+        Value::Number(number) => {
+            if let Some(float) = number.as_f64().and_then(serde_json::Number::from_f64) {
+                *number = float;
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(normalize),
+        Value::Object(node) => {
+            // `RegExp` and `BigInt` values
+            if node.contains_key("regex") || node.contains_key("bigint") {
+                node.insert("value".into(), Value::Null);
+            }
+            node.values_mut().for_each(normalize);
+        }
+        _ => {}
+    }
+}
+
+fn remove_keys(value: &mut Value, keys: &[&str]) {
+    match value {
+        Value::Array(items) => items.iter_mut().for_each(|item| remove_keys(item, keys)),
+        Value::Object(node) => {
+            for key in keys {
+                node.remove(*key);
+            }
+            node.values_mut().for_each(|v| remove_keys(v, keys));
+        }
+        _ => {}
+    }
+}
+
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Oxc AST node types which must have been converted to ESTree nodes.
+const OXC_NODE_TYPES: [&str; 21] = [
+    "ArrayAssignmentTarget",
+    "AssignmentTargetPropertyIdentifier",
+    "AssignmentTargetPropertyProperty",
+    "AssignmentTargetWithDefault",
+    "BigIntLiteral",
+    "BindingProperty",
+    "BooleanLiteral",
+    "CatchParameter",
+    "ComputedMemberExpression",
+    "Directive",
+    "FormalParameter",
+    "FormalParameters",
+    "FunctionBody",
+    "NullLiteral",
+    "NumericLiteral",
+    "ObjectAssignmentTarget",
+    "ObjectProperty",
+    "PrivateFieldExpression",
+    "RegExpLiteral",
+    "StaticMemberExpression",
+    "StringLiteral",
+];
+
+/// Check that all nodes are ESTree nodes with valid spans.
+fn validate(value: &Value, source_len: u64) -> Result<(), String> {
+    match value {
+        Value::Array(items) => items.iter().try_for_each(|item| validate(item, source_len)),
+        Value::Object(node) => {
+            if let Some(Value::String(ty)) = node.get("type") {
+                if OXC_NODE_TYPES.contains(&ty.as_str()) {
+                    return Err(format!("{ty} is not an ESTree node"));
+                }
+                let start = node.get("start").and_then(Value::as_u64);
+                let end = node.get("end").and_then(Value::as_u64);
+                match (start, end) {
+                    (Some(start), Some(end)) if start <= end && end <= source_len => {}
+                    _ => return Err(format!("{ty} has an invalid span {start:?}..{end:?}")),
+                }
+            }
+            node.values().try_for_each(|value| validate(value, source_len))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Check that the ESTree output of the misc fixtures is well-formed.
+pub struct EstreeMiscCase {
+    base: MiscCase,
+}
+
+impl Case for EstreeMiscCase {
+    fn new(path: PathBuf, code: String) -> Self {
+        Self { base: MiscCase::new(path, code) }
+    }
+
+    fn code(&self) -> &str {
+        self.base.code()
+    }
+
+    fn path(&self) -> &Path {
+        self.base.path()
+    }
+
+    fn test_result(&self) -> &TestResult {
+        self.base.test_result()
+    }
+
+    fn skip_test_case(&self) -> bool {
+        self.base.skip_test_case() || self.base.should_fail()
+    }
+
+    fn run(&mut self) {
+        let source_text = self.base.code();
+        let source_type = self.base.source_type();
+        let source_len = source_text.encode_utf16().count() as u64;
+        let validate_flavor = |flavor| {
+            let value = parse_to_estree(source_text, source_type, flavor)?;
+            validate(&value, source_len)
+                .map_err(|err| TestResult::GenericError("estree", format!("{flavor:?}: {err}")))
+        };
+        // Serialization to `serde_json::Value` is recursive,
+        // give the `huge_*` cases more stack than the default of rayon threads.
+        let result = thread::scope(|scope| {
+            thread::Builder::new()
+                .stack_size(STACK_SIZE)
+                .spawn_scoped(scope, || {
+                    validate_flavor(EstreeFlavor::JavaScript)
+                        .and_then(|()| validate_flavor(EstreeFlavor::TypeScript))
+                })
+                .unwrap()
+                .join()
+                .unwrap()
+        });
+        self.base.set_result(result.map_or_else(|result| result, |()| TestResult::Passed));
+    }
+}
+
+/// Compare against the AST produced by acorn.
+pub struct EstreeTest262Case {
+    base: Test262Case,
+    acorn_json_path: PathBuf,
+}
+
+impl Case for EstreeTest262Case {
+    fn new(path: PathBuf, code: String) -> Self {
+        let acorn_json_path = workspace_root()
+            .join(ACORN_FIXTURES_PATH)
+            .join(path.strip_prefix("test262").unwrap_or(&path))
+            .with_extension("json");
+        Self { base: Test262Case::new(path, code), acorn_json_path }
+    }
+
+    fn code(&self) -> &str {
+        self.base.code()
+    }
+
+    fn path(&self) -> &Path {
+        self.base.path()
+    }
+
+    fn test_result(&self) -> &TestResult {
+        self.base.test_result()
+    }
+
+    fn skip_test_case(&self) -> bool {
+        self.base.should_fail() || self.base.skip_test_case() || !self.acorn_json_path.exists()
+    }
+
+    fn run(&mut self) {
+        let is_module = self.base.meta().flags.contains(&TestFlag::Module);
+        let source_type = SourceType::default().with_module(is_module);
+        let result = match parse_to_estree(self.base.code(), source_type, EstreeFlavor::JavaScript)
+        {
+            Ok(actual) => match fs::read_to_string(&self.acorn_json_path)
+                .map_err(|err| err.to_string())
+                .and_then(|json| serde_json::from_str(&json).map_err(|err| err.to_string()))
+            {
+                Ok(expected) => compare(actual, expected),
+                Err(err) => TestResult::GenericError("acorn", err),
+            },
+            Err(result) => result,
+        };
+        self.base.set_result(result);
+    }
+}
+
+pub struct TypeScriptEslintSuite<T: Case> {
+    test_root: PathBuf,
+    test_cases: Vec<T>,
+}
+
+impl<T: Case> TypeScriptEslintSuite<T> {
+    pub fn new() -> Self {
+        Self { test_root: PathBuf::from(TYPESCRIPT_ESLINT_FIXTURES_PATH), test_cases: vec![] }
+    }
+}
+
+impl<T: Case> Suite<T> for TypeScriptEslintSuite<T> {
+    fn get_test_root(&self) -> &Path {
+        &self.test_root
+    }
+
+    fn skip_test_path(&self, path: &Path) -> bool {
+        let is_fixture = path.file_stem().is_some_and(|stem| stem == "fixture")
+            && path.extension().is_some_and(|ext| ext == "ts" || ext == "tsx");
+        // Fixtures in `_error_` directories are expected to fail
+        !is_fixture || path.to_string_lossy().contains("_error_")
+    }
+
+    fn save_test_cases(&mut self, cases: Vec<T>) {
+        self.test_cases = cases;
+    }
+
+    fn get_test_cases(&self) -> &Vec<T> {
+        &self.test_cases
+    }
+
+    fn get_test_cases_mut(&mut self) -> &mut Vec<T> {
+        &mut self.test_cases
+    }
+}
+
+/// Compare against the TS-ESTree snapshot of a typescript-eslint fixture.
+pub struct EstreeTypeScriptCase {
+    path: PathBuf,
+    code: String,
+    result: TestResult,
+}
+
+impl EstreeTypeScriptCase {
+    fn snapshot_path(&self) -> PathBuf {
+        workspace_root().join(&self.path).with_file_name("snapshots").join("1-TSESTree-AST.shot")
+    }
+}
+
+impl Case for EstreeTypeScriptCase {
+    fn new(path: PathBuf, code: String) -> Self {
+        Self { path, code, result: TestResult::ToBeRun }
+    }
+
+    fn code(&self) -> &str {
+        &self.code
+    }
+
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn test_result(&self) -> &TestResult {
+        &self.result
+    }
+
+    fn skip_test_case(&self) -> bool {
+        !self.snapshot_path().exists()
+    }
+
+    fn run(&mut self) {
+        let source_type = SourceType::from_path(&self.path).unwrap_or_else(|_| SourceType::ts());
+        self.result = match parse_to_estree(&self.code, source_type, EstreeFlavor::TypeScript) {
+            Ok(mut actual) => {
+                match fs::read_to_string(self.snapshot_path())
+                    .map_err(|err| err.to_string())
+                    .and_then(|shot| parse_snapshot(&shot))
+                {
+                    Ok(mut expected) => {
+                        // TS-ESTree nodes only have `range`, and locations are not produced
+                        remove_keys(&mut actual, &["start", "end"]);
+                        remove_keys(&mut expected, &["loc"]);
+                        if let Value::Object(program) = &mut expected {
+                            program.remove("comments");
+                            program.remove("tokens");
+                        }
+                        compare(actual, expected)
+                    }
+                    Err(err) => TestResult::GenericError("snapshot", err),
+                }
+            }
+            Err(result) => result,
+        };
+    }
+}
+
+/// Convert a jest snapshot of a `pretty-format`ted AST to JSON.
+///
+/// ```text
+/// exports[`AST Fixtures ... TSESTree - AST 1`] = `
+/// Program {
+///   type: "Program",
+///   body: [],
+///   range: [0, 0],
+/// }
+/// `;
+/// ```
+fn parse_snapshot(shot: &str) -> Result<Value, String> {
+    let start = shot.find("= `\n").ok_or("snapshot not found")? + 4;
+    let end = shot.rfind("\n`;").ok_or("snapshot not found")?;
+    let snapshot = shot[start..end].replace("\\`", "`").replace("\\\\", "\\");
+
+    let mut json = String::with_capacity(snapshot.len());
+    for line in snapshot.lines() {
+        let indent = line.len() - line.trim_start().len();
+        let mut line = line.trim_start();
+        json.push_str(&" ".repeat(indent));
+        // `key: value`
+        if let Some((key, value)) = line.split_once(": ") {
+            if key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$') {
+                json.push('"');
+                json.push_str(key);
+                json.push_str("\": ");
+                line = value;
+            }
+        }
+        let (value, comma) = line.strip_suffix(',').map_or((line, ""), |value| (value, ","));
+        let value = if let Some(object) = value.strip_suffix(" {") {
+            // `Identifier {`
+            if object.chars().all(|c| c.is_ascii_alphanumeric()) {
+                "{"
+            } else {
+                value
+            }
+        } else if value == "undefined"
+            // `RegExp` and `BigInt` values
+            || (value.starts_with('/') && value.len() > 1)
+            || (value.ends_with('n') && value[..value.len() - 1].chars().all(|c| c.is_ascii_digit()))
+        {
+            "null"
+        } else {
+            value
+        };
+        json.push_str(value);
+        json.push_str(comma);
+        json.push('\n');
+    }
+
+    // Remove trailing commas
+    let json = regex::Regex::new(r",(\s*[}\]])").unwrap().replace_all(&json, "$1");
+    serde_json::from_str(&json).map_err(|err| err.to_string())
+}
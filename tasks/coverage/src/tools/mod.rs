@@ -1,4 +1,5 @@
 pub mod codegen;
+pub mod estree;
 pub mod minifier;
 pub mod prettier;
 pub mod semantic;
@@ -9,6 +9,7 @@ use oxc_prettier::{Prettier, PrettierOptions};
 
 use crate::{
     babel::BabelCase,
+    driver::MAX_NESTING_DEPTH,
     misc::MiscCase,
     suite::{Case, TestResult},
     test262::{Test262Case, TestFlag},
@@ -20,7 +21,11 @@ fn get_result(source_text: &str, source_type: SourceType) -> TestResult {
     let options = PrettierOptions::default();
 
     let allocator = Allocator::default();
-    let parse_options = ParseOptions { preserve_parens: false, ..ParseOptions::default() };
+    let parse_options = ParseOptions {
+        preserve_parens: false,
+        max_nesting_depth: MAX_NESTING_DEPTH,
+        ..ParseOptions::default()
+    };
     let ParserReturn { program, .. } =
         Parser::new(&allocator, source_text, source_type).with_options(parse_options).parse();
     let source_text1 = Prettier::new(&allocator, options).build(&program);